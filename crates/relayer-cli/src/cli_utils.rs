@@ -38,8 +38,15 @@ impl<Chain: ChainHandle> ChainHandlePair<Chain> {
         src_chain_id: &ChainId,
         dst_chain_id: &ChainId,
     ) -> Result<Self, Error> {
-        let src = spawn_chain_runtime_generic(config, src_chain_id)?;
-        let dst = spawn_chain_runtime_generic(config, dst_chain_id)?;
+        let src: Chain = spawn_chain_runtime_generic(config, src_chain_id)?;
+
+        // Both sides share the same runtime when relaying between two modules
+        // of the same chain, over the localhost client.
+        let dst = if dst_chain_id == src_chain_id {
+            src.clone()
+        } else {
+            spawn_chain_runtime_generic(config, dst_chain_id)?
+        };
 
         Ok(ChainHandlePair { src, dst })
     }
@@ -1,6 +1,7 @@
 //! Definition of all the Hermes subcommands
 
 mod clear;
+mod client;
 mod completions;
 mod config;
 mod create;
@@ -17,8 +18,8 @@ mod upgrade;
 mod version;
 
 use self::{
-    clear::ClearCmds, completions::CompletionsCmd, config::ConfigCmd, create::CreateCmds,
-    fee::FeeCmd, health::HealthCheckCmd, keys::KeysCmd, listen::ListenCmd,
+    clear::ClearCmds, client::ClientCmds, completions::CompletionsCmd, config::ConfigCmd,
+    create::CreateCmds, fee::FeeCmd, health::HealthCheckCmd, keys::KeysCmd, listen::ListenCmd,
     misbehaviour::MisbehaviourCmd, query::QueryCmd, start::StartCmd, tx::TxCmd, update::UpdateCmds,
     upgrade::UpgradeCmds, version::VersionCmd,
 };
@@ -65,6 +66,10 @@ pub enum CliCmd {
     #[clap(subcommand)]
     Clear(ClearCmds),
 
+    /// Recover expired or frozen clients
+    #[clap(subcommand)]
+    Client(ClientCmds),
+
     /// Start the relayer in multi-chain mode.
     ///
     /// Relays packets and open handshake messages between all chains in the config.
@@ -0,0 +1,292 @@
+//! `client` subcommand
+
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+use serde_json::json;
+
+use ibc_relayer::chain::handle::ChainHandle;
+use ibc_relayer::chain::requests::{IncludeProof, QueryClientStateRequest, QueryHeight};
+use ibc_relayer::recover_client::{
+    prepare_client_recovery, submit_client_recovery, RecoverClientOptions,
+};
+use ibc_relayer_types::core::ics02_client::client_state::ClientState;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
+
+use crate::cli_utils::spawn_chain_runtime;
+use crate::conclude::Output;
+use crate::error::Error;
+use crate::prelude::*;
+
+#[derive(Command, Debug, Parser, Runnable)]
+pub enum ClientCmds {
+    /// Recover an expired or frozen client with a substitute client
+    Recover(ClientRecoverCmd),
+}
+
+/// Creates a substitute client for an expired or frozen client, waits for it
+/// to catch up, and outputs the governance proposal recovering the client with
+/// it, in the format expected by `tx gov submit-proposal`.
+///
+/// With `--submit`, the recovery is also submitted to the host chain: directly
+/// if the relayer key is the authority of the client module, or as a governance
+/// proposal otherwise.
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct ClientRecoverCmd {
+    #[clap(
+        long = "host-chain",
+        required = true,
+        value_name = "HOST_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the chain that hosts the client"
+    )]
+    host_chain_id: ChainId,
+
+    #[clap(
+        long = "client",
+        required = true,
+        value_name = "CLIENT_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the expired or frozen client to recover"
+    )]
+    client_id: ClientId,
+
+    #[clap(
+        long = "substitute-client",
+        value_name = "SUBSTITUTE_CLIENT_ID",
+        help = "Identifier of an existing client to substitute the client with. Leave unspecified to create a new one."
+    )]
+    substitute_client_id: Option<ClientId>,
+
+    #[clap(
+        long = "title",
+        value_name = "TITLE",
+        help = "Title of the proposal (default: 'Recover client <CLIENT_ID>')"
+    )]
+    title: Option<String>,
+
+    #[clap(
+        long = "summary",
+        value_name = "SUMMARY",
+        help = "Summary of the proposal (default: a description of the substitution)"
+    )]
+    summary: Option<String>,
+
+    #[clap(
+        long = "amount",
+        value_name = "AMOUNT",
+        default_value = "0",
+        help = "Amount of the deposit for the proposal"
+    )]
+    amount: u64,
+
+    #[clap(
+        long = "denom",
+        value_name = "DENOM",
+        help = "Denomination for the deposit (default: 'stake')"
+    )]
+    denom: Option<String>,
+
+    #[clap(
+        long = "authority",
+        value_name = "AUTHORITY",
+        help = "Address of the authority of the client module (default: the address of the governance module)"
+    )]
+    authority: Option<String>,
+
+    #[clap(
+        long = "legacy",
+        help = "Recover the client with a legacy `ClientUpdateProposal`, for host chains running ibc-go v7 or earlier"
+    )]
+    legacy: bool,
+
+    #[clap(
+        long = "submit",
+        help = "Submit the recovery to the host chain instead of only outputting the proposal"
+    )]
+    submit: bool,
+}
+
+impl ClientRecoverCmd {
+    fn options(&self) -> RecoverClientOptions {
+        RecoverClientOptions {
+            subject_client_id: self.client_id.clone(),
+            substitute_client_id: self.substitute_client_id.clone(),
+            title: self
+                .title
+                .clone()
+                .unwrap_or_else(|| format!("Recover client {}", self.client_id)),
+            summary: self.summary.clone().unwrap_or_else(|| {
+                format!(
+                    "Recover the expired or frozen client {} on chain {} with a substitute client",
+                    self.client_id, self.host_chain_id
+                )
+            }),
+            amount: self.amount,
+            denom: self.denom.as_deref().unwrap_or("stake").into(),
+            authority: self.authority.clone(),
+            legacy: self.legacy,
+        }
+    }
+}
+
+impl Runnable for ClientRecoverCmd {
+    fn run(&self) {
+        let config = app_config();
+
+        let host_chain = match spawn_chain_runtime(&config, &self.host_chain_id) {
+            Ok(handle) => handle,
+            Err(e) => Output::error(e).exit(),
+        };
+
+        let reference_chain_id = match host_chain.query_client_state(
+            QueryClientStateRequest {
+                client_id: self.client_id.clone(),
+                height: QueryHeight::Latest,
+            },
+            IncludeProof::No,
+        ) {
+            Ok((cs, _)) => cs.chain_id(),
+            Err(e) => {
+                Output::error(format!(
+                    "Query of client '{}' on chain '{}' failed with error: {}",
+                    self.client_id, self.host_chain_id, e
+                ))
+                .exit();
+            }
+        };
+
+        let reference_chain = match spawn_chain_runtime(&config, &reference_chain_id) {
+            Ok(handle) => handle,
+            Err(e) => Output::error(e).exit(),
+        };
+
+        let opts = self.options();
+
+        let recovery = match prepare_client_recovery(host_chain.clone(), reference_chain, &opts) {
+            Ok(recovery) => recovery,
+            Err(e) => Output::error(Error::recover_client(e)).exit(),
+        };
+
+        if !self.submit {
+            Output::success(json!({
+                "subject_client_id": recovery.subject_client_id,
+                "substitute_client_id": recovery.substitute_client_id,
+                "proposal": recovery.proposal,
+            }))
+            .exit()
+        }
+
+        match submit_client_recovery(host_chain, &recovery, &opts) {
+            Ok(tx_hash) => Output::success(json!({
+                "subject_client_id": recovery.subject_client_id,
+                "substitute_client_id": recovery.substitute_client_id,
+                "tx_hash": tx_hash.to_string(),
+            }))
+            .exit(),
+            Err(e) => Output::error(Error::recover_client(e)).exit(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ClientRecoverCmd;
+
+    use abscissa_core::clap::Parser;
+    use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
+    use std::str::FromStr;
+
+    #[test]
+    fn test_recover_client_required_only() {
+        assert_eq!(
+            ClientRecoverCmd {
+                host_chain_id: ChainId::from_string("chain_host"),
+                client_id: ClientId::from_str("client_to_recover").unwrap(),
+                substitute_client_id: None,
+                title: None,
+                summary: None,
+                amount: 0,
+                denom: None,
+                authority: None,
+                legacy: false,
+                submit: false,
+            },
+            ClientRecoverCmd::parse_from([
+                "test",
+                "--host-chain",
+                "chain_host",
+                "--client",
+                "client_to_recover"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_recover_client_substitute() {
+        assert_eq!(
+            ClientRecoverCmd {
+                host_chain_id: ChainId::from_string("chain_host"),
+                client_id: ClientId::from_str("client_to_recover").unwrap(),
+                substitute_client_id: Some(ClientId::from_str("substitute_client").unwrap()),
+                title: None,
+                summary: None,
+                amount: 0,
+                denom: None,
+                authority: None,
+                legacy: false,
+                submit: false,
+            },
+            ClientRecoverCmd::parse_from([
+                "test",
+                "--host-chain",
+                "chain_host",
+                "--client",
+                "client_to_recover",
+                "--substitute-client",
+                "substitute_client"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_recover_client_submit_legacy() {
+        assert_eq!(
+            ClientRecoverCmd {
+                host_chain_id: ChainId::from_string("chain_host"),
+                client_id: ClientId::from_str("client_to_recover").unwrap(),
+                substitute_client_id: None,
+                title: Some("title".to_owned()),
+                summary: Some("summary".to_owned()),
+                amount: 42,
+                denom: Some("my_denom".to_owned()),
+                authority: Some("cosmos1authority".to_owned()),
+                legacy: true,
+                submit: true,
+            },
+            ClientRecoverCmd::parse_from([
+                "test",
+                "--host-chain",
+                "chain_host",
+                "--client",
+                "client_to_recover",
+                "--title",
+                "title",
+                "--summary",
+                "summary",
+                "--amount",
+                "42",
+                "--denom",
+                "my_denom",
+                "--authority",
+                "cosmos1authority",
+                "--legacy",
+                "--submit"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_recover_client_no_client() {
+        assert!(ClientRecoverCmd::try_parse_from(["test", "--host-chain", "chain_host"]).is_err())
+    }
+}
@@ -15,7 +15,7 @@ use ibc_relayer_types::core::ics02_client::client_state::ClientState;
 use ibc_relayer_types::core::ics03_connection::connection::IdentifiedConnectionEnd;
 use ibc_relayer_types::core::ics04_channel::channel::Ordering;
 use ibc_relayer_types::core::ics04_channel::version::Version;
-use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ConnectionId, PortId};
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId, ConnectionId, PortId};
 
 use crate::cli_utils::{spawn_chain_runtime, ChainHandlePair};
 use crate::conclude::{exit_with_unrecoverable_error, Output};
@@ -182,15 +182,33 @@ impl CreateChannelCommand {
         let chains = ChainHandlePair::spawn(&config, &self.chain_a, chain_b)
             .unwrap_or_else(exit_with_unrecoverable_error);
 
-        info!(
-            "Creating new clients, new connection, and a new channel with order {}",
-            self.order
-        );
+        let (client_a, client_b) = if &self.chain_a == chain_b {
+            info!(
+                "Using the localhost connection to create a new channel with order {}",
+                self.order
+            );
 
-        let client_a = ForeignClient::new(chains.src.clone(), chains.dst.clone())
-            .unwrap_or_else(exit_with_unrecoverable_error);
-        let client_b = ForeignClient::new(chains.dst.clone(), chains.src)
-            .unwrap_or_else(exit_with_unrecoverable_error);
+            let localhost = ClientId::localhost();
+
+            (
+                ForeignClient::find(chains.dst.clone(), chains.src.clone(), &localhost)
+                    .unwrap_or_else(exit_with_unrecoverable_error),
+                ForeignClient::find(chains.src, chains.dst, &localhost)
+                    .unwrap_or_else(exit_with_unrecoverable_error),
+            )
+        } else {
+            info!(
+                "Creating new clients, new connection, and a new channel with order {}",
+                self.order
+            );
+
+            (
+                ForeignClient::new(chains.src.clone(), chains.dst.clone())
+                    .unwrap_or_else(exit_with_unrecoverable_error),
+                ForeignClient::new(chains.dst.clone(), chains.src)
+                    .unwrap_or_else(exit_with_unrecoverable_error),
+            )
+        };
 
         // Create the connection.
         let con = Connection::new(client_a, client_b, connection_delay())
@@ -240,9 +258,12 @@ impl CreateChannelCommand {
             .map(|(cs, _)| cs.chain_id())
             .unwrap_or_else(exit_with_unrecoverable_error);
 
-        // Spawn the runtime for side b.
-        let chain_b =
-            spawn_chain_runtime(&config, &chain_b).unwrap_or_else(exit_with_unrecoverable_error);
+        // Spawn the runtime for side b, unless the connection is the localhost connection.
+        let chain_b = if chain_b == self.chain_a {
+            chain_a.clone()
+        } else {
+            spawn_chain_runtime(&config, &chain_b).unwrap_or_else(exit_with_unrecoverable_error)
+        };
 
         // Create the foreign client handles.
         let client_a = ForeignClient::find(chain_b.clone(), chain_a.clone(), conn_end.client_id())
@@ -87,15 +87,33 @@ impl CreateConnectionCommand {
         let chains = ChainHandlePair::spawn(&config, &self.chain_a_id, chain_b_id)
             .unwrap_or_else(exit_with_unrecoverable_error);
 
-        info!(
-            "Creating new clients hosted on chains {} and {}",
-            self.chain_a_id, chain_b_id
-        );
-
-        let client_a = ForeignClient::new(chains.src.clone(), chains.dst.clone())
-            .unwrap_or_else(exit_with_unrecoverable_error);
-        let client_b = ForeignClient::new(chains.dst.clone(), chains.src)
-            .unwrap_or_else(exit_with_unrecoverable_error);
+        let (client_a, client_b) = if &self.chain_a_id == chain_b_id {
+            info!(
+                "Using the localhost client on both sides of chain {}",
+                self.chain_a_id
+            );
+
+            let localhost = ClientId::localhost();
+
+            (
+                ForeignClient::find(chains.dst.clone(), chains.src.clone(), &localhost)
+                    .unwrap_or_else(exit_with_unrecoverable_error),
+                ForeignClient::find(chains.src, chains.dst, &localhost)
+                    .unwrap_or_else(exit_with_unrecoverable_error),
+            )
+        } else {
+            info!(
+                "Creating new clients hosted on chains {} and {}",
+                self.chain_a_id, chain_b_id
+            );
+
+            (
+                ForeignClient::new(chains.src.clone(), chains.dst.clone())
+                    .unwrap_or_else(exit_with_unrecoverable_error),
+                ForeignClient::new(chains.dst.clone(), chains.src)
+                    .unwrap_or_else(exit_with_unrecoverable_error),
+            )
+        };
 
         // Finally, execute the connection handshake.
         let delay = Duration::from_secs(self.delay);
@@ -140,10 +158,14 @@ impl CreateConnectionCommand {
             .exit(),
         };
 
-        // Validate & spawn runtime for chain_b.
-        let chain_b = match spawn_chain_runtime(&config, &chain_b_id) {
-            Ok(handle) => handle,
-            Err(e) => Output::error(e).exit(),
+        // Validate & spawn runtime for chain_b, unless the client is a localhost client.
+        let chain_b = if chain_b_id == self.chain_a_id {
+            chain_a.clone()
+        } else {
+            match spawn_chain_runtime(&config, &chain_b_id) {
+                Ok(handle) => handle,
+                Err(e) => Output::error(e).exit(),
+            }
         };
 
         // Unwrap the identifier of the client on chain_b.
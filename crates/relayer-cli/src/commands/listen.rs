@@ -14,7 +14,15 @@ use tendermint_rpc::{client::CompatMode, Client, HttpClient};
 use tokio::runtime::Runtime as TokioRuntime;
 use tracing::{error, info, instrument};
 
-use ibc_relayer::{chain::handle::Subscription, config::ChainConfig, event::monitor::EventMonitor};
+use ibc_relayer::{
+    chain::{
+        cosmos::endpoints::{Endpoints, RpcEndpoint},
+        handle::Subscription,
+    },
+    config::{ChainConfig, EventSourceMode},
+    event::{monitor::EventMonitor, poller::EventPoller},
+    util::lock::{LockExt, RwArc},
+};
 use ibc_relayer_types::{core::ics24_host::identifier::ChainId, events::IbcEvent};
 
 use crate::prelude::*;
@@ -140,23 +148,60 @@ fn subscribe(
     compat_mode: CompatMode,
     rt: Arc<TokioRuntime>,
 ) -> eyre::Result<Subscription> {
-    let (mut event_monitor, tx_cmd) = EventMonitor::new(
-        chain_config.id.clone(),
-        chain_config.websocket_addr.clone(),
-        compat_mode,
-        chain_config.batch_delay,
-        rt,
-    )
-    .map_err(|e| eyre!("could not initialize event monitor: {}", e))?;
-
-    event_monitor
-        .init_subscriptions()
-        .map_err(|e| eyre!("could not initialize subscriptions: {}", e))?;
-
-    let queries = event_monitor.queries();
-    info!("listening for queries: {}", queries.iter().format(", "),);
-
-    thread::spawn(|| event_monitor.run());
+    let tx_cmd = match chain_config.event_source {
+        EventSourceMode::Push => {
+            let (mut event_monitor, tx_cmd) = EventMonitor::new(
+                chain_config.id.clone(),
+                chain_config.websocket_addr.clone(),
+                chain_config.backup_websocket_addrs.clone(),
+                compat_mode,
+                chain_config.batch_delay,
+                rt,
+            )
+            .map_err(|e| eyre!("could not initialize event monitor: {}", e))?;
+
+            event_monitor
+                .init_subscriptions()
+                .map_err(|e| eyre!("could not initialize subscriptions: {}", e))?;
+
+            let queries = event_monitor.queries();
+            info!("listening for queries: {}", queries.iter().format(", "),);
+
+            thread::spawn(|| event_monitor.run());
+
+            tx_cmd
+        }
+        EventSourceMode::Pull { interval } => {
+            let mut rpc_endpoints = chain_config
+                .rpc_addrs()
+                .map(|url| {
+                    let mut client = HttpClient::new(url.clone())?;
+                    client.set_compat_mode(compat_mode);
+
+                    Ok(RpcEndpoint {
+                        url: url.clone(),
+                        client,
+                    })
+                })
+                .collect::<eyre::Result<Vec<_>>>()?;
+
+            let rpc_endpoint = rpc_endpoints.remove(0);
+            let rpc_endpoints = RwArc::new_lock(Endpoints::new(rpc_endpoint, rpc_endpoints));
+
+            let (event_poller, tx_cmd) =
+                EventPoller::new(chain_config.id.clone(), rpc_endpoints, interval, rt);
+
+            info!(
+                "polling {} for new blocks every {}",
+                chain_config.rpc_addr,
+                humantime::format_duration(interval)
+            );
+
+            thread::spawn(|| event_poller.run());
+
+            tx_cmd
+        }
+    };
 
     let subscription = tx_cmd.subscribe()?;
     Ok(subscription)
@@ -1,6 +1,7 @@
 use abscissa_core::clap::Parser;
 use abscissa_core::{Command, Runnable};
 use color_eyre::eyre::eyre;
+use serde::Serialize;
 
 use ibc_relayer::chain::handle::ChainHandle;
 use ibc_relayer::chain::requests::{
@@ -8,6 +9,8 @@ use ibc_relayer::chain::requests::{
     QueryClientStateRequest, QueryConsensusStateHeightsRequest, QueryConsensusStateRequest,
     QueryHeight, QueryTxRequest,
 };
+use ibc_relayer::client_state::AnyClientState;
+use ibc_relayer::config::client_overrides::ClientOverrides;
 
 use ibc_relayer_types::core::ics02_client::client_state::ClientState;
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
@@ -69,12 +72,34 @@ impl Runnable for QueryClientStateCmd {
             },
             IncludeProof::No,
         ) {
-            Ok((cs, _)) => Output::success(cs).exit(),
+            Ok((cs, _)) => {
+                let client_overrides = config
+                    .find_chain(&self.chain_id)
+                    .map(|chain_config| chain_config.client_overrides_for(&cs.chain_id()))
+                    .filter(|overrides| !overrides.is_empty());
+
+                match client_overrides {
+                    Some(client_overrides) => Output::success(ClientStateWithOverrides {
+                        client_state: cs,
+                        client_overrides,
+                    })
+                    .exit(),
+                    None => Output::success(cs).exit(),
+                }
+            }
             Err(e) => Output::error(e).exit(),
         }
     }
 }
 
+/// The state of a client, reported along with the client parameters
+/// overridden in the configuration of its host chain, if any.
+#[derive(Debug, Serialize)]
+struct ClientStateWithOverrides {
+    client_state: AnyClientState,
+    client_overrides: ClientOverrides,
+}
+
 /// Query client consensus command
 #[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
 pub struct QueryClientConsensusCmd {
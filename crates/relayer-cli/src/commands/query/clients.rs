@@ -1,11 +1,17 @@
+use core::time::Duration;
+
 use abscissa_core::clap::Parser;
 use abscissa_core::{Command, Runnable};
 use ibc_relayer::chain::handle::ChainHandle;
 use serde::Serialize;
 
-use ibc_relayer::chain::requests::{PageRequest, QueryClientStatesRequest};
+use ibc_relayer::chain::requests::{
+    IncludeProof, PageRequest, QueryClientStatesRequest, QueryConsensusStateRequest, QueryHeight,
+};
+use ibc_relayer::client_state::IdentifiedAnyClientState;
 use ibc_relayer_types::core::ics02_client::client_state::ClientState;
 use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
+use ibc_relayer_types::timestamp::Timestamp;
 
 use crate::cli_utils::spawn_chain_runtime;
 use crate::conclude::{exit_with_unrecoverable_error, Output};
@@ -36,6 +42,13 @@ pub struct QueryAllClientsCmd {
         help = "Omit printing the reference (or target) chain for each client"
     )]
     omit_chain_ids: bool,
+
+    #[clap(
+        long = "expiring-within",
+        help = "Filter for clients which expire within the given duration, or which are already expired (e.g. '3days')",
+        value_name = "DURATION"
+    )]
+    expiring_within: Option<humantime::Duration>,
 }
 
 #[derive(Debug, Serialize)]
@@ -59,6 +72,13 @@ impl Runnable for QueryAllClientsCmd {
             })
             .map_err(Error::relayer);
 
+        let res = match (res, self.expiring_within) {
+            (Ok(clients), Some(expiring_within)) => {
+                Ok(expiring_clients(&chain, clients, expiring_within.into()))
+            }
+            (res, _) => res,
+        };
+
         match res {
             Ok(clients) => {
                 match self.src_chain_id.clone() {
@@ -108,6 +128,62 @@ impl Runnable for QueryAllClientsCmd {
     }
 }
 
+/// Retains the clients whose latest consensus state, as of the current time,
+/// falls outside of their trusting period within the given duration.
+///
+/// Clients which do not expire, such as solo machine clients, are left out.
+fn expiring_clients(
+    chain: &impl ChainHandle,
+    clients: Vec<IdentifiedAnyClientState>,
+    expiring_within: Duration,
+) -> Vec<IdentifiedAnyClientState> {
+    let now = Timestamp::now();
+    let mut expiring = Vec::new();
+
+    for client in clients {
+        let Some(trusting_period) = client.client_state.trusting_period() else {
+            continue;
+        };
+
+        let consensus_state = match chain.query_consensus_state(
+            QueryConsensusStateRequest {
+                client_id: client.client_id.clone(),
+                consensus_height: client.client_state.latest_height(),
+                query_height: QueryHeight::Latest,
+            },
+            IncludeProof::No,
+        ) {
+            Ok((consensus_state, _)) => consensus_state,
+            Err(e) => {
+                warn!(
+                    "skipping client {}, failed to query its latest consensus state: {}",
+                    client.client_id, e
+                );
+
+                continue;
+            }
+        };
+
+        let elapsed = now
+            .duration_since(&consensus_state.timestamp())
+            .unwrap_or_default();
+
+        let time_until_expiry = trusting_period.saturating_sub(elapsed);
+
+        if time_until_expiry <= expiring_within {
+            debug!(
+                "client {} expires in {}",
+                client.client_id,
+                humantime::format_duration(time_until_expiry)
+            );
+
+            expiring.push(client);
+        }
+    }
+
+    expiring
+}
+
 #[cfg(test)]
 mod tests {
     use super::QueryAllClientsCmd;
@@ -121,7 +197,8 @@ mod tests {
             QueryAllClientsCmd {
                 chain_id: ChainId::from_string("chain_host_id"),
                 src_chain_id: None,
-                omit_chain_ids: false
+                omit_chain_ids: false,
+                expiring_within: None
             },
             QueryAllClientsCmd::parse_from(["test", "--host-chain", "chain_host_id"])
         )
@@ -133,7 +210,8 @@ mod tests {
             QueryAllClientsCmd {
                 chain_id: ChainId::from_string("chain_host_id"),
                 src_chain_id: None,
-                omit_chain_ids: true
+                omit_chain_ids: true,
+                expiring_within: None
             },
             QueryAllClientsCmd::parse_from([
                 "test",
@@ -150,7 +228,8 @@ mod tests {
             QueryAllClientsCmd {
                 chain_id: ChainId::from_string("chain_host_id"),
                 src_chain_id: Some(ChainId::from_string("reference_chain_id")),
-                omit_chain_ids: false
+                omit_chain_ids: false,
+                expiring_within: None
             },
             QueryAllClientsCmd::parse_from([
                 "test",
@@ -162,6 +241,25 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_query_clients_expiring_within() {
+        assert_eq!(
+            QueryAllClientsCmd {
+                chain_id: ChainId::from_string("chain_host_id"),
+                src_chain_id: None,
+                omit_chain_ids: false,
+                expiring_within: Some("3days".parse().unwrap())
+            },
+            QueryAllClientsCmd::parse_from([
+                "test",
+                "--host-chain",
+                "chain_host_id",
+                "--expiring-within",
+                "3days"
+            ])
+        )
+    }
+
     #[test]
     fn test_query_clients_no_chain() {
         assert!(QueryAllClientsCmd::try_parse_from(["test"]).is_err())
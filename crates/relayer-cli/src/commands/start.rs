@@ -59,6 +59,7 @@ impl Runnable for StartCmd {
         let options = SupervisorOptions {
             force_full_scan: self.full_scan,
             health_check: true,
+            config_path: crate::config::config_path(),
         };
 
         let supervisor_handle = make_supervisor::<CachingChainHandle>(config, options)
@@ -84,13 +85,13 @@ impl Runnable for StartCmd {
 }
 
 /// Register the SIGHUP and SIGUSR1 signals, and notify the supervisor.
-/// - [DEPRECATED] SIGHUP: Trigger a reload of the configuration.
+/// - SIGHUP: Trigger a reload of the configuration.
 /// - SIGUSR1: Ask the supervisor to dump its state and print it to the console.
 fn register_signals(tx_cmd: Sender<SupervisorCmd>) -> Result<(), io::Error> {
     use signal_hook::{consts::signal::*, iterator::Signals};
 
     let sigs = vec![
-        SIGHUP,  // Reload of configuration (disabled)
+        SIGHUP,  // Reload of configuration
         SIGUSR1, // Dump state
     ];
 
@@ -99,10 +100,23 @@ fn register_signals(tx_cmd: Sender<SupervisorCmd>) -> Result<(), io::Error> {
     std::thread::spawn(move || {
         for signal in &mut signals {
             match signal {
-                SIGHUP => warn!(
-                    "configuration reloading via SIGHUP has been disabled, \
-                     the signal handler will be removed in the future"
-                ),
+                SIGHUP => {
+                    info!("reloading configuration (triggered by SIGHUP)");
+
+                    let (tx, rx) = crossbeam_channel::bounded(1);
+                    tx_cmd.try_send(SupervisorCmd::ReloadConfig(tx)).unwrap();
+
+                    std::thread::spawn(move || match rx.recv() {
+                        Ok(Ok(diff)) => info!(
+                            "configuration reloaded: {} chain(s) added, {} removed, {} updated",
+                            diff.added.len(),
+                            diff.removed.len(),
+                            diff.updated.len()
+                        ),
+                        Ok(Err(e)) => error!("failed to reload configuration: {}", e),
+                        Err(_) => error!("failed to reload configuration: supervisor is gone"),
+                    });
+                }
                 SIGUSR1 => {
                     info!("dumping state (triggered by SIGUSR1)");
 
@@ -149,7 +163,8 @@ fn spawn_rest_server(config: &Config) -> Option<rest::Receiver> {
     let (tx, rx) = crossbeam_channel::unbounded();
 
     spawn_blocking(async move {
-        let result = ibc_relayer_rest::spawn((rest.host.as_str(), rest.port), tx);
+        let result =
+            ibc_relayer_rest::spawn((rest.host.as_str(), rest.port), rest.token.clone(), tx);
 
         match result {
             Ok(handle) => {
@@ -193,9 +208,31 @@ fn spawn_telemetry_server(config: &Config) {
 
     let _span = tracing::error_span!("telemetry").entered();
 
-    let state = ibc_telemetry::global();
     let telemetry = config.telemetry.clone();
 
+    let defaults = ibc_telemetry::HistogramBuckets::default();
+
+    let buckets = ibc_telemetry::HistogramBuckets {
+        packet_recv_latency: telemetry
+            .buckets
+            .packet_recv_latency
+            .clone()
+            .unwrap_or(defaults.packet_recv_latency),
+        packet_ack_latency: telemetry
+            .buckets
+            .packet_ack_latency
+            .clone()
+            .unwrap_or(defaults.packet_ack_latency),
+    };
+
+    let state = match ibc_telemetry::init(buckets) {
+        Some(state) => state,
+        None => {
+            warn!("telemetry was initialized before the configured histogram buckets were applied");
+            ibc_telemetry::global()
+        }
+    };
+
     if !telemetry.enabled {
         info!("telemetry disabled");
         return;
@@ -44,6 +44,27 @@ pub enum TxCmd {
     /// Confirm the closing of a channel (ChannelCloseConfirm)
     ChanCloseConfirm(channel::TxChanCloseConfirmCmd),
 
+    /// Initiate the upgrade of a channel (ChannelUpgradeInit)
+    ChanUpgradeInit(channel::TxChanUpgradeInitCmd),
+
+    /// Relay the channel upgrade attempt (ChannelUpgradeTry)
+    ChanUpgradeTry(channel::TxChanUpgradeTryCmd),
+
+    /// Relay acknowledgment of a channel upgrade attempt (ChannelUpgradeAck)
+    ChanUpgradeAck(channel::TxChanUpgradeAckCmd),
+
+    /// Confirm the channel upgrade (ChannelUpgradeConfirm)
+    ChanUpgradeConfirm(channel::TxChanUpgradeConfirmCmd),
+
+    /// Complete the channel upgrade once in-flight packets are flushed (ChannelUpgradeOpen)
+    ChanUpgradeOpen(channel::TxChanUpgradeOpenCmd),
+
+    /// Abort a channel upgrade which timed out on the counterparty (ChannelUpgradeTimeout)
+    ChanUpgradeTimeout(channel::TxChanUpgradeTimeoutCmd),
+
+    /// Abort a channel upgrade which was aborted on the counterparty (ChannelUpgradeCancel)
+    ChanUpgradeCancel(channel::TxChanUpgradeCancelCmd),
+
     /// Send a fungible token transfer test transaction (ICS20 MsgTransfer)
     FtTransfer(transfer::TxIcs20MsgTransferCmd),
 
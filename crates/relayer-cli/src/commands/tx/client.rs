@@ -14,10 +14,12 @@ use ibc_relayer::config::Config;
 use ibc_relayer::event::IbcEventWithHeight;
 use ibc_relayer::foreign_client::{CreateOptions, ForeignClient};
 use ibc_relayer::{chain::handle::ChainHandle, config::GenesisRestart};
+use ibc_relayer_types::clients::ics08_wasm::CHECKSUM_LENGTH;
 use ibc_relayer_types::core::ics02_client::client_state::ClientState;
 use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
 use ibc_relayer_types::events::IbcEvent;
 use ibc_relayer_types::Height;
+use subtle_encoding::hex;
 use tendermint::block::Height as BlockHeight;
 use tendermint_light_client_verifier::types::TrustThreshold;
 use tendermint_rpc::Url;
@@ -73,8 +75,19 @@ pub struct TxCreateClientCmd {
     /// and trusted validator set is sufficient for a commit to be accepted going forward.
     #[clap(long = "trust-threshold", value_name = "TRUST_THRESHOLD", parse(try_from_str = parse_trust_threshold))]
     trust_threshold: Option<TrustThreshold>,
+
+    /// Create an 08-wasm client, verified by the light client contract with the given checksum.
+    ///
+    /// The checksum is the hex-encoded SHA-256 hash of the code of the contract,
+    /// which must already be stored on the host chain.
+    #[clap(long = "wasm-checksum", value_name = "WASM_CHECKSUM", parse(try_from_str = parse_wasm_checksum))]
+    wasm_checksum: Option<WasmChecksum>,
 }
 
+/// The checksum is aliased so that clap parses it from a single value,
+/// rather than as a list of bytes.
+type WasmChecksum = Vec<u8>;
+
 /// Sample to run this tx:
 ///     `hermes create client --host-chain ibc-0 --reference-chain ibc-1`
 impl Runnable for TxCreateClientCmd {
@@ -96,6 +109,7 @@ impl Runnable for TxCreateClientCmd {
             max_clock_drift: self.clock_drift.map(Into::into),
             trusting_period: self.trusting_period.map(Into::into),
             trust_threshold: self.trust_threshold.map(Into::into),
+            wasm_checksum: self.wasm_checksum.clone(),
         };
 
         // Trigger client creation via the "build" interface, so that we obtain the resulting event
@@ -520,6 +534,20 @@ fn parse_trust_threshold(input: &str) -> Result<TrustThreshold, Error> {
         .map_err(|e| Error::cli_arg(format!("invalid trust threshold fraction: {e}")))
 }
 
+fn parse_wasm_checksum(input: &str) -> Result<WasmChecksum, Error> {
+    let checksum = hex::decode(input.trim().to_lowercase())
+        .map_err(|e| Error::cli_arg(format!("invalid hex-encoded checksum: {e}")))?;
+
+    if checksum.len() != CHECKSUM_LENGTH {
+        return Err(Error::cli_arg(format!(
+            "invalid checksum: expected {CHECKSUM_LENGTH} bytes, got {}",
+            checksum.len()
+        )));
+    }
+
+    Ok(checksum)
+}
+
 type UpgradeClientResult = Result<Vec<IbcEvent>, Error>;
 type UpgradeClientsForChainResult = Result<Vec<UpgradeClientResult>, Error>;
 
@@ -596,8 +624,8 @@ impl OutputBuffer {
 #[cfg(test)]
 mod tests {
     use super::{
-        parse_trust_threshold, TxCreateClientCmd, TxUpdateClientCmd, TxUpgradeClientCmd,
-        TxUpgradeClientsCmd,
+        parse_trust_threshold, parse_wasm_checksum, TxCreateClientCmd, TxUpdateClientCmd,
+        TxUpgradeClientCmd, TxUpgradeClientsCmd,
     };
 
     use std::str::FromStr;
@@ -630,7 +658,8 @@ mod tests {
                 src_chain_id: ChainId::from_string("reference_chain"),
                 clock_drift: None,
                 trusting_period: None,
-                trust_threshold: None
+                trust_threshold: None,
+                wasm_checksum: None
             },
             TxCreateClientCmd::parse_from([
                 "test",
@@ -650,7 +679,8 @@ mod tests {
                 src_chain_id: ChainId::from_string("reference_chain"),
                 clock_drift: Some("5s".parse::<Duration>().unwrap()),
                 trusting_period: None,
-                trust_threshold: None
+                trust_threshold: None,
+                wasm_checksum: None
             },
             TxCreateClientCmd::parse_from([
                 "test",
@@ -668,7 +698,8 @@ mod tests {
                 src_chain_id: ChainId::from_string("reference_chain"),
                 clock_drift: Some("3s".parse::<Duration>().unwrap()),
                 trusting_period: None,
-                trust_threshold: None
+                trust_threshold: None,
+                wasm_checksum: None
             },
             TxCreateClientCmd::parse_from([
                 "test",
@@ -690,7 +721,8 @@ mod tests {
                 src_chain_id: ChainId::from_string("reference_chain"),
                 clock_drift: None,
                 trusting_period: Some("5s".parse::<Duration>().unwrap()),
-                trust_threshold: None
+                trust_threshold: None,
+                wasm_checksum: None
             },
             TxCreateClientCmd::parse_from([
                 "test",
@@ -708,7 +740,8 @@ mod tests {
                 src_chain_id: ChainId::from_string("reference_chain"),
                 clock_drift: None,
                 trusting_period: Some("3s".parse::<Duration>().unwrap()),
-                trust_threshold: None
+                trust_threshold: None,
+                wasm_checksum: None
             },
             TxCreateClientCmd::parse_from([
                 "test",
@@ -730,7 +763,8 @@ mod tests {
                 src_chain_id: ChainId::from_string("reference_chain"),
                 clock_drift: None,
                 trusting_period: None,
-                trust_threshold: Some(TrustThreshold::new(1, 2).unwrap())
+                trust_threshold: Some(TrustThreshold::new(1, 2).unwrap()),
+                wasm_checksum: None
             },
             TxCreateClientCmd::parse_from([
                 "test",
@@ -752,7 +786,8 @@ mod tests {
                 src_chain_id: ChainId::from_string("reference_chain"),
                 clock_drift: Some("5s".parse::<Duration>().unwrap()),
                 trusting_period: Some("3s".parse::<Duration>().unwrap()),
-                trust_threshold: Some(TrustThreshold::new(1, 2).unwrap())
+                trust_threshold: Some(TrustThreshold::new(1, 2).unwrap()),
+                wasm_checksum: None
             },
             TxCreateClientCmd::parse_from([
                 "test",
@@ -770,6 +805,38 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_create_client_wasm_checksum() {
+        let checksum = "AB".repeat(32);
+
+        assert_eq!(
+            TxCreateClientCmd {
+                dst_chain_id: ChainId::from_string("host_chain"),
+                src_chain_id: ChainId::from_string("reference_chain"),
+                clock_drift: None,
+                trusting_period: None,
+                trust_threshold: None,
+                wasm_checksum: Some(vec![0xab; 32])
+            },
+            TxCreateClientCmd::parse_from([
+                "test",
+                "--host-chain",
+                "host_chain",
+                "--reference-chain",
+                "reference_chain",
+                "--wasm-checksum",
+                &checksum
+            ])
+        )
+    }
+
+    #[test]
+    fn test_parse_wasm_checksum() {
+        assert!(parse_wasm_checksum(&"ab".repeat(32)).is_ok());
+        assert!(parse_wasm_checksum(&"ab".repeat(31)).is_err());
+        assert!(parse_wasm_checksum("not hex").is_err());
+    }
+
     #[test]
     fn test_create_client_no_host_chain() {
         assert!(TxCreateClientCmd::try_parse_from([
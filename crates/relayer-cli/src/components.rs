@@ -1,10 +1,20 @@
 //! Various components for internal use by the Abscissa subsystem.
 
 use abscissa_core::{Component, FrameworkError, FrameworkErrorKind};
-use tracing_subscriber::{filter::EnvFilter, util::SubscriberInitExt, FmtSubscriber};
+use opentelemetry::sdk::trace::{self, Sampler, Tracer, TracerProvider};
+use opentelemetry::sdk::Resource;
+use opentelemetry::KeyValue;
+use opentelemetry_otlp::WithExportConfig;
+use tokio::runtime::Runtime;
+use tracing::Subscriber;
+use tracing_opentelemetry::OpenTelemetryLayer;
+use tracing_subscriber::{
+    filter::EnvFilter, layer::SubscriberExt, registry::LookupSpan, util::SubscriberInitExt,
+    FmtSubscriber,
+};
 
 use ibc_relayer::{
-    config::{GlobalConfig, LogLevel},
+    config::{GlobalConfig, LogLevel, TracingConfig},
     util::debug_section::DebugSection,
 };
 
@@ -20,12 +30,19 @@ const HERMES_LOG_VAR: &str = "RUST_LOG";
 /// - Customizing the log output level, for filtering the output produced via tracing macros
 ///   (`debug!`, `info!`, etc.) or abscissa macros (`status_err`, `status_info`, etc.).
 /// - Enabling JSON-formatted output without coloring
+/// - Exporting the spans to an OpenTelemetry collector, if enabled
 #[derive(Component, Debug)]
-pub struct JsonTracing;
+pub struct JsonTracing {
+    _exporter: Option<OtlpExporter>,
+}
 
 impl JsonTracing {
     /// Creates a new [`JsonTracing`] component
-    pub fn new(cfg: GlobalConfig, debug_sections: &[DebugSection]) -> Result<Self, FrameworkError> {
+    pub fn new(
+        cfg: GlobalConfig,
+        tracing_cfg: &TracingConfig,
+        debug_sections: &[DebugSection],
+    ) -> Result<Self, FrameworkError> {
         let filter = build_tracing_filter(cfg.log_level, debug_sections)?;
         // Note: JSON formatter is un-affected by ANSI 'color' option. Set to 'false'.
         let use_color = false;
@@ -40,9 +57,12 @@ impl JsonTracing {
             .json();
 
         let subscriber = builder.finish();
-        subscriber.init();
+        let (layer, exporter) = otlp_layer(tracing_cfg)?;
+        subscriber.with(layer).init();
 
-        Ok(Self)
+        Ok(Self {
+            _exporter: exporter,
+        })
     }
 }
 
@@ -54,11 +74,18 @@ impl JsonTracing {
 /// - Customizing the log output level, for filtering the output produced via tracing macros
 ///   (`debug!`, `info!`, etc.) or abscissa macros (`status_err`, `status_info`, etc.).
 /// - Enabling pretty output with coloring
-pub struct PrettyTracing;
+/// - Exporting the spans to an OpenTelemetry collector, if enabled
+pub struct PrettyTracing {
+    _exporter: Option<OtlpExporter>,
+}
 
 impl PrettyTracing {
     /// Creates a new [`PrettyTracing`] component
-    pub fn new(cfg: GlobalConfig, debug_sections: &[DebugSection]) -> Result<Self, FrameworkError> {
+    pub fn new(
+        cfg: GlobalConfig,
+        tracing_cfg: &TracingConfig,
+        debug_sections: &[DebugSection],
+    ) -> Result<Self, FrameworkError> {
         let filter = build_tracing_filter(cfg.log_level, debug_sections)?;
 
         // Construct a tracing subscriber with the supplied filter and enable reloading.
@@ -70,10 +97,103 @@ impl PrettyTracing {
             .with_thread_ids(true);
 
         let subscriber = builder.finish();
-        subscriber.init();
+        let (layer, exporter) = otlp_layer(tracing_cfg)?;
+        subscriber.with(layer).init();
+
+        Ok(Self {
+            _exporter: exporter,
+        })
+    }
+}
+
+/// Exports the spans to an OpenTelemetry collector in batches, from a dedicated
+/// runtime which lives as long as the tracing component owning the exporter.
+#[derive(Debug)]
+pub struct OtlpExporter {
+    provider: TracerProvider,
+    _runtime: Runtime,
+}
+
+impl Drop for OtlpExporter {
+    fn drop(&mut self) {
+        // Export the spans of the last batch before stopping the runtime
+        for result in self.provider.force_flush() {
+            if let Err(e) = result {
+                eprintln!("ERROR: failed to export the last spans: {e}");
+            }
+        }
+    }
+}
+
+/// Layer of the subscriber recording the spans for the [`OtlpExporter`].
+type OtlpLayer<S> = OpenTelemetryLayer<S, Tracer>;
+
+/// Builds the layer exporting the spans to the OpenTelemetry collector
+/// configured in `cfg`, if the export is enabled.
+fn otlp_layer<S>(
+    cfg: &TracingConfig,
+) -> Result<(Option<OtlpLayer<S>>, Option<OtlpExporter>), FrameworkError>
+where
+    S: Subscriber + for<'span> LookupSpan<'span>,
+{
+    if !cfg.enabled {
+        return Ok((None, None));
+    }
 
-        Ok(Self)
+    if !(0.0..=1.0).contains(&cfg.sampling_ratio) {
+        return Err(FrameworkErrorKind::ConfigError
+            .context(Error::invalid_sampling_ratio(cfg.sampling_ratio))
+            .into());
     }
+
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(1)
+        .thread_name("otlp-exporter")
+        .enable_all()
+        .build()
+        .map_err(|e| {
+            FrameworkErrorKind::ComponentError.context(Error::trace_exporter_runtime(e))
+        })?;
+
+    // The batch span processor is spawned on the runtime entered here
+    let _guard = runtime.enter();
+
+    let tracer = opentelemetry_otlp::new_pipeline()
+        .tracing()
+        .with_exporter(
+            opentelemetry_otlp::new_exporter()
+                .http()
+                .with_endpoint(&cfg.endpoint),
+        )
+        .with_trace_config(
+            trace::config()
+                .with_sampler(Sampler::ParentBased(Box::new(Sampler::TraceIdRatioBased(
+                    cfg.sampling_ratio,
+                ))))
+                .with_resource(Resource::new(vec![KeyValue::new(
+                    "service.name",
+                    cfg.service_name.clone(),
+                )])),
+        )
+        .install_batch(opentelemetry::runtime::Tokio)
+        .map_err(|e| {
+            FrameworkErrorKind::ComponentError
+                .context(Error::trace_exporter(cfg.endpoint.clone(), e))
+        })?;
+
+    let provider = tracer
+        .provider()
+        .expect("the tracer provider was just installed");
+
+    let exporter = OtlpExporter {
+        provider,
+        _runtime: runtime,
+    };
+
+    Ok((
+        Some(tracing_opentelemetry::layer().with_tracer(tracer)),
+        Some(exporter),
+    ))
 }
 
 /// Check if both stdout and stderr are proper terminal (tty),
@@ -127,3 +247,83 @@ fn build_tracing_filter(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+    use std::thread;
+    use std::time::Duration;
+
+    use tracing_subscriber::Registry;
+
+    /// Stands in for an OpenTelemetry collector, replying to each
+    /// request with `200 OK` and forwarding it to the returned channel.
+    fn collector() -> (String, mpsc::Receiver<Vec<u8>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = format!("http://{}/v1/traces", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                let mut buf = vec![0; 1 << 16];
+                let len = stream.read(&mut buf).unwrap_or(0);
+                let _ = stream.write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\n\r\n");
+                let _ = sender.send(buf[..len].to_vec());
+            }
+        });
+
+        (endpoint, receiver)
+    }
+
+    #[test]
+    fn export_spans_to_collector() {
+        let (endpoint, requests) = collector();
+
+        let cfg = TracingConfig {
+            enabled: true,
+            endpoint,
+            service_name: "hermes-test".to_string(),
+            sampling_ratio: 1.0,
+        };
+
+        let (layer, exporter) = otlp_layer::<Registry>(&cfg).unwrap();
+        let subscriber = Registry::default().with(layer);
+
+        tracing::subscriber::with_default(subscriber, || {
+            let _span = tracing::info_span!("relay", tracking_id = "packet-tracking-id").entered();
+        });
+
+        // Dropping the exporter exports the last batch of spans
+        drop(exporter);
+
+        let request = requests.recv_timeout(Duration::from_secs(10)).unwrap();
+        let request = String::from_utf8_lossy(&request);
+
+        assert!(request.starts_with("POST /v1/traces"));
+        assert!(request.contains("hermes-test"));
+        assert!(request.contains("packet-tracking-id"));
+    }
+
+    #[test]
+    fn reject_invalid_sampling_ratio() {
+        let cfg = TracingConfig {
+            enabled: true,
+            sampling_ratio: 1.5,
+            ..TracingConfig::default()
+        };
+
+        assert!(otlp_layer::<Registry>(&cfg).is_err());
+    }
+
+    #[test]
+    fn no_export_unless_enabled() {
+        let (layer, exporter) = otlp_layer::<Registry>(&TracingConfig::default()).unwrap();
+
+        assert!(layer.is_none());
+        assert!(exporter.is_none());
+    }
+}
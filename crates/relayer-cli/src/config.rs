@@ -8,7 +8,7 @@ use alloc::collections::BTreeSet;
 use std::path::PathBuf;
 
 use flex_error::{define_error, TraceError};
-use ibc_relayer::config::{ChainConfig, Config, ModeConfig};
+use ibc_relayer::config::{ChainConfig, Config, ModeConfig, TelemetryBuckets};
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 use tendermint_light_client_verifier::types::TrustThreshold;
 use tracing_subscriber::filter::ParseError;
@@ -35,6 +35,30 @@ define_error! {
                 format!("invalid log directive: {0:?}", e.directive)
             },
 
+        InvalidSamplingRatio
+            { ratio: f64 }
+            |e| {
+                format!("invalid tracing `sampling_ratio` ({0}), must be between 0 and 1", e.ratio)
+            },
+
+        TraceExporterRuntime
+            [ TraceError<std::io::Error> ]
+            |_| { "failed to start the runtime exporting the spans" },
+
+        TraceExporter
+            { endpoint: String }
+            [ TraceError<opentelemetry::trace::TraceError> ]
+            |e| {
+                format!("failed to initialize the export of the spans to {0}", e.endpoint)
+            },
+
+        InvalidTelemetryBuckets
+            { histogram: String }
+            |e| {
+                format!("config file specifies invalid `telemetry.buckets.{0}`, the bounds must be strictly increasing",
+                    e.histogram)
+            },
+
         InvalidMode
             { reason: String, }
             |e| {
@@ -94,6 +118,14 @@ pub fn validate_config(config: &Config) -> Result<(), Diagnostic<Error>> {
 
         validate_trust_threshold(&c.id, c.trust_threshold)?;
 
+        for trust_threshold in c
+            .client_overrides
+            .values()
+            .filter_map(|o| o.trust_threshold)
+        {
+            validate_trust_threshold(&c.id, trust_threshold)?;
+        }
+
         // Validate gas-related settings
         validate_gas_settings(&c.id, c)?;
     }
@@ -101,6 +133,29 @@ pub fn validate_config(config: &Config) -> Result<(), Diagnostic<Error>> {
     // Check for invalid mode config
     validate_mode(&config.mode)?;
 
+    validate_telemetry_buckets(&config.telemetry.buckets)?;
+
+    Ok(())
+}
+
+fn validate_telemetry_buckets(buckets: &TelemetryBuckets) -> Result<(), Diagnostic<Error>> {
+    let histograms = [
+        ("packet_recv_latency", &buckets.packet_recv_latency),
+        ("packet_ack_latency", &buckets.packet_ack_latency),
+    ];
+
+    for (histogram, bounds) in histograms {
+        let Some(bounds) = bounds else {
+            continue;
+        };
+
+        if bounds.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err(Diagnostic::Error(Error::invalid_telemetry_buckets(
+                histogram.to_string(),
+            )));
+        }
+    }
+
     Ok(())
 }
 
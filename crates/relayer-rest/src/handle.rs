@@ -4,15 +4,19 @@ use tracing::error;
 
 use crossbeam_channel as channel;
 
-use ibc_relayer::supervisor::dump_state::SupervisorState;
+use ibc_relayer::supervisor::{dump_state::SupervisorState, reload::ConfigDiff};
 use ibc_relayer::{
     config::ChainConfig,
+    object::Object,
     rest::{
-        request::{reply_channel, ReplySender, Request, VersionInfo},
+        request::{
+            reply_channel, ClearPacketsParams, ReplySender, Request, UpdateClientParams,
+            VersionInfo,
+        },
         RestApiError,
     },
 };
-use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+use ibc_relayer_types::{core::ics24_host::identifier::ChainId, events::IbcEvent};
 
 pub const NAME: &str = env!(
     "CARGO_PKG_NAME",
@@ -64,6 +68,44 @@ pub fn supervisor_state(
     submit_request(sender, |reply_to| Request::State { reply_to })
 }
 
+pub fn reload_config(sender: &channel::Sender<Request>) -> Result<ConfigDiff, RestApiError> {
+    submit_request(sender, |reply_to| Request::ReloadConfig { reply_to })
+}
+
+pub fn clear_packets(
+    sender: &channel::Sender<Request>,
+    params: ClearPacketsParams,
+) -> Result<Vec<Object>, RestApiError> {
+    submit_request(sender, |reply_to| Request::ClearPackets {
+        params,
+        reply_to,
+    })
+}
+
+pub fn update_client(
+    sender: &channel::Sender<Request>,
+    params: UpdateClientParams,
+) -> Result<Vec<IbcEvent>, RestApiError> {
+    submit_request(sender, |reply_to| Request::UpdateClient {
+        params,
+        reply_to,
+    })
+}
+
+pub fn pause_worker(sender: &channel::Sender<Request>, object: Object) -> Result<(), RestApiError> {
+    submit_request(sender, |reply_to| Request::PauseWorker { object, reply_to })
+}
+
+pub fn resume_worker(
+    sender: &channel::Sender<Request>,
+    object: Object,
+) -> Result<(), RestApiError> {
+    submit_request(sender, |reply_to| Request::ResumeWorker {
+        object,
+        reply_to,
+    })
+}
+
 pub fn assemble_version_info(sender: &channel::Sender<Request>) -> Vec<VersionInfo> {
     // Fetch the relayer library version
     let lib_version = submit_request(sender, |reply_to| Request::Version { reply_to })
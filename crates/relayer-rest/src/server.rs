@@ -3,26 +3,46 @@ use std::{
     net::{SocketAddr, ToSocketAddrs},
 };
 
-use axum::{extract::Path, response::IntoResponse, routing::get, Extension, Json, Router, Server};
+use axum::{
+    extract::{Path, State},
+    http::{header::AUTHORIZATION, Request as HttpRequest, StatusCode},
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Extension, Json, Router, Server,
+};
 use crossbeam_channel as channel;
 use serde::{Deserialize, Serialize};
 use tokio::task::JoinHandle;
 
 use ibc_relayer::{
-    rest::{request::Request, RestApiError},
-    supervisor::dump_state::SupervisorState,
+    object::Object,
+    rest::{
+        request::{ClearPacketsParams, Request, UpdateClientParams},
+        RestApiError,
+    },
+    supervisor::{dump_state::SupervisorState, reload::ConfigDiff},
 };
+use ibc_relayer_types::events::IbcEvent;
 
-use crate::handle::{all_chain_ids, assemble_version_info, chain_config, supervisor_state};
+use crate::handle::{
+    all_chain_ids, assemble_version_info, chain_config, clear_packets, pause_worker, reload_config,
+    resume_worker, supervisor_state, update_client,
+};
 
 pub type BoxError = Box<dyn Error + Send + Sync>;
 
+/// Spawn the REST server, listening on the given address.
+///
+/// If a `token` is given, the endpoints performing actions on behalf of the relayer
+/// only accept requests which supply it as a bearer token in their `Authorization` header.
 pub fn spawn(
     addr: impl ToSocketAddrs,
+    token: Option<String>,
     sender: channel::Sender<Request>,
 ) -> Result<JoinHandle<()>, BoxError> {
     let addr = addr.to_socket_addrs()?.next().unwrap();
-    let handle = tokio::spawn(run(addr, sender));
+    let handle = tokio::spawn(run(addr, token, sender));
     Ok(handle)
 }
 
@@ -68,14 +88,95 @@ async fn get_state(
     Json(JsonResult::from(state))
 }
 
+async fn post_reload(
+    Extension(sender): Extension<Sender>,
+) -> Json<JsonResult<ConfigDiff, RestApiError>> {
+    let diff = reload_config(&sender);
+    Json(JsonResult::from(diff))
+}
+
+async fn post_clear_packets(
+    Extension(sender): Extension<Sender>,
+    Json(params): Json<ClearPacketsParams>,
+) -> Json<JsonResult<Vec<Object>, RestApiError>> {
+    let result = clear_packets(&sender, params);
+    Json(JsonResult::from(result))
+}
+
+async fn post_update_client(
+    Extension(sender): Extension<Sender>,
+    Json(params): Json<UpdateClientParams>,
+) -> Json<JsonResult<Vec<IbcEvent>, RestApiError>> {
+    let events = update_client(&sender, params);
+    Json(JsonResult::from(events))
+}
+
+async fn post_pause_worker(
+    Extension(sender): Extension<Sender>,
+    Json(object): Json<Object>,
+) -> Json<JsonResult<(), RestApiError>> {
+    let result = pause_worker(&sender, object);
+    Json(JsonResult::from(result))
+}
+
+async fn post_resume_worker(
+    Extension(sender): Extension<Sender>,
+    Json(object): Json<Object>,
+) -> Json<JsonResult<(), RestApiError>> {
+    let result = resume_worker(&sender, object);
+    Json(JsonResult::from(result))
+}
+
+/// Reject the requests which do not supply the configured bearer token, if any.
+async fn authorize<B>(
+    State(token): State<Option<String>>,
+    request: HttpRequest<B>,
+    next: Next<B>,
+) -> Response {
+    let Some(token) = token else {
+        return next.run(request).await;
+    };
+
+    let authorized = request
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .map_or(false, |provided| {
+            constant_time_eq(provided.as_bytes(), token.as_bytes())
+        });
+
+    if authorized {
+        next.run(request).await
+    } else {
+        let result: JsonResult<(), _> = JsonResult::Error(RestApiError::Unauthorized);
+        (StatusCode::UNAUTHORIZED, Json(result)).into_response()
+    }
+}
+
+/// Compare two byte strings in a time which only depends on their length,
+/// so as not to leak how much of the token was guessed right.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
 type Sender = channel::Sender<Request>;
 
-async fn run(addr: SocketAddr, sender: Sender) {
+async fn run(addr: SocketAddr, token: Option<String>, sender: Sender) {
+    let actions = Router::new()
+        .route("/reload", post(post_reload))
+        .route("/clear_packets", post(post_clear_packets))
+        .route("/update_client", post(post_update_client))
+        .route("/worker/pause", post(post_pause_worker))
+        .route("/worker/resume", post(post_resume_worker))
+        .route_layer(middleware::from_fn_with_state(token, authorize));
+
     let app = Router::new()
         .route("/version", get(get_version))
         .route("/chains", get(get_chains))
         .route("/chain/:id", get(get_chain))
         .route("/state", get(get_state))
+        .merge(actions)
         .layer(Extension(sender));
 
     Server::bind(&addr)
@@ -4,8 +4,9 @@ use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
 use ibc_relayer::{
     config::ChainConfig,
-    rest::request::{Request, VersionInfo},
-    supervisor::dump_state::SupervisorState,
+    object::{Object, Packet},
+    rest::request::{ClearPacketsParams, Request, VersionInfo},
+    supervisor::{dump_state::SupervisorState, reload::ConfigDiff},
 };
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 
@@ -28,10 +29,24 @@ async fn run_test<R, F>(port: u16, path: &str, expected: R, handler: F)
 where
     R: Serialize + DeserializeOwned + Debug + PartialEq,
     F: FnOnce(Request) -> TestResult + Send + 'static,
+{
+    run_request_test(port, None, |c| c.get(path), expected, handler).await
+}
+
+async fn run_request_test<R, B, F>(
+    port: u16,
+    token: Option<&str>,
+    build_request: B,
+    expected: R,
+    handler: F,
+) where
+    R: Serialize + DeserializeOwned + Debug + PartialEq,
+    B: FnOnce(RequestBuilder) -> reqwest::RequestBuilder,
+    F: FnOnce(Request) -> TestResult + Send + 'static,
 {
     let (tx, rx) = crossbeam_channel::unbounded();
 
-    let handle = spawn(("127.0.0.1", port), tx).unwrap();
+    let handle = spawn(("127.0.0.1", port), token.map(String::from), tx).unwrap();
 
     std::thread::spawn(move || match rx.recv() {
         Ok(r) => match handler(r) {
@@ -43,7 +58,13 @@ where
 
     tokio::time::sleep(Duration::from_millis(500)).await;
 
-    let response = reqwest::get(&format!("http://127.0.0.1:{port}{path}"))
+    let builder = RequestBuilder {
+        client: reqwest::Client::new(),
+        port,
+    };
+
+    let response = build_request(builder)
+        .send()
         .await
         .unwrap()
         .json::<R>()
@@ -55,6 +76,26 @@ where
     drop(handle);
 }
 
+/// Builds requests to the mock REST server listening on the given port.
+struct RequestBuilder {
+    client: reqwest::Client,
+    port: u16,
+}
+
+impl RequestBuilder {
+    fn get(&self, path: &str) -> reqwest::RequestBuilder {
+        self.client.get(self.url(path))
+    }
+
+    fn post(&self, path: &str) -> reqwest::RequestBuilder {
+        self.client.post(self.url(path))
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("http://127.0.0.1:{}{path}", self.port)
+    }
+}
+
 #[tokio::test]
 async fn version() {
     let version = VersionInfo {
@@ -142,3 +183,110 @@ async fn state() {
     })
     .await;
 }
+
+#[tokio::test]
+async fn reload() {
+    let diff = ConfigDiff {
+        added: vec!["mock-1".parse().unwrap()],
+        ..Default::default()
+    };
+    let result: JsonResult<_, ()> = JsonResult::Success(diff.clone());
+
+    run_request_test(
+        19105,
+        None,
+        |c| c.post("/reload"),
+        result,
+        |req| match req {
+            Request::ReloadConfig { reply_to } => {
+                reply_to.send(Ok(diff)).unwrap();
+                TestResult::Success
+            }
+            req => TestResult::WrongRequest(req),
+        },
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn clear_packets() {
+    let params = ClearPacketsParams {
+        chain_id: "mock-0".parse().unwrap(),
+        port_id: "transfer".parse().unwrap(),
+        channel_id: "channel-0".parse().unwrap(),
+    };
+    let objects = vec![Object::Packet(Packet {
+        dst_chain_id: "mock-1".parse().unwrap(),
+        src_chain_id: "mock-0".parse().unwrap(),
+        src_channel_id: "channel-0".parse().unwrap(),
+        src_port_id: "transfer".parse().unwrap(),
+    })];
+    let result: JsonResult<_, ()> = JsonResult::Success(objects.clone());
+
+    let body = params.clone();
+    run_request_test(
+        19106,
+        Some("secret"),
+        |c| c.post("/clear_packets").bearer_auth("secret").json(&body),
+        result,
+        move |req| match req {
+            Request::ClearPackets {
+                params: received,
+                reply_to,
+            } if received == params => {
+                reply_to.send(Ok(objects)).unwrap();
+                TestResult::Success
+            }
+            req => TestResult::WrongRequest(req),
+        },
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn pause_worker() {
+    let object = Object::Packet(Packet {
+        dst_chain_id: "mock-1".parse().unwrap(),
+        src_chain_id: "mock-0".parse().unwrap(),
+        src_channel_id: "channel-0".parse().unwrap(),
+        src_port_id: "transfer".parse().unwrap(),
+    });
+    let result: JsonResult<(), ()> = JsonResult::Success(());
+
+    let body = object.clone();
+    run_request_test(
+        19107,
+        None,
+        |c| c.post("/worker/pause").json(&body),
+        result,
+        move |req| match req {
+            Request::PauseWorker {
+                object: received,
+                reply_to,
+            } if received == object => {
+                reply_to.send(Ok(())).unwrap();
+                TestResult::Success
+            }
+            req => TestResult::WrongRequest(req),
+        },
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn unauthorized() {
+    let result: JsonResult<(), _> = JsonResult::Error(serde_json::json!({
+        "name": "Unauthorized",
+        "msg": "missing or invalid bearer token",
+    }));
+
+    run_request_test(
+        19108,
+        Some("secret"),
+        |c| c.post("/reload").bearer_auth("wrong"),
+        result,
+        // The request never reaches the supervisor
+        TestResult::WrongRequest,
+    )
+    .await;
+}
@@ -27,6 +27,12 @@ impl FromStr for BaseDenom {
     }
 }
 
+impl BaseDenom {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
 #[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq)]
 pub struct TracePrefix {
     port_id: PortId,
@@ -153,6 +159,11 @@ impl PrefixedDenom {
     pub fn add_trace_prefix(&mut self, prefix: TracePrefix) {
         self.trace_path.add_prefix(prefix)
     }
+
+    /// The base denomination of the token, stripped of its trace path.
+    pub fn base_denom(&self) -> &BaseDenom {
+        &self.base_denom
+    }
 }
 
 /// Returns true if the denomination originally came from the sender chain and
@@ -47,3 +47,73 @@ impl From<PacketData> for RawPacketData {
         }
     }
 }
+
+impl PacketData {
+    /// Decodes and validates the JSON-encoded `FungibleTokenPacketData` carried
+    /// in the data of an ICS-20 packet.
+    pub fn from_json(data: &[u8]) -> Result<Self, Error> {
+        Self::try_from(raw_packet_data_from_json(data)?)
+    }
+}
+
+/// Decodes the JSON-encoded `FungibleTokenPacketData` carried in the data of an
+/// ICS-20 packet, without validating its fields.
+///
+/// The memo may be missing altogether, as is the case for packets sent by
+/// chains which predate the introduction of the memo field.
+pub fn raw_packet_data_from_json(data: &[u8]) -> Result<RawPacketData, Error> {
+    #[derive(Deserialize)]
+    struct JsonPacketData {
+        denom: String,
+        amount: String,
+        sender: String,
+        receiver: String,
+        #[serde(default)]
+        memo: String,
+    }
+
+    let json: JsonPacketData =
+        serde_json::from_slice(data).map_err(|_| Error::packet_data_deserialization())?;
+
+    Ok(RawPacketData {
+        denom: json.denom,
+        amount: json.amount,
+        sender: json.sender,
+        receiver: json.receiver,
+        memo: json.memo,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_packet_data_from_json() {
+        let data = br#"{"denom":"transfer/channel-0/uatom","amount":"100","sender":"cosmos1sender","receiver":"cosmos1receiver","memo":"hello"}"#;
+
+        let packet_data = PacketData::from_json(data).unwrap();
+        assert_eq!(packet_data.token.amount, Amount::from_str("100").unwrap());
+        assert_eq!(packet_data.receiver.as_ref(), "cosmos1receiver");
+        assert_eq!(packet_data.memo.as_deref(), Some("hello"));
+    }
+
+    #[test]
+    fn decode_packet_data_without_memo() {
+        let data = br#"{"denom":"uatom","amount":"100","sender":"cosmos1sender","receiver":"cosmos1receiver"}"#;
+
+        let packet_data = PacketData::from_json(data).unwrap();
+        assert_eq!(packet_data.memo, None);
+
+        assert!(PacketData::from_json(b"not json").is_err());
+    }
+
+    #[test]
+    fn decode_raw_packet_data_from_json() {
+        let data = br#"{"denom":"factory/osmo1creator/ufoo","amount":"1","sender":"osmo1sender","receiver":"cosmos1receiver"}"#;
+
+        let raw = raw_packet_data_from_json(data).unwrap();
+        assert_eq!(raw.denom, "factory/osmo1creator/ufoo");
+        assert_eq!(raw.memo, "");
+    }
+}
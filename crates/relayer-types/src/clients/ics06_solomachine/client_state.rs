@@ -0,0 +1,182 @@
+use crate::prelude::*;
+
+use core::time::Duration;
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::protobuf::Protobuf;
+use serde::{Deserialize, Serialize};
+
+use crate::clients::ics06_solomachine::consensus_state::ConsensusState;
+use crate::clients::ics06_solomachine::error::Error;
+use crate::clients::ics06_solomachine::raw::ClientState as RawClientState;
+use crate::core::ics02_client::client_state::{
+    ClientState as Ics2ClientState, UpgradeOptions as CoreUpgradeOptions,
+};
+use crate::core::ics02_client::client_type::ClientType;
+use crate::core::ics02_client::error::Error as Ics02Error;
+use crate::core::ics24_host::identifier::ChainId;
+use crate::Height;
+
+pub const SOLOMACHINE_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.solomachine.v3.ClientState";
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClientState {
+    /// The sequence of the next signature expected from the solo machine.
+    pub sequence: u64,
+    pub is_frozen: bool,
+    pub consensus_state: ConsensusState,
+}
+
+impl ClientState {
+    pub fn new(sequence: u64, consensus_state: ConsensusState) -> Result<Self, Error> {
+        if sequence == 0 {
+            return Err(Error::invalid_raw_client_state(
+                "sequence cannot be zero".into(),
+            ));
+        }
+
+        Ok(Self {
+            sequence,
+            is_frozen: false,
+            consensus_state,
+        })
+    }
+
+    /// The height of a solo machine client is the sequence of its
+    /// client state, in revision zero.
+    pub fn latest_height(&self) -> Height {
+        Height::new(0, self.sequence).expect("sequence is never zero")
+    }
+}
+
+impl Ics2ClientState for ClientState {
+    /// A solo machine has no chain identifier, so we use its diversifier,
+    /// which the solo machines Hermes drives set to their chain identifier.
+    fn chain_id(&self) -> ChainId {
+        ChainId::from_string(&self.consensus_state.diversifier)
+    }
+
+    fn client_type(&self) -> ClientType {
+        ClientType::Solomachine
+    }
+
+    fn latest_height(&self) -> Height {
+        self.latest_height()
+    }
+
+    fn frozen_height(&self) -> Option<Height> {
+        // Solo machine clients are frozen at height `0-1`, as in ibc-go
+        self.is_frozen
+            .then(|| Height::new(0, 1).expect("height is not zero"))
+    }
+
+    fn upgrade(
+        &mut self,
+        _upgrade_height: Height,
+        _upgrade_options: &dyn CoreUpgradeOptions,
+        _chain_id: ChainId,
+    ) {
+        // Solo machine clients cannot be upgraded, their public key
+        // is instead rotated with a header.
+    }
+
+    fn expired(&self, _elapsed: Duration) -> bool {
+        // Signatures do not expire, so neither do solo machine clients
+        false
+    }
+}
+
+impl Protobuf<RawClientState> for ClientState {}
+
+impl TryFrom<RawClientState> for ClientState {
+    type Error = Error;
+
+    fn try_from(raw: RawClientState) -> Result<Self, Self::Error> {
+        let consensus_state = raw
+            .consensus_state
+            .ok_or_else(|| Error::invalid_raw_client_state("missing consensus state".into()))?
+            .try_into()?;
+
+        let mut client_state = Self::new(raw.sequence, consensus_state)?;
+        client_state.is_frozen = raw.is_frozen;
+
+        Ok(client_state)
+    }
+}
+
+impl From<ClientState> for RawClientState {
+    fn from(value: ClientState) -> Self {
+        RawClientState {
+            sequence: value.sequence,
+            is_frozen: value.is_frozen,
+            consensus_state: Some(value.consensus_state.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = Ics02Error;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        use bytes::Buf;
+        use core::ops::Deref;
+        use prost::Message;
+
+        fn decode_client_state<B: Buf>(buf: B) -> Result<ClientState, Error> {
+            RawClientState::decode(buf)
+                .map_err(Error::decode)?
+                .try_into()
+        }
+
+        match raw.type_url.as_str() {
+            SOLOMACHINE_CLIENT_STATE_TYPE_URL => {
+                decode_client_state(raw.value.deref()).map_err(Into::into)
+            }
+            _ => Err(Ics02Error::unknown_client_state_type(raw.type_url)),
+        }
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        Any {
+            type_url: SOLOMACHINE_CLIENT_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawClientState>::encode_vec(&client_state),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_proto::google::protobuf::Any;
+    use test_log::test;
+
+    use super::{ClientState, RawClientState};
+    use crate::clients::ics06_solomachine::consensus_state::test_util::get_dummy_solomachine_consensus_state;
+    use crate::core::ics02_client::client_state::ClientState as _;
+    use crate::core::ics24_host::identifier::ChainId;
+    use crate::Height;
+
+    #[test]
+    fn client_state_to_and_from_any() {
+        let client_state = ClientState::new(3, get_dummy_solomachine_consensus_state()).unwrap();
+        assert_eq!(client_state.latest_height(), Height::new(0, 3).unwrap());
+        assert_eq!(client_state.chain_id(), ChainId::from_string("solo-0"));
+
+        let any = Any::from(client_state.clone());
+        let decoded = ClientState::try_from(any).unwrap();
+        assert_eq!(client_state, decoded);
+    }
+
+    #[test]
+    fn client_state_with_zero_sequence() {
+        let raw = RawClientState {
+            sequence: 0,
+            is_frozen: false,
+            consensus_state: Some(get_dummy_solomachine_consensus_state().into()),
+        };
+        assert!(ClientState::try_from(raw).is_err());
+    }
+}
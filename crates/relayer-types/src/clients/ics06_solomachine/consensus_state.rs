@@ -0,0 +1,198 @@
+use crate::prelude::*;
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::protobuf::Protobuf;
+use serde::{Deserialize, Serialize};
+
+use crate::clients::ics06_solomachine::error::Error;
+use crate::clients::ics06_solomachine::raw::ConsensusState as RawConsensusState;
+use crate::core::ics02_client::client_type::ClientType;
+use crate::core::ics02_client::error::Error as Ics02Error;
+use crate::core::ics23_commitment::commitment::CommitmentRoot;
+use crate::timestamp::Timestamp;
+
+pub const SOLOMACHINE_CONSENSUS_STATE_TYPE_URL: &str =
+    "/ibc.lightclients.solomachine.v3.ConsensusState";
+
+/// A solo machine does not commit to its state in a Merkle tree,
+/// so its consensus states all share the same empty root.
+static EMPTY_ROOT: CommitmentRoot = CommitmentRoot::empty();
+
+/// The public key of a solo machine, as it is encoded in a `google.protobuf.Any`,
+/// e.g. with type URL `/cosmos.crypto.secp256k1.PubKey`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PublicKey {
+    pub type_url: String,
+    pub value: Vec<u8>,
+}
+
+impl From<Any> for PublicKey {
+    fn from(any: Any) -> Self {
+        Self {
+            type_url: any.type_url,
+            value: any.value,
+        }
+    }
+}
+
+impl From<PublicKey> for Any {
+    fn from(public_key: PublicKey) -> Self {
+        Any {
+            type_url: public_key.type_url,
+            value: public_key.value,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConsensusState {
+    /// The key with which the solo machine signs its proofs.
+    pub public_key: PublicKey,
+    /// A string included in every signature, so that the same key
+    /// can be used by several solo machines without replay attacks.
+    pub diversifier: String,
+    pub timestamp: Timestamp,
+}
+
+impl ConsensusState {
+    pub fn new(public_key: PublicKey, diversifier: String, timestamp: Timestamp) -> Self {
+        Self {
+            public_key,
+            diversifier,
+            timestamp,
+        }
+    }
+}
+
+impl crate::core::ics02_client::consensus_state::ConsensusState for ConsensusState {
+    fn client_type(&self) -> ClientType {
+        ClientType::Solomachine
+    }
+
+    fn root(&self) -> &CommitmentRoot {
+        &EMPTY_ROOT
+    }
+
+    fn timestamp(&self) -> Timestamp {
+        self.timestamp
+    }
+}
+
+impl Protobuf<RawConsensusState> for ConsensusState {}
+
+impl TryFrom<RawConsensusState> for ConsensusState {
+    type Error = Error;
+
+    fn try_from(raw: RawConsensusState) -> Result<Self, Self::Error> {
+        let public_key = raw.public_key.ok_or_else(Error::missing_public_key)?;
+
+        if raw.diversifier.trim().is_empty() {
+            return Err(Error::invalid_raw_consensus_state(
+                "diversifier cannot be blank".into(),
+            ));
+        }
+
+        if raw.timestamp == 0 {
+            return Err(Error::invalid_raw_consensus_state(
+                "timestamp cannot be zero".into(),
+            ));
+        }
+
+        Ok(Self {
+            public_key: public_key.into(),
+            diversifier: raw.diversifier,
+            timestamp: Timestamp::from_nanoseconds(raw.timestamp)
+                .map_err(Error::invalid_timestamp)?,
+        })
+    }
+}
+
+impl From<ConsensusState> for RawConsensusState {
+    fn from(value: ConsensusState) -> Self {
+        RawConsensusState {
+            public_key: Some(value.public_key.into()),
+            diversifier: value.diversifier,
+            timestamp: value.timestamp.nanoseconds(),
+        }
+    }
+}
+
+impl Protobuf<Any> for ConsensusState {}
+
+impl TryFrom<Any> for ConsensusState {
+    type Error = Ics02Error;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        use bytes::Buf;
+        use core::ops::Deref;
+        use prost::Message;
+
+        fn decode_consensus_state<B: Buf>(buf: B) -> Result<ConsensusState, Error> {
+            RawConsensusState::decode(buf)
+                .map_err(Error::decode)?
+                .try_into()
+        }
+
+        match raw.type_url.as_str() {
+            SOLOMACHINE_CONSENSUS_STATE_TYPE_URL => {
+                decode_consensus_state(raw.value.deref()).map_err(Into::into)
+            }
+            _ => Err(Ics02Error::unknown_consensus_state_type(raw.type_url)),
+        }
+    }
+}
+
+impl From<ConsensusState> for Any {
+    fn from(consensus_state: ConsensusState) -> Self {
+        Any {
+            type_url: SOLOMACHINE_CONSENSUS_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawConsensusState>::encode_vec(&consensus_state),
+        }
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+pub mod test_util {
+    use crate::prelude::*;
+
+    use super::{ConsensusState, PublicKey};
+    use crate::timestamp::Timestamp;
+
+    /// Returns a dummy solo machine `ConsensusState`, for testing only!
+    pub fn get_dummy_solomachine_consensus_state() -> ConsensusState {
+        ConsensusState::new(
+            PublicKey {
+                type_url: "/cosmos.crypto.secp256k1.PubKey".to_string(),
+                value: vec![10, 33, 2, 1, 2, 3],
+            },
+            "solo-0".to_string(),
+            Timestamp::from_nanoseconds(1_700_000_000_000_000_000).unwrap(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_proto::google::protobuf::Any;
+    use test_log::test;
+
+    use super::test_util::get_dummy_solomachine_consensus_state;
+    use super::{ConsensusState, RawConsensusState};
+
+    #[test]
+    fn consensus_state_to_and_from_any() {
+        let consensus_state = get_dummy_solomachine_consensus_state();
+        let any = Any::from(consensus_state.clone());
+        let decoded = ConsensusState::try_from(any).unwrap();
+        assert_eq!(consensus_state, decoded);
+    }
+
+    #[test]
+    fn consensus_state_without_diversifier() {
+        let raw = RawConsensusState {
+            diversifier: " ".into(),
+            ..get_dummy_solomachine_consensus_state().into()
+        };
+        assert!(ConsensusState::try_from(raw).is_err());
+    }
+}
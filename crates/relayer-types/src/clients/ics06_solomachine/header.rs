@@ -0,0 +1,147 @@
+use crate::prelude::*;
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::protobuf::Protobuf;
+use serde::{Deserialize, Serialize};
+
+use crate::clients::ics06_solomachine::consensus_state::PublicKey;
+use crate::clients::ics06_solomachine::error::Error;
+use crate::clients::ics06_solomachine::raw::Header as RawHeader;
+use crate::core::ics02_client::client_type::ClientType;
+use crate::core::ics02_client::error::Error as Ics02Error;
+use crate::timestamp::Timestamp;
+use crate::Height;
+
+pub const SOLOMACHINE_HEADER_TYPE_URL: &str = "/ibc.lightclients.solomachine.v3.Header";
+
+/// A solo machine header, which sets the public key and diversifier
+/// of a solo machine client.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Header {
+    /// The sequence of the client state the header is signed for.
+    ///
+    /// This is not part of the encoded header, since the client checks the
+    /// signature against its own sequence, so it is zero in decoded headers.
+    pub sequence: u64,
+    pub timestamp: Timestamp,
+    pub signature: Vec<u8>,
+    pub new_public_key: PublicKey,
+    pub new_diversifier: String,
+}
+
+impl crate::core::ics02_client::header::Header for Header {
+    fn client_type(&self) -> ClientType {
+        ClientType::Solomachine
+    }
+
+    /// The height of the consensus state installed by this header,
+    /// ie. the sequence of the client once the header is applied.
+    fn height(&self) -> Height {
+        Height::new(0, self.sequence + 1).expect("height is not zero")
+    }
+
+    fn timestamp(&self) -> Timestamp {
+        self.timestamp
+    }
+}
+
+impl Protobuf<RawHeader> for Header {}
+
+impl TryFrom<RawHeader> for Header {
+    type Error = Error;
+
+    fn try_from(raw: RawHeader) -> Result<Self, Self::Error> {
+        if raw.signature.is_empty() {
+            return Err(Error::invalid_raw_header(
+                "signature cannot be empty".into(),
+            ));
+        }
+
+        if raw.new_diversifier.trim().is_empty() {
+            return Err(Error::invalid_raw_header(
+                "diversifier cannot be blank".into(),
+            ));
+        }
+
+        Ok(Self {
+            sequence: 0,
+            timestamp: Timestamp::from_nanoseconds(raw.timestamp)
+                .map_err(Error::invalid_timestamp)?,
+            signature: raw.signature,
+            new_public_key: raw
+                .new_public_key
+                .ok_or_else(Error::missing_public_key)?
+                .into(),
+            new_diversifier: raw.new_diversifier,
+        })
+    }
+}
+
+impl From<Header> for RawHeader {
+    fn from(value: Header) -> Self {
+        RawHeader {
+            timestamp: value.timestamp.nanoseconds(),
+            signature: value.signature,
+            new_public_key: Some(value.new_public_key.into()),
+            new_diversifier: value.new_diversifier,
+        }
+    }
+}
+
+impl Protobuf<Any> for Header {}
+
+impl TryFrom<Any> for Header {
+    type Error = Ics02Error;
+
+    fn try_from(raw: Any) -> Result<Self, Ics02Error> {
+        use core::ops::Deref;
+
+        match raw.type_url.as_str() {
+            SOLOMACHINE_HEADER_TYPE_URL => decode_header(raw.value.deref()).map_err(Into::into),
+            _ => Err(Ics02Error::unknown_header_type(raw.type_url)),
+        }
+    }
+}
+
+impl From<Header> for Any {
+    fn from(header: Header) -> Self {
+        Any {
+            type_url: SOLOMACHINE_HEADER_TYPE_URL.to_string(),
+            value: Protobuf::<RawHeader>::encode_vec(&header),
+        }
+    }
+}
+
+pub fn decode_header<B: bytes::Buf>(buf: B) -> Result<Header, Error> {
+    use prost::Message;
+
+    RawHeader::decode(buf).map_err(Error::decode)?.try_into()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use ibc_proto::google::protobuf::Any;
+    use test_log::test;
+
+    use super::Header;
+    use crate::clients::ics06_solomachine::consensus_state::test_util::get_dummy_solomachine_consensus_state;
+    use crate::core::ics02_client::header::Header as _;
+    use crate::Height;
+
+    #[test]
+    fn header_to_and_from_any() {
+        let consensus_state = get_dummy_solomachine_consensus_state();
+        let header = Header {
+            sequence: 0,
+            timestamp: consensus_state.timestamp,
+            signature: vec![1, 2, 3],
+            new_public_key: consensus_state.public_key,
+            new_diversifier: "solo-1".to_string(),
+        };
+
+        let decoded = Header::try_from(Any::from(header.clone())).unwrap();
+        assert_eq!(header, decoded);
+        assert_eq!(decoded.height(), Height::new(0, 1).unwrap());
+    }
+}
@@ -0,0 +1,107 @@
+use crate::prelude::*;
+
+use ibc_proto::protobuf::Protobuf;
+use serde::{Deserialize, Serialize};
+
+use crate::clients::ics06_solomachine::error::Error;
+use crate::clients::ics06_solomachine::raw::{
+    Misbehaviour as RawMisbehaviour, SignatureAndData as RawSignatureAndData,
+};
+use crate::core::ics24_host::identifier::ClientId;
+use crate::Height;
+
+pub const SOLOMACHINE_MISBEHAVIOUR_TYPE_URL: &str = "/ibc.lightclients.solomachine.v3.Misbehaviour";
+
+/// A signature made by a solo machine over some data at a given path.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignatureAndData {
+    pub signature: Vec<u8>,
+    pub path: Vec<u8>,
+    pub data: Vec<u8>,
+    pub timestamp: u64,
+}
+
+impl From<RawSignatureAndData> for SignatureAndData {
+    fn from(raw: RawSignatureAndData) -> Self {
+        Self {
+            signature: raw.signature,
+            path: raw.path,
+            data: raw.data,
+            timestamp: raw.timestamp,
+        }
+    }
+}
+
+impl From<SignatureAndData> for RawSignatureAndData {
+    fn from(value: SignatureAndData) -> Self {
+        Self {
+            signature: value.signature,
+            path: value.path,
+            data: value.data,
+            timestamp: value.timestamp,
+        }
+    }
+}
+
+/// Evidence that a solo machine signed two different pieces of data
+/// with the same sequence.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Misbehaviour {
+    pub client_id: ClientId,
+    pub sequence: u64,
+    pub signature_one: SignatureAndData,
+    pub signature_two: SignatureAndData,
+}
+
+impl crate::core::ics02_client::misbehaviour::Misbehaviour for Misbehaviour {
+    fn client_id(&self) -> &ClientId {
+        &self.client_id
+    }
+
+    fn height(&self) -> Height {
+        Height::new(0, self.sequence).expect("sequence is never zero")
+    }
+}
+
+impl Protobuf<RawMisbehaviour> for Misbehaviour {}
+
+impl TryFrom<RawMisbehaviour> for Misbehaviour {
+    type Error = Error;
+
+    fn try_from(raw: RawMisbehaviour) -> Result<Self, Self::Error> {
+        if raw.sequence == 0 {
+            return Err(Error::invalid_raw_misbehaviour(
+                "sequence cannot be zero".into(),
+            ));
+        }
+
+        Ok(Self {
+            client_id: Default::default(),
+            sequence: raw.sequence,
+            signature_one: raw
+                .signature_one
+                .ok_or_else(|| Error::invalid_raw_misbehaviour("missing signature one".into()))?
+                .into(),
+            signature_two: raw
+                .signature_two
+                .ok_or_else(|| Error::invalid_raw_misbehaviour("missing signature two".into()))?
+                .into(),
+        })
+    }
+}
+
+impl From<Misbehaviour> for RawMisbehaviour {
+    fn from(value: Misbehaviour) -> Self {
+        RawMisbehaviour {
+            sequence: value.sequence,
+            signature_one: Some(value.signature_one.into()),
+            signature_two: Some(value.signature_two.into()),
+        }
+    }
+}
+
+impl core::fmt::Display for Misbehaviour {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+        write!(f, "{} sequence: {}", self.client_id, self.sequence)
+    }
+}
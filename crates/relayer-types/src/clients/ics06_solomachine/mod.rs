@@ -0,0 +1,13 @@
+//! ICS 06: Solo Machine Client implements a client verification algorithm for standalone
+//! machines, such as phones or browsers, which sign each piece of state they want to
+//! prove with a single public key instead of committing to it in a Merkle tree.
+
+pub mod client_state;
+pub mod consensus_state;
+pub mod error;
+pub mod header;
+pub mod misbehaviour;
+pub mod raw;
+
+/// Path under which the signature of a [`header::Header`] is made.
+pub const SENTINEL_HEADER_PATH: &str = "solomachine:header";
@@ -0,0 +1,185 @@
+//! The headers and misbehaviours submitted to a wasm client, which are both
+//! wrapped in a `ClientMessage`.
+
+use crate::prelude::*;
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::lightclients::tendermint::v1::Misbehaviour as RawTmMisbehaviour;
+use ibc_proto::protobuf::Protobuf;
+use serde::{Deserialize, Serialize};
+
+use crate::clients::ics07_tendermint::header::Header as TmHeader;
+use crate::clients::ics07_tendermint::misbehaviour::{
+    Misbehaviour as TmMisbehaviour, TENDERMINT_MISBEHAVIOR_TYPE_URL,
+};
+use crate::clients::ics08_wasm::error::Error;
+use crate::clients::ics08_wasm::raw::ClientMessage as RawClientMessage;
+use crate::clients::ics08_wasm::{decode_data, encode_data};
+use crate::core::ics02_client::client_type::ClientType;
+use crate::core::ics02_client::error::Error as Ics02Error;
+use crate::core::ics24_host::identifier::ClientId;
+use crate::timestamp::Timestamp;
+use crate::Height;
+
+pub const WASM_CLIENT_MESSAGE_TYPE_URL: &str = "/ibc.lightclients.wasm.v1.ClientMessage";
+
+/// A header for a wasm client.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Header {
+    pub inner: TmHeader,
+}
+
+impl Header {
+    pub fn new(inner: TmHeader) -> Self {
+        Self { inner }
+    }
+}
+
+impl crate::core::ics02_client::header::Header for Header {
+    fn client_type(&self) -> ClientType {
+        ClientType::Wasm
+    }
+
+    fn height(&self) -> Height {
+        self.inner.height()
+    }
+
+    fn timestamp(&self) -> Timestamp {
+        self.inner.timestamp()
+    }
+}
+
+impl Protobuf<RawClientMessage> for Header {}
+
+impl TryFrom<RawClientMessage> for Header {
+    type Error = Error;
+
+    fn try_from(raw: RawClientMessage) -> Result<Self, Self::Error> {
+        let inner =
+            TmHeader::try_from(decode_data(&raw.data)?).map_err(Error::invalid_wrapped_value)?;
+
+        Ok(Self { inner })
+    }
+}
+
+impl From<Header> for RawClientMessage {
+    fn from(value: Header) -> Self {
+        RawClientMessage {
+            data: encode_data(value.inner.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for Header {}
+
+impl TryFrom<Any> for Header {
+    type Error = Ics02Error;
+
+    fn try_from(raw: Any) -> Result<Self, Ics02Error> {
+        use core::ops::Deref;
+
+        match raw.type_url.as_str() {
+            WASM_CLIENT_MESSAGE_TYPE_URL => decode_header(raw.value.deref()).map_err(Into::into),
+            _ => Err(Ics02Error::unknown_header_type(raw.type_url)),
+        }
+    }
+}
+
+impl From<Header> for Any {
+    fn from(header: Header) -> Self {
+        Any {
+            type_url: WASM_CLIENT_MESSAGE_TYPE_URL.to_string(),
+            value: Protobuf::<RawClientMessage>::encode_vec(&header),
+        }
+    }
+}
+
+pub fn decode_header<B: bytes::Buf>(buf: B) -> Result<Header, Error> {
+    use prost::Message;
+
+    RawClientMessage::decode(buf)
+        .map_err(Error::decode)?
+        .try_into()
+}
+
+/// A misbehaviour submitted to a wasm client.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Misbehaviour {
+    pub inner: TmMisbehaviour,
+}
+
+impl Misbehaviour {
+    pub fn new(inner: TmMisbehaviour) -> Self {
+        Self { inner }
+    }
+}
+
+impl crate::core::ics02_client::misbehaviour::Misbehaviour for Misbehaviour {
+    fn client_id(&self) -> &ClientId {
+        self.inner.client_id()
+    }
+
+    fn height(&self) -> Height {
+        self.inner.height()
+    }
+}
+
+impl Protobuf<RawClientMessage> for Misbehaviour {}
+
+impl TryFrom<RawClientMessage> for Misbehaviour {
+    type Error = Error;
+
+    fn try_from(raw: RawClientMessage) -> Result<Self, Self::Error> {
+        let any = decode_data(&raw.data)?;
+
+        if any.type_url != TENDERMINT_MISBEHAVIOR_TYPE_URL {
+            return Err(Error::invalid_wrapped_value(
+                Ics02Error::unknown_misbehaviour_type(any.type_url),
+            ));
+        }
+
+        let inner = Protobuf::<RawTmMisbehaviour>::decode_vec(&any.value)
+            .map_err(|e| Error::invalid_wrapped_value(Ics02Error::decode_raw_misbehaviour(e)))?;
+
+        Ok(Self { inner })
+    }
+}
+
+impl From<Misbehaviour> for RawClientMessage {
+    fn from(value: Misbehaviour) -> Self {
+        let inner = Any {
+            type_url: TENDERMINT_MISBEHAVIOR_TYPE_URL.to_string(),
+            value: Protobuf::<RawTmMisbehaviour>::encode_vec(&value.inner),
+        };
+
+        RawClientMessage {
+            data: encode_data(inner),
+        }
+    }
+}
+
+impl core::fmt::Display for Misbehaviour {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+        write!(f, "{}", self.inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_proto::google::protobuf::Any;
+    use test_log::test;
+
+    use super::Header;
+    use crate::clients::ics07_tendermint::header::test_util::get_dummy_ics07_header;
+    use crate::core::ics02_client::header::Header as _;
+
+    #[test]
+    fn header_to_and_from_any() {
+        let inner = get_dummy_ics07_header();
+        let header = Header::new(inner.clone());
+
+        let decoded = Header::try_from(Any::from(header.clone())).unwrap();
+        assert_eq!(header, decoded);
+        assert_eq!(decoded.height(), inner.height());
+    }
+}
@@ -0,0 +1,176 @@
+use crate::prelude::*;
+
+use core::time::Duration;
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::protobuf::Protobuf;
+use serde::{Deserialize, Serialize};
+
+use crate::clients::ics07_tendermint::client_state::ClientState as TmClientState;
+use crate::clients::ics08_wasm::error::Error;
+use crate::clients::ics08_wasm::raw::ClientState as RawClientState;
+use crate::clients::ics08_wasm::{decode_data, encode_data, CHECKSUM_LENGTH};
+use crate::core::ics02_client::client_state::{
+    ClientState as Ics2ClientState, UpgradeOptions as CoreUpgradeOptions,
+};
+use crate::core::ics02_client::client_type::ClientType;
+use crate::core::ics02_client::error::Error as Ics02Error;
+use crate::core::ics24_host::identifier::ChainId;
+use crate::Height;
+
+pub const WASM_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.wasm.v1.ClientState";
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ClientState {
+    /// The checksum of the light client contract which verifies the wrapped client state.
+    #[serde(serialize_with = "crate::serializers::ser_hex_upper")]
+    pub checksum: Vec<u8>,
+    pub latest_height: Height,
+    pub inner: TmClientState,
+}
+
+impl ClientState {
+    pub fn new(checksum: Vec<u8>, inner: TmClientState) -> Result<Self, Error> {
+        if checksum.len() != CHECKSUM_LENGTH {
+            return Err(Error::invalid_checksum(checksum.len()));
+        }
+
+        Ok(Self {
+            checksum,
+            latest_height: inner.latest_height,
+            inner,
+        })
+    }
+}
+
+impl Ics2ClientState for ClientState {
+    fn chain_id(&self) -> ChainId {
+        self.inner.chain_id()
+    }
+
+    fn client_type(&self) -> ClientType {
+        ClientType::Wasm
+    }
+
+    fn latest_height(&self) -> Height {
+        self.latest_height
+    }
+
+    fn frozen_height(&self) -> Option<Height> {
+        self.inner.frozen_height()
+    }
+
+    fn upgrade(
+        &mut self,
+        upgrade_height: Height,
+        upgrade_options: &dyn CoreUpgradeOptions,
+        chain_id: ChainId,
+    ) {
+        self.inner
+            .upgrade(upgrade_height, upgrade_options, chain_id);
+        self.latest_height = self.inner.latest_height;
+    }
+
+    fn expired(&self, elapsed: Duration) -> bool {
+        self.inner.expired(elapsed)
+    }
+}
+
+impl Protobuf<RawClientState> for ClientState {}
+
+impl TryFrom<RawClientState> for ClientState {
+    type Error = Error;
+
+    fn try_from(raw: RawClientState) -> Result<Self, Self::Error> {
+        let inner = TmClientState::try_from(decode_data(&raw.data)?)
+            .map_err(Error::invalid_wrapped_value)?;
+
+        let latest_height = raw
+            .latest_height
+            .ok_or_else(Error::missing_latest_height)?
+            .try_into()
+            .map_err(|_| Error::missing_latest_height())?;
+
+        let mut client_state = Self::new(raw.checksum, inner)?;
+        client_state.latest_height = latest_height;
+
+        Ok(client_state)
+    }
+}
+
+impl From<ClientState> for RawClientState {
+    fn from(value: ClientState) -> Self {
+        RawClientState {
+            data: encode_data(value.inner.into()),
+            checksum: value.checksum,
+            latest_height: Some(value.latest_height.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = Ics02Error;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        use bytes::Buf;
+        use core::ops::Deref;
+        use prost::Message;
+
+        fn decode_client_state<B: Buf>(buf: B) -> Result<ClientState, Error> {
+            RawClientState::decode(buf)
+                .map_err(Error::decode)?
+                .try_into()
+        }
+
+        match raw.type_url.as_str() {
+            WASM_CLIENT_STATE_TYPE_URL => {
+                decode_client_state(raw.value.deref()).map_err(Into::into)
+            }
+            _ => Err(Ics02Error::unknown_client_state_type(raw.type_url)),
+        }
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        Any {
+            type_url: WASM_CLIENT_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawClientState>::encode_vec(&client_state),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    use ibc_proto::google::protobuf::Any;
+    use test_log::test;
+
+    use super::ClientState;
+    use crate::clients::ics07_tendermint::client_state::test_util::get_dummy_tendermint_client_state;
+    use crate::clients::ics07_tendermint::header::test_util::get_dummy_tendermint_header;
+    use crate::core::ics02_client::client_state::ClientState as _;
+    use crate::core::ics02_client::client_type::ClientType;
+
+    #[test]
+    fn client_state_to_and_from_any() {
+        let inner = get_dummy_tendermint_client_state(get_dummy_tendermint_header());
+        let client_state = ClientState::new(vec![0xab; 32], inner.clone()).unwrap();
+
+        assert_eq!(client_state.latest_height(), inner.latest_height());
+        assert_eq!(client_state.chain_id(), inner.chain_id());
+        assert_eq!(client_state.client_type(), ClientType::Wasm);
+
+        let decoded = ClientState::try_from(Any::from(client_state.clone())).unwrap();
+        assert_eq!(client_state, decoded);
+    }
+
+    #[test]
+    fn client_state_with_invalid_checksum() {
+        let inner = get_dummy_tendermint_client_state(get_dummy_tendermint_header());
+        assert!(ClientState::new(vec![0xab; 31], inner).is_err());
+    }
+}
@@ -0,0 +1,96 @@
+use crate::prelude::*;
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::protobuf::Protobuf;
+use serde::{Deserialize, Serialize};
+
+use crate::clients::ics07_tendermint::consensus_state::ConsensusState as TmConsensusState;
+use crate::clients::ics08_wasm::error::Error;
+use crate::clients::ics08_wasm::raw::ConsensusState as RawConsensusState;
+use crate::clients::ics08_wasm::{decode_data, encode_data};
+use crate::core::ics02_client::client_type::ClientType;
+use crate::core::ics02_client::error::Error as Ics02Error;
+use crate::core::ics23_commitment::commitment::CommitmentRoot;
+use crate::timestamp::Timestamp;
+
+pub const WASM_CONSENSUS_STATE_TYPE_URL: &str = "/ibc.lightclients.wasm.v1.ConsensusState";
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConsensusState {
+    pub inner: TmConsensusState,
+}
+
+impl ConsensusState {
+    pub fn new(inner: TmConsensusState) -> Self {
+        Self { inner }
+    }
+}
+
+impl crate::core::ics02_client::consensus_state::ConsensusState for ConsensusState {
+    fn client_type(&self) -> ClientType {
+        ClientType::Wasm
+    }
+
+    fn root(&self) -> &CommitmentRoot {
+        self.inner.root()
+    }
+
+    fn timestamp(&self) -> Timestamp {
+        self.inner.timestamp()
+    }
+}
+
+impl Protobuf<RawConsensusState> for ConsensusState {}
+
+impl TryFrom<RawConsensusState> for ConsensusState {
+    type Error = Error;
+
+    fn try_from(raw: RawConsensusState) -> Result<Self, Self::Error> {
+        let inner = TmConsensusState::try_from(decode_data(&raw.data)?)
+            .map_err(Error::invalid_wrapped_value)?;
+
+        Ok(Self { inner })
+    }
+}
+
+impl From<ConsensusState> for RawConsensusState {
+    fn from(value: ConsensusState) -> Self {
+        RawConsensusState {
+            data: encode_data(value.inner.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for ConsensusState {}
+
+impl TryFrom<Any> for ConsensusState {
+    type Error = Ics02Error;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        use bytes::Buf;
+        use core::ops::Deref;
+        use prost::Message;
+
+        fn decode_consensus_state<B: Buf>(buf: B) -> Result<ConsensusState, Error> {
+            RawConsensusState::decode(buf)
+                .map_err(Error::decode)?
+                .try_into()
+        }
+
+        match raw.type_url.as_str() {
+            WASM_CONSENSUS_STATE_TYPE_URL => {
+                decode_consensus_state(raw.value.deref()).map_err(Into::into)
+            }
+            _ => Err(Ics02Error::unknown_consensus_state_type(raw.type_url)),
+        }
+    }
+}
+
+impl From<ConsensusState> for Any {
+    fn from(consensus_state: ConsensusState) -> Self {
+        Any {
+            type_url: WASM_CONSENSUS_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawConsensusState>::encode_vec(&consensus_state),
+        }
+    }
+}
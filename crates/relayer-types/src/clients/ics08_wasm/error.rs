@@ -0,0 +1,38 @@
+use crate::prelude::*;
+
+use flex_error::{define_error, TraceError};
+
+use crate::core::ics02_client::error::Error as Ics02Error;
+
+define_error! {
+    #[derive(Debug, PartialEq, Eq)]
+    Error {
+        Decode
+            [ TraceError<prost::DecodeError> ]
+            | _ | { "decode error" },
+
+        InvalidChecksum
+            { length: usize }
+            |e| {
+                format_args!("invalid checksum: expected {} bytes, got {}",
+                    super::CHECKSUM_LENGTH, e.length)
+            },
+
+        InvalidRawClientState
+            { reason: String }
+            |e| { format_args!("invalid raw client state: {}", e.reason) },
+
+        MissingLatestHeight
+            |_| { "missing latest height" },
+
+        InvalidWrappedValue
+            [ TraceError<Ics02Error> ]
+            |_| { "invalid wrapped value" },
+    }
+}
+
+impl From<Error> for Ics02Error {
+    fn from(e: Error) -> Self {
+        Self::client_specific(e.to_string())
+    }
+}
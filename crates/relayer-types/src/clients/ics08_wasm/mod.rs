@@ -0,0 +1,32 @@
+//! ICS 08: Wasm Client wraps the client state, consensus states and headers of a light
+//! client implemented as a CosmWasm contract, which the host chain runs through its
+//! `08-wasm` module.
+//!
+//! The wrapped values are encoded as `google.protobuf.Any` in the `data` field of the
+//! wasm types. Only Tendermint light clients can currently be wrapped.
+
+pub mod client_message;
+pub mod client_state;
+pub mod consensus_state;
+pub mod error;
+pub mod raw;
+
+/// Length of the checksum of a light client contract, ie. of the SHA-256 hash of its code.
+pub const CHECKSUM_LENGTH: usize = 32;
+
+use ibc_proto::google::protobuf::Any;
+use prost::Message;
+
+use crate::prelude::*;
+
+use self::error::Error;
+
+/// Encodes a wrapped value into the `data` field of a wasm type.
+fn encode_data(value: Any) -> Vec<u8> {
+    value.encode_to_vec()
+}
+
+/// Decodes the wrapped value held in the `data` field of a wasm type.
+fn decode_data(data: &[u8]) -> Result<Any, Error> {
+    Any::decode(data).map_err(Error::decode)
+}
@@ -0,0 +1,30 @@
+//! Protobuf definitions for the `08-wasm` light client of ibc-go v8,
+//! which `ibc-proto` does not provide yet.
+//!
+//! Field numbers follow `ibc/lightclients/wasm/v1/wasm.proto`.
+
+use crate::prelude::*;
+
+use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClientState {
+    #[prost(bytes = "vec", tag = "1")]
+    pub data: Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub checksum: Vec<u8>,
+    #[prost(message, optional, tag = "3")]
+    pub latest_height: Option<RawHeight>,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ConsensusState {
+    #[prost(bytes = "vec", tag = "1")]
+    pub data: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClientMessage {
+    #[prost(bytes = "vec", tag = "1")]
+    pub data: Vec<u8>,
+}
@@ -0,0 +1,157 @@
+use crate::prelude::*;
+
+use core::time::Duration;
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::protobuf::Protobuf;
+use serde::{Deserialize, Serialize};
+
+use crate::clients::ics09_localhost::error::Error;
+use crate::clients::ics09_localhost::raw::ClientState as RawClientState;
+use crate::core::ics02_client::client_state::{
+    ClientState as Ics2ClientState, UpgradeOptions as CoreUpgradeOptions,
+};
+use crate::core::ics02_client::client_type::ClientType;
+use crate::core::ics02_client::error::Error as Ics02Error;
+use crate::core::ics24_host::identifier::ChainId;
+use crate::Height;
+
+pub const LOCALHOST_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.localhost.v2.ClientState";
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClientState {
+    /// Identifier of the chain hosting the client, which is also the chain it verifies.
+    ///
+    /// The on-chain client state does not record it, so a decoded client state holds
+    /// the default chain identifier until [`ClientState::with_chain_id`] is called.
+    pub chain_id: ChainId,
+    pub latest_height: Height,
+}
+
+impl ClientState {
+    pub fn new(chain_id: ChainId, latest_height: Height) -> Self {
+        Self {
+            chain_id,
+            latest_height,
+        }
+    }
+
+    pub fn with_chain_id(self, chain_id: ChainId) -> Self {
+        Self { chain_id, ..self }
+    }
+}
+
+impl Ics2ClientState for ClientState {
+    fn chain_id(&self) -> ChainId {
+        self.chain_id.clone()
+    }
+
+    fn client_type(&self) -> ClientType {
+        ClientType::Localhost
+    }
+
+    fn latest_height(&self) -> Height {
+        self.latest_height
+    }
+
+    fn frozen_height(&self) -> Option<Height> {
+        // The localhost client can never be frozen
+        None
+    }
+
+    fn upgrade(
+        &mut self,
+        upgrade_height: Height,
+        _upgrade_options: &dyn CoreUpgradeOptions,
+        chain_id: ChainId,
+    ) {
+        self.chain_id = chain_id;
+        self.latest_height = upgrade_height;
+    }
+
+    fn expired(&self, _elapsed: Duration) -> bool {
+        // The localhost client is updated by the host chain at every block
+        false
+    }
+}
+
+impl Protobuf<RawClientState> for ClientState {}
+
+impl TryFrom<RawClientState> for ClientState {
+    type Error = Error;
+
+    fn try_from(raw: RawClientState) -> Result<Self, Self::Error> {
+        let latest_height = raw
+            .latest_height
+            .ok_or_else(Error::missing_latest_height)?
+            .try_into()
+            .map_err(|_| Error::missing_latest_height())?;
+
+        Ok(Self::new(ChainId::default(), latest_height))
+    }
+}
+
+impl From<ClientState> for RawClientState {
+    fn from(value: ClientState) -> Self {
+        RawClientState {
+            latest_height: Some(value.latest_height.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = Ics02Error;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        use bytes::Buf;
+        use core::ops::Deref;
+        use prost::Message;
+
+        fn decode_client_state<B: Buf>(buf: B) -> Result<ClientState, Error> {
+            RawClientState::decode(buf)
+                .map_err(Error::decode)?
+                .try_into()
+        }
+
+        match raw.type_url.as_str() {
+            LOCALHOST_CLIENT_STATE_TYPE_URL => {
+                decode_client_state(raw.value.deref()).map_err(Into::into)
+            }
+            _ => Err(Ics02Error::unknown_client_state_type(raw.type_url)),
+        }
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        Any {
+            type_url: LOCALHOST_CLIENT_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawClientState>::encode_vec(&client_state),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    use ibc_proto::google::protobuf::Any;
+    use test_log::test;
+
+    use super::ClientState;
+    use crate::core::ics02_client::client_state::ClientState as _;
+    use crate::core::ics24_host::identifier::ChainId;
+    use crate::Height;
+
+    #[test]
+    fn client_state_to_and_from_any() {
+        let chain_id = ChainId::new("ibc".to_string(), 1);
+        let client_state = ClientState::new(chain_id.clone(), Height::new(1, 10).unwrap());
+
+        let decoded = ClientState::try_from(Any::from(client_state.clone())).unwrap();
+        assert_eq!(decoded.latest_height(), client_state.latest_height());
+        assert_eq!(decoded.with_chain_id(chain_id), client_state);
+    }
+}
@@ -0,0 +1,23 @@
+use crate::prelude::*;
+
+use flex_error::{define_error, TraceError};
+
+use crate::core::ics02_client::error::Error as Ics02Error;
+
+define_error! {
+    #[derive(Debug, PartialEq, Eq)]
+    Error {
+        Decode
+            [ TraceError<prost::DecodeError> ]
+            | _ | { "decode error" },
+
+        MissingLatestHeight
+            |_| { "missing latest height" },
+    }
+}
+
+impl From<Error> for Ics02Error {
+    fn from(e: Error) -> Self {
+        Self::client_specific(e.to_string())
+    }
+}
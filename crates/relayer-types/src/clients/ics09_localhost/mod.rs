@@ -0,0 +1,76 @@
+//! ICS 09: Localhost Client lets a chain verify its own state, so that a connection
+//! and channels can be opened between two modules of the same chain.
+//!
+//! Since ibc-go v7.1, the `09-localhost` client and the `connection-localhost` connection
+//! are created by the host chain itself and never need to be updated. Membership proofs
+//! are not checked against a commitment root: the client reads the values directly from
+//! the store of the host chain, and only requires the proof to be the sentinel proof.
+
+pub mod client_state;
+pub mod error;
+pub mod raw;
+
+use crate::prelude::*;
+
+use crate::core::ics23_commitment::commitment::CommitmentProofBytes;
+use crate::proofs::Proofs;
+
+/// Identifier of the localhost client, which is unique on every chain.
+pub const LOCALHOST_CLIENT_ID: &str = "09-localhost";
+
+/// Identifier of the connection which uses the localhost client on both of its ends.
+pub const LOCALHOST_CONNECTION_ID: &str = "connection-localhost";
+
+/// The proof expected by the localhost client in place of any membership proof.
+pub const SENTINEL_PROOF: &[u8] = &[0x01];
+
+/// Returns the sentinel proof as [`CommitmentProofBytes`].
+pub fn sentinel_proof() -> CommitmentProofBytes {
+    CommitmentProofBytes::try_from(SENTINEL_PROOF.to_vec())
+        .expect("the sentinel proof is not empty")
+}
+
+/// Replaces every proof in `proofs` with the sentinel proof, keeping the proof height.
+///
+/// The client and consensus state proofs are dropped, since the localhost client
+/// never verifies them.
+pub fn sentinel_proofs(proofs: &Proofs) -> Proofs {
+    Proofs::new(
+        sentinel_proof(),
+        None,
+        None,
+        proofs.other_proof().as_ref().map(|_| sentinel_proof()),
+        proofs.height(),
+    )
+    .expect("the sentinel proofs are valid")
+}
+
+#[cfg(test)]
+mod tests {
+    use test_log::test;
+
+    use super::*;
+    use crate::Height;
+
+    #[test]
+    fn sentinel_proofs_keep_shape_and_height() {
+        let height = Height::new(1, 42).unwrap();
+        let proof = CommitmentProofBytes::try_from(vec![0xab; 8]).unwrap();
+
+        let proofs = Proofs::new(
+            proof.clone(),
+            Some(proof.clone()),
+            None,
+            Some(proof),
+            height,
+        )
+        .unwrap();
+
+        let sentinel = sentinel_proofs(&proofs);
+
+        assert_eq!(sentinel.height(), height);
+        assert_eq!(sentinel.object_proof(), &sentinel_proof());
+        assert_eq!(sentinel.client_proof(), &None);
+        assert_eq!(sentinel.other_proof(), &Some(sentinel_proof()));
+    }
+}
@@ -0,0 +1,12 @@
+//! Protobuf definitions for the `09-localhost` light client of ibc-go v7.1,
+//! which `ibc-proto` does not provide yet.
+//!
+//! Field numbers follow `ibc/lightclients/localhost/v2/localhost.proto`.
+
+use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClientState {
+    #[prost(message, optional, tag = "1")]
+    pub latest_height: Option<RawHeight>,
+}
@@ -7,14 +7,20 @@ use super::error::Error;
 /// Type of the client, depending on the specific consensus algorithm.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum ClientType {
+    Solomachine = 6,
     Tendermint = 1,
+    Wasm = 8,
+    Localhost = 9,
 
     #[cfg(any(test, feature = "mocks"))]
     Mock = 9999,
 }
 
 impl ClientType {
+    const SOLOMACHINE_STR: &'static str = "06-solomachine";
     const TENDERMINT_STR: &'static str = "07-tendermint";
+    const WASM_STR: &'static str = "08-wasm";
+    const LOCALHOST_STR: &'static str = "09-localhost";
 
     #[cfg_attr(not(test), allow(dead_code))]
     const MOCK_STR: &'static str = "9999-mock";
@@ -22,7 +28,10 @@ impl ClientType {
     /// Yields the identifier of this client type as a string
     pub fn as_str(&self) -> &'static str {
         match self {
+            Self::Solomachine => Self::SOLOMACHINE_STR,
             Self::Tendermint => Self::TENDERMINT_STR,
+            Self::Wasm => Self::WASM_STR,
+            Self::Localhost => Self::LOCALHOST_STR,
 
             #[cfg(any(test, feature = "mocks"))]
             Self::Mock => Self::MOCK_STR,
@@ -41,7 +50,10 @@ impl core::str::FromStr for ClientType {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
+            Self::SOLOMACHINE_STR => Ok(Self::Solomachine),
             Self::TENDERMINT_STR => Ok(Self::Tendermint),
+            Self::WASM_STR => Ok(Self::Wasm),
+            Self::LOCALHOST_STR => Ok(Self::Localhost),
 
             #[cfg(any(test, feature = "mocks"))]
             Self::MOCK_STR => Ok(Self::Mock),
@@ -69,6 +81,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_solomachine_client_type() {
+        let client_type = ClientType::from_str("06-solomachine");
+
+        match client_type {
+            Ok(ClientType::Solomachine) => (),
+            _ => panic!("parse failed"),
+        }
+    }
+
+    #[test]
+    fn parse_wasm_client_type() {
+        let client_type = ClientType::from_str("08-wasm");
+
+        match client_type {
+            Ok(ClientType::Wasm) => (),
+            _ => panic!("parse failed"),
+        }
+    }
+
+    #[test]
+    fn parse_localhost_client_type() {
+        let client_type = ClientType::from_str("09-localhost");
+
+        match client_type {
+            Ok(ClientType::Localhost) => (),
+            _ => panic!("parse failed"),
+        }
+    }
+
     #[test]
     fn parse_mock_client_type() {
         let client_type = ClientType::from_str("9999-mock");
@@ -11,6 +11,7 @@ use crate::core::ics02_client::msgs::upgrade_client::MsgUpgradeClient;
 
 pub mod create_client;
 pub mod misbehaviour;
+pub mod recover_client;
 pub mod update_client;
 pub mod upgrade_client;
 
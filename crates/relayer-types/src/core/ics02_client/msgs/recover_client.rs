@@ -0,0 +1,120 @@
+//! Definition of domain type message `MsgRecoverClient`, introduced in ibc-go v8
+//! to replace the legacy `ClientUpdateProposal` governance proposal.
+
+use crate::prelude::*;
+
+use ibc_proto::protobuf::Protobuf;
+
+use crate::core::ics02_client::error::Error;
+use crate::core::ics24_host::error::ValidationError;
+use crate::core::ics24_host::identifier::ClientId;
+use crate::signer::Signer;
+use crate::tx_msg::Msg;
+
+pub const TYPE_URL: &str = "/ibc.core.client.v1.MsgRecoverClient";
+
+/// The `MsgRecoverClient` protobuf message, which `ibc-proto` does not provide yet.
+///
+/// Field numbers follow `ibc/core/client/v1/tx.proto`.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RawMsgRecoverClient {
+    #[prost(string, tag = "1")]
+    pub subject_client_id: String,
+    #[prost(string, tag = "2")]
+    pub substitute_client_id: String,
+    #[prost(string, tag = "3")]
+    pub signer: String,
+}
+
+/// A message which replaces the state of an expired or frozen client, the subject,
+/// with the state of an active client tracking the same chain, the substitute.
+///
+/// The signer must be the authority of the IBC client module, which is usually
+/// the governance module, so that the message is typically submitted as part of
+/// a governance proposal.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgRecoverClient {
+    pub subject_client_id: ClientId,
+    pub substitute_client_id: ClientId,
+    pub signer: Signer,
+}
+
+impl MsgRecoverClient {
+    pub fn new(
+        subject_client_id: ClientId,
+        substitute_client_id: ClientId,
+        signer: Signer,
+    ) -> Self {
+        MsgRecoverClient {
+            subject_client_id,
+            substitute_client_id,
+            signer,
+        }
+    }
+}
+
+impl Msg for MsgRecoverClient {
+    type ValidationError = ValidationError;
+    type Raw = RawMsgRecoverClient;
+
+    fn route(&self) -> String {
+        crate::keys::ROUTER_KEY.to_string()
+    }
+
+    fn type_url(&self) -> String {
+        TYPE_URL.to_string()
+    }
+}
+
+impl Protobuf<RawMsgRecoverClient> for MsgRecoverClient {}
+
+impl TryFrom<RawMsgRecoverClient> for MsgRecoverClient {
+    type Error = Error;
+
+    fn try_from(raw: RawMsgRecoverClient) -> Result<Self, Self::Error> {
+        Ok(MsgRecoverClient {
+            subject_client_id: raw
+                .subject_client_id
+                .parse()
+                .map_err(Error::invalid_client_identifier)?,
+            substitute_client_id: raw
+                .substitute_client_id
+                .parse()
+                .map_err(Error::invalid_client_identifier)?,
+            signer: raw.signer.parse().map_err(Error::signer)?,
+        })
+    }
+}
+
+impl From<MsgRecoverClient> for RawMsgRecoverClient {
+    fn from(ics_msg: MsgRecoverClient) -> Self {
+        RawMsgRecoverClient {
+            subject_client_id: ics_msg.subject_client_id.to_string(),
+            substitute_client_id: ics_msg.substitute_client_id.to_string(),
+            signer: ics_msg.signer.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_log::test;
+
+    use super::{MsgRecoverClient, RawMsgRecoverClient};
+    use crate::core::ics24_host::identifier::ClientId;
+    use crate::test_utils::get_dummy_account_id;
+
+    #[test]
+    fn msg_recover_client_serialization() {
+        let subject_client_id: ClientId = "07-tendermint-0".parse().unwrap();
+        let substitute_client_id: ClientId = "07-tendermint-1".parse().unwrap();
+        let signer = get_dummy_account_id();
+
+        let msg = MsgRecoverClient::new(subject_client_id, substitute_client_id, signer);
+        let raw = RawMsgRecoverClient::from(msg.clone());
+        let msg_back = MsgRecoverClient::try_from(raw.clone()).unwrap();
+        let raw_back = RawMsgRecoverClient::from(msg_back.clone());
+        assert_eq!(msg, msg_back);
+        assert_eq!(raw, raw_back);
+    }
+}
@@ -12,6 +12,8 @@ use ibc_proto::ibc::core::channel::v1::{
     IdentifiedChannel as RawIdentifiedChannel,
 };
 
+use crate::core::ics04_channel::packet::Sequence;
+use crate::core::ics04_channel::upgrade::raw::Channel as RawUpgradableChannel;
 use crate::core::ics04_channel::{error::Error, version::Version};
 use crate::core::ics24_host::identifier::{ChannelId, ConnectionId, PortId};
 
@@ -80,14 +82,16 @@ pub struct ChannelEnd {
     pub remote: Counterparty,
     pub connection_hops: Vec<ConnectionId>,
     pub version: Version,
+    #[serde(default)]
+    pub upgrade_sequence: Sequence,
 }
 
 impl Display for ChannelEnd {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
         write!(
             f,
-            "ChannelEnd {{ state: {}, ordering: {}, remote: {}, connection_hops: {}, version: {} }}",
-            self.state, self.ordering, self.remote, PrettySlice(&self.connection_hops), self.version
+            "ChannelEnd {{ state: {}, ordering: {}, remote: {}, connection_hops: {}, version: {}, upgrade_sequence: {} }}",
+            self.state, self.ordering, self.remote, PrettySlice(&self.connection_hops), self.version, self.upgrade_sequence
         )
     }
 }
@@ -100,6 +104,7 @@ impl Default for ChannelEnd {
             remote: Counterparty::default(),
             connection_hops: Vec::new(),
             version: Version::default(),
+            upgrade_sequence: Sequence::default(),
         }
     }
 }
@@ -160,6 +165,44 @@ impl From<ChannelEnd> for RawChannel {
     }
 }
 
+impl Protobuf<RawUpgradableChannel> for ChannelEnd {}
+
+/// Decodes a channel end as stored by chains supporting channel upgrades,
+/// which also carries the upgrade sequence of the channel.
+impl TryFrom<RawUpgradableChannel> for ChannelEnd {
+    type Error = Error;
+
+    fn try_from(value: RawUpgradableChannel) -> Result<Self, Self::Error> {
+        let upgrade_sequence = Sequence::from(value.upgrade_sequence);
+
+        let channel_end = ChannelEnd::try_from(RawChannel {
+            state: value.state,
+            ordering: value.ordering,
+            counterparty: value.counterparty,
+            connection_hops: value.connection_hops,
+            version: value.version,
+        })?;
+
+        Ok(channel_end.with_upgrade_sequence(upgrade_sequence))
+    }
+}
+
+impl From<ChannelEnd> for RawUpgradableChannel {
+    fn from(value: ChannelEnd) -> Self {
+        let upgrade_sequence = value.upgrade_sequence.into();
+        let raw = RawChannel::from(value);
+
+        RawUpgradableChannel {
+            state: raw.state,
+            ordering: raw.ordering,
+            counterparty: raw.counterparty,
+            connection_hops: raw.connection_hops,
+            version: raw.version,
+            upgrade_sequence,
+        }
+    }
+}
+
 impl ChannelEnd {
     /// Creates a new ChannelEnd in state Uninitialized and other fields parametrized.
     pub fn new(
@@ -175,6 +218,15 @@ impl ChannelEnd {
             remote,
             connection_hops,
             version,
+            upgrade_sequence: Sequence::default(),
+        }
+    }
+
+    /// Sets the sequence of the last upgrade attempted on this channel.
+    pub fn with_upgrade_sequence(self, upgrade_sequence: Sequence) -> Self {
+        Self {
+            upgrade_sequence,
+            ..self
         }
     }
 
@@ -216,6 +268,10 @@ impl ChannelEnd {
         &self.version
     }
 
+    pub fn upgrade_sequence(&self) -> Sequence {
+        self.upgrade_sequence
+    }
+
     pub fn validate_basic(&self) -> Result<(), Error> {
         if self.connection_hops.len() != 1 {
             return Err(Error::invalid_connection_hops_length(
@@ -379,6 +435,8 @@ pub enum State {
     TryOpen = 2,
     Open = 3,
     Closed = 4,
+    Flushing = 5,
+    FlushComplete = 6,
 }
 
 impl State {
@@ -390,6 +448,8 @@ impl State {
             Self::TryOpen => "TRYOPEN",
             Self::Open => "OPEN",
             Self::Closed => "CLOSED",
+            Self::Flushing => "FLUSHING",
+            Self::FlushComplete => "FLUSHCOMPLETE",
         }
     }
 
@@ -401,6 +461,8 @@ impl State {
             2 => Ok(Self::TryOpen),
             3 => Ok(Self::Open),
             4 => Ok(Self::Closed),
+            5 => Ok(Self::Flushing),
+            6 => Ok(Self::FlushComplete),
             _ => Err(Error::unknown_state(s)),
         }
     }
@@ -415,9 +477,18 @@ impl State {
         self == State::Closed
     }
 
+    /// Returns whether or not the channel is in the middle of an upgrade,
+    /// ie. flushing its in-flight packets before the upgrade can complete.
+    pub fn is_upgrading(self) -> bool {
+        matches!(self, State::Flushing | State::FlushComplete)
+    }
+
     /// Returns whether or not the channel with this state
     /// has progressed less or the same than the argument.
     ///
+    /// Note that the upgrade states, `Flushing` and `FlushComplete`,
+    /// rank after `Closed`, and must be special-cased by the callers.
+    ///
     /// # Example
     /// ```rust,ignore
     /// assert!(State::Init.less_or_equal_progress(State::Open));
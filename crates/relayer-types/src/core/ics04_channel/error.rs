@@ -327,6 +327,25 @@ define_error! {
                     e.client_id, e.height)
             },
 
+        MissingUpgrade
+            | _ | { "missing channel upgrade" },
+
+        MissingUpgradeFields
+            | _ | { "missing channel upgrade fields" },
+
+        MissingUpgradeTimeout
+            | _ | { "missing channel upgrade timeout" },
+
+        InvalidUpgradeTimeout
+            | _ | { "channel upgrade timeout height and timestamp cannot both be zero" },
+
+        InvalidUpgradeTimestamp
+            [ crate::timestamp::ParseTimestampError ]
+            | _ | { "invalid channel upgrade timeout timestamp value" },
+
+        MissingErrorReceipt
+            | _ | { "missing channel upgrade error receipt" },
+
         RouteNotFound
             | _ | { "route not found" },
 
@@ -6,7 +6,7 @@ use serde_derive::{Deserialize, Serialize};
 use tendermint::abci;
 
 use crate::core::ics04_channel::error::Error;
-use crate::core::ics04_channel::packet::Packet;
+use crate::core::ics04_channel::packet::{Packet, Sequence};
 use crate::core::ics24_host::identifier::{ChannelId, ConnectionId, PortId};
 use crate::events::{Error as EventError, IbcEvent, IbcEventType};
 use crate::prelude::*;
@@ -30,6 +30,9 @@ pub const PKT_TIMEOUT_HEIGHT_ATTRIBUTE_KEY: &str = "packet_timeout_height";
 pub const PKT_TIMEOUT_TIMESTAMP_ATTRIBUTE_KEY: &str = "packet_timeout_timestamp";
 pub const PKT_ACK_ATTRIBUTE_KEY: &str = "packet_ack";
 
+/// Channel upgrade event attribute keys
+pub const UPGRADE_SEQUENCE_ATTRIBUTE_KEY: &str = "upgrade_sequence";
+
 #[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
 pub struct Attributes {
     pub port_id: PortId,
@@ -514,6 +517,171 @@ impl_from_ibc_to_abci_event!(
     CloseConfirm
 );
 
+/// Attributes shared by all the events of the channel upgrade handshake.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct UpgradeAttributes {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub counterparty_port_id: PortId,
+    pub counterparty_channel_id: Option<ChannelId>,
+    pub upgrade_sequence: Sequence,
+}
+
+impl UpgradeAttributes {
+    pub fn port_id(&self) -> &PortId {
+        &self.port_id
+    }
+
+    pub fn channel_id(&self) -> &ChannelId {
+        &self.channel_id
+    }
+}
+
+impl Display for UpgradeAttributes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        match &self.counterparty_channel_id {
+            Some(counterparty_channel_id) => write!(f, "UpgradeAttributes {{ port_id: {}, channel_id: {}, counterparty_port_id: {}, counterparty_channel_id: {}, upgrade_sequence: {} }}", self.port_id, self.channel_id, self.counterparty_port_id, counterparty_channel_id, self.upgrade_sequence),
+            None => write!(f, "UpgradeAttributes {{ port_id: {}, channel_id: {}, counterparty_port_id: {}, counterparty_channel_id: None, upgrade_sequence: {} }}", self.port_id, self.channel_id, self.counterparty_port_id, self.upgrade_sequence),
+        }
+    }
+}
+
+/// Convert attributes to Tendermint ABCI tags
+impl From<UpgradeAttributes> for Vec<abci::EventAttribute> {
+    fn from(a: UpgradeAttributes) -> Self {
+        let mut attributes = vec![];
+        let port_id = (PORT_ID_ATTRIBUTE_KEY, a.port_id.as_str()).into();
+        attributes.push(port_id);
+        let channel_id = (CHANNEL_ID_ATTRIBUTE_KEY, a.channel_id.as_str()).into();
+        attributes.push(channel_id);
+        let counterparty_port_id = (
+            COUNTERPARTY_PORT_ID_ATTRIBUTE_KEY,
+            a.counterparty_port_id.as_str(),
+        )
+            .into();
+        attributes.push(counterparty_port_id);
+        if let Some(channel_id) = a.counterparty_channel_id {
+            let channel_id = (COUNTERPARTY_CHANNEL_ID_ATTRIBUTE_KEY, channel_id.as_str()).into();
+            attributes.push(channel_id);
+        }
+        let upgrade_sequence = (
+            UPGRADE_SEQUENCE_ATTRIBUTE_KEY,
+            a.upgrade_sequence.to_string(),
+        )
+            .into();
+        attributes.push(upgrade_sequence);
+        attributes
+    }
+}
+
+macro_rules! impl_channel_upgrade_event {
+    ($($(#[$doc:meta])* $event:ident => $event_type:ident),+ $(,)?) => {
+        $(
+            $(#[$doc])*
+            #[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+            pub struct $event {
+                pub port_id: PortId,
+                pub channel_id: ChannelId,
+                pub counterparty_port_id: PortId,
+                pub counterparty_channel_id: Option<ChannelId>,
+                pub upgrade_sequence: Sequence,
+            }
+
+            impl $event {
+                pub fn port_id(&self) -> &PortId {
+                    &self.port_id
+                }
+
+                pub fn channel_id(&self) -> &ChannelId {
+                    &self.channel_id
+                }
+
+                pub fn counterparty_port_id(&self) -> &PortId {
+                    &self.counterparty_port_id
+                }
+
+                pub fn counterparty_channel_id(&self) -> Option<&ChannelId> {
+                    self.counterparty_channel_id.as_ref()
+                }
+            }
+
+            impl Display for $event {
+                fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+                    match &self.counterparty_channel_id {
+                        Some(counterparty_channel_id) => write!(f, "{} {{ port_id: {}, channel_id: {}, counterparty_port_id: {}, counterparty_channel_id: {}, upgrade_sequence: {} }}", stringify!($event), self.port_id, self.channel_id, self.counterparty_port_id, counterparty_channel_id, self.upgrade_sequence),
+                        None => write!(f, "{} {{ port_id: {}, channel_id: {}, counterparty_port_id: {}, counterparty_channel_id: None, upgrade_sequence: {} }}", stringify!($event), self.port_id, self.channel_id, self.counterparty_port_id, self.upgrade_sequence),
+                    }
+                }
+            }
+
+            impl From<$event> for UpgradeAttributes {
+                fn from(ev: $event) -> Self {
+                    Self {
+                        port_id: ev.port_id,
+                        channel_id: ev.channel_id,
+                        counterparty_port_id: ev.counterparty_port_id,
+                        counterparty_channel_id: ev.counterparty_channel_id,
+                        upgrade_sequence: ev.upgrade_sequence,
+                    }
+                }
+            }
+
+            impl From<UpgradeAttributes> for $event {
+                fn from(attrs: UpgradeAttributes) -> Self {
+                    Self {
+                        port_id: attrs.port_id,
+                        channel_id: attrs.channel_id,
+                        counterparty_port_id: attrs.counterparty_port_id,
+                        counterparty_channel_id: attrs.counterparty_channel_id,
+                        upgrade_sequence: attrs.upgrade_sequence,
+                    }
+                }
+            }
+
+            impl From<$event> for IbcEvent {
+                fn from(v: $event) -> Self {
+                    IbcEvent::$event_type(v)
+                }
+            }
+
+            impl EventType for $event {
+                fn event_type() -> IbcEventType {
+                    IbcEventType::$event_type
+                }
+            }
+
+            impl From<$event> for abci::Event {
+                fn from(v: $event) -> Self {
+                    Self {
+                        kind: <$event>::event_type().as_str().to_owned(),
+                        attributes: UpgradeAttributes::from(v).into(),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_channel_upgrade_event!(
+    /// Emitted when an upgrade of the channel is initiated, usually by governance.
+    UpgradeInit => UpgradeInitChannel,
+    /// Emitted when the counterparty upgrade has been accepted.
+    UpgradeTry => UpgradeTryChannel,
+    /// Emitted when the counterparty acceptance of the upgrade has been verified,
+    /// at which point the channel starts flushing its in-flight packets.
+    UpgradeAck => UpgradeAckChannel,
+    /// Emitted when the counterparty acknowledgement of the upgrade has been verified.
+    UpgradeConfirm => UpgradeConfirmChannel,
+    /// Emitted when the upgrade is complete and the channel is open again.
+    UpgradeOpen => UpgradeOpenChannel,
+    /// Emitted when the upgrade has been aborted after timing out on the counterparty.
+    UpgradeTimeout => UpgradeTimeoutChannel,
+    /// Emitted when the upgrade has been aborted after the counterparty aborted it.
+    UpgradeCancel => UpgradeCancelChannel,
+    /// Emitted when the upgrade fails and an error receipt is written.
+    UpgradeError => UpgradeErrorChannel,
+);
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize)]
 pub struct SendPacket {
     pub packet: Packet,
@@ -1,5 +1,5 @@
-//! Message definitions for all ICS4 domain types: channel open & close handshake datagrams, as well
-//! as packets.
+//! Message definitions for all ICS4 domain types: channel open, close & upgrade handshake
+//! datagrams, as well as packets.
 
 use crate::core::ics04_channel::msgs::acknowledgement::MsgAcknowledgement;
 use crate::core::ics04_channel::msgs::chan_close_confirm::MsgChannelCloseConfirm;
@@ -8,6 +8,13 @@ use crate::core::ics04_channel::msgs::chan_open_ack::MsgChannelOpenAck;
 use crate::core::ics04_channel::msgs::chan_open_confirm::MsgChannelOpenConfirm;
 use crate::core::ics04_channel::msgs::chan_open_init::MsgChannelOpenInit;
 use crate::core::ics04_channel::msgs::chan_open_try::MsgChannelOpenTry;
+use crate::core::ics04_channel::msgs::chan_upgrade_ack::MsgChannelUpgradeAck;
+use crate::core::ics04_channel::msgs::chan_upgrade_cancel::MsgChannelUpgradeCancel;
+use crate::core::ics04_channel::msgs::chan_upgrade_confirm::MsgChannelUpgradeConfirm;
+use crate::core::ics04_channel::msgs::chan_upgrade_init::MsgChannelUpgradeInit;
+use crate::core::ics04_channel::msgs::chan_upgrade_open::MsgChannelUpgradeOpen;
+use crate::core::ics04_channel::msgs::chan_upgrade_timeout::MsgChannelUpgradeTimeout;
+use crate::core::ics04_channel::msgs::chan_upgrade_try::MsgChannelUpgradeTry;
 use crate::core::ics04_channel::msgs::recv_packet::MsgRecvPacket;
 use crate::core::ics04_channel::msgs::timeout::MsgTimeout;
 use crate::core::ics04_channel::msgs::timeout_on_close::MsgTimeoutOnClose;
@@ -22,6 +29,15 @@ pub mod chan_open_try;
 pub mod chan_close_confirm;
 pub mod chan_close_init;
 
+// Upgrade handshake messages.
+pub mod chan_upgrade_ack;
+pub mod chan_upgrade_cancel;
+pub mod chan_upgrade_confirm;
+pub mod chan_upgrade_init;
+pub mod chan_upgrade_open;
+pub mod chan_upgrade_timeout;
+pub mod chan_upgrade_try;
+
 // Packet specific messages.
 pub mod acknowledgement;
 pub mod recv_packet;
@@ -37,6 +53,13 @@ pub enum ChannelMsg {
     ChannelOpenConfirm(MsgChannelOpenConfirm),
     ChannelCloseInit(MsgChannelCloseInit),
     ChannelCloseConfirm(MsgChannelCloseConfirm),
+    ChannelUpgradeInit(MsgChannelUpgradeInit),
+    ChannelUpgradeTry(MsgChannelUpgradeTry),
+    ChannelUpgradeAck(MsgChannelUpgradeAck),
+    ChannelUpgradeConfirm(MsgChannelUpgradeConfirm),
+    ChannelUpgradeOpen(MsgChannelUpgradeOpen),
+    ChannelUpgradeTimeout(MsgChannelUpgradeTimeout),
+    ChannelUpgradeCancel(MsgChannelUpgradeCancel),
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
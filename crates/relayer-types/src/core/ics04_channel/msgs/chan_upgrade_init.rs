@@ -0,0 +1,140 @@
+use crate::core::ics04_channel::error::Error;
+use crate::core::ics04_channel::upgrade::raw::MsgChannelUpgradeInit as RawMsgChannelUpgradeInit;
+use crate::core::ics04_channel::upgrade::UpgradeFields;
+use crate::core::ics24_host::identifier::{ChannelId, PortId};
+use crate::prelude::*;
+use crate::signer::Signer;
+use crate::tx_msg::Msg;
+
+use ibc_proto::protobuf::Protobuf;
+
+pub const TYPE_URL: &str = "/ibc.core.channel.v1.MsgChannelUpgradeInit";
+
+///
+/// Message definition for the first step in the channel upgrade handshake (`ChanUpgradeInit`
+/// datagram).
+/// On chains running ibc-go, this message can only be submitted by the chain's authority,
+/// typically through a governance proposal.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgChannelUpgradeInit {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub fields: UpgradeFields,
+    pub signer: Signer,
+}
+
+impl MsgChannelUpgradeInit {
+    pub fn new(
+        port_id: PortId,
+        channel_id: ChannelId,
+        fields: UpgradeFields,
+        signer: Signer,
+    ) -> Self {
+        Self {
+            port_id,
+            channel_id,
+            fields,
+            signer,
+        }
+    }
+}
+
+impl Msg for MsgChannelUpgradeInit {
+    type ValidationError = Error;
+    type Raw = RawMsgChannelUpgradeInit;
+
+    fn route(&self) -> String {
+        crate::keys::ROUTER_KEY.to_string()
+    }
+
+    fn type_url(&self) -> String {
+        TYPE_URL.to_string()
+    }
+}
+
+impl Protobuf<RawMsgChannelUpgradeInit> for MsgChannelUpgradeInit {}
+
+impl TryFrom<RawMsgChannelUpgradeInit> for MsgChannelUpgradeInit {
+    type Error = Error;
+
+    fn try_from(raw_msg: RawMsgChannelUpgradeInit) -> Result<Self, Self::Error> {
+        Ok(MsgChannelUpgradeInit {
+            port_id: raw_msg.port_id.parse().map_err(Error::identifier)?,
+            channel_id: raw_msg.channel_id.parse().map_err(Error::identifier)?,
+            fields: raw_msg
+                .fields
+                .ok_or_else(Error::missing_upgrade_fields)?
+                .try_into()?,
+            signer: raw_msg.signer.parse().map_err(Error::signer)?,
+        })
+    }
+}
+
+impl From<MsgChannelUpgradeInit> for RawMsgChannelUpgradeInit {
+    fn from(domain_msg: MsgChannelUpgradeInit) -> Self {
+        RawMsgChannelUpgradeInit {
+            port_id: domain_msg.port_id.to_string(),
+            channel_id: domain_msg.channel_id.to_string(),
+            fields: Some(domain_msg.fields.into()),
+            signer: domain_msg.signer.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod test_util {
+    use crate::prelude::*;
+
+    use crate::core::ics04_channel::upgrade::raw::MsgChannelUpgradeInit as RawMsgChannelUpgradeInit;
+    use crate::core::ics04_channel::upgrade::test_util::get_dummy_raw_upgrade_fields;
+    use crate::core::ics24_host::identifier::{ChannelId, PortId};
+    use crate::test_utils::get_dummy_bech32_account;
+
+    /// Returns a dummy `RawMsgChannelUpgradeInit`, for testing only!
+    pub fn get_dummy_raw_msg_chan_upgrade_init() -> RawMsgChannelUpgradeInit {
+        RawMsgChannelUpgradeInit {
+            port_id: PortId::default().to_string(),
+            channel_id: ChannelId::default().to_string(),
+            fields: Some(get_dummy_raw_upgrade_fields()),
+            signer: get_dummy_bech32_account(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use test_log::test;
+
+    use crate::core::ics04_channel::msgs::chan_upgrade_init::test_util::get_dummy_raw_msg_chan_upgrade_init;
+    use crate::core::ics04_channel::msgs::chan_upgrade_init::MsgChannelUpgradeInit;
+    use crate::core::ics04_channel::upgrade::raw::MsgChannelUpgradeInit as RawMsgChannelUpgradeInit;
+
+    #[test]
+    fn parse_channel_upgrade_init_msg() {
+        let default_raw_msg = get_dummy_raw_msg_chan_upgrade_init();
+
+        let missing_fields = RawMsgChannelUpgradeInit {
+            fields: None,
+            ..default_raw_msg.clone()
+        };
+        assert!(MsgChannelUpgradeInit::try_from(missing_fields).is_err());
+
+        let bad_channel = RawMsgChannelUpgradeInit {
+            channel_id: "chshort".to_string(),
+            ..default_raw_msg
+        };
+        assert!(MsgChannelUpgradeInit::try_from(bad_channel).is_err());
+    }
+
+    #[test]
+    fn to_and_from() {
+        let raw = get_dummy_raw_msg_chan_upgrade_init();
+        let msg = MsgChannelUpgradeInit::try_from(raw.clone()).unwrap();
+        let raw_back = RawMsgChannelUpgradeInit::from(msg.clone());
+        let msg_back = MsgChannelUpgradeInit::try_from(raw_back.clone()).unwrap();
+        assert_eq!(raw, raw_back);
+        assert_eq!(msg, msg_back);
+    }
+}
@@ -0,0 +1,141 @@
+use crate::core::ics04_channel::channel::State;
+use crate::core::ics04_channel::error::Error;
+use crate::core::ics04_channel::packet::Sequence;
+use crate::core::ics04_channel::upgrade::raw::MsgChannelUpgradeOpen as RawMsgChannelUpgradeOpen;
+use crate::core::ics24_host::identifier::{ChannelId, PortId};
+use crate::prelude::*;
+use crate::proofs::Proofs;
+use crate::signer::Signer;
+use crate::tx_msg::Msg;
+
+use ibc_proto::protobuf::Protobuf;
+
+pub const TYPE_URL: &str = "/ibc.core.channel.v1.MsgChannelUpgradeOpen";
+
+///
+/// Message definition for the last step in the channel upgrade handshake (`ChanUpgradeOpen`
+/// datagram), sent once both ends have flushed their in-flight packets.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgChannelUpgradeOpen {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub counterparty_channel_state: State,
+    pub counterparty_upgrade_sequence: Sequence,
+    pub proofs: Proofs,
+    pub signer: Signer,
+}
+
+impl Msg for MsgChannelUpgradeOpen {
+    type ValidationError = Error;
+    type Raw = RawMsgChannelUpgradeOpen;
+
+    fn route(&self) -> String {
+        crate::keys::ROUTER_KEY.to_string()
+    }
+
+    fn type_url(&self) -> String {
+        TYPE_URL.to_string()
+    }
+}
+
+impl Protobuf<RawMsgChannelUpgradeOpen> for MsgChannelUpgradeOpen {}
+
+impl TryFrom<RawMsgChannelUpgradeOpen> for MsgChannelUpgradeOpen {
+    type Error = Error;
+
+    fn try_from(raw_msg: RawMsgChannelUpgradeOpen) -> Result<Self, Self::Error> {
+        let proofs = Proofs::new(
+            raw_msg
+                .proof_channel
+                .try_into()
+                .map_err(Error::invalid_proof)?,
+            None,
+            None,
+            None,
+            raw_msg
+                .proof_height
+                .and_then(|raw_height| raw_height.try_into().ok())
+                .ok_or_else(Error::missing_height)?,
+        )
+        .map_err(Error::invalid_proof)?;
+
+        Ok(MsgChannelUpgradeOpen {
+            port_id: raw_msg.port_id.parse().map_err(Error::identifier)?,
+            channel_id: raw_msg.channel_id.parse().map_err(Error::identifier)?,
+            counterparty_channel_state: State::from_i32(raw_msg.counterparty_channel_state)?,
+            counterparty_upgrade_sequence: raw_msg.counterparty_upgrade_sequence.into(),
+            proofs,
+            signer: raw_msg.signer.parse().map_err(Error::signer)?,
+        })
+    }
+}
+
+impl From<MsgChannelUpgradeOpen> for RawMsgChannelUpgradeOpen {
+    fn from(domain_msg: MsgChannelUpgradeOpen) -> Self {
+        RawMsgChannelUpgradeOpen {
+            port_id: domain_msg.port_id.to_string(),
+            channel_id: domain_msg.channel_id.to_string(),
+            counterparty_channel_state: domain_msg.counterparty_channel_state as i32,
+            counterparty_upgrade_sequence: domain_msg.counterparty_upgrade_sequence.into(),
+            proof_channel: domain_msg.proofs.object_proof().clone().into(),
+            proof_height: Some(domain_msg.proofs.height().into()),
+            signer: domain_msg.signer.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod test_util {
+    use crate::prelude::*;
+    use ibc_proto::ibc::core::client::v1::Height;
+
+    use crate::core::ics04_channel::upgrade::raw::MsgChannelUpgradeOpen as RawMsgChannelUpgradeOpen;
+    use crate::core::ics24_host::identifier::{ChannelId, PortId};
+    use crate::test_utils::{get_dummy_bech32_account, get_dummy_proof};
+
+    /// Returns a dummy `RawMsgChannelUpgradeOpen`, for testing only!
+    pub fn get_dummy_raw_msg_chan_upgrade_open(proof_height: u64) -> RawMsgChannelUpgradeOpen {
+        RawMsgChannelUpgradeOpen {
+            port_id: PortId::default().to_string(),
+            channel_id: ChannelId::default().to_string(),
+            counterparty_channel_state: 3,
+            counterparty_upgrade_sequence: 1,
+            proof_channel: get_dummy_proof(),
+            proof_height: Some(Height {
+                revision_number: 0,
+                revision_height: proof_height,
+            }),
+            signer: get_dummy_bech32_account(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use test_log::test;
+
+    use crate::core::ics04_channel::msgs::chan_upgrade_open::test_util::get_dummy_raw_msg_chan_upgrade_open;
+    use crate::core::ics04_channel::msgs::chan_upgrade_open::MsgChannelUpgradeOpen;
+    use crate::core::ics04_channel::upgrade::raw::MsgChannelUpgradeOpen as RawMsgChannelUpgradeOpen;
+
+    #[test]
+    fn parse_channel_upgrade_open_msg() {
+        let raw = RawMsgChannelUpgradeOpen {
+            counterparty_channel_state: 42,
+            ..get_dummy_raw_msg_chan_upgrade_open(10)
+        };
+        assert!(MsgChannelUpgradeOpen::try_from(raw).is_err());
+    }
+
+    #[test]
+    fn to_and_from() {
+        let raw = get_dummy_raw_msg_chan_upgrade_open(10);
+        let msg = MsgChannelUpgradeOpen::try_from(raw.clone()).unwrap();
+        let raw_back = RawMsgChannelUpgradeOpen::from(msg.clone());
+        let msg_back = MsgChannelUpgradeOpen::try_from(raw_back.clone()).unwrap();
+        assert_eq!(raw, raw_back);
+        assert_eq!(msg, msg_back);
+    }
+}
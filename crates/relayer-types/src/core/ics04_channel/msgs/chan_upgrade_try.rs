@@ -0,0 +1,184 @@
+use core::str::FromStr;
+
+use crate::core::ics04_channel::error::Error;
+use crate::core::ics04_channel::packet::Sequence;
+use crate::core::ics04_channel::upgrade::raw::MsgChannelUpgradeTry as RawMsgChannelUpgradeTry;
+use crate::core::ics04_channel::upgrade::UpgradeFields;
+use crate::core::ics24_host::identifier::{ChannelId, ConnectionId, PortId};
+use crate::prelude::*;
+use crate::proofs::Proofs;
+use crate::signer::Signer;
+use crate::tx_msg::Msg;
+
+use ibc_proto::protobuf::Protobuf;
+
+pub const TYPE_URL: &str = "/ibc.core.channel.v1.MsgChannelUpgradeTry";
+
+///
+/// Message definition for the second step in the channel upgrade handshake (`ChanUpgradeTry`
+/// datagram).
+///
+/// The object proof is the proof of the counterparty channel end, the other proof
+/// is the proof of the upgrade stored by the counterparty.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgChannelUpgradeTry {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub proposed_upgrade_connection_hops: Vec<ConnectionId>,
+    pub counterparty_upgrade_fields: UpgradeFields,
+    pub counterparty_upgrade_sequence: Sequence,
+    pub proofs: Proofs,
+    pub signer: Signer,
+}
+
+impl Msg for MsgChannelUpgradeTry {
+    type ValidationError = Error;
+    type Raw = RawMsgChannelUpgradeTry;
+
+    fn route(&self) -> String {
+        crate::keys::ROUTER_KEY.to_string()
+    }
+
+    fn type_url(&self) -> String {
+        TYPE_URL.to_string()
+    }
+}
+
+impl Protobuf<RawMsgChannelUpgradeTry> for MsgChannelUpgradeTry {}
+
+impl TryFrom<RawMsgChannelUpgradeTry> for MsgChannelUpgradeTry {
+    type Error = Error;
+
+    fn try_from(raw_msg: RawMsgChannelUpgradeTry) -> Result<Self, Self::Error> {
+        let proofs = Proofs::new(
+            raw_msg
+                .proof_channel
+                .try_into()
+                .map_err(Error::invalid_proof)?,
+            None,
+            None,
+            Some(
+                raw_msg
+                    .proof_upgrade
+                    .try_into()
+                    .map_err(Error::invalid_proof)?,
+            ),
+            raw_msg
+                .proof_height
+                .and_then(|raw_height| raw_height.try_into().ok())
+                .ok_or_else(Error::missing_height)?,
+        )
+        .map_err(Error::invalid_proof)?;
+
+        let proposed_upgrade_connection_hops = raw_msg
+            .proposed_upgrade_connection_hops
+            .into_iter()
+            .map(|conn_id| ConnectionId::from_str(conn_id.as_str()))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(Error::identifier)?;
+
+        Ok(MsgChannelUpgradeTry {
+            port_id: raw_msg.port_id.parse().map_err(Error::identifier)?,
+            channel_id: raw_msg.channel_id.parse().map_err(Error::identifier)?,
+            proposed_upgrade_connection_hops,
+            counterparty_upgrade_fields: raw_msg
+                .counterparty_upgrade_fields
+                .ok_or_else(Error::missing_upgrade_fields)?
+                .try_into()?,
+            counterparty_upgrade_sequence: raw_msg.counterparty_upgrade_sequence.into(),
+            proofs,
+            signer: raw_msg.signer.parse().map_err(Error::signer)?,
+        })
+    }
+}
+
+impl From<MsgChannelUpgradeTry> for RawMsgChannelUpgradeTry {
+    fn from(domain_msg: MsgChannelUpgradeTry) -> Self {
+        RawMsgChannelUpgradeTry {
+            port_id: domain_msg.port_id.to_string(),
+            channel_id: domain_msg.channel_id.to_string(),
+            proposed_upgrade_connection_hops: domain_msg
+                .proposed_upgrade_connection_hops
+                .iter()
+                .map(|v| v.as_str().to_string())
+                .collect(),
+            counterparty_upgrade_fields: Some(domain_msg.counterparty_upgrade_fields.into()),
+            counterparty_upgrade_sequence: domain_msg.counterparty_upgrade_sequence.into(),
+            proof_channel: domain_msg.proofs.object_proof().clone().into(),
+            proof_upgrade: domain_msg
+                .proofs
+                .other_proof()
+                .clone()
+                .map_or_else(Vec::new, |v| v.into()),
+            proof_height: Some(domain_msg.proofs.height().into()),
+            signer: domain_msg.signer.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod test_util {
+    use crate::prelude::*;
+    use ibc_proto::ibc::core::client::v1::Height;
+
+    use crate::core::ics04_channel::upgrade::raw::MsgChannelUpgradeTry as RawMsgChannelUpgradeTry;
+    use crate::core::ics04_channel::upgrade::test_util::get_dummy_raw_upgrade_fields;
+    use crate::core::ics24_host::identifier::{ChannelId, ConnectionId, PortId};
+    use crate::test_utils::{get_dummy_bech32_account, get_dummy_proof};
+
+    /// Returns a dummy `RawMsgChannelUpgradeTry`, for testing only!
+    pub fn get_dummy_raw_msg_chan_upgrade_try(proof_height: u64) -> RawMsgChannelUpgradeTry {
+        RawMsgChannelUpgradeTry {
+            port_id: PortId::default().to_string(),
+            channel_id: ChannelId::default().to_string(),
+            proposed_upgrade_connection_hops: vec![ConnectionId::default().to_string()],
+            counterparty_upgrade_fields: Some(get_dummy_raw_upgrade_fields()),
+            counterparty_upgrade_sequence: 1,
+            proof_channel: get_dummy_proof(),
+            proof_upgrade: get_dummy_proof(),
+            proof_height: Some(Height {
+                revision_number: 0,
+                revision_height: proof_height,
+            }),
+            signer: get_dummy_bech32_account(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use test_log::test;
+
+    use crate::core::ics04_channel::msgs::chan_upgrade_try::test_util::get_dummy_raw_msg_chan_upgrade_try;
+    use crate::core::ics04_channel::msgs::chan_upgrade_try::MsgChannelUpgradeTry;
+    use crate::core::ics04_channel::upgrade::raw::MsgChannelUpgradeTry as RawMsgChannelUpgradeTry;
+
+    #[test]
+    fn parse_channel_upgrade_try_msg() {
+        let default_raw_msg = get_dummy_raw_msg_chan_upgrade_try(10);
+
+        let missing_proof = RawMsgChannelUpgradeTry {
+            proof_upgrade: Vec::new(),
+            ..default_raw_msg.clone()
+        };
+        assert!(MsgChannelUpgradeTry::try_from(missing_proof).is_err());
+
+        let bad_connection = RawMsgChannelUpgradeTry {
+            proposed_upgrade_connection_hops: vec!["con nection".to_string()],
+            ..default_raw_msg
+        };
+        assert!(MsgChannelUpgradeTry::try_from(bad_connection).is_err());
+    }
+
+    #[test]
+    fn to_and_from() {
+        let raw = get_dummy_raw_msg_chan_upgrade_try(10);
+        let msg = MsgChannelUpgradeTry::try_from(raw.clone()).unwrap();
+        let raw_back = RawMsgChannelUpgradeTry::from(msg.clone());
+        let msg_back = MsgChannelUpgradeTry::try_from(raw_back.clone()).unwrap();
+        assert_eq!(raw, raw_back);
+        assert_eq!(msg, msg_back);
+    }
+}
@@ -0,0 +1,291 @@
+//! Domain types for the channel upgradability handshake.
+
+use crate::prelude::*;
+
+use core::str::FromStr;
+
+use ibc_proto::protobuf::Protobuf;
+use serde::Serialize;
+
+use crate::core::ics04_channel::channel::Ordering;
+use crate::core::ics04_channel::error::Error;
+use crate::core::ics04_channel::packet::Sequence;
+use crate::core::ics04_channel::timeout::TimeoutHeight;
+use crate::core::ics04_channel::version::Version;
+use crate::core::ics24_host::identifier::ConnectionId;
+use crate::timestamp::Timestamp;
+use crate::Height;
+
+use raw::{
+    ErrorReceipt as RawErrorReceipt, Timeout as RawTimeout, Upgrade as RawUpgrade,
+    UpgradeFields as RawUpgradeFields,
+};
+
+pub mod raw;
+
+/// The parameters of a channel which may be changed by an upgrade.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct UpgradeFields {
+    pub ordering: Ordering,
+    pub connection_hops: Vec<ConnectionId>,
+    pub version: Version,
+}
+
+impl UpgradeFields {
+    pub fn new(ordering: Ordering, connection_hops: Vec<ConnectionId>, version: Version) -> Self {
+        Self {
+            ordering,
+            connection_hops,
+            version,
+        }
+    }
+}
+
+impl Protobuf<RawUpgradeFields> for UpgradeFields {}
+
+impl TryFrom<RawUpgradeFields> for UpgradeFields {
+    type Error = Error;
+
+    fn try_from(value: RawUpgradeFields) -> Result<Self, Self::Error> {
+        let ordering = Ordering::from_i32(value.ordering)?;
+
+        let connection_hops = value
+            .connection_hops
+            .into_iter()
+            .map(|conn_id| ConnectionId::from_str(conn_id.as_str()))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(Error::identifier)?;
+
+        Ok(Self::new(ordering, connection_hops, value.version.into()))
+    }
+}
+
+impl From<UpgradeFields> for RawUpgradeFields {
+    fn from(value: UpgradeFields) -> Self {
+        Self {
+            ordering: value.ordering as i32,
+            connection_hops: value
+                .connection_hops
+                .iter()
+                .map(|v| v.as_str().to_string())
+                .collect(),
+            version: value.version.to_string(),
+        }
+    }
+}
+
+/// The height and/or timestamp on the counterparty chain after which
+/// an upgrade is aborted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub struct UpgradeTimeout {
+    pub height: TimeoutHeight,
+    pub timestamp: Timestamp,
+}
+
+impl UpgradeTimeout {
+    /// Whether the timeout has elapsed on a counterparty chain whose latest
+    /// block has the given height and timestamp.
+    pub fn has_elapsed(&self, height: Height, timestamp: Timestamp) -> bool {
+        let height_elapsed = match self.height {
+            TimeoutHeight::At(timeout_height) => height >= timeout_height,
+            TimeoutHeight::Never => false,
+        };
+
+        let timestamp_elapsed = self.timestamp != Timestamp::none()
+            && timestamp.nanoseconds() >= self.timestamp.nanoseconds();
+
+        height_elapsed || timestamp_elapsed
+    }
+}
+
+impl TryFrom<RawTimeout> for UpgradeTimeout {
+    type Error = Error;
+
+    fn try_from(value: RawTimeout) -> Result<Self, Self::Error> {
+        let height: TimeoutHeight = value
+            .height
+            .try_into()
+            .map_err(|_| Error::invalid_timeout_height())?;
+
+        let timestamp = Timestamp::from_nanoseconds(value.timestamp)
+            .map_err(Error::invalid_upgrade_timestamp)?;
+
+        if height == TimeoutHeight::Never && timestamp == Timestamp::none() {
+            return Err(Error::invalid_upgrade_timeout());
+        }
+
+        Ok(Self { height, timestamp })
+    }
+}
+
+impl From<UpgradeTimeout> for RawTimeout {
+    fn from(value: UpgradeTimeout) -> Self {
+        Self {
+            height: value.height.into(),
+            timestamp: value.timestamp.nanoseconds(),
+        }
+    }
+}
+
+/// An upgrade proposed for a channel, as stored by the chain while the
+/// upgrade handshake is in progress.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Upgrade {
+    pub fields: UpgradeFields,
+    pub timeout: UpgradeTimeout,
+    pub next_sequence_send: Sequence,
+}
+
+impl Protobuf<RawUpgrade> for Upgrade {}
+
+impl TryFrom<RawUpgrade> for Upgrade {
+    type Error = Error;
+
+    fn try_from(value: RawUpgrade) -> Result<Self, Self::Error> {
+        Ok(Self {
+            fields: value
+                .fields
+                .ok_or_else(Error::missing_upgrade_fields)?
+                .try_into()?,
+            timeout: value
+                .timeout
+                .ok_or_else(Error::missing_upgrade_timeout)?
+                .try_into()?,
+            next_sequence_send: value.next_sequence_send.into(),
+        })
+    }
+}
+
+impl From<Upgrade> for RawUpgrade {
+    fn from(value: Upgrade) -> Self {
+        Self {
+            fields: Some(value.fields.into()),
+            timeout: Some(value.timeout.into()),
+            next_sequence_send: value.next_sequence_send.into(),
+        }
+    }
+}
+
+/// Written by a chain when it aborts an upgrade, so that the counterparty
+/// can be proven to have aborted as well and cancel its own upgrade.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ErrorReceipt {
+    pub sequence: Sequence,
+    pub message: String,
+}
+
+impl Protobuf<RawErrorReceipt> for ErrorReceipt {}
+
+impl TryFrom<RawErrorReceipt> for ErrorReceipt {
+    type Error = Error;
+
+    fn try_from(value: RawErrorReceipt) -> Result<Self, Self::Error> {
+        Ok(Self {
+            sequence: value.sequence.into(),
+            message: value.message,
+        })
+    }
+}
+
+impl From<ErrorReceipt> for RawErrorReceipt {
+    fn from(value: ErrorReceipt) -> Self {
+        Self {
+            sequence: value.sequence.into(),
+            message: value.message,
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod test_util {
+    use crate::core::ics24_host::identifier::ConnectionId;
+    use crate::prelude::*;
+    use ibc_proto::ibc::core::client::v1::Height;
+
+    use super::raw::{
+        Timeout as RawTimeout, Upgrade as RawUpgrade, UpgradeFields as RawUpgradeFields,
+    };
+
+    /// Returns dummy `RawUpgradeFields`, for testing only!
+    pub fn get_dummy_raw_upgrade_fields() -> RawUpgradeFields {
+        RawUpgradeFields {
+            ordering: 1,
+            connection_hops: vec![ConnectionId::default().to_string()],
+            version: "ics20".to_string(),
+        }
+    }
+
+    /// Returns a dummy `RawUpgrade`, for testing only!
+    pub fn get_dummy_raw_upgrade() -> RawUpgrade {
+        RawUpgrade {
+            fields: Some(get_dummy_raw_upgrade_fields()),
+            timeout: Some(RawTimeout {
+                height: Some(Height {
+                    revision_number: 0,
+                    revision_height: 0,
+                }),
+                timestamp: 1_700_000_000_000_000_000,
+            }),
+            next_sequence_send: 1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use test_log::test;
+
+    use super::raw::{Timeout as RawTimeout, Upgrade as RawUpgrade};
+    use super::test_util::get_dummy_raw_upgrade;
+    use super::{Upgrade, UpgradeTimeout};
+    use crate::core::ics04_channel::timeout::TimeoutHeight;
+    use crate::timestamp::Timestamp;
+    use crate::Height;
+
+    #[test]
+    fn upgrade_to_and_from() {
+        let raw = get_dummy_raw_upgrade();
+        let upgrade = Upgrade::try_from(raw).unwrap();
+        let raw_back = RawUpgrade::from(upgrade.clone());
+        let upgrade_back = Upgrade::try_from(raw_back).unwrap();
+        assert_eq!(upgrade, upgrade_back);
+    }
+
+    #[test]
+    fn upgrade_without_timeout() {
+        let raw = RawUpgrade {
+            timeout: Some(RawTimeout {
+                height: None,
+                timestamp: 0,
+            }),
+            ..get_dummy_raw_upgrade()
+        };
+        assert!(Upgrade::try_from(raw).is_err());
+
+        let raw = RawUpgrade {
+            timeout: None,
+            ..get_dummy_raw_upgrade()
+        };
+        assert!(Upgrade::try_from(raw).is_err());
+    }
+
+    #[test]
+    fn upgrade_timeout_elapsed() {
+        let timeout = UpgradeTimeout {
+            height: TimeoutHeight::At(Height::new(0, 10).unwrap()),
+            timestamp: Timestamp::none(),
+        };
+        let now = Timestamp::from_nanoseconds(1).unwrap();
+        assert!(!timeout.has_elapsed(Height::new(0, 9).unwrap(), now));
+        assert!(timeout.has_elapsed(Height::new(0, 10).unwrap(), now));
+
+        let timeout = UpgradeTimeout {
+            height: TimeoutHeight::Never,
+            timestamp: Timestamp::from_nanoseconds(100).unwrap(),
+        };
+        let height = Height::new(0, 100).unwrap();
+        assert!(!timeout.has_elapsed(height, Timestamp::from_nanoseconds(99).unwrap()));
+        assert!(timeout.has_elapsed(height, Timestamp::from_nanoseconds(100).unwrap()));
+    }
+}
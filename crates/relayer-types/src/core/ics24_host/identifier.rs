@@ -5,6 +5,7 @@ use core::str::FromStr;
 use serde::{Deserialize, Serialize};
 
 use super::validate::*;
+use crate::clients::ics09_localhost::{LOCALHOST_CLIENT_ID, LOCALHOST_CONNECTION_ID};
 use crate::core::ics02_client::client_type::ClientType;
 use crate::core::ics24_host::error::ValidationError;
 use crate::prelude::*;
@@ -170,7 +171,10 @@ impl ClientId {
     /// chain, for example, will have the prefix '07-tendermint'.
     pub fn prefix(client_type: ClientType) -> &'static str {
         match client_type {
+            ClientType::Solomachine => ClientType::Solomachine.as_str(),
             ClientType::Tendermint => ClientType::Tendermint.as_str(),
+            ClientType::Wasm => ClientType::Wasm.as_str(),
+            ClientType::Localhost => ClientType::Localhost.as_str(),
 
             #[cfg(any(test, feature = "mocks"))]
             ClientType::Mock => ClientType::Mock.as_str(),
@@ -181,6 +185,21 @@ impl ClientId {
     pub fn as_bytes(&self) -> &[u8] {
         self.0.as_bytes()
     }
+
+    /// The identifier of the localhost client, which is the same on every chain.
+    ///
+    /// ```
+    /// # use ibc_relayer_types::core::ics24_host::identifier::ClientId;
+    /// assert_eq!(&ClientId::localhost(), "09-localhost");
+    /// ```
+    pub fn localhost() -> Self {
+        Self(LOCALHOST_CLIENT_ID.to_string())
+    }
+
+    /// Whether this is the identifier of the localhost client.
+    pub fn is_localhost(&self) -> bool {
+        self.as_str() == LOCALHOST_CLIENT_ID
+    }
 }
 
 /// This implementation provides a `to_string` method.
@@ -251,6 +270,22 @@ impl ConnectionId {
     pub fn as_bytes(&self) -> &[u8] {
         self.0.as_bytes()
     }
+
+    /// The identifier of the connection opened by the host chain on top of the
+    /// localhost client, which is the same on every chain.
+    ///
+    /// ```
+    /// # use ibc_relayer_types::core::ics24_host::identifier::ConnectionId;
+    /// assert_eq!(&ConnectionId::localhost(), "connection-localhost");
+    /// ```
+    pub fn localhost() -> Self {
+        Self(LOCALHOST_CONNECTION_ID.to_string())
+    }
+
+    /// Whether this is the identifier of the localhost connection.
+    pub fn is_localhost(&self) -> bool {
+        self.as_str() == LOCALHOST_CONNECTION_ID
+    }
 }
 
 /// This implementation provides a `to_string` method.
@@ -44,6 +44,8 @@ pub enum Path {
     Acks(AcksPath),
     Receipts(ReceiptsPath),
     Upgrade(ClientUpgradePath),
+    ChannelUpgrades(ChannelUpgradesPath),
+    ChannelUpgradeErrors(ChannelUpgradeErrorsPath),
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
@@ -78,6 +80,14 @@ pub struct PortsPath(pub PortId);
 #[display(fmt = "channelEnds/ports/{_0}/channels/{_1}")]
 pub struct ChannelEndsPath(pub PortId, pub ChannelId);
 
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+#[display(fmt = "channelUpgrades/upgrades/ports/{_0}/channels/{_1}")]
+pub struct ChannelUpgradesPath(pub PortId, pub ChannelId);
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+#[display(fmt = "channelUpgrades/upgradeError/ports/{_0}/channels/{_1}")]
+pub struct ChannelUpgradeErrorsPath(pub PortId, pub ChannelId);
+
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
 #[display(fmt = "nextSequenceSend/ports/{_0}/channels/{_1}")]
 pub struct SeqSendsPath(pub PortId, pub ChannelId);
@@ -163,6 +173,7 @@ impl FromStr for Path {
             .or_else(|| parse_connections(&components))
             .or_else(|| parse_ports(&components))
             .or_else(|| parse_channel_ends(&components))
+            .or_else(|| parse_channel_upgrades(&components))
             .or_else(|| parse_seqs(&components))
             .or_else(|| parse_commitments(&components))
             .or_else(|| parse_acks(&components))
@@ -374,6 +385,37 @@ fn parse_channel_ends(components: &[&str]) -> Option<Path> {
     Some(ChannelEndsPath(port_id, channel_id).into())
 }
 
+fn parse_channel_upgrades(components: &[&str]) -> Option<Path> {
+    if components.len() != 6 {
+        return None;
+    }
+
+    if components[0] != "channelUpgrades" {
+        return None;
+    }
+
+    let port = parse_ports(&components[2..=3]);
+    let channel = parse_channels(&components[4..=5]);
+
+    let port_id = if let Some(Path::Ports(PortsPath(port_id))) = port {
+        port_id
+    } else {
+        return None;
+    };
+
+    let channel_id = if let Some(SubPath::Channels(channel_id)) = channel {
+        channel_id
+    } else {
+        return None;
+    };
+
+    match components[1] {
+        "upgrades" => Some(ChannelUpgradesPath(port_id, channel_id).into()),
+        "upgradeError" => Some(ChannelUpgradeErrorsPath(port_id, channel_id).into()),
+        _ => None,
+    }
+}
+
 fn parse_seqs(components: &[&str]) -> Option<Path> {
     if components.len() != 5 {
         return None;
@@ -780,6 +822,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn channel_upgrades_path_parses() {
+        let path = "channelUpgrades/upgrades/ports/defaultPort/channels/channel-0";
+        let path = Path::from_str(path);
+
+        assert_eq!(
+            path.unwrap(),
+            Path::ChannelUpgrades(ChannelUpgradesPath(PortId::default(), ChannelId::default())),
+        );
+
+        let path = "channelUpgrades/upgradeError/ports/defaultPort/channels/channel-0";
+        let path = Path::from_str(path);
+
+        assert_eq!(
+            path.unwrap(),
+            Path::ChannelUpgradeErrors(ChannelUpgradeErrorsPath(
+                PortId::default(),
+                ChannelId::default()
+            )),
+        );
+    }
+
     #[test]
     fn test_parse_seqs_fn() {
         let path = "nextSequenceSend/ports/defaultPort/channels/channel-0";
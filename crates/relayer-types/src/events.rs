@@ -22,6 +22,7 @@ use crate::core::ics03_connection::events::Attributes as ConnectionAttributes;
 use crate::core::ics04_channel::error as channel_error;
 use crate::core::ics04_channel::events as ChannelEvents;
 use crate::core::ics04_channel::events::Attributes as ChannelAttributes;
+use crate::core::ics04_channel::events::UpgradeAttributes;
 use crate::core::ics04_channel::packet::Packet;
 use crate::core::ics24_host::error::ValidationError;
 use crate::timestamp::ParseTimestampError;
@@ -130,6 +131,14 @@ const CHANNEL_OPEN_ACK_EVENT: &str = "channel_open_ack";
 const CHANNEL_OPEN_CONFIRM_EVENT: &str = "channel_open_confirm";
 const CHANNEL_CLOSE_INIT_EVENT: &str = "channel_close_init";
 const CHANNEL_CLOSE_CONFIRM_EVENT: &str = "channel_close_confirm";
+const CHANNEL_UPGRADE_INIT_EVENT: &str = "channel_upgrade_init";
+const CHANNEL_UPGRADE_TRY_EVENT: &str = "channel_upgrade_try";
+const CHANNEL_UPGRADE_ACK_EVENT: &str = "channel_upgrade_ack";
+const CHANNEL_UPGRADE_CONFIRM_EVENT: &str = "channel_upgrade_confirm";
+const CHANNEL_UPGRADE_OPEN_EVENT: &str = "channel_upgrade_open";
+const CHANNEL_UPGRADE_TIMEOUT_EVENT: &str = "channel_upgrade_timeout";
+const CHANNEL_UPGRADE_CANCEL_EVENT: &str = "channel_upgrade_cancelled";
+const CHANNEL_UPGRADE_ERROR_EVENT: &str = "channel_upgrade_error";
 /// Packet event types
 const SEND_PACKET_EVENT: &str = "send_packet";
 const RECEIVE_PACKET_EVENT: &str = "receive_packet";
@@ -161,6 +170,14 @@ pub enum IbcEventType {
     OpenConfirmChannel,
     CloseInitChannel,
     CloseConfirmChannel,
+    UpgradeInitChannel,
+    UpgradeTryChannel,
+    UpgradeAckChannel,
+    UpgradeConfirmChannel,
+    UpgradeOpenChannel,
+    UpgradeTimeoutChannel,
+    UpgradeCancelChannel,
+    UpgradeErrorChannel,
     SendPacket,
     ReceivePacket,
     WriteAck,
@@ -193,6 +210,14 @@ impl IbcEventType {
             IbcEventType::OpenConfirmChannel => CHANNEL_OPEN_CONFIRM_EVENT,
             IbcEventType::CloseInitChannel => CHANNEL_CLOSE_INIT_EVENT,
             IbcEventType::CloseConfirmChannel => CHANNEL_CLOSE_CONFIRM_EVENT,
+            IbcEventType::UpgradeInitChannel => CHANNEL_UPGRADE_INIT_EVENT,
+            IbcEventType::UpgradeTryChannel => CHANNEL_UPGRADE_TRY_EVENT,
+            IbcEventType::UpgradeAckChannel => CHANNEL_UPGRADE_ACK_EVENT,
+            IbcEventType::UpgradeConfirmChannel => CHANNEL_UPGRADE_CONFIRM_EVENT,
+            IbcEventType::UpgradeOpenChannel => CHANNEL_UPGRADE_OPEN_EVENT,
+            IbcEventType::UpgradeTimeoutChannel => CHANNEL_UPGRADE_TIMEOUT_EVENT,
+            IbcEventType::UpgradeCancelChannel => CHANNEL_UPGRADE_CANCEL_EVENT,
+            IbcEventType::UpgradeErrorChannel => CHANNEL_UPGRADE_ERROR_EVENT,
             IbcEventType::SendPacket => SEND_PACKET_EVENT,
             IbcEventType::ReceivePacket => RECEIVE_PACKET_EVENT,
             IbcEventType::WriteAck => WRITE_ACK_EVENT,
@@ -229,6 +254,14 @@ impl FromStr for IbcEventType {
             CHANNEL_OPEN_CONFIRM_EVENT => Ok(IbcEventType::OpenConfirmChannel),
             CHANNEL_CLOSE_INIT_EVENT => Ok(IbcEventType::CloseInitChannel),
             CHANNEL_CLOSE_CONFIRM_EVENT => Ok(IbcEventType::CloseConfirmChannel),
+            CHANNEL_UPGRADE_INIT_EVENT => Ok(IbcEventType::UpgradeInitChannel),
+            CHANNEL_UPGRADE_TRY_EVENT => Ok(IbcEventType::UpgradeTryChannel),
+            CHANNEL_UPGRADE_ACK_EVENT => Ok(IbcEventType::UpgradeAckChannel),
+            CHANNEL_UPGRADE_CONFIRM_EVENT => Ok(IbcEventType::UpgradeConfirmChannel),
+            CHANNEL_UPGRADE_OPEN_EVENT => Ok(IbcEventType::UpgradeOpenChannel),
+            CHANNEL_UPGRADE_TIMEOUT_EVENT => Ok(IbcEventType::UpgradeTimeoutChannel),
+            CHANNEL_UPGRADE_CANCEL_EVENT => Ok(IbcEventType::UpgradeCancelChannel),
+            CHANNEL_UPGRADE_ERROR_EVENT => Ok(IbcEventType::UpgradeErrorChannel),
             SEND_PACKET_EVENT => Ok(IbcEventType::SendPacket),
             RECEIVE_PACKET_EVENT => Ok(IbcEventType::ReceivePacket),
             WRITE_ACK_EVENT => Ok(IbcEventType::WriteAck),
@@ -268,6 +301,15 @@ pub enum IbcEvent {
     CloseInitChannel(ChannelEvents::CloseInit),
     CloseConfirmChannel(ChannelEvents::CloseConfirm),
 
+    UpgradeInitChannel(ChannelEvents::UpgradeInit),
+    UpgradeTryChannel(ChannelEvents::UpgradeTry),
+    UpgradeAckChannel(ChannelEvents::UpgradeAck),
+    UpgradeConfirmChannel(ChannelEvents::UpgradeConfirm),
+    UpgradeOpenChannel(ChannelEvents::UpgradeOpen),
+    UpgradeTimeoutChannel(ChannelEvents::UpgradeTimeout),
+    UpgradeCancelChannel(ChannelEvents::UpgradeCancel),
+    UpgradeErrorChannel(ChannelEvents::UpgradeError),
+
     SendPacket(ChannelEvents::SendPacket),
     ReceivePacket(ChannelEvents::ReceivePacket),
     WriteAcknowledgement(ChannelEvents::WriteAcknowledgement),
@@ -307,6 +349,15 @@ impl Display for IbcEvent {
             IbcEvent::CloseInitChannel(ev) => write!(f, "CloseInitChannel({ev})"),
             IbcEvent::CloseConfirmChannel(ev) => write!(f, "CloseConfirmChannel({ev})"),
 
+            IbcEvent::UpgradeInitChannel(ev) => write!(f, "UpgradeInitChannel({ev})"),
+            IbcEvent::UpgradeTryChannel(ev) => write!(f, "UpgradeTryChannel({ev})"),
+            IbcEvent::UpgradeAckChannel(ev) => write!(f, "UpgradeAckChannel({ev})"),
+            IbcEvent::UpgradeConfirmChannel(ev) => write!(f, "UpgradeConfirmChannel({ev})"),
+            IbcEvent::UpgradeOpenChannel(ev) => write!(f, "UpgradeOpenChannel({ev})"),
+            IbcEvent::UpgradeTimeoutChannel(ev) => write!(f, "UpgradeTimeoutChannel({ev})"),
+            IbcEvent::UpgradeCancelChannel(ev) => write!(f, "UpgradeCancelChannel({ev})"),
+            IbcEvent::UpgradeErrorChannel(ev) => write!(f, "UpgradeErrorChannel({ev})"),
+
             IbcEvent::SendPacket(ev) => write!(f, "SendPacket({ev})"),
             IbcEvent::ReceivePacket(ev) => write!(f, "ReceivePacket({ev})"),
             IbcEvent::WriteAcknowledgement(ev) => write!(f, "WriteAcknowledgement({ev})"),
@@ -345,6 +396,14 @@ impl TryFrom<IbcEvent> for abci::Event {
             IbcEvent::OpenConfirmChannel(event) => event.into(),
             IbcEvent::CloseInitChannel(event) => event.into(),
             IbcEvent::CloseConfirmChannel(event) => event.into(),
+            IbcEvent::UpgradeInitChannel(event) => event.into(),
+            IbcEvent::UpgradeTryChannel(event) => event.into(),
+            IbcEvent::UpgradeAckChannel(event) => event.into(),
+            IbcEvent::UpgradeConfirmChannel(event) => event.into(),
+            IbcEvent::UpgradeOpenChannel(event) => event.into(),
+            IbcEvent::UpgradeTimeoutChannel(event) => event.into(),
+            IbcEvent::UpgradeCancelChannel(event) => event.into(),
+            IbcEvent::UpgradeErrorChannel(event) => event.into(),
             IbcEvent::SendPacket(event) => event.try_into().map_err(Error::channel)?,
             IbcEvent::ReceivePacket(event) => event.try_into().map_err(Error::channel)?,
             IbcEvent::WriteAcknowledgement(event) => event.try_into().map_err(Error::channel)?,
@@ -387,6 +446,14 @@ impl IbcEvent {
             IbcEvent::OpenConfirmChannel(_) => IbcEventType::OpenConfirmChannel,
             IbcEvent::CloseInitChannel(_) => IbcEventType::CloseInitChannel,
             IbcEvent::CloseConfirmChannel(_) => IbcEventType::CloseConfirmChannel,
+            IbcEvent::UpgradeInitChannel(_) => IbcEventType::UpgradeInitChannel,
+            IbcEvent::UpgradeTryChannel(_) => IbcEventType::UpgradeTryChannel,
+            IbcEvent::UpgradeAckChannel(_) => IbcEventType::UpgradeAckChannel,
+            IbcEvent::UpgradeConfirmChannel(_) => IbcEventType::UpgradeConfirmChannel,
+            IbcEvent::UpgradeOpenChannel(_) => IbcEventType::UpgradeOpenChannel,
+            IbcEvent::UpgradeTimeoutChannel(_) => IbcEventType::UpgradeTimeoutChannel,
+            IbcEvent::UpgradeCancelChannel(_) => IbcEventType::UpgradeCancelChannel,
+            IbcEvent::UpgradeErrorChannel(_) => IbcEventType::UpgradeErrorChannel,
             IbcEvent::SendPacket(_) => IbcEventType::SendPacket,
             IbcEvent::ReceivePacket(_) => IbcEventType::ReceivePacket,
             IbcEvent::WriteAcknowledgement(_) => IbcEventType::WriteAck,
@@ -411,6 +478,20 @@ impl IbcEvent {
         }
     }
 
+    pub fn upgrade_attributes(self) -> Option<UpgradeAttributes> {
+        match self {
+            IbcEvent::UpgradeInitChannel(ev) => Some(ev.into()),
+            IbcEvent::UpgradeTryChannel(ev) => Some(ev.into()),
+            IbcEvent::UpgradeAckChannel(ev) => Some(ev.into()),
+            IbcEvent::UpgradeConfirmChannel(ev) => Some(ev.into()),
+            IbcEvent::UpgradeOpenChannel(ev) => Some(ev.into()),
+            IbcEvent::UpgradeTimeoutChannel(ev) => Some(ev.into()),
+            IbcEvent::UpgradeCancelChannel(ev) => Some(ev.into()),
+            IbcEvent::UpgradeErrorChannel(ev) => Some(ev.into()),
+            _ => None,
+        }
+    }
+
     pub fn connection_attributes(&self) -> Option<&ConnectionAttributes> {
         match self {
             IbcEvent::OpenInitConnection(ev) => Some(ev.attributes()),
@@ -5,6 +5,7 @@ pub mod endpoint;
 pub mod handle;
 pub mod requests;
 pub mod runtime;
+pub mod solomachine;
 pub mod tracking;
 
 use serde::{de::Error, Deserialize, Serialize};
@@ -18,6 +19,8 @@ use serde::{de::Error, Deserialize, Serialize};
 pub enum ChainType {
     /// Chains based on the Cosmos SDK
     CosmosSdk,
+    /// Solo machines, which sign their state with a single key
+    SoloMachine,
 }
 
 impl<'de> Deserialize<'de> for ChainType {
@@ -30,9 +33,13 @@ impl<'de> Deserialize<'de> for ChainType {
 
         match s.as_str() {
             "cosmossdk" => Ok(Self::CosmosSdk),
+            "solomachine" => Ok(Self::SoloMachine),
 
             // NOTE(new): Add a case here
-            _ => Err(D::Error::unknown_variant(&original, &["cosmos-sdk"])), // NOTE(new): mention the new variant here
+            _ => Err(D::Error::unknown_variant(
+                &original,
+                &["cosmos-sdk", "solo-machine"],
+            )), // NOTE(new): mention the new variant here
         }
     }
 }
@@ -58,6 +65,10 @@ mod tests {
         assert!(matches!(parse("cosmossdk"), Ok(CosmosSdk)));
         assert!(matches!(parse("cosmos-sdk"), Ok(CosmosSdk)));
 
+        assert!(matches!(parse("SoloMachine"), Ok(SoloMachine)));
+        assert!(matches!(parse("solomachine"), Ok(SoloMachine)));
+        assert!(matches!(parse("solo-machine"), Ok(SoloMachine)));
+
         // NOTE(new): Add tests here
 
         assert!(matches!(parse("hello-world"), Err(_)));
@@ -14,7 +14,8 @@ pub enum ClientSettings {
 
 impl ClientSettings {
     /// Takes the settings from the user-supplied options if they have been specified,
-    /// falling back to defaults using the configuration of the source
+    /// then from the client overrides configured on the destination chain for the
+    /// source chain, falling back to defaults using the configuration of the source
     /// and the destination chain.
     pub fn for_create_command(
         options: CreateOptions,
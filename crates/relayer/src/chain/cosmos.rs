@@ -1,19 +1,21 @@
+use alloc::collections::BTreeMap;
 use alloc::sync::Arc;
 use bytes::{Buf, Bytes};
 use core::{
     convert::{TryFrom, TryInto},
+    fmt::Display,
     future::Future,
     str::FromStr,
     time::Duration,
 };
 use futures::future::join_all;
 use num_bigint::BigInt;
-use std::{cmp::Ordering, thread};
+use std::{cmp::Ordering, thread, time::Instant};
 
 use tokio::runtime::Runtime as TokioRuntime;
 use tonic::codegen::http::Uri;
 use tonic::metadata::AsciiMetadataValue;
-use tracing::{error, instrument, trace, warn};
+use tracing::{debug, error, instrument, trace, warn};
 
 use ibc_proto::cosmos::{
     base::node::v1beta1::ConfigResponse, staking::v1beta1::Params as StakingParams,
@@ -21,6 +23,7 @@ use ibc_proto::cosmos::{
 
 use ibc_proto::interchain_security::ccv::consumer::v1::Params as CcvConsumerParams;
 
+use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::apps::fee::v1::{
     QueryIncentivizedPacketRequest, QueryIncentivizedPacketResponse,
 };
@@ -39,14 +42,17 @@ use ibc_relayer_types::core::ics03_connection::connection::{
 };
 use ibc_relayer_types::core::ics04_channel::channel::{ChannelEnd, IdentifiedChannelEnd};
 use ibc_relayer_types::core::ics04_channel::packet::Sequence;
+use ibc_relayer_types::core::ics04_channel::upgrade::raw::Channel as RawUpgradableChannel;
+use ibc_relayer_types::core::ics04_channel::upgrade::{ErrorReceipt, Upgrade};
 use ibc_relayer_types::core::ics23_commitment::commitment::CommitmentPrefix;
 use ibc_relayer_types::core::ics23_commitment::merkle::MerkleProof;
 use ibc_relayer_types::core::ics24_host::identifier::{
     ChainId, ChannelId, ClientId, ConnectionId, PortId,
 };
 use ibc_relayer_types::core::ics24_host::path::{
-    AcksPath, ChannelEndsPath, ClientConsensusStatePath, ClientStatePath, CommitmentsPath,
-    ConnectionsPath, ReceiptsPath, SeqRecvsPath,
+    AcksPath, ChannelEndsPath, ChannelUpgradeErrorsPath, ChannelUpgradesPath,
+    ClientConsensusStatePath, ClientStatePath, CommitmentsPath, ConnectionsPath, ReceiptsPath,
+    SeqRecvsPath,
 };
 use ibc_relayer_types::core::ics24_host::{
     ClientUpgradePath, Path, IBC_QUERY_PATH, SDK_UPGRADE_QUERY_PATH,
@@ -61,7 +67,7 @@ use tendermint_light_client::verifier::types::LightBlock as TmLightBlock;
 use tendermint_rpc::client::CompatMode;
 use tendermint_rpc::endpoint::broadcast::tx_sync::Response;
 use tendermint_rpc::endpoint::status;
-use tendermint_rpc::{Client, HttpClient, Order};
+use tendermint_rpc::{Client, HttpClient, Order, Url};
 
 use crate::account::Balance;
 use crate::chain::client::ClientSettings;
@@ -69,7 +75,8 @@ use crate::chain::cosmos::batch::{
     send_batched_messages_and_wait_check_tx, send_batched_messages_and_wait_commit,
     sequential_send_batched_messages_and_wait_commit,
 };
-use crate::chain::cosmos::encode::key_pair_to_signer;
+use crate::chain::cosmos::encode::{key_pair_to_signer, replace_signer, signer_field_tag};
+use crate::chain::cosmos::endpoints::{Endpoints, RpcEndpoint};
 use crate::chain::cosmos::fee::maybe_register_counterparty_payee;
 use crate::chain::cosmos::gas::{calculate_fee, mul_ceil};
 use crate::chain::cosmos::query::account::get_or_fetch_account;
@@ -93,16 +100,20 @@ use crate::chain::handle::Subscription;
 use crate::chain::requests::*;
 use crate::chain::tracking::TrackedMsgs;
 use crate::client_state::{AnyClientState, IdentifiedAnyClientState};
-use crate::config::{parse_gas_prices, ChainConfig, GasPrice};
+use crate::config::{parse_gas_prices, ChainConfig, EventSourceMode, GasPrice};
 use crate::consensus_state::AnyConsensusState;
 use crate::denom::DenomTrace;
 use crate::error::Error;
 use crate::event::monitor::{EventMonitor, TxMonitorCmd};
+use crate::event::poller::EventPoller;
 use crate::event::IbcEventWithHeight;
-use crate::keyring::{KeyRing, Secp256k1KeyPair, SigningKeyPair};
+use crate::keyring::{
+    KeyRing, RemoteSigner, Secp256k1KeyPair, Secp256k1SigningKey, SigningKeyPair,
+};
 use crate::light_client::tendermint::LightClient as TmLightClient;
 use crate::light_client::{LightClient, Verified};
 use crate::misbehaviour::MisbehaviourEvidence;
+use crate::util::lock::{LockExt, RwArc};
 use crate::util::pretty::{
     PrettyIdentifiedChannel, PrettyIdentifiedClientState, PrettyIdentifiedConnection,
 };
@@ -111,6 +122,7 @@ pub mod batch;
 pub mod client;
 pub mod compatibility;
 pub mod encode;
+pub mod endpoints;
 pub mod estimate;
 pub mod fee;
 pub mod gas;
@@ -140,15 +152,27 @@ pub const BLOCK_MAX_BYTES_MAX_FRACTION: f64 = 0.9;
 pub struct CosmosSdkChain {
     config: ChainConfig,
     tx_config: TxConfig,
-    rpc_client: HttpClient,
+    /// The RPC endpoints of the chain, along with their health
+    rpc_endpoints: RwArc<Endpoints<RpcEndpoint>>,
     compat_mode: CompatMode,
-    grpc_addr: Uri,
+    /// The gRPC endpoints of the chain, along with their health
+    grpc_endpoints: RwArc<Endpoints<Uri>>,
     light_client: TmLightClient,
+    /// The address of the RPC endpoint the light client fetches light blocks from
+    light_client_addr: Url,
     rt: Arc<TokioRuntime>,
     keybase: KeyRing<Secp256k1KeyPair>,
 
-    /// A cached copy of the account information
-    account: Option<Account>,
+    /// The remote signer to sign transactions with,
+    /// if one is configured instead of a local key
+    remote_signer: Option<RemoteSigner>,
+
+    /// A cached copy of the account information of each signing key, by key name
+    accounts: BTreeMap<String, Option<Account>>,
+
+    /// Index of the signing key to use for the next batch of messages,
+    /// when additional signing keys are configured
+    next_key_index: usize,
 
     tx_monitor_cmd: Option<TxMonitorCmd>,
 }
@@ -170,6 +194,77 @@ impl CosmosSdkChain {
             .map_err(Error::key_base)
     }
 
+    /// The key with which to sign transactions: the remote signer if one
+    /// is configured, otherwise the key `key_name` from the key store.
+    fn signing_key(&self) -> Result<Secp256k1SigningKey, Error> {
+        match &self.remote_signer {
+            Some(remote_signer) => Ok(remote_signer.clone().into()),
+            None => self.key().map(Into::into),
+        }
+    }
+
+    fn additional_signing_key(&self, key_name: &str) -> Result<Secp256k1SigningKey, Error> {
+        self.keybase()
+            .get_key(key_name)
+            .map(Into::into)
+            .map_err(Error::key_base)
+    }
+
+    /// All the keys with which transactions may be signed, by key name:
+    /// the key `key_name` followed by the additional signing keys, if any.
+    fn signing_keys(&self) -> Result<Vec<(String, Secp256k1SigningKey)>, Error> {
+        let mut keys = vec![(self.config.key_name.clone(), self.signing_key()?)];
+
+        for key_name in &self.config.additional_key_names {
+            keys.push((key_name.clone(), self.additional_signing_key(key_name)?));
+        }
+
+        Ok(keys)
+    }
+
+    /// Pick the key with which to sign the given messages.
+    ///
+    /// When additional signing keys are configured, batches made only of IBC core
+    /// messages with a `signer` field are distributed across `key_name` and the
+    /// additional keys in a round-robin fashion, so that they do not all depend on
+    /// the sequence of a single account. The signer of the messages, which is always
+    /// the account of `key_name`, is then rewritten to the account of the selected key.
+    /// Any other batch is signed with `key_name`.
+    fn select_signing_key(
+        &mut self,
+        msgs: Vec<Any>,
+    ) -> Result<(String, Secp256k1SigningKey, Vec<Any>), Error> {
+        let key_pair = self.signing_key()?;
+
+        let round_robin = !self.config.additional_key_names.is_empty()
+            && msgs
+                .iter()
+                .all(|msg| signer_field_tag(&msg.type_url).is_some());
+
+        if !round_robin {
+            return Ok((self.config.key_name.clone(), key_pair, msgs));
+        }
+
+        let key_count = self.config.additional_key_names.len() + 1;
+        let key_index = self.next_key_index % key_count;
+        self.next_key_index = (key_index + 1) % key_count;
+
+        if key_index == 0 {
+            return Ok((self.config.key_name.clone(), key_pair, msgs));
+        }
+
+        let key_name = self.config.additional_key_names[key_index - 1].clone();
+        let key = self.additional_signing_key(&key_name)?;
+
+        let (from, to) = (key_pair.account(), key.account());
+        let msgs = msgs
+            .iter()
+            .map(|msg| replace_signer(msg, &from, &to))
+            .collect::<Result<_, _>>()?;
+
+        Ok((key_name, key, msgs))
+    }
+
     /// Fetches the trusting period as a `Duration` from the chain config.
     /// If no trusting period exists in the config, the trusting period is calculated
     /// as two-thirds of the `unbonding_period`.
@@ -231,16 +326,19 @@ impl CosmosSdkChain {
         let latest_height = self.query_chain_latest_height()?;
 
         // Check on the configured max_tx_size against the consensus parameters at latest height
-        let result = self
-            .block_on(self.rpc_client.consensus_params(latest_height))
-            .map_err(|e| {
-                Error::config_validation_json_rpc(
-                    self.id().clone(),
-                    self.config.rpc_addr.to_string(),
-                    "/consensus_params".to_string(),
-                    e,
-                )
-            })?;
+        let result = self.rpc_request(|rpc_client, rpc_addr| async move {
+            rpc_client
+                .consensus_params(latest_height)
+                .await
+                .map_err(|e| {
+                    Error::config_validation_json_rpc(
+                        self.id().clone(),
+                        rpc_addr.to_string(),
+                        "/consensus_params".to_string(),
+                        e,
+                    )
+                })
+        })?;
 
         let max_bound = result.consensus_params.block.max_bytes;
         let max_allowed = mul_ceil(max_bound, BLOCK_MAX_BYTES_MAX_FRACTION);
@@ -294,20 +392,39 @@ impl CosmosSdkChain {
             }
         );
 
-        let (mut event_monitor, monitor_tx) = EventMonitor::new(
-            self.config.id.clone(),
-            self.config.websocket_addr.clone(),
-            self.compat_mode,
-            self.config.batch_delay,
-            self.rt.clone(),
-        )
-        .map_err(Error::event_monitor)?;
+        let monitor_tx = match self.config.event_source {
+            EventSourceMode::Push => {
+                let (mut event_monitor, monitor_tx) = EventMonitor::new(
+                    self.config.id.clone(),
+                    self.config.websocket_addr.clone(),
+                    self.config.backup_websocket_addrs.clone(),
+                    self.compat_mode,
+                    self.config.batch_delay,
+                    self.rt.clone(),
+                )
+                .map_err(Error::event_monitor)?;
+
+                event_monitor
+                    .init_subscriptions()
+                    .map_err(Error::event_monitor)?;
+
+                thread::spawn(move || event_monitor.run());
+
+                monitor_tx
+            }
+            EventSourceMode::Pull { interval } => {
+                let (event_poller, monitor_tx) = EventPoller::new(
+                    self.config.id.clone(),
+                    self.rpc_endpoints.clone(),
+                    interval,
+                    self.rt.clone(),
+                );
 
-        event_monitor
-            .init_subscriptions()
-            .map_err(Error::event_monitor)?;
+                thread::spawn(move || event_poller.run());
 
-        thread::spawn(move || event_monitor.run());
+                monitor_tx
+            }
+        };
 
         Ok(monitor_tx)
     }
@@ -322,24 +439,27 @@ impl CosmosSdkChain {
         );
         crate::telemetry!(query, self.id(), "query_ccv_consumer_chain_params");
 
-        let mut client = self
-            .block_on(
+        let max_decoding_size = self.config().max_grpc_decoding_size.get_bytes() as usize;
+
+        let response = self.grpc_request(|grpc_addr| async move {
+            let mut client =
                 ibc_proto::interchain_security::ccv::consumer::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone()
-                ),
-            )
-            .map_err(Error::grpc_transport)?;
+                    grpc_addr,
+                )
+                .await
+                .map_err(Error::grpc_transport)?;
 
-        client = client
-            .max_decoding_message_size(self.config().max_grpc_decoding_size.get_bytes() as usize);
+            client = client.max_decoding_message_size(max_decoding_size);
 
-        let request = tonic::Request::new(
-            ibc_proto::interchain_security::ccv::consumer::v1::QueryParamsRequest {},
-        );
+            let request = tonic::Request::new(
+                ibc_proto::interchain_security::ccv::consumer::v1::QueryParamsRequest {},
+            );
 
-        let response = self
-            .block_on(client.query_params(request))
-            .map_err(|e| Error::grpc_status(e, "query_ccv_consumer_chain_params".to_owned()))?;
+            client
+                .query_params(request)
+                .await
+                .map_err(|e| Error::grpc_status(e, "query_ccv_consumer_chain_params".to_owned()))
+        })?;
 
         let params = response
             .into_inner()
@@ -359,23 +479,24 @@ impl CosmosSdkChain {
         );
         crate::telemetry!(query, self.id(), "query_staking_params");
 
-        let mut client = self
-            .block_on(
-                ibc_proto::cosmos::staking::v1beta1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
-            )
-            .map_err(Error::grpc_transport)?;
+        let max_decoding_size = self.config().max_grpc_decoding_size.get_bytes() as usize;
+
+        let response = self.grpc_request(|grpc_addr| async move {
+            let mut client =
+                ibc_proto::cosmos::staking::v1beta1::query_client::QueryClient::connect(grpc_addr)
+                    .await
+                    .map_err(Error::grpc_transport)?;
 
-        client = client
-            .max_decoding_message_size(self.config().max_grpc_decoding_size.get_bytes() as usize);
+            client = client.max_decoding_message_size(max_decoding_size);
 
-        let request =
-            tonic::Request::new(ibc_proto::cosmos::staking::v1beta1::QueryParamsRequest {});
+            let request =
+                tonic::Request::new(ibc_proto::cosmos::staking::v1beta1::QueryParamsRequest {});
 
-        let response = self
-            .block_on(client.params(request))
-            .map_err(|e| Error::grpc_status(e, "query_staking_params".to_owned()))?;
+            client
+                .params(request)
+                .await
+                .map_err(|e| Error::grpc_status(e, "query_staking_params".to_owned()))
+        })?;
 
         let params = response
             .into_inner()
@@ -414,33 +535,36 @@ impl CosmosSdkChain {
                 .contains("unknown service cosmos.base.node.v1beta1.Service")
         }
 
-        let mut client = self
-            .block_on(
+        let max_decoding_size = self.config().max_grpc_decoding_size.get_bytes() as usize;
+
+        self.grpc_request(|grpc_addr| async move {
+            let mut client =
                 ibc_proto::cosmos::base::node::v1beta1::service_client::ServiceClient::connect(
-                    self.grpc_addr.clone(),
-                ),
-            )
-            .map_err(Error::grpc_transport)?;
+                    grpc_addr,
+                )
+                .await
+                .map_err(Error::grpc_transport)?;
 
-        client = client
-            .max_decoding_message_size(self.config().max_grpc_decoding_size.get_bytes() as usize);
+            client = client.max_decoding_message_size(max_decoding_size);
 
-        let request = tonic::Request::new(ibc_proto::cosmos::base::node::v1beta1::ConfigRequest {});
+            let request =
+                tonic::Request::new(ibc_proto::cosmos::base::node::v1beta1::ConfigRequest {});
 
-        match self.block_on(client.config(request)) {
-            Ok(response) => {
-                let params = response.into_inner();
+            match client.config(request).await {
+                Ok(response) => {
+                    let params = response.into_inner();
 
-                Ok(Some(params))
-            }
-            Err(e) => {
-                if is_unimplemented_node_query(&e) {
-                    Ok(None)
-                } else {
-                    Err(Error::grpc_status(e, "query_config_params".to_owned()))
+                    Ok(Some(params))
+                }
+                Err(e) => {
+                    if is_unimplemented_node_query(&e) {
+                        Ok(None)
+                    } else {
+                        Err(Error::grpc_status(e, "query_config_params".to_owned()))
+                    }
                 }
             }
-        }
+        })
     }
 
     /// The minimum gas price that this node accepts
@@ -513,6 +637,115 @@ impl CosmosSdkChain {
         self.rt.block_on(f)
     }
 
+    /// The RPC endpoint currently in use.
+    fn rpc_endpoint(&self) -> RpcEndpoint {
+        self.rpc_endpoints.acquire_read().current().clone()
+    }
+
+    /// The light client, fetching light blocks from the RPC endpoint currently in use.
+    ///
+    /// If another endpoint was selected since the light client was built, it is built
+    /// again against that endpoint, with the identifier of the node behind it.
+    fn light_client(&mut self) -> Result<&mut TmLightClient, Error> {
+        let rpc_addr = self.rpc_endpoint().url;
+
+        if rpc_addr != self.light_client_addr {
+            let config = &self.config;
+            let node_info = self.rpc_request(|rpc_client, rpc_addr| async move {
+                fetch_node_info(&rpc_client, &rpc_addr, config).await
+            })?;
+
+            debug!(
+                chain = %self.id(),
+                "switching the light client to RPC endpoint {rpc_addr}"
+            );
+
+            self.light_client = TmLightClient::from_config(&self.config, &rpc_addr, node_info.id)?;
+            self.light_client_addr = rpc_addr;
+        }
+
+        Ok(&mut self.light_client)
+    }
+
+    /// The address of the gRPC endpoint currently in use.
+    fn grpc_addr(&self) -> Uri {
+        self.grpc_endpoints.acquire_read().current().clone()
+    }
+
+    /// Send a request to the RPC endpoint currently in use.
+    ///
+    /// The outcome and latency of the request are recorded in the health of the
+    /// endpoint, and another endpoint is selected if the request failed because of it.
+    fn rpc_request<T, F, Fut>(&self, request: F) -> Result<T, Error>
+    where
+        F: FnOnce(HttpClient, Url) -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
+        let RpcEndpoint { url, client } = self.rpc_endpoint();
+
+        let start = Instant::now();
+        let result = self.block_on(request(client, url));
+
+        let failed = matches!(&result, Err(e) if e.is_rpc_endpoint_failure());
+        record_request(self.id(), &self.rpc_endpoints, start.elapsed(), failed);
+
+        result
+    }
+
+    /// Send a request to the RPC endpoint currently in use with its Tendermint RPC client,
+    /// recording the outcome of the request as with [`Self::rpc_request`].
+    fn rpc_call<T, F, Fut>(&self, call: F) -> Result<T, Error>
+    where
+        F: FnOnce(HttpClient) -> Fut,
+        Fut: Future<Output = Result<T, tendermint_rpc::Error>>,
+    {
+        self.rpc_request(|rpc_client, rpc_addr| async move {
+            call(rpc_client).await.map_err(|e| Error::rpc(rpc_addr, e))
+        })
+    }
+
+    /// Send a request to the gRPC endpoint currently in use.
+    ///
+    /// The outcome and latency of the request are recorded in the health of the
+    /// endpoint, and another endpoint is selected if the request failed because of it.
+    fn grpc_request<T, F, Fut>(&self, request: F) -> Result<T, Error>
+    where
+        F: FnOnce(Uri) -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
+        let grpc_addr = self.grpc_addr();
+
+        let start = Instant::now();
+        let result = self.block_on(request(grpc_addr));
+
+        let failed = matches!(&result, Err(e) if e.is_grpc_endpoint_failure());
+        record_request(self.id(), &self.grpc_endpoints, start.elapsed(), failed);
+
+        result
+    }
+
+    /// Record a failure to send transactions against the endpoint which caused it, if any.
+    ///
+    /// Only failures are recorded, since the time it takes to submit transactions
+    /// and wait for them to be committed says little about the latency of an endpoint.
+    fn record_send_failure(&self, e: &Error) {
+        if e.is_rpc_endpoint_failure() {
+            record_failure(self.id(), &self.rpc_endpoints);
+        } else if e.is_grpc_endpoint_failure() {
+            record_failure(self.id(), &self.grpc_endpoints);
+        }
+    }
+
+    /// The configuration used to submit transactions,
+    /// targeting the RPC and gRPC endpoints currently in use.
+    fn tx_config(&self) -> TxConfig {
+        TxConfig {
+            rpc_address: self.rpc_endpoint().url,
+            grpc_address: self.grpc_addr(),
+            ..self.tx_config.clone()
+        }
+    }
+
     fn query(
         &self,
         data: impl Into<Path>,
@@ -529,14 +762,17 @@ impl CosmosSdkChain {
             return Err(Error::private_store());
         }
 
-        let response = self.block_on(abci_query(
-            &self.rpc_client,
-            &self.config.rpc_addr,
-            IBC_QUERY_PATH.to_string(),
-            data.to_string(),
-            height_query.into(),
-            prove,
-        ))?;
+        let response = self.rpc_request(|rpc_client, rpc_addr| async move {
+            abci_query(
+                &rpc_client,
+                &rpc_addr,
+                IBC_QUERY_PATH.to_string(),
+                data.to_string(),
+                height_query.into(),
+                prove,
+            )
+            .await
+        })?;
 
         // TODO - Verify response proof, if requested.
         if prove {}
@@ -568,14 +804,17 @@ impl CosmosSdkChain {
     ) -> Result<(Vec<u8>, MerkleProof), Error> {
         let path = SDK_UPGRADE_QUERY_PATH.into();
 
-        let response: QueryResponse = self.block_on(abci_query(
-            &self.rpc_client,
-            &self.config.rpc_addr,
-            path,
-            Path::Upgrade(query_data).to_string(),
-            query_height.into(),
-            true,
-        ))?;
+        let response: QueryResponse = self.rpc_request(|rpc_client, rpc_addr| async move {
+            abci_query(
+                &rpc_client,
+                &rpc_addr,
+                path,
+                Path::Upgrade(query_data).to_string(),
+                query_height.into(),
+                true,
+            )
+            .await
+        })?;
 
         let proof = response.proof.ok_or_else(Error::empty_response_proof)?;
 
@@ -595,13 +834,11 @@ impl CosmosSdkChain {
         );
         crate::telemetry!(query, self.id(), "status");
 
-        let status = self
-            .block_on(self.rpc_client.status())
-            .map_err(|e| Error::rpc(self.config.rpc_addr.clone(), e))?;
+        let status = self.rpc_call(|rpc_client| async move { rpc_client.status().await })?;
 
         if status.sync_info.catching_up {
             return Err(Error::chain_not_caught_up(
-                self.config.rpc_addr.to_string(),
+                self.rpc_endpoint().url.to_string(),
                 self.config().id.clone(),
             ));
         }
@@ -619,11 +856,9 @@ impl CosmosSdkChain {
         );
         crate::telemetry!(query, self.id(), "query_latest_height");
 
-        let status = self.rt.block_on(query_status(
-            self.id(),
-            &self.rpc_client,
-            &self.config.rpc_addr,
-        ))?;
+        let status = self.rpc_request(|rpc_client, rpc_addr| async move {
+            query_status(self.id(), &rpc_client, &rpc_addr).await
+        })?;
 
         Ok(status.height)
     }
@@ -648,18 +883,23 @@ impl CosmosSdkChain {
             }
         );
 
-        let proto_msgs = tracked_msgs.msgs;
-
-        let key_pair = self.key()?;
+        let (key_name, key_pair, proto_msgs) = self.select_signing_key(tracked_msgs.msgs)?;
         let key_account = key_pair.account();
 
-        let account =
-            get_or_fetch_account(&self.grpc_addr, &key_account, &mut self.account).await?;
+        let rpc_client = self.rpc_endpoint().client;
+        let tx_config = self.tx_config();
+
+        let account = get_or_fetch_account(
+            &tx_config.grpc_address,
+            &key_account,
+            self.accounts.entry(key_name).or_default(),
+        )
+        .await?;
 
         if self.config.sequential_batch_tx {
             sequential_send_batched_messages_and_wait_commit(
-                &self.rpc_client,
-                &self.tx_config,
+                &rpc_client,
+                &tx_config,
                 &key_pair,
                 account,
                 &self.config.memo_prefix,
@@ -668,8 +908,8 @@ impl CosmosSdkChain {
             .await
         } else {
             send_batched_messages_and_wait_commit(
-                &self.rpc_client,
-                &self.tx_config,
+                &rpc_client,
+                &tx_config,
                 &key_pair,
                 account,
                 &self.config.memo_prefix,
@@ -699,17 +939,22 @@ impl CosmosSdkChain {
             }
         );
 
-        let proto_msgs = tracked_msgs.msgs;
-
-        let key_pair = self.key()?;
+        let (key_name, key_pair, proto_msgs) = self.select_signing_key(tracked_msgs.msgs)?;
         let key_account = key_pair.account();
 
-        let account =
-            get_or_fetch_account(&self.grpc_addr, &key_account, &mut self.account).await?;
+        let rpc_client = self.rpc_endpoint().client;
+        let tx_config = self.tx_config();
+
+        let account = get_or_fetch_account(
+            &tx_config.grpc_address,
+            &key_account,
+            self.accounts.entry(key_name).or_default(),
+        )
+        .await?;
 
         send_batched_messages_and_wait_check_tx(
-            &self.rpc_client,
-            &self.tx_config,
+            &rpc_client,
+            &tx_config,
             &key_pair,
             account,
             &self.config.memo_prefix,
@@ -738,9 +983,8 @@ impl CosmosSdkChain {
         let tm_height =
             tendermint::block::Height::try_from(block_height.revision_height()).unwrap();
 
-        let response = self
-            .block_on(self.rpc_client.block_results(tm_height))
-            .map_err(|e| Error::rpc(self.config.rpc_addr.clone(), e))?;
+        let response =
+            self.rpc_call(|rpc_client| async move { rpc_client.block_results(tm_height).await })?;
 
         let response_height = ICSHeight::new(self.id().version(), u64::from(response.height))
             .map_err(|_| Error::invalid_height_no_source())?;
@@ -784,26 +1028,28 @@ impl CosmosSdkChain {
         let mut end_block_events = vec![];
 
         for seq in request.sequences.iter().copied() {
-            let response = self
-                .block_on(self.rpc_client.block_search(
-                    packet_query(request, seq),
-                    // We only need the first page
-                    1,
-                    // There should only be a single match for this query, but due to
-                    // the fact that the indexer treat the query as a disjunction over
-                    // all events in a block rather than a conjunction over a single event,
-                    // we may end up with partial matches and therefore have to account for
-                    // that by fetching multiple results and filter it down after the fact.
-                    // In the worst case we get N blocks where N is the number of channels,
-                    // but 10 seems to work well enough in practice while keeping the response
-                    // size, and therefore pressure on the node, fairly low.
-                    10,
-                    // We could pick either ordering here, since matching blocks may be at pretty
-                    // much any height relative to the target blocks, so we went with most recent
-                    // blocks first.
-                    Order::Descending,
-                ))
-                .map_err(|e| Error::rpc(self.config.rpc_addr.clone(), e))?;
+            let response = self.rpc_call(|rpc_client| async move {
+                rpc_client
+                    .block_search(
+                        packet_query(request, seq),
+                        // We only need the first page
+                        1,
+                        // There should only be a single match for this query, but due to
+                        // the fact that the indexer treat the query as a disjunction over
+                        // all events in a block rather than a conjunction over a single event,
+                        // we may end up with partial matches and therefore have to account for
+                        // that by fetching multiple results and filter it down after the fact.
+                        // In the worst case we get N blocks where N is the number of channels,
+                        // but 10 seems to work well enough in practice while keeping the response
+                        // size, and therefore pressure on the node, fairly low.
+                        10,
+                        // We could pick either ordering here, since matching blocks may be at pretty
+                        // much any height relative to the target blocks, so we went with most recent
+                        // blocks first.
+                        Order::Descending,
+                    )
+                    .await
+            })?;
 
             for block in response.blocks.into_iter().map(|response| response.block) {
                 let response_height =
@@ -839,16 +1085,56 @@ impl ChainEndpoint for CosmosSdkChain {
     type SigningKeyPair = Secp256k1KeyPair;
 
     fn bootstrap(config: ChainConfig, rt: Arc<TokioRuntime>) -> Result<Self, Error> {
-        let mut rpc_client = HttpClient::new(config.rpc_addr.clone())
-            .map_err(|e| Error::rpc(config.rpc_addr.clone(), e))?;
+        let mut rpc_endpoints = config
+            .rpc_addrs()
+            .map(|url| {
+                HttpClient::new(url.clone())
+                    .map(|client| RpcEndpoint {
+                        url: url.clone(),
+                        client,
+                    })
+                    .map_err(|e| Error::rpc(url.clone(), e))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
 
-        let node_info = rt.block_on(fetch_node_info(&rpc_client, &config))?;
+        // Bootstrap against the first RPC endpoint which responds, in order of preference.
+        let mut node = None;
+        let mut last_error = None;
+
+        for (index, endpoint) in rpc_endpoints.iter().enumerate() {
+            match rt.block_on(fetch_node_info(&endpoint.client, &endpoint.url, &config)) {
+                Ok(node_info) => {
+                    node = Some((index, node_info));
+                    break;
+                }
+                Err(e) => {
+                    warn!(chain = %config.id, "failed to reach RPC endpoint {endpoint}: {e}");
+                    last_error = Some(e);
+                }
+            }
+        }
+
+        let Some((index, node_info)) = node else {
+            // SAFETY: there is always at least one RPC endpoint, so an error was recorded
+            return Err(last_error.expect("no RPC endpoint configured"));
+        };
 
         let compat_mode = CompatMode::from_version(node_info.version)
-            .map_err(|e| Error::rpc(config.rpc_addr.clone(), e))?;
-        rpc_client.set_compat_mode(compat_mode);
+            .map_err(|e| Error::rpc(rpc_endpoints[index].url.clone(), e))?;
+
+        for endpoint in rpc_endpoints.iter_mut() {
+            endpoint.client.set_compat_mode(compat_mode);
+        }
 
-        let light_client = TmLightClient::from_config(&config, node_info.id)?;
+        // Start with the endpoint which responded, keeping the order of preference of the others.
+        rpc_endpoints.rotate_left(index);
+        let rpc_endpoint = rpc_endpoints.remove(0);
+
+        // The light client must verify that the light blocks are provided by the peer with
+        // the identifier of the node it fetches them from, so it is rebuilt whenever another
+        // RPC endpoint is selected, see `Self::light_client`.
+        let light_client = TmLightClient::from_config(&config, &rpc_endpoint.url, node_info.id)?;
+        let light_client_addr = rpc_endpoint.url.clone();
 
         // Initialize key store and load key
         let keybase = KeyRing::new_secp256k1(
@@ -859,8 +1145,29 @@ impl ChainEndpoint for CosmosSdkChain {
         )
         .map_err(Error::key_base)?;
 
-        let grpc_addr = Uri::from_str(&config.grpc_addr.to_string())
-            .map_err(|e| Error::invalid_uri(config.grpc_addr.to_string(), e))?;
+        let remote_signer = config
+            .remote_signer
+            .as_ref()
+            .map(|remote_signer| {
+                RemoteSigner::connect(
+                    remote_signer,
+                    &config.key_name,
+                    &config.address_type,
+                    &config.account_prefix,
+                )
+            })
+            .transpose()
+            .map_err(Error::key_base)?;
+
+        let mut grpc_addrs = config
+            .grpc_addrs()
+            .map(|addr| {
+                Uri::from_str(&addr.to_string())
+                    .map_err(|e| Error::invalid_uri(addr.to_string(), e))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let grpc_addr = grpc_addrs.remove(0);
 
         let tx_config = TxConfig::try_from(&config)?;
 
@@ -868,14 +1175,17 @@ impl ChainEndpoint for CosmosSdkChain {
 
         let chain = Self {
             config,
-            rpc_client,
+            rpc_endpoints: RwArc::new_lock(Endpoints::new(rpc_endpoint, rpc_endpoints)),
             compat_mode,
-            grpc_addr,
+            grpc_endpoints: RwArc::new_lock(Endpoints::new(grpc_addr, grpc_addrs)),
             light_client,
+            light_client_addr,
             rt,
             keybase,
+            remote_signer,
             tx_config,
-            account: None,
+            accounts: BTreeMap::new(),
+            next_key_index: 0,
             tx_monitor_cmd: None,
         };
 
@@ -959,7 +1269,7 @@ impl ChainEndpoint for CosmosSdkChain {
 
         let now = self.chain_status()?.sync_info.latest_block_time;
 
-        self.light_client
+        self.light_client()?
             .verify(trusted, target, client_state, now)
             .map(|v| v.target)
     }
@@ -979,7 +1289,7 @@ impl ChainEndpoint for CosmosSdkChain {
 
         let now = self.chain_status()?.sync_info.latest_block_time;
 
-        self.light_client
+        self.light_client()?
             .detect_misbehaviour(update, client_state, now)
     }
 
@@ -999,7 +1309,13 @@ impl ChainEndpoint for CosmosSdkChain {
     ) -> Result<Vec<IbcEventWithHeight>, Error> {
         let runtime = self.rt.clone();
 
-        runtime.block_on(self.do_send_messages_and_wait_commit(tracked_msgs))
+        let result = runtime.block_on(self.do_send_messages_and_wait_commit(tracked_msgs));
+
+        if let Err(e) = &result {
+            self.record_send_failure(e);
+        }
+
+        result
     }
 
     fn send_messages_and_wait_check_tx(
@@ -1008,13 +1324,19 @@ impl ChainEndpoint for CosmosSdkChain {
     ) -> Result<Vec<Response>, Error> {
         let runtime = self.rt.clone();
 
-        runtime.block_on(self.do_send_messages_and_wait_check_tx(tracked_msgs))
+        let result = runtime.block_on(self.do_send_messages_and_wait_check_tx(tracked_msgs));
+
+        if let Err(e) = &result {
+            self.record_send_failure(e);
+        }
+
+        result
     }
 
     /// Get the account for the signer
     fn get_signer(&self) -> Result<Signer, Error> {
-        // Get the key from key seed file
-        let key_pair = self.key()?;
+        // Get the key from key seed file, or from the remote signer
+        let key_pair = self.signing_key()?;
 
         let signer = key_pair_to_signer(&key_pair)?;
 
@@ -1027,21 +1349,28 @@ impl ChainEndpoint for CosmosSdkChain {
     }
 
     fn ibc_version(&self) -> Result<Option<semver::Version>, Error> {
-        let version_specs = self.block_on(fetch_version_specs(self.id(), &self.grpc_addr))?;
+        let version_specs = self.grpc_request(|grpc_addr| async move {
+            fetch_version_specs(self.id(), &grpc_addr).await
+        })?;
         Ok(version_specs.ibc_go)
     }
 
     fn query_balance(&self, key_name: Option<&str>, denom: Option<&str>) -> Result<Balance, Error> {
         // If a key_name is given, extract the account hash.
         // Else retrieve the account from the configuration file.
-        let key = match key_name {
-            Some(key_name) => self.keybase().get_key(key_name).map_err(Error::key_base)?,
-            None => self.key()?,
+        let account = match key_name {
+            Some(key_name) => self
+                .keybase()
+                .get_key(key_name)
+                .map_err(Error::key_base)?
+                .account(),
+            None => self.signing_key()?.account(),
         };
-        let account = key.account();
 
         let denom = denom.unwrap_or(&self.config.gas_price.denom);
-        let balance = self.block_on(query_balance(&self.grpc_addr, &account, denom))?;
+        let balance = self.grpc_request(|grpc_addr| async move {
+            query_balance(&grpc_addr, &account, denom).await
+        })?;
 
         Ok(balance)
     }
@@ -1049,19 +1378,25 @@ impl ChainEndpoint for CosmosSdkChain {
     fn query_all_balances(&self, key_name: Option<&str>) -> Result<Vec<Balance>, Error> {
         // If a key_name is given, extract the account hash.
         // Else retrieve the account from the configuration file.
-        let key = match key_name {
-            Some(key_name) => self.keybase().get_key(key_name).map_err(Error::key_base)?,
-            None => self.key()?,
+        let account = match key_name {
+            Some(key_name) => self
+                .keybase()
+                .get_key(key_name)
+                .map_err(Error::key_base)?
+                .account(),
+            None => self.signing_key()?.account(),
         };
-        let account = key.account();
 
-        let balance = self.block_on(query_all_balances(&self.grpc_addr, &account))?;
+        let balance = self.grpc_request(|grpc_addr| async move {
+            query_all_balances(&grpc_addr, &account).await
+        })?;
 
         Ok(balance)
     }
 
     fn query_denom_trace(&self, hash: String) -> Result<DenomTrace, Error> {
-        let denom_trace = self.block_on(query_denom_trace(&self.grpc_addr, &hash))?;
+        let denom_trace = self
+            .grpc_request(|grpc_addr| async move { query_denom_trace(&grpc_addr, &hash).await })?;
 
         Ok(denom_trace)
     }
@@ -1093,14 +1428,12 @@ impl ChainEndpoint for CosmosSdkChain {
         // We cannot rely on `/status` endpoint to provide details about the latest block.
         // Instead, we need to pull block height via `/abci_info` and then fetch block
         // metadata at the given height via `/blockchain` endpoint.
-        let abci_info = self
-            .block_on(self.rpc_client.abci_info())
-            .map_err(|e| Error::rpc(self.config.rpc_addr.clone(), e))?;
+        let abci_info = self.rpc_call(|rpc_client| async move { rpc_client.abci_info().await })?;
 
         // Query `/header` endpoint to pull the latest block that the application committed.
-        let response = self
-            .block_on(self.rpc_client.header(abci_info.last_block_height))
-            .map_err(|e| Error::rpc(self.config.rpc_addr.clone(), e))?;
+        let response = self.rpc_call(|rpc_client| async move {
+            rpc_client.header(abci_info.last_block_height).await
+        })?;
 
         let height = ICSHeight::new(
             ChainId::chain_version(response.header.chain_id.as_str()),
@@ -1124,21 +1457,23 @@ impl ChainEndpoint for CosmosSdkChain {
         );
         crate::telemetry!(query, self.id(), "query_clients");
 
-        let mut client = self
-            .block_on(
-                ibc_proto::ibc::core::client::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
-            )
-            .map_err(Error::grpc_transport)?;
-
-        client = client
-            .max_decoding_message_size(self.config().max_grpc_decoding_size.get_bytes() as usize);
+        let max_decoding_size = self.config().max_grpc_decoding_size.get_bytes() as usize;
 
-        let request = tonic::Request::new(request.into());
         let response = self
-            .block_on(client.client_states(request))
-            .map_err(|e| Error::grpc_status(e, "query_clients".to_owned()))?
+            .grpc_request(|grpc_addr| async move {
+                let mut client =
+                    ibc_proto::ibc::core::client::v1::query_client::QueryClient::connect(grpc_addr)
+                        .await
+                        .map_err(Error::grpc_transport)?;
+
+                client = client.max_decoding_message_size(max_decoding_size);
+
+                let request = tonic::Request::new(request.into());
+                client
+                    .client_states(request)
+                    .await
+                    .map_err(|e| Error::grpc_status(e, "query_clients".to_owned()))
+            })?
             .into_inner();
 
         // Deserialize into domain type
@@ -1156,6 +1491,10 @@ impl ChainEndpoint for CosmosSdkChain {
                     })
                     .ok()
             })
+            .map(|mut c| {
+                c.client_state = c.client_state.with_localhost_chain_id(&self.config.id);
+                c
+            })
             .collect();
 
         // Sort by client identifier counter
@@ -1182,7 +1521,9 @@ impl ChainEndpoint for CosmosSdkChain {
             request.height,
             matches!(include_proof, IncludeProof::Yes),
         )?;
-        let client_state = AnyClientState::decode_vec(&res.value).map_err(Error::decode)?;
+        let client_state = AnyClientState::decode_vec(&res.value)
+            .map_err(Error::decode)?
+            .with_localhost_chain_id(&self.config.id);
 
         match include_proof {
             IncludeProof::Yes => {
@@ -1255,11 +1596,9 @@ impl ChainEndpoint for CosmosSdkChain {
         &self,
         request: QueryConsensusStateHeightsRequest,
     ) -> Result<Vec<ICSHeight>, Error> {
-        self.block_on(query_consensus_state_heights(
-            self.id(),
-            &self.grpc_addr,
-            request,
-        ))
+        self.grpc_request(|grpc_addr| async move {
+            query_consensus_state_heights(self.id(), &grpc_addr, request).await
+        })
     }
 
     fn query_consensus_state(
@@ -1315,23 +1654,27 @@ impl ChainEndpoint for CosmosSdkChain {
         );
         crate::telemetry!(query, self.id(), "query_client_connections");
 
-        let mut client = self
-            .block_on(
-                ibc_proto::ibc::core::connection::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
-            )
-            .map_err(Error::grpc_transport)?;
+        let max_decoding_size = self.config().max_grpc_decoding_size.get_bytes() as usize;
+
+        let response = self.grpc_request(|grpc_addr| async move {
+            let mut client =
+                ibc_proto::ibc::core::connection::v1::query_client::QueryClient::connect(grpc_addr)
+                    .await
+                    .map_err(Error::grpc_transport)?;
 
-        client = client
-            .max_decoding_message_size(self.config().max_grpc_decoding_size.get_bytes() as usize);
+            client = client.max_decoding_message_size(max_decoding_size);
 
-        let request = tonic::Request::new(request.into());
+            let request = tonic::Request::new(request.into());
+
+            match client.client_connections(request).await {
+                Ok(res) => Ok(Some(res.into_inner())),
+                Err(e) if e.code() == tonic::Code::NotFound => Ok(None),
+                Err(e) => Err(Error::grpc_status(e, "query_client_connections".to_owned())),
+            }
+        })?;
 
-        let response = match self.block_on(client.client_connections(request)) {
-            Ok(res) => res.into_inner(),
-            Err(e) if e.code() == tonic::Code::NotFound => return Ok(vec![]),
-            Err(e) => return Err(Error::grpc_status(e, "query_client_connections".to_owned())),
+        let Some(response) = response else {
+            return Ok(vec![]);
         };
 
         let ids = response
@@ -1359,22 +1702,26 @@ impl ChainEndpoint for CosmosSdkChain {
         );
         crate::telemetry!(query, self.id(), "query_connections");
 
-        let mut client = self
-            .block_on(
-                ibc_proto::ibc::core::connection::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
-            )
-            .map_err(Error::grpc_transport)?;
+        let max_decoding_size = self.config().max_grpc_decoding_size.get_bytes() as usize;
 
-        client = client
-            .max_decoding_message_size(self.config().max_grpc_decoding_size.get_bytes() as usize);
+        let response = self
+            .grpc_request(|grpc_addr| async move {
+                let mut client =
+                    ibc_proto::ibc::core::connection::v1::query_client::QueryClient::connect(
+                        grpc_addr,
+                    )
+                    .await
+                    .map_err(Error::grpc_transport)?;
 
-        let request = tonic::Request::new(request.into());
+                client = client.max_decoding_message_size(max_decoding_size);
 
-        let response = self
-            .block_on(client.connections(request))
-            .map_err(|e| Error::grpc_status(e, "query_connections".to_owned()))?
+                let request = tonic::Request::new(request.into());
+
+                client
+                    .connections(request)
+                    .await
+                    .map_err(|e| Error::grpc_status(e, "query_connections".to_owned()))
+            })?
             .into_inner();
 
         let connections = response
@@ -1411,16 +1758,16 @@ impl ChainEndpoint for CosmosSdkChain {
 
         async fn do_query_connection(
             chain: &CosmosSdkChain,
+            grpc_addr: Uri,
             connection_id: &ConnectionId,
             height_query: QueryHeight,
         ) -> Result<ConnectionEnd, Error> {
             use ibc_proto::ibc::core::connection::v1 as connection;
             use tonic::IntoRequest;
 
-            let mut client =
-                connection::query_client::QueryClient::connect(chain.grpc_addr.clone())
-                    .await
-                    .map_err(Error::grpc_transport)?;
+            let mut client = connection::query_client::QueryClient::connect(grpc_addr)
+                .await
+                .map_err(Error::grpc_transport)?;
 
             client = client.max_decoding_message_size(
                 chain.config().max_grpc_decoding_size.get_bytes() as usize,
@@ -1477,8 +1824,9 @@ impl ChainEndpoint for CosmosSdkChain {
                 ))
             }
             IncludeProof::No => self
-                .block_on(async {
-                    do_query_connection(self, &request.connection_id, request.height).await
+                .grpc_request(|grpc_addr| async move {
+                    do_query_connection(self, grpc_addr, &request.connection_id, request.height)
+                        .await
                 })
                 .map(|conn_end| (conn_end, None)),
         }
@@ -1496,22 +1844,26 @@ impl ChainEndpoint for CosmosSdkChain {
         );
         crate::telemetry!(query, self.id(), "query_connection_channels");
 
-        let mut client = self
-            .block_on(
-                ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
-            )
-            .map_err(Error::grpc_transport)?;
+        let max_decoding_size = self.config().max_grpc_decoding_size.get_bytes() as usize;
 
-        client = client
-            .max_decoding_message_size(self.config().max_grpc_decoding_size.get_bytes() as usize);
+        let response = self
+            .grpc_request(|grpc_addr| async move {
+                let mut client =
+                    ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
+                        grpc_addr,
+                    )
+                    .await
+                    .map_err(Error::grpc_transport)?;
 
-        let request = tonic::Request::new(request.into());
+                client = client.max_decoding_message_size(max_decoding_size);
 
-        let response = self
-            .block_on(client.connection_channels(request))
-            .map_err(|e| Error::grpc_status(e, "query_connection_channels".to_owned()))?
+                let request = tonic::Request::new(request.into());
+
+                client
+                    .connection_channels(request)
+                    .await
+                    .map_err(|e| Error::grpc_status(e, "query_connection_channels".to_owned()))
+            })?
             .into_inner();
 
         let channels = response
@@ -1544,22 +1896,26 @@ impl ChainEndpoint for CosmosSdkChain {
         );
         crate::telemetry!(query, self.id(), "query_channels");
 
-        let mut client = self
-            .block_on(
-                ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
-            )
-            .map_err(Error::grpc_transport)?;
+        let max_decoding_size = self.config().max_grpc_decoding_size.get_bytes() as usize;
+
+        let response = self
+            .grpc_request(|grpc_addr| async move {
+                let mut client =
+                    ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
+                        grpc_addr,
+                    )
+                    .await
+                    .map_err(Error::grpc_transport)?;
 
-        client = client
-            .max_decoding_message_size(self.config().max_grpc_decoding_size.get_bytes() as usize);
+                client = client.max_decoding_message_size(max_decoding_size);
 
-        let request = tonic::Request::new(request.into());
+                let request = tonic::Request::new(request.into());
 
-        let response = self
-            .block_on(client.channels(request))
-            .map_err(|e| Error::grpc_status(e, "query_channels".to_owned()))?
+                client
+                    .channels(request)
+                    .await
+                    .map_err(|e| Error::grpc_status(e, "query_channels".to_owned()))
+            })?
             .into_inner();
 
         let channels = response
@@ -1600,7 +1956,8 @@ impl ChainEndpoint for CosmosSdkChain {
             matches!(include_proof, IncludeProof::Yes),
         )?;
 
-        let channel_end = ChannelEnd::decode_vec(&res.value).map_err(Error::decode)?;
+        let channel_end =
+            Protobuf::<RawUpgradableChannel>::decode_vec(&res.value).map_err(Error::decode)?;
 
         match include_proof {
             IncludeProof::Yes => {
@@ -1623,22 +1980,26 @@ impl ChainEndpoint for CosmosSdkChain {
         );
         crate::telemetry!(query, self.id(), "query_channel_client_state");
 
-        let mut client = self
-            .block_on(
-                ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
-            )
-            .map_err(Error::grpc_transport)?;
+        let max_decoding_size = self.config().max_grpc_decoding_size.get_bytes() as usize;
 
-        client = client
-            .max_decoding_message_size(self.config().max_grpc_decoding_size.get_bytes() as usize);
+        let response = self
+            .grpc_request(|grpc_addr| async move {
+                let mut client =
+                    ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
+                        grpc_addr,
+                    )
+                    .await
+                    .map_err(Error::grpc_transport)?;
 
-        let request = tonic::Request::new(request.into());
+                client = client.max_decoding_message_size(max_decoding_size);
 
-        let response = self
-            .block_on(client.channel_client_state(request))
-            .map_err(|e| Error::grpc_status(e, "query_channel_client_state".to_owned()))?
+                let request = tonic::Request::new(request.into());
+
+                client
+                    .channel_client_state(request)
+                    .await
+                    .map_err(|e| Error::grpc_status(e, "query_channel_client_state".to_owned()))
+            })?
             .into_inner();
 
         let client_state: Option<IdentifiedAnyClientState> = response
@@ -1686,22 +2047,26 @@ impl ChainEndpoint for CosmosSdkChain {
         );
         crate::telemetry!(query, self.id(), "query_packet_commitments");
 
-        let mut client = self
-            .block_on(
-                ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
-            )
-            .map_err(Error::grpc_transport)?;
+        let max_decoding_size = self.config().max_grpc_decoding_size.get_bytes() as usize;
+
+        let response = self
+            .grpc_request(|grpc_addr| async move {
+                let mut client =
+                    ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
+                        grpc_addr,
+                    )
+                    .await
+                    .map_err(Error::grpc_transport)?;
 
-        client = client
-            .max_decoding_message_size(self.config().max_grpc_decoding_size.get_bytes() as usize);
+                client = client.max_decoding_message_size(max_decoding_size);
 
-        let request = tonic::Request::new(request.into());
+                let request = tonic::Request::new(request.into());
 
-        let response = self
-            .block_on(client.packet_commitments(request))
-            .map_err(|e| Error::grpc_status(e, "query_packet_commitments".to_owned()))?
+                client
+                    .packet_commitments(request)
+                    .await
+                    .map_err(|e| Error::grpc_status(e, "query_packet_commitments".to_owned()))
+            })?
             .into_inner();
 
         let mut commitment_sequences: Vec<Sequence> = response
@@ -1757,22 +2122,26 @@ impl ChainEndpoint for CosmosSdkChain {
         );
         crate::telemetry!(query, self.id(), "query_unreceived_packets");
 
-        let mut client = self
-            .block_on(
-                ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
-            )
-            .map_err(Error::grpc_transport)?;
+        let max_decoding_size = self.config().max_grpc_decoding_size.get_bytes() as usize;
+
+        let mut response = self
+            .grpc_request(|grpc_addr| async move {
+                let mut client =
+                    ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
+                        grpc_addr,
+                    )
+                    .await
+                    .map_err(Error::grpc_transport)?;
 
-        client = client
-            .max_decoding_message_size(self.config().max_grpc_decoding_size.get_bytes() as usize);
+                client = client.max_decoding_message_size(max_decoding_size);
 
-        let request = tonic::Request::new(request.into());
+                let request = tonic::Request::new(request.into());
 
-        let mut response = self
-            .block_on(client.unreceived_packets(request))
-            .map_err(|e| Error::grpc_status(e, "query_unreceived_packets".to_owned()))?
+                client
+                    .unreceived_packets(request)
+                    .await
+                    .map_err(|e| Error::grpc_status(e, "query_unreceived_packets".to_owned()))
+            })?
             .into_inner();
 
         response.sequences.sort_unstable();
@@ -1825,22 +2194,26 @@ impl ChainEndpoint for CosmosSdkChain {
             return Ok((Vec::new(), self.query_chain_latest_height()?));
         }
 
-        let mut client = self
-            .block_on(
-                ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
-            )
-            .map_err(Error::grpc_transport)?;
+        let max_decoding_size = self.config().max_grpc_decoding_size.get_bytes() as usize;
 
-        client = client
-            .max_decoding_message_size(self.config().max_grpc_decoding_size.get_bytes() as usize);
+        let response = self
+            .grpc_request(|grpc_addr| async move {
+                let mut client =
+                    ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
+                        grpc_addr,
+                    )
+                    .await
+                    .map_err(Error::grpc_transport)?;
 
-        let request = tonic::Request::new(request.into());
+                client = client.max_decoding_message_size(max_decoding_size);
 
-        let response = self
-            .block_on(client.packet_acknowledgements(request))
-            .map_err(|e| Error::grpc_status(e, "query_packet_acknowledgements".to_owned()))?
+                let request = tonic::Request::new(request.into());
+
+                client
+                    .packet_acknowledgements(request)
+                    .await
+                    .map_err(|e| Error::grpc_status(e, "query_packet_acknowledgements".to_owned()))
+            })?
             .into_inner();
 
         let acks_sequences = response
@@ -1870,22 +2243,25 @@ impl ChainEndpoint for CosmosSdkChain {
         );
         crate::telemetry!(query, self.id(), "query_unreceived_acknowledgements");
 
-        let mut client = self
-            .block_on(
-                ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
-                    self.grpc_addr.clone(),
-                ),
-            )
-            .map_err(Error::grpc_transport)?;
+        let max_decoding_size = self.config().max_grpc_decoding_size.get_bytes() as usize;
 
-        client = client
-            .max_decoding_message_size(self.config().max_grpc_decoding_size.get_bytes() as usize);
+        let mut response = self
+            .grpc_request(|grpc_addr| async move {
+                let mut client =
+                    ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
+                        grpc_addr,
+                    )
+                    .await
+                    .map_err(Error::grpc_transport)?;
 
-        let request = tonic::Request::new(request.into());
+                client = client.max_decoding_message_size(max_decoding_size);
 
-        let mut response = self
-            .block_on(client.unreceived_acks(request))
-            .map_err(|e| Error::grpc_status(e, "query_unreceived_acknowledgements".to_owned()))?
+                let request = tonic::Request::new(request.into());
+
+                client.unreceived_acks(request).await.map_err(|e| {
+                    Error::grpc_status(e, "query_unreceived_acknowledgements".to_owned())
+                })
+            })?
             .into_inner();
 
         response.sequences.sort_unstable();
@@ -1929,23 +2305,25 @@ impl ChainEndpoint for CosmosSdkChain {
                 Ok((seq, Some(proof)))
             }
             IncludeProof::No => {
-                let mut client = self
-                    .block_on(
-                        ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
-                            self.grpc_addr.clone(),
-                        ),
-                    )
-                    .map_err(Error::grpc_transport)?;
-
-                client = client.max_decoding_message_size(
-                    self.config().max_grpc_decoding_size.get_bytes() as usize,
-                );
-
-                let request = tonic::Request::new(request.into());
+                let max_decoding_size = self.config().max_grpc_decoding_size.get_bytes() as usize;
 
                 let response = self
-                    .block_on(client.next_sequence_receive(request))
-                    .map_err(|e| Error::grpc_status(e, "query_next_sequence_receive".to_owned()))?
+                    .grpc_request(|grpc_addr| async move {
+                        let mut client =
+                            ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
+                                grpc_addr,
+                            )
+                            .await
+                            .map_err(Error::grpc_transport)?;
+
+                        client = client.max_decoding_message_size(max_decoding_size);
+
+                        let request = tonic::Request::new(request.into());
+
+                        client.next_sequence_receive(request).await.map_err(|e| {
+                            Error::grpc_status(e, "query_next_sequence_receive".to_owned())
+                        })
+                    })?
                     .into_inner();
 
                 Ok((Sequence::from(response.next_sequence_receive), None))
@@ -1953,6 +2331,66 @@ impl ChainEndpoint for CosmosSdkChain {
         }
     }
 
+    fn query_upgrade(
+        &self,
+        request: QueryUpgradeRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(Upgrade, Option<MerkleProof>), Error> {
+        crate::time!(
+            "query_upgrade",
+            {
+                "src_chain": self.config().id.to_string(),
+            }
+        );
+        crate::telemetry!(query, self.id(), "query_upgrade");
+
+        let res = self.query(
+            ChannelUpgradesPath(request.port_id, request.channel_id),
+            request.height,
+            matches!(include_proof, IncludeProof::Yes),
+        )?;
+
+        let upgrade = Upgrade::decode_vec(&res.value).map_err(Error::decode)?;
+
+        match include_proof {
+            IncludeProof::Yes => {
+                let proof = res.proof.ok_or_else(Error::empty_response_proof)?;
+                Ok((upgrade, Some(proof)))
+            }
+            IncludeProof::No => Ok((upgrade, None)),
+        }
+    }
+
+    fn query_upgrade_error(
+        &self,
+        request: QueryUpgradeErrorRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(ErrorReceipt, Option<MerkleProof>), Error> {
+        crate::time!(
+            "query_upgrade_error",
+            {
+                "src_chain": self.config().id.to_string(),
+            }
+        );
+        crate::telemetry!(query, self.id(), "query_upgrade_error");
+
+        let res = self.query(
+            ChannelUpgradeErrorsPath(request.port_id, request.channel_id),
+            request.height,
+            matches!(include_proof, IncludeProof::Yes),
+        )?;
+
+        let error_receipt = ErrorReceipt::decode_vec(&res.value).map_err(Error::decode)?;
+
+        match include_proof {
+            IncludeProof::Yes => {
+                let proof = res.proof.ok_or_else(Error::empty_response_proof)?;
+                Ok((error_receipt, Some(proof)))
+            }
+            IncludeProof::No => Ok((error_receipt, None)),
+        }
+    }
+
     /// This function queries transactions for events matching certain criteria.
     /// 1. Client Update request - returns a vector with at most one update client event
     /// 2. Transaction event request - returns all IBC events resulted from a Tx execution
@@ -1963,12 +2401,9 @@ impl ChainEndpoint for CosmosSdkChain {
         });
         crate::telemetry!(query, self.id(), "query_txs");
 
-        self.block_on(query_txs(
-            self.id(),
-            &self.rpc_client,
-            &self.config.rpc_addr,
-            request,
-        ))
+        self.rpc_request(|rpc_client, rpc_addr| async move {
+            query_txs(self.id(), &rpc_client, &rpc_addr, request).await
+        })
     }
 
     /// This function queries transactions for packet events matching certain criteria.
@@ -1996,19 +2431,14 @@ impl ChainEndpoint for CosmosSdkChain {
             // Usage note: `Qualified::Equal` is currently only used in the call hierarchy involving
             // the CLI methods, namely the CLI for `tx packet-recv` and `tx packet-ack` when the
             // user passes the flag `packet-data-query-height`.
-            Qualified::Equal(_) => self.block_on(query_packets_from_block(
-                self.id(),
-                &self.rpc_client,
-                &self.config.rpc_addr,
-                &request,
-            )),
+            Qualified::Equal(_) => self.rpc_request(|rpc_client, rpc_addr| async move {
+                query_packets_from_block(self.id(), &rpc_client, &rpc_addr, &request).await
+            }),
             Qualified::SmallerEqual(_) => {
-                let tx_events = self.block_on(query_packets_from_txs(
-                    self.id(),
-                    &self.rpc_client,
-                    &self.config.rpc_addr,
-                    &request,
-                ))?;
+                let query = &request;
+                let tx_events = self.rpc_request(|rpc_client, rpc_addr| async move {
+                    query_packets_from_txs(self.id(), &rpc_client, &rpc_addr, query).await
+                })?;
 
                 let recvd_sequences: Vec<_> = tx_events
                     .iter()
@@ -2062,22 +2492,21 @@ impl ChainEndpoint for CosmosSdkChain {
         };
 
         let header = if height.value() == 0 {
-            self.block_on(async {
-                self.rpc_client
+            self.rpc_call(|rpc_client| async move {
+                rpc_client
                     .latest_block()
                     .await
                     .map(|response| response.block.header)
-            })
+            })?
         } else {
-            self.block_on(async {
-                self.rpc_client
+            self.rpc_call(|rpc_client| async move {
+                rpc_client
                     .header(height)
                     .await
                     .map(|response| response.header)
-            })
+            })?
         };
 
-        let header = header.map_err(|e| Error::rpc(self.config.rpc_addr.clone(), e))?;
         Ok(header.into())
     }
 
@@ -2142,7 +2571,7 @@ impl ChainEndpoint for CosmosSdkChain {
         let now = self.chain_status()?.sync_info.latest_block_time;
 
         // Get the light block at target_height from chain.
-        let Verified { target, supporting } = self.light_client.header_and_minimal_set(
+        let Verified { target, supporting } = self.light_client()?.header_and_minimal_set(
             trusted_height,
             target_height,
             client_state,
@@ -2158,29 +2587,39 @@ impl ChainEndpoint for CosmosSdkChain {
         port_id: &PortId,
         counterparty_payee: &Signer,
     ) -> Result<(), Error> {
-        let address = self.get_signer()?;
-        let key_pair = self.key()?;
+        let rpc_client = self.rpc_endpoint().client;
+        let tx_config = self.tx_config();
 
-        self.rt.block_on(maybe_register_counterparty_payee(
-            &self.rpc_client,
-            &self.tx_config,
-            &key_pair,
-            &mut self.account,
-            &self.config.memo_prefix,
-            channel_id,
-            port_id,
-            &address,
-            counterparty_payee,
-        ))
+        // Any of the signing keys may end up relaying the packets,
+        // so the payee is registered for each of them
+        for (key_name, key_pair) in self.signing_keys()? {
+            let address = key_pair_to_signer(&key_pair)?;
+
+            self.rt.block_on(maybe_register_counterparty_payee(
+                &rpc_client,
+                &tx_config,
+                &key_pair,
+                self.accounts.entry(key_name).or_default(),
+                &self.config.memo_prefix,
+                channel_id,
+                port_id,
+                &address,
+                counterparty_payee,
+            ))?;
+        }
+
+        Ok(())
     }
 
     fn cross_chain_query(
         &self,
         requests: Vec<CrossChainQueryRequest>,
     ) -> Result<Vec<CrossChainQueryResponse>, Error> {
+        let rpc_client = self.rpc_endpoint().client;
+
         let tasks = requests
             .into_iter()
-            .map(|req| cross_chain_query_via_rpc(&self.rpc_client, req))
+            .map(|req| cross_chain_query_via_rpc(&rpc_client, req))
             .collect::<Vec<_>>();
 
         let joined_tasks = join_all(tasks);
@@ -2197,8 +2636,9 @@ impl ChainEndpoint for CosmosSdkChain {
         &self,
         request: QueryIncentivizedPacketRequest,
     ) -> Result<QueryIncentivizedPacketResponse, Error> {
-        let incentivized_response =
-            self.block_on(query_incentivized_packet(&self.grpc_addr, request))?;
+        let incentivized_response = self.grpc_request(|grpc_addr| async move {
+            query_incentivized_packet(&grpc_addr, request).await
+        })?;
         Ok(incentivized_response)
     }
 }
@@ -2213,8 +2653,34 @@ fn sort_events_by_sequence(events: &mut [IbcEventWithHeight]) {
     });
 }
 
+/// Record the outcome of a request to the endpoint currently in use,
+/// switching to another endpoint if the request failed because of the endpoint.
+fn record_request<E: Display>(
+    chain_id: &ChainId,
+    endpoints: &RwArc<Endpoints<E>>,
+    latency: Duration,
+    failed: bool,
+) {
+    if failed {
+        record_failure(chain_id, endpoints);
+    } else {
+        endpoints.acquire_write().record_success(latency);
+    }
+}
+
+/// Record a failed request to the endpoint currently in use, and switch to another endpoint.
+fn record_failure<E: Display>(chain_id: &ChainId, endpoints: &RwArc<Endpoints<E>>) {
+    let mut endpoints = endpoints.acquire_write();
+    let failed = endpoints.current().to_string();
+
+    if let Some(next) = endpoints.record_failure() {
+        warn!(chain = %chain_id, "request to endpoint {failed} failed, switching to endpoint {next}");
+    }
+}
+
 async fn fetch_node_info(
     rpc_client: &HttpClient,
+    rpc_addr: &Url,
     config: &ChainConfig,
 ) -> Result<node::Info, Error> {
     crate::time!("fetch_node_info",
@@ -2226,7 +2692,7 @@ async fn fetch_node_info(
         .status()
         .await
         .map(|s| s.node_info)
-        .map_err(|e| Error::rpc(config.rpc_addr.clone(), e))
+        .map_err(|e| Error::rpc(rpc_addr.clone(), e))
 }
 
 /// Returns the suffix counter for a CosmosSDK client id.
@@ -2254,10 +2720,11 @@ fn client_id_suffix(client_id: &ClientId) -> Option<u64> {
 ///    advertised by the node Hermes is connected to.
 fn do_health_check(chain: &CosmosSdkChain) -> Result<(), Error> {
     let chain_id = chain.id();
-    let grpc_address = chain.grpc_addr.to_string();
-    let rpc_address = chain.config.rpc_addr.to_string();
+    let grpc_addr = chain.grpc_addr();
+    let rpc_endpoint = chain.rpc_endpoint();
+    let rpc_address = rpc_endpoint.url.to_string();
 
-    chain.block_on(chain.rpc_client.health()).map_err(|e| {
+    chain.block_on(rpc_endpoint.client.health()).map_err(|e| {
         Error::health_check_json_rpc(
             chain_id.clone(),
             rpc_address.clone(),
@@ -2316,12 +2783,12 @@ fn do_health_check(chain: &CosmosSdkChain) -> Result<(), Error> {
         );
     }
 
-    let version_specs = chain.block_on(fetch_version_specs(&chain.config.id, &chain.grpc_addr))?;
+    let version_specs = chain.block_on(fetch_version_specs(&chain.config.id, &grpc_addr))?;
 
     if let Err(diagnostic) = compatibility::run_diagnostic(&version_specs) {
         return Err(Error::sdk_module_version(
             chain_id.clone(),
-            grpc_address,
+            grpc_addr.to_string(),
             diagnostic.to_string(),
         ));
     }
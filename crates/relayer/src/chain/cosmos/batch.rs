@@ -14,12 +14,13 @@ use crate::chain::cosmos::gas::gas_amount_to_fee;
 use crate::chain::cosmos::retry::send_tx_with_account_sequence_retry;
 use crate::chain::cosmos::types::account::Account;
 use crate::chain::cosmos::types::config::TxConfig;
+use crate::chain::cosmos::types::gas::GasConfig;
 use crate::chain::cosmos::types::tx::{TxStatus, TxSyncResult};
 use crate::chain::cosmos::wait::wait_for_block_commits;
 use crate::config::types::Memo;
 use crate::error::Error;
 use crate::event::IbcEventWithHeight;
-use crate::keyring::Secp256k1KeyPair;
+use crate::keyring::Secp256k1SigningKey;
 
 /**
    Broadcast messages as multiple batched transactions to the chain all at once,
@@ -31,7 +32,7 @@ use crate::keyring::Secp256k1KeyPair;
 pub async fn send_batched_messages_and_wait_commit(
     rpc_client: &HttpClient,
     config: &TxConfig,
-    key_pair: &Secp256k1KeyPair,
+    key_pair: &Secp256k1SigningKey,
     account: &mut Account,
     tx_memo: &Memo,
     messages: Vec<Any>,
@@ -52,6 +53,12 @@ pub async fn send_batched_messages_and_wait_commit(
     )
     .await?;
 
+    crate::telemetry!(
+        tx_costs_committed,
+        tx_sync_results.iter().map(|result| result.response.hash),
+        &config.chain_id
+    );
+
     let events = tx_sync_results
         .into_iter()
         .flat_map(|el| el.events)
@@ -68,7 +75,7 @@ pub async fn send_batched_messages_and_wait_commit(
 pub async fn sequential_send_batched_messages_and_wait_commit(
     rpc_client: &HttpClient,
     config: &TxConfig,
-    key_pair: &Secp256k1KeyPair,
+    key_pair: &Secp256k1SigningKey,
     account: &mut Account,
     tx_memo: &Memo,
     messages: Vec<Any>,
@@ -82,6 +89,12 @@ pub async fn sequential_send_batched_messages_and_wait_commit(
     )
     .await?;
 
+    crate::telemetry!(
+        tx_costs_committed,
+        tx_sync_results.iter().map(|result| result.response.hash),
+        &config.chain_id
+    );
+
     let events = tx_sync_results
         .into_iter()
         .flat_map(|el| el.events)
@@ -93,7 +106,7 @@ pub async fn sequential_send_batched_messages_and_wait_commit(
 pub async fn send_batched_messages_and_wait_check_tx(
     rpc_client: &HttpClient,
     config: &TxConfig,
-    key_pair: &Secp256k1KeyPair,
+    key_pair: &Secp256k1SigningKey,
     account: &mut Account,
     tx_memo: &Memo,
     messages: Vec<Any>,
@@ -121,7 +134,7 @@ pub async fn send_batched_messages_and_wait_check_tx(
 async fn send_messages_as_batches(
     rpc_client: &HttpClient,
     config: &TxConfig,
-    key_pair: &Secp256k1KeyPair,
+    key_pair: &Secp256k1SigningKey,
     account: &mut Account,
     tx_memo: &Memo,
     messages: Vec<Any>,
@@ -162,7 +175,7 @@ async fn send_messages_as_batches(
 async fn sequential_send_messages_as_batches(
     rpc_client: &HttpClient,
     config: &TxConfig,
-    key_pair: &Secp256k1KeyPair,
+    key_pair: &Secp256k1SigningKey,
     account: &mut Account,
     tx_memo: &Memo,
     messages: Vec<Any>,
@@ -240,7 +253,7 @@ fn response_to_tx_sync_result(
 
 fn batch_messages(
     config: &TxConfig,
-    key_pair: &Secp256k1KeyPair,
+    key_pair: &Secp256k1SigningKey,
     account: &Account,
     tx_memo: &Memo,
     messages: Vec<Any>,
@@ -252,8 +265,13 @@ fn batch_messages(
 
     // Estimate the overhead of the transaction envelope's encoding,
     // by taking the encoded length of an empty tx with the same auth info and signatures.
-    // Use the maximum possible fee to get an upper bound for varint encoding.
-    let max_fee = gas_amount_to_fee(&config.gas_config, config.gas_config.max_gas);
+    // Use the maximum possible fee to get an upper bound for varint encoding,
+    // at the highest gas price, as the gas price may be queried from the chain.
+    let gas_config = GasConfig {
+        gas_price: config.gas_config.max_gas_price(),
+        ..config.gas_config.clone()
+    };
+    let max_fee = gas_amount_to_fee(&gas_config, gas_config.max_gas);
     let tx_metrics = encoded_tx_metrics(config, key_pair, account, tx_memo, &[], &max_fee)?;
     let tx_envelope_len = tx_metrics.envelope_len;
     let empty_body_len = tx_metrics.body_bytes_len;
@@ -316,14 +334,14 @@ mod tests {
     use crate::chain::cosmos::types::config::TxConfig;
     use crate::config;
     use crate::config::types::{MaxMsgNum, MaxTxSize, Memo};
-    use crate::keyring::{self, KeyRing, Secp256k1KeyPair, SigningKeyPair};
+    use crate::keyring::{Secp256k1KeyPair, Secp256k1SigningKey, SigningKeyPair};
     use ibc_proto::google::protobuf::Any;
     use ibc_relayer_types::core::ics24_host::identifier::ChainId;
     use std::fs;
 
     const COSMOS_HD_PATH: &str = "m/44'/118'/0'/0/0";
 
-    fn test_fixture() -> (TxConfig, Secp256k1KeyPair, Account) {
+    fn test_fixture() -> (TxConfig, Secp256k1SigningKey, Account) {
         let path = concat!(
             env!("CARGO_MANIFEST_DIR"),
             "/tests/config/fixtures/relayer_conf_example.toml"
@@ -339,15 +357,10 @@ mod tests {
             "/tests/config/fixtures/relayer-seed.json"
         );
         let seed_file_content = fs::read_to_string(path).unwrap();
-        let _keyring = KeyRing::new_secp256k1(
-            keyring::Store::Memory,
-            "cosmos",
-            &chain_id,
-            &chain_config.key_store_folder,
-        )
-        .unwrap();
         let hd_path = COSMOS_HD_PATH.parse().unwrap();
-        let key_pair = Secp256k1KeyPair::from_seed_file(&seed_file_content, &hd_path).unwrap();
+        let key_pair = Secp256k1KeyPair::from_seed_file(&seed_file_content, &hd_path)
+            .unwrap()
+            .into();
 
         let account = Account {
             address: AccountAddress::new("".to_owned()),
@@ -24,7 +24,9 @@ impl Settings {
         src_chain_config: &ChainConfig,
         dst_chain_config: &ChainConfig,
     ) -> Self {
-        let max_clock_drift = match options.max_clock_drift {
+        let overrides = dst_chain_config.client_overrides_for(&src_chain_config.id);
+
+        let max_clock_drift = match options.max_clock_drift.or(overrides.clock_drift) {
             None => calculate_client_state_drift(src_chain_config, dst_chain_config),
             Some(user_value) => {
                 if user_value > dst_chain_config.max_block_time {
@@ -41,11 +43,12 @@ impl Settings {
 
         let trust_threshold = options
             .trust_threshold
+            .or_else(|| overrides.trust_threshold.map(Into::into))
             .unwrap_or_else(|| src_chain_config.trust_threshold.into());
 
         Settings {
             max_clock_drift,
-            trusting_period: options.trusting_period,
+            trusting_period: options.trusting_period.or(overrides.trusting_period),
             trust_threshold,
         }
     }
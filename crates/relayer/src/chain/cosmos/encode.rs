@@ -6,7 +6,10 @@ use ibc_proto::google::protobuf::Any;
 use ibc_relayer_types::core::ics02_client::error::Error as ClientError;
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 use ibc_relayer_types::signer::Signer;
-use prost::Message;
+use prost::encoding::{
+    decode_key, decode_varint, encode_key, encode_varint, skip_field, DecodeContext, WireType,
+};
+use prost::{DecodeError, Message};
 use tendermint::account::Id as AccountId;
 
 use crate::chain::cosmos::types::account::{Account, AccountNumber, AccountSequence};
@@ -15,11 +18,14 @@ use crate::chain::cosmos::types::tx::SignedTx;
 use crate::config::types::Memo;
 use crate::config::AddressType;
 use crate::error::Error;
-use crate::keyring::{Secp256k1KeyPair, SigningKeyPair};
+use crate::keyring::Secp256k1SigningKey;
+
+/// Length of a compact secp256k1 signature.
+const SIGNATURE_LEN: usize = 64;
 
 pub fn sign_and_encode_tx(
     config: &TxConfig,
-    key_pair: &Secp256k1KeyPair,
+    key_pair: &Secp256k1SigningKey,
     account: &Account,
     tx_memo: &Memo,
     messages: &[Any],
@@ -36,6 +42,32 @@ pub fn sign_and_encode_tx(
     encode_tx_raw(tx_raw)
 }
 
+/// Same as [`sign_and_encode_tx`], except that the async runtime is not blocked
+/// while a remote signer is signing the transaction.
+pub async fn sign_and_encode_tx_async(
+    config: &TxConfig,
+    key_pair: &Secp256k1SigningKey,
+    account: &Account,
+    tx_memo: &Memo,
+    messages: &[Any],
+    fee: &Fee,
+) -> Result<Vec<u8>, Error> {
+    let (unsigned_tx, sign_doc) = unsigned_tx(config, key_pair, account, tx_memo, messages, fee)?;
+
+    let signature = key_pair
+        .sign_async(sign_doc)
+        .await
+        .map_err(Error::key_base)?;
+
+    let tx_raw = TxRaw {
+        body_bytes: unsigned_tx.body_bytes,
+        auth_info_bytes: unsigned_tx.auth_info_bytes,
+        signatures: vec![signature],
+    };
+
+    encode_tx_raw(tx_raw)
+}
+
 /// Length information for an encoded transaction.
 pub struct EncodedTxMetrics {
     /// Length of the encoded message, excluding the `body_bytes` field.
@@ -46,13 +78,13 @@ pub struct EncodedTxMetrics {
 
 pub fn encoded_tx_metrics(
     config: &TxConfig,
-    key_pair: &Secp256k1KeyPair,
+    key_pair: &Secp256k1SigningKey,
     account: &Account,
     tx_memo: &Memo,
     messages: &[Any],
     fee: &Fee,
 ) -> Result<EncodedTxMetrics, Error> {
-    let signed_tx = sign_tx(config, key_pair, account, tx_memo, messages, fee)?;
+    let signed_tx = placeholder_signed_tx(config, key_pair, account, tx_memo, messages, fee)?;
 
     let tx_raw = TxRaw {
         body_bytes: signed_tx.body_bytes,
@@ -76,12 +108,48 @@ pub fn encoded_tx_metrics(
 
 pub fn sign_tx(
     config: &TxConfig,
-    key_pair: &Secp256k1KeyPair,
+    key_pair: &Secp256k1SigningKey,
+    account: &Account,
+    tx_memo: &Memo,
+    messages: &[Any],
+    fee: &Fee,
+) -> Result<SignedTx, Error> {
+    let (mut signed_tx, sign_doc) = unsigned_tx(config, key_pair, account, tx_memo, messages, fee)?;
+
+    let signature = key_pair.sign(&sign_doc).map_err(Error::key_base)?;
+    signed_tx.signatures = vec![signature];
+
+    Ok(signed_tx)
+}
+
+/// Build the transaction as [`sign_tx`] would, but with a placeholder of the same
+/// length in place of the signature, for when only the size of the transaction
+/// or the gas it consumes matters. This spares a request to the remote signer,
+/// if any, for every size measurement or simulation.
+pub fn placeholder_signed_tx(
+    config: &TxConfig,
+    key_pair: &Secp256k1SigningKey,
     account: &Account,
     tx_memo: &Memo,
     messages: &[Any],
     fee: &Fee,
 ) -> Result<SignedTx, Error> {
+    let (mut signed_tx, _) = unsigned_tx(config, key_pair, account, tx_memo, messages, fee)?;
+    signed_tx.signatures = vec![vec![0; SIGNATURE_LEN]];
+
+    Ok(signed_tx)
+}
+
+/// Build the transaction without any signature, together with the sign doc
+/// which has to be signed for it.
+fn unsigned_tx(
+    config: &TxConfig,
+    key_pair: &Secp256k1SigningKey,
+    account: &Account,
+    tx_memo: &Memo,
+    messages: &[Any],
+    fee: &Fee,
+) -> Result<(SignedTx, Vec<u8>), Error> {
     let key_bytes = encode_key_bytes(key_pair)?;
 
     let signer = encode_signer_info(&config.address_type, account.sequence, key_bytes)?;
@@ -91,27 +159,28 @@ pub fn sign_tx(
 
     let (auth_info, auth_info_bytes) = auth_info_and_bytes(signer, fee.clone())?;
 
-    let signed_doc = encode_sign_doc(
+    let sign_doc = encode_sign_doc(
         &config.chain_id,
-        key_pair,
         account.number,
         auth_info_bytes.clone(),
         body_bytes.clone(),
-    )?;
+    );
 
-    Ok(SignedTx {
+    let unsigned_tx = SignedTx {
         body,
         body_bytes,
         auth_info,
         auth_info_bytes,
-        signatures: vec![signed_doc],
-    })
+        signatures: vec![],
+    };
+
+    Ok((unsigned_tx, sign_doc))
 }
 
-fn encode_key_bytes(key_pair: &Secp256k1KeyPair) -> Result<Vec<u8>, Error> {
+fn encode_key_bytes(key_pair: &Secp256k1SigningKey) -> Result<Vec<u8>, Error> {
     let mut pk_buf = Vec::new();
 
-    prost::Message::encode(&key_pair.public_key.serialize().to_vec(), &mut pk_buf)
+    prost::Message::encode(&key_pair.public_key().serialize().to_vec(), &mut pk_buf)
         .map_err(|e| Error::protobuf_encode("PublicKey".into(), e))?;
 
     Ok(pk_buf)
@@ -119,11 +188,10 @@ fn encode_key_bytes(key_pair: &Secp256k1KeyPair) -> Result<Vec<u8>, Error> {
 
 fn encode_sign_doc(
     chain_id: &ChainId,
-    key_pair: &Secp256k1KeyPair,
     account_number: AccountNumber,
     auth_info_bytes: Vec<u8>,
     body_bytes: Vec<u8>,
-) -> Result<Vec<u8>, Error> {
+) -> Vec<u8> {
     let sign_doc = SignDoc {
         body_bytes,
         auth_info_bytes,
@@ -135,9 +203,7 @@ fn encode_sign_doc(
     let mut signdoc_buf = Vec::new();
     prost::Message::encode(&sign_doc, &mut signdoc_buf).unwrap();
 
-    let signed = key_pair.sign(&signdoc_buf).map_err(Error::key_base)?;
-
-    Ok(signed)
+    signdoc_buf
 }
 
 fn encode_signer_info(
@@ -225,7 +291,86 @@ fn tx_body_and_bytes(
     Ok((body, body_buf))
 }
 
-pub fn key_pair_to_signer(key_pair: &Secp256k1KeyPair) -> Result<Signer, Error> {
+/// Returns the tag of the `signer` field of the given IBC core message,
+/// or `None` if the message is not one whose signer can be replaced.
+pub fn signer_field_tag(type_url: &str) -> Option<u32> {
+    let tag = match type_url {
+        "/ibc.core.client.v1.MsgCreateClient"
+        | "/ibc.core.client.v1.MsgUpdateClient"
+        | "/ibc.core.client.v1.MsgSubmitMisbehaviour"
+        | "/ibc.core.channel.v1.MsgChannelOpenInit"
+        | "/ibc.core.channel.v1.MsgChannelCloseInit" => 3,
+        "/ibc.core.channel.v1.MsgRecvPacket"
+        | "/ibc.core.connection.v1.MsgConnectionOpenConfirm" => 4,
+        "/ibc.core.channel.v1.MsgAcknowledgement"
+        | "/ibc.core.channel.v1.MsgTimeout"
+        | "/ibc.core.channel.v1.MsgChannelOpenConfirm"
+        | "/ibc.core.channel.v1.MsgChannelCloseConfirm"
+        | "/ibc.core.connection.v1.MsgConnectionOpenInit" => 5,
+        "/ibc.core.client.v1.MsgUpgradeClient" | "/ibc.core.channel.v1.MsgTimeoutOnClose" => 6,
+        "/ibc.core.channel.v1.MsgChannelOpenTry" | "/ibc.core.channel.v1.MsgChannelOpenAck" => 7,
+        "/ibc.core.connection.v1.MsgConnectionOpenAck" => 10,
+        "/ibc.core.connection.v1.MsgConnectionOpenTry" => 12,
+        _ => return None,
+    };
+
+    Some(tag)
+}
+
+/// Replace the `signer` field of the given IBC core message, if it holds
+/// the account address `from`, with the address `to`.
+///
+/// This allows a message built with one of the relayer's accounts as signer
+/// to be signed by another one of its accounts. Any other field is left as is,
+/// even if it holds the same address.
+pub fn replace_signer(message: &Any, from: &str, to: &str) -> Result<Any, Error> {
+    let signer_tag = signer_field_tag(&message.type_url).ok_or_else(|| {
+        Error::protobuf_decode(
+            message.type_url.clone(),
+            DecodeError::new("message has no replaceable signer field"),
+        )
+    })?;
+
+    let decode_error = |e: DecodeError| Error::protobuf_decode(message.type_url.clone(), e);
+
+    let mut buf = message.value.as_slice();
+    let mut value = Vec::with_capacity(message.value.len());
+
+    while !buf.is_empty() {
+        let field_start = buf;
+        let (tag, wire_type) = decode_key(&mut buf).map_err(decode_error)?;
+
+        if wire_type == WireType::LengthDelimited {
+            let len = decode_varint(&mut buf).map_err(decode_error)? as usize;
+            if len > buf.len() {
+                return Err(decode_error(DecodeError::new("buffer underflow")));
+            }
+
+            let (field, rest) = buf.split_at(len);
+
+            if tag == signer_tag && field == from.as_bytes() {
+                encode_key(tag, WireType::LengthDelimited, &mut value);
+                encode_varint(to.len() as u64, &mut value);
+                value.extend_from_slice(to.as_bytes());
+            } else {
+                value.extend_from_slice(&field_start[..field_start.len() - rest.len()]);
+            }
+
+            buf = rest;
+        } else {
+            skip_field(wire_type, tag, &mut buf, DecodeContext::default()).map_err(decode_error)?;
+
+            value.extend_from_slice(&field_start[..field_start.len() - buf.len()]);
+        }
+    }
+
+    Ok(Any {
+        type_url: message.type_url.clone(),
+        value,
+    })
+}
+
+pub fn key_pair_to_signer(key_pair: &Secp256k1SigningKey) -> Result<Signer, Error> {
     let signer = key_pair
         .account()
         .parse()
@@ -233,3 +378,48 @@ pub fn key_pair_to_signer(key_pair: &Secp256k1KeyPair) -> Result<Signer, Error>
 
     Ok(signer)
 }
+
+#[cfg(test)]
+mod tests {
+    use ibc_proto::ibc::core::client::v1::MsgUpdateClient;
+
+    use super::*;
+
+    #[test]
+    fn replace_signer_rejects_unknown_messages() {
+        let any = Any {
+            type_url: "/ibc.applications.transfer.v1.MsgTransfer".to_string(),
+            value: vec![],
+        };
+
+        assert!(replace_signer(&any, "from", "to").is_err());
+    }
+
+    #[test]
+    fn replace_signer_only_rewrites_signer() {
+        let from = "cosmos1hrt8x3lh7f3yfpvgs4vyk4ssk4y7kxpjy4l9zj";
+        let to = "cosmos1k4lxkqmc6zvs8g2c73lm3x3cgvq0vlvjcfrc80";
+
+        let msg = MsgUpdateClient {
+            client_id: from.to_string(),
+            header: Some(Any {
+                type_url: "/ibc.lightclients.tendermint.v1.Header".to_string(),
+                value: from.as_bytes().to_vec(),
+            }),
+            signer: from.to_string(),
+        };
+
+        let any = Any {
+            type_url: "/ibc.core.client.v1.MsgUpdateClient".to_string(),
+            value: msg.encode_to_vec(),
+        };
+
+        let replaced = replace_signer(&any, from, to).unwrap();
+        let decoded = MsgUpdateClient::decode(replaced.value.as_slice()).unwrap();
+
+        assert_eq!(replaced.type_url, any.type_url);
+        assert_eq!(decoded.signer, to);
+        assert_eq!(decoded.client_id, msg.client_id);
+        assert_eq!(decoded.header, msg.header);
+    }
+}
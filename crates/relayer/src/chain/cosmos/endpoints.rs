@@ -0,0 +1,196 @@
+//! Health tracking of the RPC and gRPC endpoints of a chain,
+//! to fail over to another endpoint when the one in use fails.
+
+use core::fmt::{Display, Error as FmtError, Formatter};
+use core::time::Duration;
+
+use tendermint_rpc::{HttpClient, Url};
+
+/// Weight of the latest request in the moving averages tracking the health of an endpoint.
+const SMOOTHING_FACTOR: f64 = 0.2;
+
+/// Error rate above which an endpoint is considered unhealthy.
+const MAX_HEALTHY_ERROR_RATE: f64 = 0.5;
+
+/// The health of an endpoint, as observed from the requests sent to it.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct EndpointHealth {
+    /// Number of requests sent to the endpoint
+    pub requests: u64,
+    /// Number of requests which failed because of the endpoint
+    pub failures: u64,
+    /// Moving average of the rate of failed requests, between 0 and 1
+    pub error_rate: f64,
+    /// Moving average of the latency of the successful requests, if any
+    pub latency: Option<Duration>,
+}
+
+impl EndpointHealth {
+    pub fn is_healthy(&self) -> bool {
+        self.error_rate <= MAX_HEALTHY_ERROR_RATE
+    }
+
+    fn record_success(&mut self, latency: Duration) {
+        self.requests += 1;
+        self.error_rate *= 1.0 - SMOOTHING_FACTOR;
+
+        self.latency = Some(match self.latency {
+            None => latency,
+            Some(average) => {
+                average.mul_f64(1.0 - SMOOTHING_FACTOR) + latency.mul_f64(SMOOTHING_FACTOR)
+            }
+        });
+    }
+
+    fn record_failure(&mut self) {
+        self.requests += 1;
+        self.failures += 1;
+        self.error_rate += SMOOTHING_FACTOR * (1.0 - self.error_rate);
+    }
+}
+
+/// A set of interchangeable endpoints of a chain, only one of which is in use at any time.
+///
+/// The endpoints are listed in order of preference, starting with the one used initially.
+/// When a request to the endpoint in use fails, the healthiest of the other endpoints is
+/// selected instead, ie. the one with the lowest latency among those whose error rate is
+/// below [`MAX_HEALTHY_ERROR_RATE`], or failing that, the one with the lowest error rate.
+#[derive(Clone, Debug)]
+pub struct Endpoints<E> {
+    endpoints: Vec<(E, EndpointHealth)>,
+    current: usize,
+}
+
+impl<E> Endpoints<E> {
+    pub fn new(endpoint: E, backups: impl IntoIterator<Item = E>) -> Self {
+        let endpoints = core::iter::once(endpoint)
+            .chain(backups)
+            .map(|endpoint| (endpoint, EndpointHealth::default()))
+            .collect();
+
+        Self {
+            endpoints,
+            current: 0,
+        }
+    }
+
+    /// The endpoint currently in use.
+    pub fn current(&self) -> &E {
+        &self.endpoints[self.current].0
+    }
+
+    /// All the endpoints, along with their health.
+    pub fn health(&self) -> impl Iterator<Item = (&E, &EndpointHealth)> {
+        self.endpoints
+            .iter()
+            .map(|(endpoint, health)| (endpoint, health))
+    }
+
+    /// Record a successful request to the endpoint currently in use.
+    pub fn record_success(&mut self, latency: Duration) {
+        self.endpoints[self.current].1.record_success(latency);
+    }
+
+    /// Record a request to the endpoint currently in use which failed because of the endpoint,
+    /// then switch to the healthiest of the other endpoints, and return it, if there is any.
+    pub fn record_failure(&mut self) -> Option<&E> {
+        self.endpoints[self.current].1.record_failure();
+
+        self.current = self.healthiest_other()?;
+
+        Some(self.current())
+    }
+
+    fn healthiest_other(&self) -> Option<usize> {
+        let count = self.endpoints.len();
+
+        // Consider the other endpoints in order, starting right after the current one,
+        // so that ties are broken by picking the next endpoint in the list.
+        let others = (1..count).map(|offset| (self.current + offset) % count);
+
+        let healthy = others
+            .clone()
+            .filter(|&index| self.endpoints[index].1.is_healthy())
+            .min_by_key(|&index| self.endpoints[index].1.latency.unwrap_or_default());
+
+        healthy.or_else(|| {
+            others.min_by(|&a, &b| {
+                let (a, b) = (&self.endpoints[a].1, &self.endpoints[b].1);
+                a.error_rate.total_cmp(&b.error_rate)
+            })
+        })
+    }
+}
+
+/// An RPC endpoint, along with the client to send requests to it.
+#[derive(Clone, Debug)]
+pub struct RpcEndpoint {
+    pub url: Url,
+    pub client: HttpClient,
+}
+
+impl Display for RpcEndpoint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, "{}", self.url)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LATENCY: Duration = Duration::from_millis(100);
+
+    #[test]
+    fn switch_to_next_endpoint_on_failure() {
+        let mut endpoints = Endpoints::new("a", ["b", "c"]);
+
+        endpoints.record_success(LATENCY);
+        assert_eq!(endpoints.current(), &"a");
+
+        assert_eq!(endpoints.record_failure(), Some(&"b"));
+        endpoints.record_success(LATENCY);
+        assert_eq!(endpoints.record_failure(), Some(&"c"));
+
+        // `a` and `b` are both still healthy and as fast, so the next one in the list is picked.
+        assert_eq!(endpoints.record_failure(), Some(&"a"));
+    }
+
+    #[test]
+    fn prefer_healthy_endpoint_with_lowest_latency() {
+        let mut endpoints = Endpoints::new("a", ["b", "c"]);
+
+        endpoints.record_success(LATENCY * 3);
+        endpoints.record_failure();
+        endpoints.record_success(LATENCY * 2);
+        endpoints.record_failure();
+        assert_eq!(endpoints.current(), &"c");
+
+        endpoints.record_success(LATENCY);
+        endpoints.record_failure();
+
+        // Both `a` and `b` are healthy, but `b` is faster than `a`.
+        assert_eq!(endpoints.current(), &"b");
+    }
+
+    #[test]
+    fn prefer_endpoint_with_lowest_error_rate_when_all_are_unhealthy() {
+        let mut endpoints = Endpoints::new("a", ["b", "c"]);
+        endpoints.endpoints[1].1.error_rate = 0.9;
+        endpoints.endpoints[2].1.error_rate = 0.6;
+
+        assert_eq!(endpoints.record_failure(), Some(&"c"));
+    }
+
+    #[test]
+    fn single_endpoint_is_kept() {
+        let mut endpoints = Endpoints::new("a", []);
+
+        assert_eq!(endpoints.record_failure(), None);
+        assert_eq!(endpoints.current(), &"a");
+
+        let (_, health) = endpoints.health().next().unwrap();
+        assert_eq!((health.requests, health.failures), (1, 1));
+        assert!(health.is_healthy());
+    }
+}
@@ -1,23 +1,26 @@
 use ibc_proto::cosmos::tx::v1beta1::{Fee, Tx};
 use ibc_proto::google::protobuf::Any;
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+use tendermint_rpc::HttpClient;
 use tonic::codegen::http::Uri;
 use tracing::{debug, error, span, warn, Level};
 
-use crate::chain::cosmos::encode::sign_tx;
+use crate::chain::cosmos::encode::placeholder_signed_tx;
 use crate::chain::cosmos::gas::gas_amount_to_fee;
+use crate::chain::cosmos::query::eip_base_fee::query_eip_base_fee;
 use crate::chain::cosmos::simulate::send_tx_simulate;
 use crate::chain::cosmos::types::account::Account;
 use crate::chain::cosmos::types::config::TxConfig;
 use crate::chain::cosmos::types::gas::GasConfig;
 use crate::config::types::Memo;
 use crate::error::Error;
-use crate::keyring::Secp256k1KeyPair;
+use crate::keyring::Secp256k1SigningKey;
 use crate::util::pretty::PrettyFee;
 
 pub async fn estimate_tx_fees(
+    rpc_client: &HttpClient,
     config: &TxConfig,
-    key_pair: &Secp256k1KeyPair,
+    key_pair: &Secp256k1SigningKey,
     account: &Account,
     tx_memo: &Memo,
     messages: &[Any],
@@ -29,7 +32,7 @@ pub async fn estimate_tx_fees(
         PrettyFee(&gas_config.max_fee)
     );
 
-    let signed_tx = sign_tx(
+    let signed_tx = placeholder_signed_tx(
         config,
         key_pair,
         account,
@@ -44,12 +47,62 @@ pub async fn estimate_tx_fees(
         signatures: signed_tx.signatures,
     };
 
+    let gas_config = dynamic_gas_config(rpc_client, config).await;
+
     let estimated_fee =
-        estimate_fee_with_tx(gas_config, &config.grpc_address, &config.chain_id, tx).await?;
+        estimate_fee_with_tx(&gas_config, &config.grpc_address, &config.chain_id, tx).await?;
 
     Ok(estimated_fee)
 }
 
+/// If `dynamic_gas_price` is enabled, return the gas config with its gas price
+/// set from the base fee currently required by the chain, otherwise return it as is.
+///
+/// If the chain has no supported fee market module, or if the base fee cannot
+/// be queried, fall back on the static gas price.
+async fn dynamic_gas_config(rpc_client: &HttpClient, config: &TxConfig) -> GasConfig {
+    let gas_config = &config.gas_config;
+    let dynamic_gas_price = &gas_config.dynamic_gas_price;
+
+    if !dynamic_gas_price.enabled {
+        return gas_config.clone();
+    }
+
+    let base_fee = query_eip_base_fee(
+        rpc_client,
+        &config.rpc_address,
+        &config.chain_id,
+        &gas_config.gas_price.denom,
+    )
+    .await;
+
+    match base_fee {
+        Ok(Some(base_fee)) => {
+            let price = dynamic_gas_price.apply(base_fee);
+
+            debug!(
+                id = %config.chain_id,
+                "using dynamic gas price {}{} from base fee {}",
+                price, gas_config.gas_price.denom, base_fee
+            );
+
+            let mut gas_config = gas_config.clone();
+            gas_config.gas_price.price = price;
+            gas_config
+        }
+        Ok(None) => gas_config.clone(),
+        Err(e) => {
+            warn!(
+                id = %config.chain_id,
+                "failed to query the base fee, falling back on static gas price {}: {}",
+                gas_config.gas_price, e
+            );
+
+            gas_config.clone()
+        }
+    }
+}
+
 async fn estimate_fee_with_tx(
     gas_config: &GasConfig,
     grpc_address: &Uri,
@@ -11,13 +11,13 @@ use crate::chain::cosmos::types::config::TxConfig;
 use crate::chain::cosmos::wait::wait_tx_succeed;
 use crate::config::types::Memo;
 use crate::error::Error;
-use crate::keyring::{Secp256k1KeyPair, SigningKeyPair};
+use crate::keyring::Secp256k1SigningKey;
 
 // FIXME: monster function, refactor
 pub async fn maybe_register_counterparty_payee(
     rpc_client: &HttpClient,
     tx_config: &TxConfig,
-    key_pair: &Secp256k1KeyPair,
+    key_pair: &Secp256k1SigningKey,
     m_account: &mut Option<Account>,
     tx_memo: &Memo,
     channel_id: &ChannelId,
@@ -19,6 +19,7 @@ pub mod balance;
 pub mod consensus_state;
 pub mod custom;
 pub mod denom_trace;
+pub mod eip_base_fee;
 pub mod fee;
 pub mod status;
 pub mod tx;
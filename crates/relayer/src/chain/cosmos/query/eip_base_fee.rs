@@ -0,0 +1,278 @@
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use ibc_proto::cosmos::base::v1beta1::DecCoin;
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+use once_cell::sync::Lazy;
+use prost::Message;
+use tendermint_rpc::{Client, HttpClient, Url};
+use tracing::{debug, warn};
+
+use crate::error::{Error, ErrorDetail};
+
+/// Path of the `GasPrice` query of the Skip `feemarket` module.
+const FEEMARKET_GAS_PRICE_PATH: &str = "/feemarket.feemarket.v1.Query/GasPrice";
+
+/// Path of the `GetEipBaseFee` query of the Osmosis `txfees` module.
+const OSMOSIS_EIP_BASE_FEE_PATH: &str = "/osmosis.txfees.v1beta1.Query/GetEipBaseFee";
+
+/// Path of the `BaseDenom` query of the Osmosis `txfees` module.
+const OSMOSIS_BASE_DENOM_PATH: &str = "/osmosis.txfees.v1beta1.Query/BaseDenom";
+
+/// Number of decimal places of a Cosmos SDK `Dec`, when encoded as an integer.
+const DEC_PRECISION: usize = 18;
+
+/// The fee market module detected on each chain, so that it only has
+/// to be detected once rather than on every transaction.
+static FEE_MARKETS: Lazy<RwLock<HashMap<ChainId, FeeMarket>>> = Lazy::new(Default::default);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum FeeMarket {
+    /// The Skip `feemarket` module
+    Skip,
+    /// The Osmosis `txfees` module, with its base fee in the gas price denomination
+    Osmosis,
+    /// No supported fee market module
+    None,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct GasPriceRequest {
+    #[prost(string, tag = "1")]
+    denom: String,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct GasPriceResponse {
+    #[prost(message, optional, tag = "1")]
+    price: Option<DecCoin>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct QueryEipBaseFeeRequest {}
+
+#[derive(Clone, PartialEq, Message)]
+struct QueryEipBaseFeeResponse {
+    #[prost(string, tag = "1")]
+    base_fee: String,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct QueryBaseDenomRequest {}
+
+#[derive(Clone, PartialEq, Message)]
+struct QueryBaseDenomResponse {
+    #[prost(string, tag = "1")]
+    base_denom: String,
+}
+
+/// Query the current base fee of the chain, in the given denomination,
+/// from either the Skip `feemarket` module or the Osmosis `txfees` module.
+///
+/// Returns `None` if the chain has neither module, or if the base fee of
+/// its `txfees` module is not in the given denomination.
+pub async fn query_eip_base_fee(
+    rpc_client: &HttpClient,
+    rpc_address: &Url,
+    chain_id: &ChainId,
+    denom: &str,
+) -> Result<Option<f64>, Error> {
+    let cached = FEE_MARKETS
+        .read()
+        .expect("poisoned lock")
+        .get(chain_id)
+        .cloned();
+
+    let fee_market = match cached {
+        Some(fee_market) => fee_market,
+        None => {
+            let fee_market = detect_fee_market(rpc_client, rpc_address, chain_id, denom).await?;
+
+            FEE_MARKETS
+                .write()
+                .expect("poisoned lock")
+                .insert(chain_id.clone(), fee_market.clone());
+
+            fee_market
+        }
+    };
+
+    match fee_market {
+        FeeMarket::Skip => query_feemarket_gas_price(rpc_client, rpc_address, chain_id, denom)
+            .await
+            .map(Some),
+        FeeMarket::Osmosis => query_osmosis_eip_base_fee(rpc_client, rpc_address, chain_id)
+            .await
+            .map(Some),
+        FeeMarket::None => Ok(None),
+    }
+}
+
+/// Find out which fee market module the chain runs, if any.
+///
+/// A module is deemed missing when the chain rejects the query to it,
+/// while any other failure, eg. of the RPC endpoint, is returned as is,
+/// so that the detection is attempted again for the next transaction.
+async fn detect_fee_market(
+    rpc_client: &HttpClient,
+    rpc_address: &Url,
+    chain_id: &ChainId,
+    denom: &str,
+) -> Result<FeeMarket, Error> {
+    match query_feemarket_gas_price(rpc_client, rpc_address, chain_id, denom).await {
+        Ok(_) => return Ok(FeeMarket::Skip),
+        Err(e) if is_rejected_query(&e) => {
+            debug!(
+                id = %chain_id,
+                "no `feemarket` module found, trying the Osmosis `txfees` module instead: {}",
+                e
+            );
+        }
+        Err(e) => return Err(e),
+    }
+
+    match query_osmosis_base_denom(rpc_client, rpc_address).await {
+        Ok(base_denom) if base_denom == denom => Ok(FeeMarket::Osmosis),
+        Ok(base_denom) => {
+            warn!(
+                id = %chain_id,
+                "the base fee of the `txfees` module is in {} rather than in the gas price denomination {}, \
+                the static gas price will be used instead",
+                base_denom, denom
+            );
+
+            Ok(FeeMarket::None)
+        }
+        Err(e) if is_rejected_query(&e) => {
+            debug!(id = %chain_id, "no supported fee market module found: {}", e);
+
+            Ok(FeeMarket::None)
+        }
+        Err(e) => Err(e),
+    }
+}
+
+fn is_rejected_query(e: &Error) -> bool {
+    matches!(e.detail(), ErrorDetail::AbciQuery(_))
+}
+
+async fn query_feemarket_gas_price(
+    rpc_client: &HttpClient,
+    rpc_address: &Url,
+    chain_id: &ChainId,
+    denom: &str,
+) -> Result<f64, Error> {
+    let request = GasPriceRequest {
+        denom: denom.to_string(),
+    };
+
+    let value = abci_query_raw(
+        rpc_client,
+        rpc_address,
+        FEEMARKET_GAS_PRICE_PATH,
+        request.encode_to_vec(),
+    )
+    .await?;
+
+    let response = GasPriceResponse::decode(value.as_slice())
+        .map_err(|e| Error::protobuf_decode("GasPriceResponse".to_string(), e))?;
+
+    let price = response
+        .price
+        .ok_or_else(|| Error::invalid_base_fee(chain_id.clone(), "<missing>".to_string()))?;
+
+    parse_dec(chain_id, &price.amount)
+}
+
+async fn query_osmosis_eip_base_fee(
+    rpc_client: &HttpClient,
+    rpc_address: &Url,
+    chain_id: &ChainId,
+) -> Result<f64, Error> {
+    let value = abci_query_raw(
+        rpc_client,
+        rpc_address,
+        OSMOSIS_EIP_BASE_FEE_PATH,
+        QueryEipBaseFeeRequest {}.encode_to_vec(),
+    )
+    .await?;
+
+    let response = QueryEipBaseFeeResponse::decode(value.as_slice())
+        .map_err(|e| Error::protobuf_decode("QueryEipBaseFeeResponse".to_string(), e))?;
+
+    parse_dec(chain_id, &response.base_fee)
+}
+
+async fn query_osmosis_base_denom(
+    rpc_client: &HttpClient,
+    rpc_address: &Url,
+) -> Result<String, Error> {
+    let value = abci_query_raw(
+        rpc_client,
+        rpc_address,
+        OSMOSIS_BASE_DENOM_PATH,
+        QueryBaseDenomRequest {}.encode_to_vec(),
+    )
+    .await?;
+
+    let response = QueryBaseDenomResponse::decode(value.as_slice())
+        .map_err(|e| Error::protobuf_decode("QueryBaseDenomResponse".to_string(), e))?;
+
+    Ok(response.base_denom)
+}
+
+async fn abci_query_raw(
+    rpc_client: &HttpClient,
+    rpc_address: &Url,
+    path: &str,
+    data: Vec<u8>,
+) -> Result<Vec<u8>, Error> {
+    let response = rpc_client
+        .abci_query(Some(path.to_string()), data, None, false)
+        .await
+        .map_err(|e| Error::rpc(rpc_address.clone(), e))?;
+
+    if !response.code.is_ok() {
+        return Err(Error::abci_query(response));
+    }
+
+    Ok(response.value)
+}
+
+/// Parse a Cosmos SDK `Dec`, which is encoded in protobuf messages as
+/// the string of an integer scaled by 10^18.
+fn parse_dec(chain_id: &ChainId, value: &str) -> Result<f64, Error> {
+    let invalid = || Error::invalid_base_fee(chain_id.clone(), value.to_string());
+
+    if value.is_empty() || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(invalid());
+    }
+
+    let padded = format!("{value:0>width$}", width = DEC_PRECISION + 1);
+    let (integer, fraction) = padded.split_at(padded.len() - DEC_PRECISION);
+
+    format!("{integer}.{fraction}")
+        .parse::<f64>()
+        .ok()
+        .filter(|v| v.is_finite())
+        .ok_or_else(invalid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_dec_values() {
+        let chain_id = ChainId::new("osmosis".to_string(), 1);
+
+        assert_eq!(parse_dec(&chain_id, "25000000000000000").unwrap(), 0.025);
+        assert_eq!(parse_dec(&chain_id, "1500000000000000000").unwrap(), 1.5);
+        assert_eq!(parse_dec(&chain_id, "1").unwrap(), 1e-18);
+        assert_eq!(parse_dec(&chain_id, "0").unwrap(), 0.0);
+        assert!(parse_dec(&chain_id, "0.0025").is_err());
+        assert!(parse_dec(&chain_id, "abc").is_err());
+        assert!(parse_dec(&chain_id, "-1").is_err());
+        assert!(parse_dec(&chain_id, "").is_err());
+    }
+}
@@ -90,6 +90,9 @@ pub async fn query_txs(
                 Ok(vec![])
             } else {
                 let tx = response.txs.remove(0);
+
+                crate::telemetry!(tx_gas_used, tx.hash, tx.tx_result.gas_used as u64);
+
                 Ok(all_ibc_events_from_tx_search_response(chain_id, tx))
             }
         }
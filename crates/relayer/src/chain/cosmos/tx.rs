@@ -4,7 +4,7 @@ use ibc_relayer_types::events::IbcEvent;
 use tendermint_rpc::endpoint::broadcast::tx_sync::Response;
 use tendermint_rpc::{Client, HttpClient, Url};
 
-use crate::chain::cosmos::encode::sign_and_encode_tx;
+use crate::chain::cosmos::encode::sign_and_encode_tx_async;
 use crate::chain::cosmos::estimate::estimate_tx_fees;
 use crate::chain::cosmos::query::account::query_account;
 use crate::chain::cosmos::query::tx::all_ibc_events_from_tx_search_response;
@@ -14,19 +14,19 @@ use crate::chain::cosmos::wait::wait_tx_succeed;
 use crate::config::types::Memo;
 use crate::error::Error;
 use crate::event::IbcEventWithHeight;
-use crate::keyring::{Secp256k1KeyPair, SigningKeyPair};
+use crate::keyring::{Secp256k1KeyPair, Secp256k1SigningKey};
 
 use super::batch::send_batched_messages_and_wait_commit;
 
 pub async fn estimate_fee_and_send_tx(
     rpc_client: &HttpClient,
     config: &TxConfig,
-    key_pair: &Secp256k1KeyPair,
+    key_pair: &Secp256k1SigningKey,
     account: &Account,
     tx_memo: &Memo,
     messages: &[Any],
 ) -> Result<Response, Error> {
-    let fee = estimate_tx_fees(config, key_pair, account, tx_memo, messages).await?;
+    let fee = estimate_tx_fees(rpc_client, config, key_pair, account, tx_memo, messages).await?;
 
     send_tx_with_fee(
         rpc_client, config, key_pair, account, tx_memo, messages, &fee,
@@ -37,16 +37,30 @@ pub async fn estimate_fee_and_send_tx(
 async fn send_tx_with_fee(
     rpc_client: &HttpClient,
     config: &TxConfig,
-    key_pair: &Secp256k1KeyPair,
+    key_pair: &Secp256k1SigningKey,
     account: &Account,
     tx_memo: &Memo,
     messages: &[Any],
     fee: &Fee,
 ) -> Result<Response, Error> {
-    let tx_bytes = sign_and_encode_tx(config, key_pair, account, tx_memo, messages, fee)?;
+    let tx_bytes =
+        sign_and_encode_tx_async(config, key_pair, account, tx_memo, messages, fee).await?;
 
     let response = broadcast_tx_sync(rpc_client, &config.rpc_address, tx_bytes).await?;
 
+    // The fee is only charged once the transaction passed `CheckTx`
+    if response.code.is_ok() {
+        crate::telemetry!(
+            tx_fee,
+            response.hash,
+            fee.gas_limit,
+            fee.amount
+                .iter()
+                .filter_map(|coin| Some((coin.denom.clone(), coin.amount.parse().ok()?)))
+                .collect()
+        );
+    }
+
     Ok(response)
 }
 
@@ -82,6 +96,7 @@ pub async fn simple_send_tx(
     key_pair: &Secp256k1KeyPair,
     messages: Vec<Any>,
 ) -> Result<Vec<IbcEventWithHeight>, Error> {
+    let key_pair = Secp256k1SigningKey::from(key_pair.clone());
     let key_account = key_pair.account();
     let account = query_account(&config.grpc_address, &key_account)
         .await?
@@ -90,7 +105,7 @@ pub async fn simple_send_tx(
     let response = estimate_fee_and_send_tx(
         rpc_client,
         config,
-        key_pair,
+        &key_pair,
         &account,
         &Memo::default(),
         &messages,
@@ -120,6 +135,7 @@ pub async fn batched_send_tx(
     key_pair: &Secp256k1KeyPair,
     messages: Vec<Any>,
 ) -> Result<Vec<IbcEventWithHeight>, Error> {
+    let key_pair = Secp256k1SigningKey::from(key_pair.clone());
     let key_account = key_pair.account();
     let mut account = query_account(&config.grpc_address, &key_account)
         .await?
@@ -128,7 +144,7 @@ pub async fn batched_send_tx(
     let events = send_batched_messages_and_wait_commit(
         rpc_client,
         config,
-        key_pair,
+        &key_pair,
         &mut account,
         &Memo::default(),
         messages,
@@ -91,6 +91,12 @@ async fn update_tx_sync_result(
         if let Some(response) = response {
             tx_sync_result.status = TxStatus::ReceivedResponse;
 
+            crate::telemetry!(
+                tx_gas_used,
+                response.hash,
+                response.tx_result.gas_used as u64
+            );
+
             let height = Height::new(chain_id.version(), u64::from(response.height)).unwrap();
             if response.tx_result.code.is_err() {
                 tx_sync_result.events = vec![
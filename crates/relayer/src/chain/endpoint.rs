@@ -17,6 +17,7 @@ use ibc_relayer_types::core::ics03_connection::connection::{
 use ibc_relayer_types::core::ics03_connection::version::{get_compatible_versions, Version};
 use ibc_relayer_types::core::ics04_channel::channel::{ChannelEnd, IdentifiedChannelEnd};
 use ibc_relayer_types::core::ics04_channel::packet::{PacketMsgType, Sequence};
+use ibc_relayer_types::core::ics04_channel::upgrade::{ErrorReceipt, Upgrade};
 use ibc_relayer_types::core::ics23_commitment::commitment::{
     CommitmentPrefix, CommitmentProofBytes,
 };
@@ -124,6 +125,13 @@ pub trait ChainEndpoint: Sized {
         Ok(key_pair)
     }
 
+    /// Get the key pair with the given name from the keybase
+    fn get_key_by_name(&self, key_name: &str) -> Result<Self::SigningKeyPair, Error> {
+        self.keybase()
+            .get_key(key_name)
+            .map_err(|e| Error::key_not_found(key_name.to_string(), e))
+    }
+
     fn add_key(&mut self, key_name: &str, key_pair: Self::SigningKeyPair) -> Result<(), Error> {
         self.keybase_mut()
             .add_key(key_name, key_pair)
@@ -353,6 +361,24 @@ pub trait ChainEndpoint: Sized {
         include_proof: IncludeProof,
     ) -> Result<(Sequence, Option<MerkleProof>), Error>;
 
+    /// Performs a query to retrieve the upgrade proposed for a channel, stored at
+    /// path `path::ChannelUpgradesPath` while the channel upgrade handshake is in
+    /// progress. A proof can optionally be returned along with the result.
+    fn query_upgrade(
+        &self,
+        request: QueryUpgradeRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(Upgrade, Option<MerkleProof>), Error>;
+
+    /// Performs a query to retrieve the error receipt of the last aborted upgrade
+    /// of a channel, stored at path `path::ChannelUpgradeErrorsPath`. A proof can
+    /// optionally be returned along with the result.
+    fn query_upgrade_error(
+        &self,
+        request: QueryUpgradeErrorRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(ErrorReceipt, Option<MerkleProof>), Error>;
+
     fn query_txs(&self, request: QueryTxRequest) -> Result<Vec<IbcEventWithHeight>, Error>;
 
     fn query_packet_events(
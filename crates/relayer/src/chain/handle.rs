@@ -18,6 +18,7 @@ use ibc_relayer_types::{
         ics04_channel::{
             channel::{ChannelEnd, IdentifiedChannelEnd},
             packet::{PacketMsgType, Sequence},
+            upgrade::{ErrorReceipt, Upgrade},
         },
         ics23_commitment::{commitment::CommitmentPrefix, merkle::MerkleProof},
         ics24_host::identifier::{ChainId, ChannelId, ClientId, ConnectionId, PortId},
@@ -135,6 +136,11 @@ pub enum ChainRequest {
         reply_to: ReplyTo<AnySigningKeyPair>,
     },
 
+    GetKeyByName {
+        key_name: String,
+        reply_to: ReplyTo<AnySigningKeyPair>,
+    },
+
     AddKey {
         key_name: String,
         key: AnySigningKeyPair,
@@ -282,6 +288,18 @@ pub enum ChainRequest {
         reply_to: ReplyTo<(Sequence, Option<MerkleProof>)>,
     },
 
+    QueryUpgrade {
+        request: QueryUpgradeRequest,
+        include_proof: IncludeProof,
+        reply_to: ReplyTo<(Upgrade, Option<MerkleProof>)>,
+    },
+
+    QueryUpgradeError {
+        request: QueryUpgradeErrorRequest,
+        include_proof: IncludeProof,
+        reply_to: ReplyTo<(ErrorReceipt, Option<MerkleProof>)>,
+    },
+
     BuildChannelProofs {
         port_id: PortId,
         channel_id: ChannelId,
@@ -406,6 +424,8 @@ pub trait ChainHandle: Clone + Display + Send + Sync + Debug + 'static {
 
     fn get_key(&self) -> Result<AnySigningKeyPair, Error>;
 
+    fn get_key_by_name(&self, key_name: String) -> Result<AnySigningKeyPair, Error>;
+
     fn add_key(&self, key_name: String, key: AnySigningKeyPair) -> Result<(), Error>;
 
     /// Return the version of the IBC protocol that this chain is running, if known.
@@ -511,6 +531,24 @@ pub trait ChainHandle: Clone + Display + Send + Sync + Debug + 'static {
         include_proof: IncludeProof,
     ) -> Result<(Sequence, Option<MerkleProof>), Error>;
 
+    /// Performs a query to retrieve the upgrade proposed for the given channel,
+    /// while the channel upgrade handshake is in progress. A proof can optionally
+    /// be returned along with the result.
+    fn query_upgrade(
+        &self,
+        request: QueryUpgradeRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(Upgrade, Option<MerkleProof>), Error>;
+
+    /// Performs a query to retrieve the error receipt written when the last
+    /// upgrade of the given channel was aborted. A proof can optionally be
+    /// returned along with the result.
+    fn query_upgrade_error(
+        &self,
+        request: QueryUpgradeErrorRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(ErrorReceipt, Option<MerkleProof>), Error>;
+
     /// Performs a query to retrieve all the channels of a chain.
     fn query_channels(
         &self,
@@ -14,6 +14,7 @@ use ibc_relayer_types::{
         ics03_connection::version::Version,
         ics04_channel::channel::{ChannelEnd, IdentifiedChannelEnd},
         ics04_channel::packet::{PacketMsgType, Sequence},
+        ics04_channel::upgrade::{ErrorReceipt, Upgrade},
         ics23_commitment::{commitment::CommitmentPrefix, merkle::MerkleProof},
         ics24_host::identifier::ChainId,
         ics24_host::identifier::ChannelId,
@@ -137,6 +138,10 @@ impl ChainHandle for BaseChainHandle {
         self.send(|reply_to| ChainRequest::GetKey { reply_to })
     }
 
+    fn get_key_by_name(&self, key_name: String) -> Result<AnySigningKeyPair, Error> {
+        self.send(|reply_to| ChainRequest::GetKeyByName { key_name, reply_to })
+    }
+
     fn add_key(&self, key_name: String, key: AnySigningKeyPair) -> Result<(), Error> {
         self.send(|reply_to| ChainRequest::AddKey {
             key_name,
@@ -278,6 +283,30 @@ impl ChainHandle for BaseChainHandle {
         })
     }
 
+    fn query_upgrade(
+        &self,
+        request: QueryUpgradeRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(Upgrade, Option<MerkleProof>), Error> {
+        self.send(|reply_to| ChainRequest::QueryUpgrade {
+            request,
+            include_proof,
+            reply_to,
+        })
+    }
+
+    fn query_upgrade_error(
+        &self,
+        request: QueryUpgradeErrorRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(ErrorReceipt, Option<MerkleProof>), Error> {
+        self.send(|reply_to| ChainRequest::QueryUpgradeError {
+            request,
+            include_proof,
+            reply_to,
+        })
+    }
+
     fn query_channels(
         &self,
         request: QueryChannelsRequest,
@@ -16,6 +16,7 @@ use ibc_relayer_types::core::ics03_connection::version::Version;
 use ibc_relayer_types::core::ics04_channel::channel::ChannelEnd;
 use ibc_relayer_types::core::ics04_channel::channel::IdentifiedChannelEnd;
 use ibc_relayer_types::core::ics04_channel::packet::{PacketMsgType, Sequence};
+use ibc_relayer_types::core::ics04_channel::upgrade::{ErrorReceipt, Upgrade};
 use ibc_relayer_types::core::ics23_commitment::commitment::CommitmentPrefix;
 use ibc_relayer_types::core::ics23_commitment::merkle::MerkleProof;
 use ibc_relayer_types::core::ics24_host::identifier::{
@@ -145,6 +146,11 @@ impl<Handle: ChainHandle> ChainHandle for CountingChainHandle<Handle> {
         self.inner().get_key()
     }
 
+    fn get_key_by_name(&self, key_name: String) -> Result<AnySigningKeyPair, Error> {
+        self.inc_metric("get_key_by_name");
+        self.inner().get_key_by_name(key_name)
+    }
+
     fn add_key(&self, key_name: String, key: AnySigningKeyPair) -> Result<(), Error> {
         self.inc_metric("add_key");
         self.inner().add_key(key_name, key)
@@ -289,6 +295,24 @@ impl<Handle: ChainHandle> ChainHandle for CountingChainHandle<Handle> {
             .query_next_sequence_receive(request, include_proof)
     }
 
+    fn query_upgrade(
+        &self,
+        request: QueryUpgradeRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(Upgrade, Option<MerkleProof>), Error> {
+        self.inc_metric("query_upgrade");
+        self.inner().query_upgrade(request, include_proof)
+    }
+
+    fn query_upgrade_error(
+        &self,
+        request: QueryUpgradeErrorRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(ErrorReceipt, Option<MerkleProof>), Error> {
+        self.inc_metric("query_upgrade_error");
+        self.inner().query_upgrade_error(request, include_proof)
+    }
+
     fn query_channels(
         &self,
         request: QueryChannelsRequest,
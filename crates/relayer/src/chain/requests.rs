@@ -400,6 +400,20 @@ impl From<QueryNextSequenceReceiveRequest> for RawQueryNextSequenceReceiveReques
     }
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct QueryUpgradeRequest {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub height: QueryHeight,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct QueryUpgradeErrorRequest {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub height: QueryHeight,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct QueryHostConsensusStateRequest {
     pub height: QueryHeight,
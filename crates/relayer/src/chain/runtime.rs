@@ -19,6 +19,7 @@ use ibc_relayer_types::{
         ics04_channel::{
             channel::{ChannelEnd, IdentifiedChannelEnd},
             packet::{PacketMsgType, Sequence},
+            upgrade::{ErrorReceipt, Upgrade},
         },
         ics23_commitment::{commitment::CommitmentPrefix, merkle::MerkleProof},
         ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId},
@@ -182,6 +183,10 @@ where
                             self.get_key(reply_to)?
                         },
 
+                        ChainRequest::GetKeyByName { key_name, reply_to } => {
+                            self.get_key_by_name(&key_name, reply_to)?
+                        },
+
                         ChainRequest::AddKey { key_name, key, reply_to } => {
                             self.add_key(key_name, key, reply_to)?
                         },
@@ -326,6 +331,14 @@ where
                             self.query_next_sequence_receive(request, include_proof, reply_to)?
                         },
 
+                        ChainRequest::QueryUpgrade { request, include_proof, reply_to } => {
+                            self.query_upgrade(request, include_proof, reply_to)?
+                        },
+
+                        ChainRequest::QueryUpgradeError { request, include_proof, reply_to } => {
+                            self.query_upgrade_error(request, include_proof, reply_to)?
+                        },
+
                         ChainRequest::QueryPacketEventDataFromTxs { request, reply_to } => {
                             self.query_txs(request, reply_to)?
                         },
@@ -432,6 +445,15 @@ where
         reply_to.send(result).map_err(Error::send)
     }
 
+    fn get_key_by_name(
+        &self,
+        key_name: &str,
+        reply_to: ReplyTo<AnySigningKeyPair>,
+    ) -> Result<(), Error> {
+        let result = self.chain.get_key_by_name(key_name).map(Into::into);
+        reply_to.send(result).map_err(Error::send)
+    }
+
     fn add_key(
         &mut self,
         key_name: String,
@@ -777,6 +799,26 @@ where
         reply_to.send(result).map_err(Error::send)
     }
 
+    fn query_upgrade(
+        &self,
+        request: QueryUpgradeRequest,
+        include_proof: IncludeProof,
+        reply_to: ReplyTo<(Upgrade, Option<MerkleProof>)>,
+    ) -> Result<(), Error> {
+        let result = self.chain.query_upgrade(request, include_proof);
+        reply_to.send(result).map_err(Error::send)
+    }
+
+    fn query_upgrade_error(
+        &self,
+        request: QueryUpgradeErrorRequest,
+        include_proof: IncludeProof,
+        reply_to: ReplyTo<(ErrorReceipt, Option<MerkleProof>)>,
+    ) -> Result<(), Error> {
+        let result = self.chain.query_upgrade_error(request, include_proof);
+        reply_to.send(result).map_err(Error::send)
+    }
+
     fn query_txs(
         &self,
         request: QueryTxRequest,
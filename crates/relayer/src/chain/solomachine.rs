@@ -0,0 +1,1095 @@
+//! A solo machine, ie. a standalone process that takes part in IBC by signing
+//! its state with a single key, as described in ICS-06.
+//!
+//! The solo machine driven by Hermes keeps its IBC state in memory and signs
+//! it with the key `key_name` of its configuration, using its chain identifier
+//! as diversifier. It can open connections and channels with Cosmos chains and
+//! relay packets with them, which makes it suitable to test the solo machine
+//! clients of these chains. It has a few limitations:
+//!
+//! - its state is lost when Hermes restarts;
+//! - the proofs of the messages it receives are not verified;
+//! - every signature consumes the sequence of the client verifying it, so a
+//!   solo machine can only be relayed with a single counterparty client,
+//!   and its proofs must be submitted in the order they are built. Proofs
+//!   which are not submitted are skipped over by updating the client with
+//!   headers for their sequences;
+//! - connections with a non-zero delay are not supported.
+
+use alloc::sync::Arc;
+use core::convert::TryFrom;
+use std::collections::HashMap;
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::apps::fee::v1::{
+    QueryIncentivizedPacketRequest, QueryIncentivizedPacketResponse,
+};
+use ibc_proto::ibc::core::channel::v1::Channel as RawChannel;
+use ibc_proto::ibc::core::connection::v1::ConnectionEnd as RawConnectionEnd;
+use ibc_proto::protobuf::Protobuf;
+use prost::Message;
+use sha2::{Digest, Sha256};
+use tendermint::abci::Code;
+use tendermint::Hash as TxHash;
+use tendermint_rpc::endpoint::broadcast::tx_sync::Response as TxResponse;
+use tokio::runtime::Runtime as TokioRuntime;
+
+use ibc_relayer_types::applications::ics31_icq::response::CrossChainQueryResponse;
+use ibc_relayer_types::clients::ics06_solomachine::client_state::ClientState as SmClientState;
+use ibc_relayer_types::clients::ics06_solomachine::consensus_state::ConsensusState as SmConsensusState;
+use ibc_relayer_types::clients::ics06_solomachine::header::Header as SmHeader;
+use ibc_relayer_types::core::ics02_client::error::Error as ClientError;
+use ibc_relayer_types::core::ics02_client::events::{NewBlock, UpdateClient};
+use ibc_relayer_types::core::ics03_connection::connection::{
+    ConnectionEnd, IdentifiedConnectionEnd,
+};
+use ibc_relayer_types::core::ics04_channel::channel::{ChannelEnd, IdentifiedChannelEnd, Ordering};
+use ibc_relayer_types::core::ics04_channel::packet::{PacketMsgType, Sequence};
+use ibc_relayer_types::core::ics04_channel::upgrade::{ErrorReceipt, Upgrade};
+use ibc_relayer_types::core::ics23_commitment::commitment::{
+    CommitmentPrefix, CommitmentProofBytes,
+};
+use ibc_relayer_types::core::ics23_commitment::merkle::MerkleProof;
+use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc_relayer_types::core::ics24_host::path::{
+    AcksPath, ChannelEndsPath, ClientConsensusStatePath, ClientStatePath, CommitmentsPath,
+    ConnectionsPath, ReceiptsPath, SeqRecvsPath,
+};
+use ibc_relayer_types::events::{IbcEvent, WithBlockDataType};
+use ibc_relayer_types::proofs::{ConsensusProof, Proofs};
+use ibc_relayer_types::signer::Signer;
+use ibc_relayer_types::timestamp::Timestamp;
+use ibc_relayer_types::Height as ICSHeight;
+
+use crate::account::Balance;
+use crate::chain::client::ClientSettings;
+use crate::chain::endpoint::{ChainEndpoint, ChainStatus, HealthCheck};
+use crate::chain::handle::Subscription;
+use crate::chain::requests::*;
+use crate::chain::tracking::TrackedMsgs;
+use crate::client_state::{AnyClientState, IdentifiedAnyClientState};
+use crate::config::ChainConfig;
+use crate::connection::ConnectionMsgType;
+use crate::consensus_state::AnyConsensusState;
+use crate::denom::DenomTrace;
+use crate::error::Error;
+use crate::event::bus::EventBus;
+use crate::event::monitor::{EventBatch, Result as MonitorResult};
+use crate::event::IbcEventWithHeight;
+use crate::keyring::{KeyRing, Secp256k1KeyPair, SigningKeyPair};
+use crate::misbehaviour::MisbehaviourEvidence;
+
+use self::sign::ProofSigner;
+use self::store::Store;
+
+pub mod sign;
+pub mod store;
+
+pub struct SoloMachineChain {
+    config: ChainConfig,
+    keybase: KeyRing<Secp256k1KeyPair>,
+    signer: ProofSigner,
+    store: Store,
+
+    /// The events emitted so far, in order
+    events: Vec<IbcEventWithHeight>,
+    /// The events emitted by each transaction
+    txs: HashMap<TxHash, Vec<IbcEventWithHeight>>,
+    event_bus: EventBus<Arc<MonitorResult<EventBatch>>>,
+}
+
+impl SoloMachineChain {
+    /// The height of the solo machine, ie. the sequence of its next signature.
+    fn height(&self) -> ICSHeight {
+        self.signer.state().height()
+    }
+
+    /// Applies the given messages to the store, atomically, and broadcasts the
+    /// resulting events to the subscribers of the solo machine.
+    ///
+    /// Returns the hash of the transaction made of these messages, and its events.
+    /// If a message cannot be applied, none of them are, and a single `ChainError`
+    /// event is returned instead.
+    fn deliver(
+        &mut self,
+        tracked_msgs: TrackedMsgs,
+    ) -> Result<(TxHash, Vec<IbcEventWithHeight>), Error> {
+        let height = self.height();
+        let timestamp = Timestamp::now();
+
+        let mut hasher = Sha256::new();
+        hasher.update((self.txs.len() as u64).to_be_bytes());
+
+        let mut store = self.store.clone();
+        let mut result = Ok(Vec::new());
+
+        for msg in &tracked_msgs.msgs {
+            hasher.update(msg.type_url.as_bytes());
+            hasher.update(&msg.value);
+
+            if let Ok(events) = &mut result {
+                match store.apply(msg, height, timestamp) {
+                    Ok(msg_events) => events.extend(msg_events),
+                    Err(e) => result = Err(e),
+                }
+            }
+        }
+
+        let hash = TxHash::Sha256(hasher.finalize().into());
+
+        let events: Vec<_> = match result {
+            Ok(events) => {
+                self.store = store;
+
+                events
+                    .into_iter()
+                    .map(|event| IbcEventWithHeight::new(event, height))
+                    .collect()
+            }
+            Err(e) => {
+                vec![IbcEventWithHeight::new(
+                    IbcEvent::ChainError(e.to_string()),
+                    height,
+                )]
+            }
+        };
+
+        self.txs.insert(hash, events.clone());
+
+        if !matches!(events.first(), Some(e) if matches!(e.event, IbcEvent::ChainError(_))) {
+            self.events.extend(events.iter().cloned());
+
+            let mut batch_events = vec![IbcEventWithHeight::new(
+                IbcEvent::NewBlock(NewBlock::new(height)),
+                height,
+            )];
+            batch_events.extend(events.iter().cloned());
+
+            self.event_bus.broadcast(Arc::new(Ok(EventBatch {
+                chain_id: self.config.id.clone(),
+                tracking_id: tracked_msgs.tracking_id,
+                height,
+                block_time: Some(timestamp),
+                events: batch_events,
+            })));
+        }
+
+        Ok((hash, events))
+    }
+
+    fn client(&self, client_id: &ClientId) -> Result<&store::ClientEntry, Error> {
+        self.store
+            .clients
+            .get(client_id)
+            .ok_or_else(|| Error::solo_machine(format!("client {client_id} does not exist")))
+    }
+
+    fn connection(&self, connection_id: &ConnectionId) -> Result<&ConnectionEnd, Error> {
+        self.store
+            .connections
+            .get(connection_id)
+            .ok_or_else(|| Error::connection_not_found(connection_id.clone()))
+    }
+
+    /// Signs the channel end stored at the given port and channel.
+    fn sign_channel(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(u64, CommitmentProofBytes), Error> {
+        let channel_end = self.store.channel(port_id, channel_id)?.clone();
+        let data = Protobuf::<RawChannel>::encode_vec(&channel_end);
+
+        self.signer.sign_proof(
+            &ChannelEndsPath(port_id.clone(), channel_id.clone()).to_string(),
+            data,
+        )
+    }
+
+    /// Signs the next sequence to be received on the given port and channel.
+    fn sign_next_sequence_recv(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(u64, CommitmentProofBytes), Error> {
+        let next_sequence_recv = self
+            .store
+            .next_sequence_recv
+            .get(&(port_id.clone(), channel_id.clone()))
+            .copied()
+            .unwrap_or_else(|| Sequence::from(1));
+
+        self.signer.sign_proof(
+            &SeqRecvsPath(port_id.clone(), channel_id.clone()).to_string(),
+            u64::from(next_sequence_recv).to_be_bytes().to_vec(),
+        )
+    }
+
+    /// Signs the absence of a receipt for the given packet.
+    fn sign_receipt_absence(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+    ) -> Result<(u64, CommitmentProofBytes), Error> {
+        let path = ReceiptsPath {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            sequence,
+        };
+
+        self.signer.sign_proof(&path.to_string(), Vec::new())
+    }
+}
+
+/// Solo machines do not commit to their state in a Merkle tree, so they cannot
+/// answer queries for proofs. Proofs are instead signed when building messages.
+fn ensure_no_proof(include_proof: IncludeProof) -> Result<(), Error> {
+    match include_proof {
+        IncludeProof::Yes => Err(Error::solo_machine_unsupported(
+            "querying Merkle proofs".to_string(),
+        )),
+        IncludeProof::No => Ok(()),
+    }
+}
+
+fn proof_height(sequence: u64) -> ICSHeight {
+    ICSHeight::new(0, sequence).expect("sequence is never zero")
+}
+
+impl ChainEndpoint for SoloMachineChain {
+    type LightBlock = SmConsensusState;
+    type Header = SmHeader;
+    type ConsensusState = SmConsensusState;
+    type ClientState = SmClientState;
+    type Time = Timestamp;
+    type SigningKeyPair = Secp256k1KeyPair;
+
+    fn config(&self) -> &ChainConfig {
+        &self.config
+    }
+
+    fn bootstrap(config: ChainConfig, _rt: Arc<TokioRuntime>) -> Result<Self, Error> {
+        let keybase = KeyRing::new_secp256k1(
+            config.key_store_type,
+            &config.account_prefix,
+            &config.id,
+            &config.key_store_folder,
+        )
+        .map_err(Error::key_base)?;
+
+        let key_pair = keybase
+            .get_key(&config.key_name)
+            .map_err(|e| Error::key_not_found(config.key_name.clone(), e))?;
+
+        let prefix = CommitmentPrefix::try_from(config.store_prefix.as_bytes().to_vec())
+            .map_err(|_| Error::ics02(ClientError::empty_prefix()))?;
+
+        let signer = ProofSigner::new(key_pair, config.id.to_string(), prefix, Timestamp::now());
+
+        Ok(Self {
+            config,
+            keybase,
+            signer,
+            store: Store::default(),
+            events: Vec::new(),
+            txs: HashMap::new(),
+            event_bus: EventBus::new(),
+        })
+    }
+
+    fn shutdown(self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn health_check(&self) -> Result<HealthCheck, Error> {
+        Ok(HealthCheck::Healthy)
+    }
+
+    fn subscribe(&mut self) -> Result<Subscription, Error> {
+        Ok(self.event_bus.subscribe())
+    }
+
+    fn keybase(&self) -> &KeyRing<Self::SigningKeyPair> {
+        &self.keybase
+    }
+
+    fn keybase_mut(&mut self) -> &mut KeyRing<Self::SigningKeyPair> {
+        &mut self.keybase
+    }
+
+    fn get_signer(&self) -> Result<Signer, Error> {
+        let key_pair = self
+            .keybase
+            .get_key(&self.config.key_name)
+            .map_err(|e| Error::key_not_found(self.config.key_name.clone(), e))?;
+
+        key_pair
+            .account()
+            .parse()
+            .map_err(|e| Error::ics02(ClientError::signer(e)))
+    }
+
+    fn ibc_version(&self) -> Result<Option<semver::Version>, Error> {
+        Ok(None)
+    }
+
+    fn send_messages_and_wait_commit(
+        &mut self,
+        tracked_msgs: TrackedMsgs,
+    ) -> Result<Vec<IbcEventWithHeight>, Error> {
+        let (_, events) = self.deliver(tracked_msgs)?;
+        Ok(events)
+    }
+
+    fn send_messages_and_wait_check_tx(
+        &mut self,
+        tracked_msgs: TrackedMsgs,
+    ) -> Result<Vec<TxResponse>, Error> {
+        let (hash, events) = self.deliver(tracked_msgs)?;
+
+        let (code, log) = match events.first().map(|e| &e.event) {
+            Some(IbcEvent::ChainError(e)) => (Code::from(1), e.clone()),
+            _ => (Code::Ok, String::new()),
+        };
+
+        Ok(vec![TxResponse {
+            code,
+            data: Default::default(),
+            log,
+            hash,
+        }])
+    }
+
+    /// The solo machine is its own light client: the consensus state verifying
+    /// its next signature is all that is needed to track it.
+    fn verify_header(
+        &mut self,
+        _trusted: ICSHeight,
+        _target: ICSHeight,
+        _client_state: &AnyClientState,
+    ) -> Result<Self::LightBlock, Error> {
+        Ok(self.signer.consensus_state())
+    }
+
+    fn check_misbehaviour(
+        &mut self,
+        _update: &UpdateClient,
+        _client_state: &AnyClientState,
+    ) -> Result<Option<MisbehaviourEvidence>, Error> {
+        // The solo machine never signs two different values with the same sequence
+        Ok(None)
+    }
+
+    fn query_balance(
+        &self,
+        _key_name: Option<&str>,
+        _denom: Option<&str>,
+    ) -> Result<Balance, Error> {
+        Err(Error::solo_machine_unsupported(
+            "querying balances".to_string(),
+        ))
+    }
+
+    fn query_all_balances(&self, _key_name: Option<&str>) -> Result<Vec<Balance>, Error> {
+        Err(Error::solo_machine_unsupported(
+            "querying balances".to_string(),
+        ))
+    }
+
+    fn query_denom_trace(&self, _hash: String) -> Result<DenomTrace, Error> {
+        Err(Error::solo_machine_unsupported(
+            "querying denomination traces".to_string(),
+        ))
+    }
+
+    fn query_commitment_prefix(&self) -> Result<CommitmentPrefix, Error> {
+        CommitmentPrefix::try_from(self.config.store_prefix.as_bytes().to_vec())
+            .map_err(|_| Error::ics02(ClientError::empty_prefix()))
+    }
+
+    fn query_application_status(&self) -> Result<ChainStatus, Error> {
+        Ok(ChainStatus {
+            height: self.height(),
+            timestamp: Timestamp::now(),
+        })
+    }
+
+    fn query_clients(
+        &self,
+        _request: QueryClientStatesRequest,
+    ) -> Result<Vec<IdentifiedAnyClientState>, Error> {
+        Ok(self
+            .store
+            .clients
+            .iter()
+            .map(|(client_id, entry)| {
+                IdentifiedAnyClientState::new(client_id.clone(), entry.client_state.clone())
+            })
+            .collect())
+    }
+
+    fn query_client_state(
+        &self,
+        request: QueryClientStateRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(AnyClientState, Option<MerkleProof>), Error> {
+        ensure_no_proof(include_proof)?;
+
+        let entry = self.client(&request.client_id)?;
+        Ok((entry.client_state.clone(), None))
+    }
+
+    fn query_consensus_state(
+        &self,
+        request: QueryConsensusStateRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(AnyConsensusState, Option<MerkleProof>), Error> {
+        ensure_no_proof(include_proof)?;
+
+        let entry = self.client(&request.client_id)?;
+        let consensus_state = entry
+            .consensus_states
+            .get(&request.consensus_height)
+            .cloned()
+            .ok_or_else(|| {
+                Error::solo_machine(format!(
+                    "client {} has no consensus state at height {}",
+                    request.client_id, request.consensus_height
+                ))
+            })?;
+
+        Ok((consensus_state, None))
+    }
+
+    fn query_consensus_state_heights(
+        &self,
+        request: QueryConsensusStateHeightsRequest,
+    ) -> Result<Vec<ICSHeight>, Error> {
+        let entry = self.client(&request.client_id)?;
+        Ok(entry.consensus_states.keys().copied().collect())
+    }
+
+    fn query_upgraded_client_state(
+        &self,
+        _request: QueryUpgradedClientStateRequest,
+    ) -> Result<(AnyClientState, MerkleProof), Error> {
+        Err(Error::solo_machine_unsupported(
+            "querying upgraded client states".to_string(),
+        ))
+    }
+
+    fn query_upgraded_consensus_state(
+        &self,
+        _request: QueryUpgradedConsensusStateRequest,
+    ) -> Result<(AnyConsensusState, MerkleProof), Error> {
+        Err(Error::solo_machine_unsupported(
+            "querying upgraded consensus states".to_string(),
+        ))
+    }
+
+    fn query_connections(
+        &self,
+        _request: QueryConnectionsRequest,
+    ) -> Result<Vec<IdentifiedConnectionEnd>, Error> {
+        Ok(self
+            .store
+            .connections
+            .iter()
+            .map(|(connection_id, connection_end)| {
+                IdentifiedConnectionEnd::new(connection_id.clone(), connection_end.clone())
+            })
+            .collect())
+    }
+
+    fn query_client_connections(
+        &self,
+        request: QueryClientConnectionsRequest,
+    ) -> Result<Vec<ConnectionId>, Error> {
+        Ok(self
+            .store
+            .connections
+            .iter()
+            .filter(|(_, connection_end)| connection_end.client_id() == &request.client_id)
+            .map(|(connection_id, _)| connection_id.clone())
+            .collect())
+    }
+
+    fn query_connection(
+        &self,
+        request: QueryConnectionRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(ConnectionEnd, Option<MerkleProof>), Error> {
+        ensure_no_proof(include_proof)?;
+
+        let connection_end = self.connection(&request.connection_id)?;
+        Ok((connection_end.clone(), None))
+    }
+
+    fn query_connection_channels(
+        &self,
+        request: QueryConnectionChannelsRequest,
+    ) -> Result<Vec<IdentifiedChannelEnd>, Error> {
+        Ok(self
+            .store
+            .channels
+            .iter()
+            .filter(|(_, channel_end)| {
+                channel_end.connection_hops().first() == Some(&request.connection_id)
+            })
+            .map(|((port_id, channel_id), channel_end)| {
+                IdentifiedChannelEnd::new(port_id.clone(), channel_id.clone(), channel_end.clone())
+            })
+            .collect())
+    }
+
+    fn query_channels(
+        &self,
+        _request: QueryChannelsRequest,
+    ) -> Result<Vec<IdentifiedChannelEnd>, Error> {
+        Ok(self
+            .store
+            .channels
+            .iter()
+            .map(|((port_id, channel_id), channel_end)| {
+                IdentifiedChannelEnd::new(port_id.clone(), channel_id.clone(), channel_end.clone())
+            })
+            .collect())
+    }
+
+    fn query_channel(
+        &self,
+        request: QueryChannelRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(ChannelEnd, Option<MerkleProof>), Error> {
+        ensure_no_proof(include_proof)?;
+
+        // As on Cosmos chains, a missing channel end is uninitialized
+        let channel_end = self
+            .store
+            .channels
+            .get(&(request.port_id, request.channel_id))
+            .cloned()
+            .unwrap_or_default();
+
+        Ok((channel_end, None))
+    }
+
+    fn query_channel_client_state(
+        &self,
+        request: QueryChannelClientStateRequest,
+    ) -> Result<Option<IdentifiedAnyClientState>, Error> {
+        let Some(channel_end) = self
+            .store
+            .channels
+            .get(&(request.port_id, request.channel_id))
+        else {
+            return Ok(None);
+        };
+
+        let Some(connection_end) = channel_end
+            .connection_hops()
+            .first()
+            .and_then(|connection_id| self.store.connections.get(connection_id))
+        else {
+            return Ok(None);
+        };
+
+        let client_id = connection_end.client_id();
+
+        Ok(self.store.clients.get(client_id).map(|entry| {
+            IdentifiedAnyClientState::new(client_id.clone(), entry.client_state.clone())
+        }))
+    }
+
+    fn query_packet_commitment(
+        &self,
+        request: QueryPacketCommitmentRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(Vec<u8>, Option<MerkleProof>), Error> {
+        ensure_no_proof(include_proof)?;
+
+        let commitment = self
+            .store
+            .commitments
+            .get(&(request.port_id, request.channel_id, request.sequence))
+            .cloned()
+            .unwrap_or_default();
+
+        Ok((commitment, None))
+    }
+
+    fn query_packet_commitments(
+        &self,
+        request: QueryPacketCommitmentsRequest,
+    ) -> Result<(Vec<Sequence>, ICSHeight), Error> {
+        let sequences = self
+            .store
+            .commitments
+            .keys()
+            .filter(|(port_id, channel_id, _)| {
+                port_id == &request.port_id && channel_id == &request.channel_id
+            })
+            .map(|(_, _, sequence)| *sequence)
+            .collect();
+
+        Ok((sequences, self.height()))
+    }
+
+    fn query_packet_receipt(
+        &self,
+        request: QueryPacketReceiptRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(Vec<u8>, Option<MerkleProof>), Error> {
+        ensure_no_proof(include_proof)?;
+
+        let receipt = if self.store.receipts.contains(&(
+            request.port_id,
+            request.channel_id,
+            request.sequence,
+        )) {
+            vec![1]
+        } else {
+            Vec::new()
+        };
+
+        Ok((receipt, None))
+    }
+
+    fn query_unreceived_packets(
+        &self,
+        request: QueryUnreceivedPacketsRequest,
+    ) -> Result<Vec<Sequence>, Error> {
+        let channel_end = self.store.channel(&request.port_id, &request.channel_id)?;
+        let key = (request.port_id, request.channel_id);
+
+        let sequences = if channel_end.order_matches(&Ordering::Ordered) {
+            let next_sequence_recv = self
+                .store
+                .next_sequence_recv
+                .get(&key)
+                .copied()
+                .unwrap_or_else(|| Sequence::from(1));
+
+            request
+                .packet_commitment_sequences
+                .into_iter()
+                .filter(|sequence| *sequence >= next_sequence_recv)
+                .collect()
+        } else {
+            request
+                .packet_commitment_sequences
+                .into_iter()
+                .filter(|sequence| {
+                    !self
+                        .store
+                        .receipts
+                        .contains(&(key.0.clone(), key.1.clone(), *sequence))
+                })
+                .collect()
+        };
+
+        Ok(sequences)
+    }
+
+    fn query_packet_acknowledgement(
+        &self,
+        request: QueryPacketAcknowledgementRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(Vec<u8>, Option<MerkleProof>), Error> {
+        ensure_no_proof(include_proof)?;
+
+        let ack = self
+            .store
+            .acknowledgements
+            .get(&(request.port_id, request.channel_id, request.sequence))
+            .cloned()
+            .unwrap_or_default();
+
+        Ok((ack, None))
+    }
+
+    fn query_packet_acknowledgements(
+        &self,
+        request: QueryPacketAcknowledgementsRequest,
+    ) -> Result<(Vec<Sequence>, ICSHeight), Error> {
+        let sequences = request
+            .packet_commitment_sequences
+            .into_iter()
+            .filter(|sequence| {
+                self.store.acknowledgements.contains_key(&(
+                    request.port_id.clone(),
+                    request.channel_id.clone(),
+                    *sequence,
+                ))
+            })
+            .collect();
+
+        Ok((sequences, self.height()))
+    }
+
+    fn query_unreceived_acknowledgements(
+        &self,
+        request: QueryUnreceivedAcksRequest,
+    ) -> Result<Vec<Sequence>, Error> {
+        Ok(request
+            .packet_ack_sequences
+            .into_iter()
+            .filter(|sequence| {
+                self.store.commitments.contains_key(&(
+                    request.port_id.clone(),
+                    request.channel_id.clone(),
+                    *sequence,
+                ))
+            })
+            .collect())
+    }
+
+    fn query_next_sequence_receive(
+        &self,
+        request: QueryNextSequenceReceiveRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(Sequence, Option<MerkleProof>), Error> {
+        ensure_no_proof(include_proof)?;
+
+        let next_sequence_recv = self
+            .store
+            .next_sequence_recv
+            .get(&(request.port_id, request.channel_id))
+            .copied()
+            .unwrap_or_else(|| Sequence::from(1));
+
+        Ok((next_sequence_recv, None))
+    }
+
+    fn query_upgrade(
+        &self,
+        _request: QueryUpgradeRequest,
+        _include_proof: IncludeProof,
+    ) -> Result<(Upgrade, Option<MerkleProof>), Error> {
+        Err(Error::solo_machine_unsupported(
+            "channel upgrades".to_string(),
+        ))
+    }
+
+    fn query_upgrade_error(
+        &self,
+        _request: QueryUpgradeErrorRequest,
+        _include_proof: IncludeProof,
+    ) -> Result<(ErrorReceipt, Option<MerkleProof>), Error> {
+        Err(Error::solo_machine_unsupported(
+            "channel upgrades".to_string(),
+        ))
+    }
+
+    fn query_txs(&self, request: QueryTxRequest) -> Result<Vec<IbcEventWithHeight>, Error> {
+        match request {
+            QueryTxRequest::Transaction(tx) => Ok(self.txs.get(&tx.0).cloned().unwrap_or_default()),
+            QueryTxRequest::Client(request) => Ok(self
+                .events
+                .iter()
+                .filter(|e| match (&request.event_id, &e.event) {
+                    (WithBlockDataType::CreateClient, IbcEvent::CreateClient(create)) => {
+                        create.client_id() == &request.client_id
+                    }
+                    (WithBlockDataType::UpdateClient, IbcEvent::UpdateClient(update)) => {
+                        update.client_id() == &request.client_id
+                            && update.consensus_height() == request.consensus_height
+                    }
+                    _ => false,
+                })
+                .cloned()
+                .collect()),
+        }
+    }
+
+    fn query_packet_events(
+        &self,
+        request: QueryPacketEventDataRequest,
+    ) -> Result<Vec<IbcEventWithHeight>, Error> {
+        let height_matches = |height: ICSHeight| match request.height {
+            Qualified::SmallerEqual(QueryHeight::Specific(h)) => height <= h,
+            Qualified::Equal(QueryHeight::Specific(h)) => height == h,
+            Qualified::SmallerEqual(QueryHeight::Latest)
+            | Qualified::Equal(QueryHeight::Latest) => true,
+        };
+
+        Ok(self
+            .events
+            .iter()
+            .filter(|e| height_matches(e.height))
+            .filter(|e| {
+                let packet = match (&request.event_id, &e.event) {
+                    (WithBlockDataType::SendPacket, IbcEvent::SendPacket(send)) => &send.packet,
+                    (WithBlockDataType::WriteAck, IbcEvent::WriteAcknowledgement(write)) => {
+                        &write.packet
+                    }
+                    _ => return false,
+                };
+
+                packet.source_port == request.source_port_id
+                    && packet.source_channel == request.source_channel_id
+                    && packet.destination_port == request.destination_port_id
+                    && packet.destination_channel == request.destination_channel_id
+                    && request.sequences.contains(&packet.sequence)
+            })
+            .cloned()
+            .collect())
+    }
+
+    fn query_host_consensus_state(
+        &self,
+        _request: QueryHostConsensusStateRequest,
+    ) -> Result<Self::ConsensusState, Error> {
+        Ok(self.signer.consensus_state())
+    }
+
+    fn build_client_state(
+        &self,
+        _height: ICSHeight,
+        _settings: ClientSettings,
+    ) -> Result<Self::ClientState, Error> {
+        let state = self.signer.state();
+
+        SmClientState::new(state.sequence, self.signer.consensus_state())
+            .map_err(|e| Error::ics02(e.into()))
+    }
+
+    fn build_consensus_state(
+        &self,
+        light_block: Self::LightBlock,
+    ) -> Result<Self::ConsensusState, Error> {
+        Ok(light_block)
+    }
+
+    /// Signs the headers which move the given client to the sequence of the proofs
+    /// signed at `target_height`, which keep the key of the solo machine.
+    ///
+    /// If the client is already past `target_height`, the proofs signed at that height
+    /// will be rejected, so signing resumes from the sequence of the client instead.
+    fn build_header(
+        &mut self,
+        _trusted_height: ICSHeight,
+        target_height: ICSHeight,
+        client_state: &AnyClientState,
+    ) -> Result<(Self::Header, Vec<Self::Header>), Error> {
+        let AnyClientState::Solomachine(client_state) = client_state else {
+            return Err(Error::client_type_mismatch(
+                ibc_relayer_types::core::ics02_client::client_type::ClientType::Solomachine,
+                client_state.client_type(),
+            ));
+        };
+
+        let sequence = client_state.sequence;
+        let target = target_height.revision_height();
+
+        if target < sequence {
+            self.signer.resync(sequence);
+
+            return Err(Error::solo_machine(format!(
+                "proofs signed at sequence {target} are behind the client at sequence {sequence}, \
+                 signing resumes from the sequence of the client"
+            )));
+        }
+
+        let mut headers =
+            self.signer
+                .sign_headers(sequence, target, client_state.consensus_state.timestamp)?;
+
+        // There is always at least one header
+        let header = headers.pop().expect("no header signed");
+
+        Ok((header, headers))
+    }
+
+    /// Signs the connection end and, for `OpenTry` and `OpenAck`, the client and
+    /// consensus states, in the order in which ibc-go verifies them.
+    fn build_connection_proofs_and_client_state(
+        &self,
+        message_type: ConnectionMsgType,
+        connection_id: &ConnectionId,
+        client_id: &ClientId,
+        _height: ICSHeight,
+    ) -> Result<(Option<AnyClientState>, Proofs), Error> {
+        let connection_end = self.connection(connection_id)?.clone();
+
+        let (sequence, connection_proof) = self.signer.sign_proof(
+            &ConnectionsPath(connection_id.clone()).to_string(),
+            Protobuf::<RawConnectionEnd>::encode_vec(&connection_end),
+        )?;
+
+        let (client_state, client_proof, consensus_proof) = match message_type {
+            ConnectionMsgType::OpenTry | ConnectionMsgType::OpenAck => {
+                let entry = self.client(client_id)?;
+                let client_state = entry.client_state.clone();
+                let consensus_height = client_state.latest_height();
+
+                let consensus_state = entry
+                    .consensus_states
+                    .get(&consensus_height)
+                    .cloned()
+                    .ok_or_else(|| {
+                        Error::solo_machine(format!(
+                            "client {client_id} has no consensus state at height {consensus_height}"
+                        ))
+                    })?;
+
+                let (_, client_proof) = self.signer.sign_proof(
+                    &ClientStatePath(client_id.clone()).to_string(),
+                    Any::from(client_state.clone()).encode_to_vec(),
+                )?;
+
+                let (_, consensus_proof) = self.signer.sign_proof(
+                    &ClientConsensusStatePath {
+                        client_id: client_id.clone(),
+                        epoch: consensus_height.revision_number(),
+                        height: consensus_height.revision_height(),
+                    }
+                    .to_string(),
+                    Any::from(consensus_state).encode_to_vec(),
+                )?;
+
+                let consensus_proof = ConsensusProof::new(consensus_proof, consensus_height)
+                    .map_err(Error::consensus_proof)?;
+
+                (
+                    Some(client_state),
+                    Some(client_proof),
+                    Some(consensus_proof),
+                )
+            }
+            ConnectionMsgType::OpenConfirm => (None, None, None),
+        };
+
+        let proofs = Proofs::new(
+            connection_proof,
+            client_proof,
+            consensus_proof,
+            None,
+            proof_height(sequence),
+        )
+        .map_err(Error::malformed_proof)?;
+
+        Ok((client_state, proofs))
+    }
+
+    fn build_channel_proofs(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        _height: ICSHeight,
+    ) -> Result<Proofs, Error> {
+        let (sequence, channel_proof) = self.sign_channel(port_id, channel_id)?;
+
+        Proofs::new(channel_proof, None, None, None, proof_height(sequence))
+            .map_err(Error::malformed_proof)
+    }
+
+    /// Signs the proofs of a packet message. For timeouts on close, the channel end
+    /// is signed first, as ibc-go verifies it before the packet proof.
+    fn build_packet_proofs(
+        &self,
+        packet_type: PacketMsgType,
+        port_id: PortId,
+        channel_id: ChannelId,
+        sequence: Sequence,
+        _height: ICSHeight,
+    ) -> Result<Proofs, Error> {
+        let (channel_proof, (proof_sequence, packet_proof)) = match packet_type {
+            PacketMsgType::Recv => {
+                let commitment = self
+                    .store
+                    .commitments
+                    .get(&(port_id.clone(), channel_id.clone(), sequence))
+                    .cloned()
+                    .ok_or_else(|| {
+                        Error::solo_machine(format!(
+                            "no commitment for packet {sequence} on {port_id}/{channel_id}"
+                        ))
+                    })?;
+
+                let path = CommitmentsPath {
+                    port_id,
+                    channel_id,
+                    sequence,
+                };
+
+                (None, self.signer.sign_proof(&path.to_string(), commitment)?)
+            }
+            PacketMsgType::Ack => {
+                let ack = self
+                    .store
+                    .acknowledgements
+                    .get(&(port_id.clone(), channel_id.clone(), sequence))
+                    .cloned()
+                    .ok_or_else(|| {
+                        Error::solo_machine(format!(
+                            "no acknowledgement for packet {sequence} on {port_id}/{channel_id}"
+                        ))
+                    })?;
+
+                let path = AcksPath {
+                    port_id,
+                    channel_id,
+                    sequence,
+                };
+
+                (None, self.signer.sign_proof(&path.to_string(), ack)?)
+            }
+            PacketMsgType::TimeoutUnordered => (
+                None,
+                self.sign_receipt_absence(&port_id, &channel_id, sequence)?,
+            ),
+            PacketMsgType::TimeoutOrdered => {
+                (None, self.sign_next_sequence_recv(&port_id, &channel_id)?)
+            }
+            PacketMsgType::TimeoutOnCloseUnordered => {
+                let (channel_sequence, channel_proof) = self.sign_channel(&port_id, &channel_id)?;
+                let (_, packet_proof) =
+                    self.sign_receipt_absence(&port_id, &channel_id, sequence)?;
+
+                (Some(channel_proof), (channel_sequence, packet_proof))
+            }
+            PacketMsgType::TimeoutOnCloseOrdered => {
+                let (channel_sequence, channel_proof) = self.sign_channel(&port_id, &channel_id)?;
+                let (_, packet_proof) = self.sign_next_sequence_recv(&port_id, &channel_id)?;
+
+                (Some(channel_proof), (channel_sequence, packet_proof))
+            }
+        };
+
+        Proofs::new(
+            packet_proof,
+            None,
+            None,
+            channel_proof,
+            proof_height(proof_sequence),
+        )
+        .map_err(Error::malformed_proof)
+    }
+
+    fn maybe_register_counterparty_payee(
+        &mut self,
+        _channel_id: &ChannelId,
+        _port_id: &PortId,
+        _counterparty_payee: &Signer,
+    ) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn cross_chain_query(
+        &self,
+        _requests: Vec<CrossChainQueryRequest>,
+    ) -> Result<Vec<CrossChainQueryResponse>, Error> {
+        Ok(vec![])
+    }
+
+    fn query_incentivized_packet(
+        &self,
+        _request: QueryIncentivizedPacketRequest,
+    ) -> Result<QueryIncentivizedPacketResponse, Error> {
+        Err(Error::solo_machine_unsupported(
+            "querying incentivized packets".to_string(),
+        ))
+    }
+}
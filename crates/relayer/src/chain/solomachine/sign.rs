@@ -0,0 +1,351 @@
+//! Signatures made by a solo machine over its own state, which stand in for
+//! the Merkle proofs that the clients of a solo machine verify.
+
+use std::sync::Mutex;
+
+use ibc_proto::cosmos::tx::signing::v1beta1::signature_descriptor::{
+    data::{Single, Sum},
+    Data as SignatureData,
+};
+use ibc_proto::google::protobuf::Any;
+use prost::Message;
+
+use ibc_relayer_types::clients::ics06_solomachine::consensus_state::{
+    ConsensusState as SmConsensusState, PublicKey,
+};
+use ibc_relayer_types::clients::ics06_solomachine::header::Header as SmHeader;
+use ibc_relayer_types::clients::ics06_solomachine::raw::{
+    HeaderData as RawHeaderData, SignBytes as RawSignBytes,
+    TimestampedSignatureData as RawTimestampedSignatureData,
+};
+use ibc_relayer_types::clients::ics06_solomachine::SENTINEL_HEADER_PATH;
+use ibc_relayer_types::core::ics23_commitment::commitment::{
+    CommitmentPrefix, CommitmentProofBytes,
+};
+use ibc_relayer_types::timestamp::Timestamp;
+use ibc_relayer_types::Height;
+
+use crate::error::Error;
+use crate::keyring::{Secp256k1KeyPair, SigningKeyPair};
+
+/// Sign mode recorded alongside signatures, as expected by ibc-go.
+/// The solo machine client does not depend on it.
+const SIGN_MODE_DIRECT: i32 = 1;
+
+/// The sequence and timestamp of the next signature of a solo machine.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SigningState {
+    pub sequence: u64,
+    pub timestamp: Timestamp,
+}
+
+impl SigningState {
+    /// The height of a solo machine is the sequence of its next signature.
+    pub fn height(&self) -> Height {
+        Height::new(0, self.sequence).expect("sequence is never zero")
+    }
+}
+
+/// Signs proofs and headers on behalf of a solo machine.
+///
+/// Each proof consumes one sequence, which must match the sequence of the client
+/// verifying it, so proofs must be submitted in the order they are signed. When
+/// some proofs are not submitted, the client falls behind, and catches up with
+/// the headers signed by [`ProofSigner::sign_headers`].
+pub struct ProofSigner {
+    key_pair: Secp256k1KeyPair,
+    diversifier: String,
+    prefix: CommitmentPrefix,
+    state: Mutex<SigningState>,
+}
+
+impl ProofSigner {
+    pub fn new(
+        key_pair: Secp256k1KeyPair,
+        diversifier: String,
+        prefix: CommitmentPrefix,
+        timestamp: Timestamp,
+    ) -> Self {
+        Self {
+            key_pair,
+            diversifier,
+            prefix,
+            state: Mutex::new(SigningState {
+                sequence: 1,
+                timestamp,
+            }),
+        }
+    }
+
+    pub fn state(&self) -> SigningState {
+        *self.state.lock().expect("poisoned lock")
+    }
+
+    pub fn public_key(&self) -> PublicKey {
+        let mut value = Vec::new();
+        self.key_pair
+            .public_key
+            .serialize()
+            .to_vec()
+            .encode(&mut value)
+            .expect("encoding into a vector cannot fail");
+
+        PublicKey {
+            type_url: "/cosmos.crypto.secp256k1.PubKey".to_string(),
+            value,
+        }
+    }
+
+    /// The consensus state that a client of this solo machine
+    /// should hold to verify its next signature.
+    pub fn consensus_state(&self) -> SmConsensusState {
+        SmConsensusState::new(
+            self.public_key(),
+            self.diversifier.clone(),
+            self.state().timestamp,
+        )
+    }
+
+    /// Signs `data` as the value stored at the given ICS-24 `path`,
+    /// and returns the sequence of the signature along with the proof.
+    pub fn sign_proof(
+        &self,
+        path: &str,
+        data: Vec<u8>,
+    ) -> Result<(u64, CommitmentProofBytes), Error> {
+        let path = merkle_path(&self.prefix, path);
+
+        let mut state = self.state.lock().expect("poisoned lock");
+
+        // Clients reject signatures older than their consensus state
+        let timestamp = Timestamp::now().max(state.timestamp);
+        let sequence = state.sequence;
+
+        let signature = self.sign(sequence, timestamp, path, data)?;
+
+        *state = SigningState {
+            sequence: sequence + 1,
+            timestamp,
+        };
+
+        let proof = RawTimestampedSignatureData {
+            signature_data: signature,
+            timestamp: timestamp.nanoseconds(),
+        }
+        .encode_to_vec();
+
+        let proof = CommitmentProofBytes::try_from(proof).map_err(Error::malformed_proof)?;
+
+        Ok((sequence, proof))
+    }
+
+    /// Signs the headers which move a client, whose next expected sequence is
+    /// `sequence`, to the `target` sequence, or by one sequence if it is already
+    /// there. The headers keep the public key and diversifier of the solo machine.
+    ///
+    /// The headers carry the `timestamp` of the consensus state of the client,
+    /// so that the client still accepts the proofs already signed for `target`.
+    /// Subsequent signatures resume after `target`, if they are not already past it.
+    pub fn sign_headers(
+        &self,
+        sequence: u64,
+        target: u64,
+        timestamp: Timestamp,
+    ) -> Result<Vec<SmHeader>, Error> {
+        let public_key = self.public_key();
+
+        let data = RawHeaderData {
+            new_pub_key: Some(Any::from(public_key.clone())),
+            new_diversifier: self.diversifier.clone(),
+        }
+        .encode_to_vec();
+
+        let mut state = self.state.lock().expect("poisoned lock");
+
+        let headers = (sequence..target.max(sequence + 1))
+            .map(|sequence| {
+                let signature = self.sign(
+                    sequence,
+                    timestamp,
+                    SENTINEL_HEADER_PATH.as_bytes().to_vec(),
+                    data.clone(),
+                )?;
+
+                Ok(SmHeader {
+                    sequence,
+                    timestamp,
+                    signature,
+                    new_public_key: public_key.clone(),
+                    new_diversifier: self.diversifier.clone(),
+                })
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        state.sequence = state.sequence.max(target.max(sequence + 1));
+        state.timestamp = state.timestamp.max(timestamp);
+
+        Ok(headers)
+    }
+
+    /// Resumes signing from the given sequence, which is the next sequence expected
+    /// by a client that is ahead of the proofs signed so far.
+    pub fn resync(&self, sequence: u64) {
+        self.state.lock().expect("poisoned lock").sequence = sequence;
+    }
+
+    /// Signs the given path and data with the given sequence and timestamp,
+    /// and returns the signature data.
+    fn sign(
+        &self,
+        sequence: u64,
+        timestamp: Timestamp,
+        path: Vec<u8>,
+        data: Vec<u8>,
+    ) -> Result<Vec<u8>, Error> {
+        let sign_bytes = RawSignBytes {
+            sequence,
+            timestamp: timestamp.nanoseconds(),
+            diversifier: self.diversifier.clone(),
+            path,
+            data,
+        }
+        .encode_to_vec();
+
+        let signature = self.key_pair.sign(&sign_bytes).map_err(Error::key_base)?;
+
+        Ok(signature_data(signature))
+    }
+}
+
+/// Wraps a raw signature into the signature data expected by the solo machine client.
+fn signature_data(signature: Vec<u8>) -> Vec<u8> {
+    SignatureData {
+        sum: Some(Sum::Single(Single {
+            mode: SIGN_MODE_DIRECT,
+            signature,
+        })),
+    }
+    .encode_to_vec()
+}
+
+/// The path signed over for a value stored at `path` under `prefix`, which matches
+/// the string representation of the corresponding Merkle path in ibc-go, where
+/// each key of the path is escaped as a URL path segment.
+fn merkle_path(prefix: &CommitmentPrefix, path: &str) -> Vec<u8> {
+    let prefix = String::from_utf8_lossy(prefix.as_bytes());
+    format!(
+        "/{}/{}",
+        prefix.replace('/', "%2F"),
+        path.replace('/', "%2F")
+    )
+    .into_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use core::str::FromStr;
+
+    use hdpath::StandardHDPath;
+
+    use crate::config::AddressType;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon \
+        abandon abandon abandon abandon abandon about";
+
+    fn signer() -> ProofSigner {
+        let hd_path = StandardHDPath::from_str("m/44'/118'/0'/0/0").unwrap();
+        let key_pair =
+            Secp256k1KeyPair::from_mnemonic(MNEMONIC, &hd_path, &AddressType::Cosmos, "cosmos")
+                .unwrap();
+
+        ProofSigner::new(
+            key_pair,
+            "solo-0".to_string(),
+            CommitmentPrefix::try_from(b"ibc".to_vec()).unwrap(),
+            Timestamp::from_nanoseconds(1).unwrap(),
+        )
+    }
+
+    #[test]
+    fn merkle_path_escapes_keys() {
+        let prefix = CommitmentPrefix::try_from(b"ibc".to_vec()).unwrap();
+        let path = merkle_path(&prefix, "connections/connection-0");
+
+        assert_eq!(path, b"/ibc/connections%2Fconnection-0".to_vec());
+    }
+
+    #[test]
+    fn signature_data_round_trip() {
+        let data = signature_data(vec![1, 2, 3]);
+        let decoded = SignatureData::decode(data.as_slice()).unwrap();
+
+        match decoded.sum {
+            Some(Sum::Single(single)) => {
+                assert_eq!(single.mode, SIGN_MODE_DIRECT);
+                assert_eq!(single.signature, vec![1, 2, 3]);
+            }
+            other => panic!("unexpected signature data: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn signatures_consume_sequences() {
+        let signer = signer();
+
+        let (first, _) = signer
+            .sign_proof("connections/connection-0", vec![1])
+            .unwrap();
+        let (second, proof) = signer
+            .sign_proof("connections/connection-0", vec![2])
+            .unwrap();
+
+        assert_eq!((first, second), (1, 2));
+        assert_eq!(signer.state().sequence, 3);
+        assert_eq!(signer.state().height(), Height::new(0, 3).unwrap());
+
+        let proof = RawTimestampedSignatureData::decode(Vec::from(proof).as_slice()).unwrap();
+        assert_eq!(proof.timestamp, signer.state().timestamp.nanoseconds());
+        assert_eq!(signer.consensus_state().timestamp, signer.state().timestamp);
+    }
+
+    #[test]
+    fn headers_catch_up_with_signed_proofs() {
+        let signer = signer();
+
+        for _ in 0..3 {
+            signer
+                .sign_proof("clients/07-tendermint-0/clientState", vec![1])
+                .unwrap();
+        }
+
+        // The client still expects sequence 1, while the last proof was signed at sequence 3
+        let timestamp = Timestamp::from_nanoseconds(1).unwrap();
+        let headers = signer.sign_headers(1, 3, timestamp).unwrap();
+
+        let sequences: Vec<_> = headers.iter().map(|header| header.sequence).collect();
+        assert_eq!(sequences, vec![1, 2]);
+        assert!(headers.iter().all(|header| header.timestamp == timestamp));
+        assert_eq!(headers[0].new_public_key, signer.public_key());
+
+        // The signatures resume after the proofs already signed
+        assert_eq!(signer.state().sequence, 4);
+    }
+
+    #[test]
+    fn resync_resumes_from_client_sequence() {
+        let signer = signer();
+
+        signer
+            .sign_proof("clients/07-tendermint-0/clientState", vec![1])
+            .unwrap();
+
+        signer.resync(5);
+
+        let (sequence, _) = signer
+            .sign_proof("clients/07-tendermint-0/clientState", vec![1])
+            .unwrap();
+        assert_eq!(sequence, 5);
+    }
+}
@@ -0,0 +1,832 @@
+//! The IBC state of a solo machine, kept in memory, and the application
+//! of the messages submitted to it.
+//!
+//! Proofs carried by incoming messages are not verified: the solo machine
+//! trusts the relayer driving it, which is enough to exercise the handshakes
+//! and packet flows of its counterparties.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::applications::transfer::v2::FungibleTokenPacketData as RawPacketData;
+use ibc_proto::protobuf::Protobuf;
+use sha2::{Digest, Sha256};
+
+use ibc_relayer_types::applications::transfer::acknowledgement::Acknowledgement as TransferAck;
+use ibc_relayer_types::applications::transfer::msgs::transfer::{
+    MsgTransfer, TYPE_URL as TRANSFER_TYPE_URL,
+};
+use ibc_relayer_types::clients::ics07_tendermint::consensus_state::ConsensusState as TmConsensusState;
+use ibc_relayer_types::core::ics02_client::events::{
+    Attributes as ClientAttributes, CreateClient, UpdateClient,
+};
+use ibc_relayer_types::core::ics02_client::header::Header;
+use ibc_relayer_types::core::ics02_client::msgs::create_client::{
+    MsgCreateClient, TYPE_URL as CREATE_CLIENT_TYPE_URL,
+};
+use ibc_relayer_types::core::ics02_client::msgs::update_client::{
+    MsgUpdateClient, TYPE_URL as UPDATE_CLIENT_TYPE_URL,
+};
+use ibc_relayer_types::core::ics03_connection::connection::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc_relayer_types::core::ics03_connection::events::{
+    self as connection_events, Attributes as ConnectionAttributes,
+};
+use ibc_relayer_types::core::ics03_connection::msgs::{
+    conn_open_ack, conn_open_confirm, conn_open_init, conn_open_try,
+};
+use ibc_relayer_types::core::ics03_connection::version::{get_compatible_versions, pick_version};
+use ibc_relayer_types::core::ics04_channel::channel::{
+    ChannelEnd, Counterparty as ChannelCounterparty, Ordering, State as ChannelState,
+};
+use ibc_relayer_types::core::ics04_channel::events as channel_events;
+use ibc_relayer_types::core::ics04_channel::msgs::{
+    acknowledgement, chan_close_confirm, chan_close_init, chan_open_ack, chan_open_confirm,
+    chan_open_init, chan_open_try, recv_packet, timeout, timeout_on_close,
+};
+use ibc_relayer_types::core::ics04_channel::packet::{Packet, Sequence};
+use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc_relayer_types::events::IbcEvent;
+use ibc_relayer_types::timestamp::Timestamp;
+use ibc_relayer_types::Height;
+
+use crate::client_state::AnyClientState;
+use crate::consensus_state::AnyConsensusState;
+use crate::error::Error;
+use crate::light_client::AnyHeader;
+
+/// Identifies a packet by the channel end it was sent or received on, and its sequence.
+pub type PacketKey = (PortId, ChannelId, Sequence);
+
+/// A client hosted by the solo machine, along with its consensus states.
+#[derive(Clone, Debug)]
+pub struct ClientEntry {
+    pub client_state: AnyClientState,
+    pub consensus_states: BTreeMap<Height, AnyConsensusState>,
+}
+
+/// The IBC state of a solo machine.
+#[derive(Clone, Debug, Default)]
+pub struct Store {
+    pub clients: BTreeMap<ClientId, ClientEntry>,
+    pub connections: BTreeMap<ConnectionId, ConnectionEnd>,
+    pub channels: BTreeMap<(PortId, ChannelId), ChannelEnd>,
+
+    pub next_sequence_send: BTreeMap<(PortId, ChannelId), Sequence>,
+    pub next_sequence_recv: BTreeMap<(PortId, ChannelId), Sequence>,
+    pub next_sequence_ack: BTreeMap<(PortId, ChannelId), Sequence>,
+
+    pub commitments: BTreeMap<PacketKey, Vec<u8>>,
+    pub receipts: BTreeSet<PacketKey>,
+    pub acknowledgements: BTreeMap<PacketKey, Vec<u8>>,
+
+    client_counter: u64,
+    connection_counter: u64,
+    channel_counter: u64,
+}
+
+impl Store {
+    /// Applies a single message to the store, given the current height and
+    /// timestamp of the solo machine, and returns the events it emitted.
+    pub fn apply(
+        &mut self,
+        msg: &Any,
+        height: Height,
+        timestamp: Timestamp,
+    ) -> Result<Vec<IbcEvent>, Error> {
+        let value = msg.value.as_slice();
+
+        match msg.type_url.as_str() {
+            CREATE_CLIENT_TYPE_URL => {
+                self.create_client(MsgCreateClient::decode_vec(value).map_err(Error::decode)?)
+            }
+            UPDATE_CLIENT_TYPE_URL => {
+                self.update_client(MsgUpdateClient::decode_vec(value).map_err(Error::decode)?)
+            }
+
+            conn_open_init::TYPE_URL => self.conn_open_init(
+                conn_open_init::MsgConnectionOpenInit::decode_vec(value).map_err(Error::decode)?,
+            ),
+            conn_open_try::TYPE_URL => self.conn_open_try(
+                conn_open_try::MsgConnectionOpenTry::decode_vec(value).map_err(Error::decode)?,
+            ),
+            conn_open_ack::TYPE_URL => self.conn_open_ack(
+                conn_open_ack::MsgConnectionOpenAck::decode_vec(value).map_err(Error::decode)?,
+            ),
+            conn_open_confirm::TYPE_URL => self.conn_open_confirm(
+                conn_open_confirm::MsgConnectionOpenConfirm::decode_vec(value)
+                    .map_err(Error::decode)?,
+            ),
+
+            chan_open_init::TYPE_URL => self.chan_open_init(
+                chan_open_init::MsgChannelOpenInit::decode_vec(value).map_err(Error::decode)?,
+            ),
+            chan_open_try::TYPE_URL => self.chan_open_try(
+                chan_open_try::MsgChannelOpenTry::decode_vec(value).map_err(Error::decode)?,
+            ),
+            chan_open_ack::TYPE_URL => self.chan_open_ack(
+                chan_open_ack::MsgChannelOpenAck::decode_vec(value).map_err(Error::decode)?,
+            ),
+            chan_open_confirm::TYPE_URL => self.chan_open_confirm(
+                chan_open_confirm::MsgChannelOpenConfirm::decode_vec(value)
+                    .map_err(Error::decode)?,
+            ),
+            chan_close_init::TYPE_URL => self.chan_close_init(
+                chan_close_init::MsgChannelCloseInit::decode_vec(value).map_err(Error::decode)?,
+            ),
+            chan_close_confirm::TYPE_URL => self.chan_close_confirm(
+                chan_close_confirm::MsgChannelCloseConfirm::decode_vec(value)
+                    .map_err(Error::decode)?,
+            ),
+
+            TRANSFER_TYPE_URL => {
+                self.transfer(MsgTransfer::decode_vec(value).map_err(Error::decode)?)
+            }
+            recv_packet::TYPE_URL => self.recv_packet(
+                recv_packet::MsgRecvPacket::decode_vec(value).map_err(Error::decode)?,
+                height,
+                timestamp,
+            ),
+            acknowledgement::TYPE_URL => self.acknowledge_packet(
+                acknowledgement::MsgAcknowledgement::decode_vec(value).map_err(Error::decode)?,
+            ),
+            timeout::TYPE_URL => {
+                let msg = timeout::MsgTimeout::decode_vec(value).map_err(Error::decode)?;
+                self.timeout_packet(msg.packet, false)
+            }
+            timeout_on_close::TYPE_URL => {
+                let msg = timeout_on_close::MsgTimeoutOnClose::decode_vec(value)
+                    .map_err(Error::decode)?;
+                self.timeout_packet(msg.packet, true)
+            }
+
+            other => Err(Error::solo_machine_unsupported(format!(
+                "message with type URL {other}"
+            ))),
+        }
+    }
+
+    pub fn channel(&self, port_id: &PortId, channel_id: &ChannelId) -> Result<&ChannelEnd, Error> {
+        self.channels
+            .get(&(port_id.clone(), channel_id.clone()))
+            .ok_or_else(|| {
+                Error::solo_machine(format!("channel {port_id}/{channel_id} does not exist"))
+            })
+    }
+
+    fn channel_mut(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<&mut ChannelEnd, Error> {
+        self.channels
+            .get_mut(&(port_id.clone(), channel_id.clone()))
+            .ok_or_else(|| {
+                Error::solo_machine(format!("channel {port_id}/{channel_id} does not exist"))
+            })
+    }
+
+    fn connection_mut(
+        &mut self,
+        connection_id: &ConnectionId,
+    ) -> Result<&mut ConnectionEnd, Error> {
+        self.connections
+            .get_mut(connection_id)
+            .ok_or_else(|| Error::connection_not_found(connection_id.clone()))
+    }
+
+    fn create_client(&mut self, msg: MsgCreateClient) -> Result<Vec<IbcEvent>, Error> {
+        let client_state = AnyClientState::try_from(msg.client_state).map_err(Error::ics02)?;
+        let consensus_state =
+            AnyConsensusState::try_from(msg.consensus_state).map_err(Error::ics02)?;
+
+        let client_type = client_state.client_type();
+        let height = client_state.latest_height();
+
+        let client_id = ClientId::new(client_type, self.client_counter)
+            .map_err(|e| Error::solo_machine(format!("failed to build client identifier: {e}")))?;
+        self.client_counter += 1;
+
+        self.clients.insert(
+            client_id.clone(),
+            ClientEntry {
+                client_state,
+                consensus_states: BTreeMap::from([(height, consensus_state)]),
+            },
+        );
+
+        Ok(vec![IbcEvent::CreateClient(CreateClient(
+            ClientAttributes {
+                client_id,
+                client_type,
+                consensus_height: height,
+            },
+        ))])
+    }
+
+    fn update_client(&mut self, msg: MsgUpdateClient) -> Result<Vec<IbcEvent>, Error> {
+        let header = AnyHeader::try_from(msg.header).map_err(Error::ics02)?;
+
+        let entry = self.clients.get_mut(&msg.client_id).ok_or_else(|| {
+            Error::solo_machine(format!("client {} does not exist", msg.client_id))
+        })?;
+
+        let (client_state, consensus_state) = match (&entry.client_state, &header) {
+            (AnyClientState::Tendermint(client_state), AnyHeader::Tendermint(header)) => {
+                let client_state = if header.height() > client_state.latest_height() {
+                    client_state
+                        .clone()
+                        .with_header(header.clone())
+                        .map_err(|e| Error::ics02(e.into()))?
+                } else {
+                    client_state.clone()
+                };
+
+                (
+                    AnyClientState::Tendermint(client_state),
+                    AnyConsensusState::Tendermint(TmConsensusState::from(header.clone())),
+                )
+            }
+            _ => {
+                return Err(Error::solo_machine_unsupported(format!(
+                    "updating a {} client with a {} header",
+                    entry.client_state.client_type(),
+                    header.client_type()
+                )))
+            }
+        };
+
+        let client_type = client_state.client_type();
+        let consensus_height = header.height();
+
+        entry.client_state = client_state;
+        entry
+            .consensus_states
+            .insert(consensus_height, consensus_state);
+
+        Ok(vec![IbcEvent::UpdateClient(UpdateClient {
+            common: ClientAttributes {
+                client_id: msg.client_id,
+                client_type,
+                consensus_height,
+            },
+            header: Some(Box::new(header)),
+        })])
+    }
+
+    fn add_connection(&mut self, connection_end: ConnectionEnd) -> ConnectionId {
+        let connection_id = ConnectionId::new(self.connection_counter);
+        self.connection_counter += 1;
+
+        self.connections
+            .insert(connection_id.clone(), connection_end);
+
+        connection_id
+    }
+
+    fn conn_open_init(
+        &mut self,
+        msg: conn_open_init::MsgConnectionOpenInit,
+    ) -> Result<Vec<IbcEvent>, Error> {
+        let versions = match msg.version {
+            Some(version) => vec![version],
+            None => get_compatible_versions(),
+        };
+
+        let connection_id = self.add_connection(ConnectionEnd::new(
+            ConnectionState::Init,
+            msg.client_id.clone(),
+            msg.counterparty.clone(),
+            versions,
+            msg.delay_period,
+        ));
+
+        Ok(vec![IbcEvent::OpenInitConnection(
+            connection_events::OpenInit(ConnectionAttributes {
+                connection_id: Some(connection_id),
+                client_id: msg.client_id,
+                counterparty_connection_id: None,
+                counterparty_client_id: msg.counterparty.client_id().clone(),
+            }),
+        )])
+    }
+
+    fn conn_open_try(
+        &mut self,
+        msg: conn_open_try::MsgConnectionOpenTry,
+    ) -> Result<Vec<IbcEvent>, Error> {
+        let version = pick_version(get_compatible_versions(), msg.counterparty_versions)
+            .map_err(|e| Error::solo_machine(format!("no compatible connection version: {e}")))?;
+
+        let connection_id = self.add_connection(ConnectionEnd::new(
+            ConnectionState::TryOpen,
+            msg.client_id.clone(),
+            msg.counterparty.clone(),
+            vec![version],
+            msg.delay_period,
+        ));
+
+        Ok(vec![IbcEvent::OpenTryConnection(
+            connection_events::OpenTry(ConnectionAttributes {
+                connection_id: Some(connection_id),
+                client_id: msg.client_id,
+                counterparty_connection_id: msg.counterparty.connection_id().cloned(),
+                counterparty_client_id: msg.counterparty.client_id().clone(),
+            }),
+        )])
+    }
+
+    fn conn_open_ack(
+        &mut self,
+        msg: conn_open_ack::MsgConnectionOpenAck,
+    ) -> Result<Vec<IbcEvent>, Error> {
+        let connection_end = self.connection_mut(&msg.connection_id)?;
+
+        let counterparty = ConnectionCounterparty::new(
+            connection_end.counterparty().client_id().clone(),
+            Some(msg.counterparty_connection_id.clone()),
+            connection_end.counterparty().prefix().clone(),
+        );
+
+        connection_end.set_state(ConnectionState::Open);
+        connection_end.set_counterparty(counterparty.clone());
+        connection_end.set_version(msg.version);
+
+        Ok(vec![IbcEvent::OpenAckConnection(
+            connection_events::OpenAck(ConnectionAttributes {
+                connection_id: Some(msg.connection_id),
+                client_id: connection_end.client_id().clone(),
+                counterparty_connection_id: Some(msg.counterparty_connection_id),
+                counterparty_client_id: counterparty.client_id().clone(),
+            }),
+        )])
+    }
+
+    fn conn_open_confirm(
+        &mut self,
+        msg: conn_open_confirm::MsgConnectionOpenConfirm,
+    ) -> Result<Vec<IbcEvent>, Error> {
+        let connection_end = self.connection_mut(&msg.connection_id)?;
+        connection_end.set_state(ConnectionState::Open);
+
+        Ok(vec![IbcEvent::OpenConfirmConnection(
+            connection_events::OpenConfirm(ConnectionAttributes {
+                connection_id: Some(msg.connection_id),
+                client_id: connection_end.client_id().clone(),
+                counterparty_connection_id: connection_end.counterparty().connection_id().cloned(),
+                counterparty_client_id: connection_end.counterparty().client_id().clone(),
+            }),
+        )])
+    }
+
+    fn add_channel(&mut self, port_id: PortId, channel_end: ChannelEnd) -> ChannelId {
+        let channel_id = ChannelId::new(self.channel_counter);
+        self.channel_counter += 1;
+
+        let key = (port_id, channel_id.clone());
+        self.next_sequence_send
+            .insert(key.clone(), Sequence::from(1));
+        self.next_sequence_recv
+            .insert(key.clone(), Sequence::from(1));
+        self.next_sequence_ack
+            .insert(key.clone(), Sequence::from(1));
+        self.channels.insert(key, channel_end);
+
+        channel_id
+    }
+
+    fn connection_id(channel_end: &ChannelEnd) -> Result<ConnectionId, Error> {
+        channel_end
+            .connection_hops()
+            .first()
+            .cloned()
+            .ok_or_else(|| Error::solo_machine("channel has no connection hops".to_string()))
+    }
+
+    fn chan_open_init(
+        &mut self,
+        msg: chan_open_init::MsgChannelOpenInit,
+    ) -> Result<Vec<IbcEvent>, Error> {
+        let connection_id = Self::connection_id(&msg.channel)?;
+        let counterparty = msg.channel.counterparty().clone();
+
+        let mut channel_end = msg.channel;
+        channel_end.set_state(ChannelState::Init);
+
+        let channel_id = self.add_channel(msg.port_id.clone(), channel_end);
+
+        Ok(vec![IbcEvent::OpenInitChannel(channel_events::OpenInit {
+            port_id: msg.port_id,
+            channel_id: Some(channel_id),
+            connection_id,
+            counterparty_port_id: counterparty.port_id,
+            counterparty_channel_id: counterparty.channel_id,
+        })])
+    }
+
+    fn chan_open_try(
+        &mut self,
+        msg: chan_open_try::MsgChannelOpenTry,
+    ) -> Result<Vec<IbcEvent>, Error> {
+        let connection_id = Self::connection_id(&msg.channel)?;
+        let counterparty = msg.channel.counterparty().clone();
+
+        let mut channel_end = msg.channel;
+        channel_end.set_state(ChannelState::TryOpen);
+
+        let channel_id = self.add_channel(msg.port_id.clone(), channel_end);
+
+        Ok(vec![IbcEvent::OpenTryChannel(channel_events::OpenTry {
+            port_id: msg.port_id,
+            channel_id: Some(channel_id),
+            connection_id,
+            counterparty_port_id: counterparty.port_id,
+            counterparty_channel_id: counterparty.channel_id,
+        })])
+    }
+
+    fn chan_open_ack(
+        &mut self,
+        msg: chan_open_ack::MsgChannelOpenAck,
+    ) -> Result<Vec<IbcEvent>, Error> {
+        let channel_end = self.channel_mut(&msg.port_id, &msg.channel_id)?;
+
+        channel_end.set_state(ChannelState::Open);
+        channel_end.set_version(msg.counterparty_version);
+        channel_end.set_counterparty_channel_id(msg.counterparty_channel_id.clone());
+
+        let connection_id = Self::connection_id(channel_end)?;
+        let counterparty_port_id = channel_end.counterparty().port_id().clone();
+
+        Ok(vec![IbcEvent::OpenAckChannel(channel_events::OpenAck {
+            port_id: msg.port_id,
+            channel_id: Some(msg.channel_id),
+            counterparty_channel_id: Some(msg.counterparty_channel_id),
+            connection_id,
+            counterparty_port_id,
+        })])
+    }
+
+    fn chan_open_confirm(
+        &mut self,
+        msg: chan_open_confirm::MsgChannelOpenConfirm,
+    ) -> Result<Vec<IbcEvent>, Error> {
+        let channel_end = self.channel_mut(&msg.port_id, &msg.channel_id)?;
+        channel_end.set_state(ChannelState::Open);
+
+        let connection_id = Self::connection_id(channel_end)?;
+        let ChannelCounterparty {
+            port_id: counterparty_port_id,
+            channel_id: counterparty_channel_id,
+        } = channel_end.counterparty().clone();
+
+        Ok(vec![IbcEvent::OpenConfirmChannel(
+            channel_events::OpenConfirm {
+                port_id: msg.port_id,
+                channel_id: Some(msg.channel_id),
+                connection_id,
+                counterparty_port_id,
+                counterparty_channel_id,
+            },
+        )])
+    }
+
+    fn chan_close_init(
+        &mut self,
+        msg: chan_close_init::MsgChannelCloseInit,
+    ) -> Result<Vec<IbcEvent>, Error> {
+        let channel_end = self.channel_mut(&msg.port_id, &msg.channel_id)?;
+        channel_end.set_state(ChannelState::Closed);
+
+        let connection_id = Self::connection_id(channel_end)?;
+        let ChannelCounterparty {
+            port_id: counterparty_port_id,
+            channel_id: counterparty_channel_id,
+        } = channel_end.counterparty().clone();
+
+        Ok(vec![IbcEvent::CloseInitChannel(
+            channel_events::CloseInit {
+                port_id: msg.port_id,
+                channel_id: msg.channel_id,
+                connection_id,
+                counterparty_port_id,
+                counterparty_channel_id,
+            },
+        )])
+    }
+
+    fn chan_close_confirm(
+        &mut self,
+        msg: chan_close_confirm::MsgChannelCloseConfirm,
+    ) -> Result<Vec<IbcEvent>, Error> {
+        let channel_end = self.channel_mut(&msg.port_id, &msg.channel_id)?;
+        channel_end.set_state(ChannelState::Closed);
+
+        let connection_id = Self::connection_id(channel_end)?;
+        let ChannelCounterparty {
+            port_id: counterparty_port_id,
+            channel_id: counterparty_channel_id,
+        } = channel_end.counterparty().clone();
+
+        Ok(vec![IbcEvent::CloseConfirmChannel(
+            channel_events::CloseConfirm {
+                channel_id: Some(msg.channel_id),
+                port_id: msg.port_id,
+                connection_id,
+                counterparty_port_id,
+                counterparty_channel_id,
+            },
+        )])
+    }
+
+    /// Sends an ICS-20 packet over the given channel. The solo machine holds no
+    /// balances, so the tokens are neither escrowed nor burnt.
+    fn transfer(&mut self, msg: MsgTransfer) -> Result<Vec<IbcEvent>, Error> {
+        let channel_end = self.channel(&msg.source_port, &msg.source_channel)?;
+
+        if !channel_end.is_open() {
+            return Err(Error::solo_machine(format!(
+                "channel {}/{} is not open",
+                msg.source_port, msg.source_channel
+            )));
+        }
+
+        let counterparty = channel_end.counterparty();
+        let destination_channel = counterparty.channel_id().cloned().ok_or_else(|| {
+            Error::solo_machine("channel has no counterparty channel".to_string())
+        })?;
+
+        let data = RawPacketData {
+            denom: msg.token.denom,
+            amount: msg.token.amount,
+            sender: msg.sender.to_string(),
+            receiver: msg.receiver.to_string(),
+            memo: msg.memo.unwrap_or_default(),
+        };
+
+        let key = (msg.source_port.clone(), msg.source_channel.clone());
+        let sequence = self
+            .next_sequence_send
+            .get(&key)
+            .copied()
+            .unwrap_or_else(|| Sequence::from(1));
+
+        let packet = Packet {
+            sequence,
+            source_port: msg.source_port,
+            source_channel: msg.source_channel,
+            destination_port: counterparty.port_id().clone(),
+            destination_channel,
+            data: serde_json::to_vec(&data)
+                .map_err(|e| Error::solo_machine(format!("failed to encode packet data: {e}")))?,
+            timeout_height: msg.timeout_height,
+            timeout_timestamp: msg.timeout_timestamp,
+        };
+
+        self.next_sequence_send.insert(key, sequence.increment());
+        self.commitments.insert(
+            (
+                packet.source_port.clone(),
+                packet.source_channel.clone(),
+                sequence,
+            ),
+            packet_commitment(&packet),
+        );
+
+        Ok(vec![IbcEvent::SendPacket(channel_events::SendPacket {
+            packet,
+        })])
+    }
+
+    fn recv_packet(
+        &mut self,
+        msg: recv_packet::MsgRecvPacket,
+        height: Height,
+        timestamp: Timestamp,
+    ) -> Result<Vec<IbcEvent>, Error> {
+        let packet = msg.packet;
+        let channel_end = self.channel(&packet.destination_port, &packet.destination_channel)?;
+
+        if !channel_end.is_open() {
+            return Err(Error::solo_machine(format!(
+                "channel {}/{} is not open",
+                packet.destination_port, packet.destination_channel
+            )));
+        }
+
+        if packet.timed_out(&timestamp, height) {
+            return Err(Error::solo_machine(format!(
+                "packet {} has timed out",
+                packet.sequence
+            )));
+        }
+
+        let key = (
+            packet.destination_port.clone(),
+            packet.destination_channel.clone(),
+        );
+        let packet_key = (key.0.clone(), key.1.clone(), packet.sequence);
+
+        if channel_end.order_matches(&Ordering::Ordered) {
+            let next_sequence_recv = self
+                .next_sequence_recv
+                .get(&key)
+                .copied()
+                .unwrap_or_else(|| Sequence::from(1));
+
+            if packet.sequence < next_sequence_recv {
+                // The packet was already received, nothing to do
+                return Ok(vec![]);
+            }
+
+            if packet.sequence != next_sequence_recv {
+                return Err(Error::solo_machine(format!(
+                    "expected packet {next_sequence_recv} on ordered channel, got {}",
+                    packet.sequence
+                )));
+            }
+
+            self.next_sequence_recv
+                .insert(key, next_sequence_recv.increment());
+        } else if !self.receipts.insert(packet_key.clone()) {
+            // The packet was already received, nothing to do
+            return Ok(vec![]);
+        }
+
+        let ack = serde_json::to_vec(&TransferAck::success())
+            .map_err(|e| Error::solo_machine(format!("failed to encode acknowledgement: {e}")))?;
+
+        self.acknowledgements
+            .insert(packet_key, Sha256::digest(&ack).to_vec());
+
+        Ok(vec![
+            IbcEvent::ReceivePacket(channel_events::ReceivePacket {
+                packet: packet.clone(),
+            }),
+            IbcEvent::WriteAcknowledgement(channel_events::WriteAcknowledgement { packet, ack }),
+        ])
+    }
+
+    fn acknowledge_packet(
+        &mut self,
+        msg: acknowledgement::MsgAcknowledgement,
+    ) -> Result<Vec<IbcEvent>, Error> {
+        let packet = msg.packet;
+        let channel_end = self.channel(&packet.source_port, &packet.source_channel)?;
+        let ordered = channel_end.order_matches(&Ordering::Ordered);
+
+        let packet_key = (
+            packet.source_port.clone(),
+            packet.source_channel.clone(),
+            packet.sequence,
+        );
+
+        if self.commitments.remove(&packet_key).is_none() {
+            // The packet was already acknowledged, nothing to do
+            return Ok(vec![]);
+        }
+
+        if ordered {
+            self.next_sequence_ack.insert(
+                (packet.source_port.clone(), packet.source_channel.clone()),
+                packet.sequence.increment(),
+            );
+        }
+
+        Ok(vec![IbcEvent::AcknowledgePacket(
+            channel_events::AcknowledgePacket { packet },
+        )])
+    }
+
+    fn timeout_packet(&mut self, packet: Packet, on_close: bool) -> Result<Vec<IbcEvent>, Error> {
+        let packet_key = (
+            packet.source_port.clone(),
+            packet.source_channel.clone(),
+            packet.sequence,
+        );
+
+        if self.commitments.remove(&packet_key).is_none() {
+            // The packet was already acknowledged or timed out, nothing to do
+            return Ok(vec![]);
+        }
+
+        let channel_end = self.channel_mut(&packet.source_port, &packet.source_channel)?;
+
+        // A timeout closes an ordered channel
+        if channel_end.order_matches(&Ordering::Ordered) {
+            channel_end.set_state(ChannelState::Closed);
+        }
+
+        let event = if on_close {
+            IbcEvent::TimeoutOnClosePacket(channel_events::TimeoutOnClosePacket { packet })
+        } else {
+            IbcEvent::TimeoutPacket(channel_events::TimeoutPacket { packet })
+        };
+
+        Ok(vec![event])
+    }
+}
+
+/// Computes the commitment to a packet, as defined in ICS-04.
+pub fn packet_commitment(packet: &Packet) -> Vec<u8> {
+    let mut input = packet
+        .timeout_timestamp
+        .nanoseconds()
+        .to_be_bytes()
+        .to_vec();
+    input.extend(
+        packet
+            .timeout_height
+            .commitment_revision_number()
+            .to_be_bytes(),
+    );
+    input.extend(
+        packet
+            .timeout_height
+            .commitment_revision_height()
+            .to_be_bytes(),
+    );
+    input.extend(Sha256::digest(&packet.data));
+
+    Sha256::digest(&input).to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use core::str::FromStr;
+    use core::time::Duration;
+
+    use ibc_relayer_types::core::ics03_connection::connection::Counterparty as ConnectionCounterparty;
+    use ibc_relayer_types::core::ics04_channel::version::Version as ChannelVersion;
+    use ibc_relayer_types::core::ics23_commitment::commitment::CommitmentPrefix;
+    use ibc_relayer_types::signer::Signer;
+    use ibc_relayer_types::tx_msg::Msg;
+
+    fn signer() -> Signer {
+        Signer::from_str("cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng").unwrap()
+    }
+
+    fn apply(store: &mut Store, msg: Any) -> Result<Vec<IbcEvent>, Error> {
+        store.apply(&msg, Height::new(0, 1).unwrap(), Timestamp::now())
+    }
+
+    #[test]
+    fn open_init_assigns_identifiers() {
+        let mut store = Store::default();
+
+        let conn_open_init = conn_open_init::MsgConnectionOpenInit {
+            client_id: ClientId::from_str("07-tendermint-0").unwrap(),
+            counterparty: ConnectionCounterparty::new(
+                ClientId::from_str("06-solomachine-0").unwrap(),
+                None,
+                CommitmentPrefix::try_from(b"ibc".to_vec()).unwrap(),
+            ),
+            version: None,
+            delay_period: Duration::ZERO,
+            signer: signer(),
+        };
+
+        let events = apply(&mut store, conn_open_init.to_any()).unwrap();
+        assert!(matches!(events[..], [IbcEvent::OpenInitConnection(_)]));
+
+        let connection_id = ConnectionId::new(0);
+        assert_eq!(
+            store.connections[&connection_id].state(),
+            &ConnectionState::Init
+        );
+
+        let chan_open_init = chan_open_init::MsgChannelOpenInit {
+            port_id: PortId::transfer(),
+            channel: ChannelEnd::new(
+                ChannelState::Init,
+                Ordering::Unordered,
+                ChannelCounterparty::new(PortId::transfer(), None),
+                vec![connection_id],
+                ChannelVersion::ics20(),
+            ),
+            signer: signer(),
+        };
+
+        let events = apply(&mut store, chan_open_init.to_any()).unwrap();
+        assert!(matches!(events[..], [IbcEvent::OpenInitChannel(_)]));
+
+        let key = (PortId::transfer(), ChannelId::new(0));
+        assert!(store.channel(&key.0, &key.1).is_ok());
+        assert_eq!(store.next_sequence_send[&key], Sequence::from(1));
+    }
+
+    #[test]
+    fn unsupported_messages_are_rejected() {
+        let mut store = Store::default();
+
+        let msg = Any {
+            type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+            value: vec![],
+        };
+
+        assert!(apply(&mut store, msg).is_err());
+    }
+}
@@ -8,7 +8,7 @@ use uuid::Uuid;
 /// transactions are submitted and/or confirmed.
 #[derive(Copy, Clone, Debug)]
 pub enum TrackingId {
-    /// Random identifier, used for tracking an event batch received over WebSocket.
+    /// Random identifier, used for tracking an event batch received from a chain.
     Uuid(Uuid),
     /// Static identifier, used as a placeholder for when there is no
     /// corresponding event batch, eg. when performing actions from
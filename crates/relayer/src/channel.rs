@@ -6,19 +6,31 @@ use serde::Serialize;
 use tracing::{debug, error, info, warn};
 
 pub use error::ChannelError;
+use ibc_relayer_types::clients::ics09_localhost::sentinel_proofs;
 use ibc_relayer_types::core::ics04_channel::channel::{
     ChannelEnd, Counterparty, IdentifiedChannelEnd, Ordering, State,
 };
+use ibc_relayer_types::core::ics04_channel::events::Attributes as ChannelAttributes;
 use ibc_relayer_types::core::ics04_channel::msgs::chan_close_confirm::MsgChannelCloseConfirm;
 use ibc_relayer_types::core::ics04_channel::msgs::chan_close_init::MsgChannelCloseInit;
 use ibc_relayer_types::core::ics04_channel::msgs::chan_open_ack::MsgChannelOpenAck;
 use ibc_relayer_types::core::ics04_channel::msgs::chan_open_confirm::MsgChannelOpenConfirm;
 use ibc_relayer_types::core::ics04_channel::msgs::chan_open_init::MsgChannelOpenInit;
 use ibc_relayer_types::core::ics04_channel::msgs::chan_open_try::MsgChannelOpenTry;
+use ibc_relayer_types::core::ics04_channel::msgs::chan_upgrade_ack::MsgChannelUpgradeAck;
+use ibc_relayer_types::core::ics04_channel::msgs::chan_upgrade_cancel::MsgChannelUpgradeCancel;
+use ibc_relayer_types::core::ics04_channel::msgs::chan_upgrade_confirm::MsgChannelUpgradeConfirm;
+use ibc_relayer_types::core::ics04_channel::msgs::chan_upgrade_init::MsgChannelUpgradeInit;
+use ibc_relayer_types::core::ics04_channel::msgs::chan_upgrade_open::MsgChannelUpgradeOpen;
+use ibc_relayer_types::core::ics04_channel::msgs::chan_upgrade_timeout::MsgChannelUpgradeTimeout;
+use ibc_relayer_types::core::ics04_channel::msgs::chan_upgrade_try::MsgChannelUpgradeTry;
+use ibc_relayer_types::core::ics04_channel::upgrade::{Upgrade, UpgradeFields};
+use ibc_relayer_types::core::ics23_commitment::commitment::CommitmentProofBytes;
 use ibc_relayer_types::core::ics24_host::identifier::{
     ChainId, ChannelId, ClientId, ConnectionId, PortId,
 };
 use ibc_relayer_types::events::IbcEvent;
+use ibc_relayer_types::proofs::Proofs;
 use ibc_relayer_types::tx_msg::Msg;
 use ibc_relayer_types::Height;
 
@@ -26,10 +38,11 @@ use crate::chain::counterparty::{channel_connection_client, channel_state_on_des
 use crate::chain::handle::ChainHandle;
 use crate::chain::requests::{
     IncludeProof, PageRequest, QueryChannelRequest, QueryConnectionChannelsRequest,
-    QueryConnectionRequest, QueryHeight,
+    QueryConnectionRequest, QueryHeight, QueryUpgradeErrorRequest, QueryUpgradeRequest,
 };
 use crate::chain::tracking::TrackedMsgs;
 use crate::connection::Connection;
+use crate::error::Error as RelayerError;
 use crate::foreign_client::{ForeignClient, HasExpiredOrFrozenError};
 use crate::object::Channel as WorkerChannelObject;
 use crate::supervisor::error::Error as SupervisorError;
@@ -231,10 +244,39 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
         counterparty_chain: ChainB,
         channel_open_event: IbcEvent,
     ) -> Result<Channel<ChainA, ChainB>, ChannelError> {
-        let channel_event_attributes = channel_open_event
-            .clone()
-            .channel_attributes()
-            .ok_or_else(|| ChannelError::invalid_event(channel_open_event))?;
+        let channel_event_attributes = match channel_open_event.clone().upgrade_attributes() {
+            // Upgrade events do not carry the connection identifier, get it from the channel end
+            Some(upgrade_attributes) => {
+                let (channel_end, _) = chain
+                    .query_channel(
+                        QueryChannelRequest {
+                            port_id: upgrade_attributes.port_id.clone(),
+                            channel_id: upgrade_attributes.channel_id.clone(),
+                            height: QueryHeight::Latest,
+                        },
+                        IncludeProof::No,
+                    )
+                    .map_err(ChannelError::relayer)?;
+
+                let connection_id = channel_end
+                    .connection_hops()
+                    .first()
+                    .cloned()
+                    .ok_or_else(|| ChannelError::missing_local_connection(chain.id()))?;
+
+                ChannelAttributes {
+                    port_id: upgrade_attributes.port_id,
+                    channel_id: Some(upgrade_attributes.channel_id),
+                    connection_id,
+                    counterparty_port_id: upgrade_attributes.counterparty_port_id,
+                    counterparty_channel_id: upgrade_attributes.counterparty_channel_id,
+                }
+            }
+            None => channel_open_event
+                .clone()
+                .channel_attributes()
+                .ok_or_else(|| ChannelError::invalid_event(channel_open_event))?,
+        };
 
         let port_id = channel_event_attributes.port_id.clone();
         let channel_id = channel_event_attributes.channel_id;
@@ -750,14 +792,23 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
         &mut self,
         state: State,
     ) -> Result<(Option<IbcEvent>, Next), ChannelError> {
-        let event = match (state, self.counterparty_state()?) {
+        let counterparty_state = self.counterparty_state()?;
+
+        // Upgrade handshake steps, including the first one, when both ends are still open
+        if state.is_upgrading()
+            || counterparty_state.is_upgrading()
+            || (state.is_open() && counterparty_state.is_open())
+        {
+            return self.upgrade_state_step();
+        }
+
+        let event = match (state, counterparty_state) {
             // Open handshake steps
             (State::Init, State::Uninitialized) => Some(self.build_chan_open_try_and_send()?),
             (State::Init, State::Init) => Some(self.build_chan_open_try_and_send()?),
             (State::TryOpen, State::Init) => Some(self.build_chan_open_ack_and_send()?),
             (State::TryOpen, State::TryOpen) => Some(self.build_chan_open_ack_and_send()?),
             (State::Open, State::TryOpen) => Some(self.build_chan_open_confirm_and_send()?),
-            (State::Open, State::Open) => return Ok((None, Next::Abort)),
 
             // If the counterparty state is already Open but current state is TryOpen,
             // return anyway as the final step is to be done by the counterparty worker.
@@ -802,7 +853,105 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
         }
     }
 
+    /// Relays the next message of the channel upgrade handshake, following
+    /// the given upgrade event emitted by the source chain.
+    pub fn upgrade_step(
+        &mut self,
+        event: &IbcEvent,
+    ) -> Result<(Option<IbcEvent>, Next), ChannelError> {
+        let event = match event {
+            IbcEvent::UpgradeInitChannel(_) => self.build_chan_upgrade_try_and_send()?,
+            IbcEvent::UpgradeTryChannel(_) => self.build_chan_upgrade_ack_and_send()?,
+            IbcEvent::UpgradeAckChannel(_) => self.build_chan_upgrade_confirm_and_send()?,
+            IbcEvent::UpgradeConfirmChannel(_) | IbcEvent::UpgradeOpenChannel(_) => {
+                return self.upgrade_state_step()
+            }
+            IbcEvent::UpgradeErrorChannel(_) => self.build_chan_upgrade_cancel_and_send()?,
+            _ => return Ok((None, Next::Abort)),
+        };
+
+        Ok((Some(event), Next::Abort))
+    }
+
+    /// Relays the next message of the channel upgrade handshake to destination,
+    /// based on the current state of both channel ends:
+    /// - `ChanUpgradeTry` if an upgrade was initialized on source, which is
+    ///   ahead of destination in upgrade sequence while both ends are open;
+    /// - `ChanUpgradeAck` once source is flushing, while destination is still open;
+    /// - `ChanUpgradeConfirm` once source has flushed its in-flight packets,
+    ///   while destination is still flushing;
+    /// - `ChanUpgradeOpen` once both channel ends have flushed their in-flight
+    ///   packets, or once source is open again;
+    /// - `ChanUpgradeTimeout` if the upgrade timed out on destination in the meantime.
+    ///
+    /// Returns [`Next::Continue`] while the channel ends are flushing, and there is
+    /// nothing to relay yet. Once a message is relayed, the next step is driven by
+    /// the event it emits, as with [`Self::upgrade_step`].
+    fn upgrade_state_step(&self) -> Result<(Option<IbcEvent>, Next), ChannelError> {
+        let src_channel = self.query_src_channel_end()?;
+        let dst_channel = self.query_dst_channel_end()?;
+
+        match (*src_channel.state(), *dst_channel.state()) {
+            (State::Open, State::Open) => {
+                if src_channel.upgrade_sequence() > dst_channel.upgrade_sequence() {
+                    Ok((Some(self.build_chan_upgrade_try_and_send()?), Next::Abort))
+                } else {
+                    Ok((None, Next::Abort))
+                }
+            }
+            (State::FlushComplete | State::Open, State::FlushComplete) => {
+                Ok((Some(self.build_chan_upgrade_open_and_send()?), Next::Abort))
+            }
+            (_, dst_state) if dst_state.is_upgrading() && self.dst_upgrade_timed_out()? => Ok((
+                Some(self.build_chan_upgrade_timeout_and_send()?),
+                Next::Abort,
+            )),
+            (State::Flushing, State::Open) => {
+                Ok((Some(self.build_chan_upgrade_ack_and_send()?), Next::Abort))
+            }
+            (State::FlushComplete, State::Flushing) => Ok((
+                Some(self.build_chan_upgrade_confirm_and_send()?),
+                Next::Abort,
+            )),
+            (src_state, dst_state) if src_state.is_upgrading() || dst_state.is_upgrading() => {
+                debug!(
+                    "waiting for channel ends to flush their in-flight packets ({}, {})",
+                    src_state, dst_state
+                );
+                Ok((None, Next::Continue))
+            }
+            _ => Ok((None, Next::Abort)),
+        }
+    }
+
+    pub fn step_upgrade_event(&mut self, event: &IbcEvent, index: u64) -> RetryResult<Next, u64> {
+        match self.upgrade_step(event) {
+            Err(e) => {
+                if e.is_expired_or_frozen_error() {
+                    error!("failed to upgrade channel on frozen client: {}", e);
+                    RetryResult::Err(index)
+                } else {
+                    error!(
+                        "failed to relay {} with error: {}",
+                        event.event_type().as_str(),
+                        e
+                    );
+                    RetryResult::Retry(index)
+                }
+            }
+            Ok((Some(ev), next)) => {
+                info!("channel upgrade step completed with events: {}", ev);
+                RetryResult::Ok(next)
+            }
+            Ok((None, next)) => RetryResult::Ok(next),
+        }
+    }
+
     pub fn step_event(&mut self, event: &IbcEvent, index: u64) -> RetryResult<Next, u64> {
+        if event.clone().upgrade_attributes().is_some() {
+            return self.step_upgrade_event(event, index);
+        }
+
         let state = match event {
             IbcEvent::OpenInitChannel(_) => State::Init,
             IbcEvent::OpenTryChannel(_) => State::TryOpen,
@@ -1012,6 +1161,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
         let proofs = self
             .src_chain()
             .build_channel_proofs(self.src_port_id(), src_channel_id, query_height)
+            .map(|proofs| self.adapt_proofs_for_dst(proofs))
             .map_err(ChannelError::channel_proof)?;
 
         // Build message(s) to update client on destination
@@ -1132,6 +1282,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
         let proofs = self
             .src_chain()
             .build_channel_proofs(self.src_port_id(), src_channel_id, query_height)
+            .map(|proofs| self.adapt_proofs_for_dst(proofs))
             .map_err(ChannelError::channel_proof)?;
 
         // Build message(s) to update client on destination
@@ -1240,6 +1391,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
         let proofs = self
             .src_chain()
             .build_channel_proofs(self.src_port_id(), src_channel_id, query_height)
+            .map(|proofs| self.adapt_proofs_for_dst(proofs))
             .map_err(ChannelError::channel_proof)?;
 
         // Build message(s) to update client on destination
@@ -1411,6 +1563,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
         let proofs = self
             .src_chain()
             .build_channel_proofs(self.src_port_id(), src_channel_id, query_height)
+            .map(|proofs| self.adapt_proofs_for_dst(proofs))
             .map_err(ChannelError::channel_proof)?;
 
         // Build message(s) to update client on destination
@@ -1465,6 +1618,497 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
         }
     }
 
+    fn query_src_channel_end(&self) -> Result<ChannelEnd, ChannelError> {
+        let src_channel_id = self
+            .src_channel_id()
+            .ok_or_else(ChannelError::missing_local_channel_id)?;
+
+        // Query at a specific height as the channel end cached for the latest height
+        // may not reflect the progress of the upgrade
+        let height = self
+            .src_chain()
+            .query_latest_height()
+            .map_err(|e| ChannelError::query(self.src_chain().id(), e))?;
+
+        let (channel_end, _) = self
+            .src_chain()
+            .query_channel(
+                QueryChannelRequest {
+                    port_id: self.src_port_id().clone(),
+                    channel_id: src_channel_id.clone(),
+                    height: QueryHeight::Specific(height),
+                },
+                IncludeProof::No,
+            )
+            .map_err(|e| ChannelError::query(self.src_chain().id(), e))?;
+
+        Ok(channel_end)
+    }
+
+    fn query_dst_channel_end(&self) -> Result<ChannelEnd, ChannelError> {
+        let dst_channel_id = self
+            .dst_channel_id()
+            .ok_or_else(ChannelError::missing_counterparty_channel_id)?;
+
+        let height = self
+            .dst_chain()
+            .query_latest_height()
+            .map_err(|e| ChannelError::query(self.dst_chain().id(), e))?;
+
+        let (channel_end, _) = self
+            .dst_chain()
+            .query_channel(
+                QueryChannelRequest {
+                    port_id: self.dst_port_id().clone(),
+                    channel_id: dst_channel_id.clone(),
+                    height: QueryHeight::Specific(height),
+                },
+                IncludeProof::No,
+            )
+            .map_err(|e| ChannelError::query(self.dst_chain().id(), e))?;
+
+        Ok(channel_end)
+    }
+
+    /// Whether the upgrade in progress on destination has timed out on source.
+    fn dst_upgrade_timed_out(&self) -> Result<bool, ChannelError> {
+        let dst_channel_id = self
+            .dst_channel_id()
+            .ok_or_else(ChannelError::missing_counterparty_channel_id)?;
+
+        let (upgrade, _) = self
+            .dst_chain()
+            .query_upgrade(
+                QueryUpgradeRequest {
+                    port_id: self.dst_port_id().clone(),
+                    channel_id: dst_channel_id.clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .map_err(|e| ChannelError::query(self.dst_chain().id(), e))?;
+
+        let status = self
+            .src_chain()
+            .query_application_status()
+            .map_err(|e| ChannelError::query(self.src_chain().id(), e))?;
+
+        Ok(upgrade.timeout.has_elapsed(status.height, status.timestamp))
+    }
+
+    /// Queries the channel end on source at the given height, along with its proof.
+    fn query_src_channel_with_proof(
+        &self,
+        height: Height,
+    ) -> Result<(ChannelEnd, CommitmentProofBytes), ChannelError> {
+        let src_channel_id = self
+            .src_channel_id()
+            .ok_or_else(ChannelError::missing_local_channel_id)?;
+
+        let (channel_end, maybe_proof) = self
+            .src_chain()
+            .query_channel(
+                QueryChannelRequest {
+                    port_id: self.src_port_id().clone(),
+                    channel_id: src_channel_id.clone(),
+                    height: QueryHeight::Specific(height),
+                },
+                IncludeProof::Yes,
+            )
+            .map_err(|e| ChannelError::query(self.src_chain().id(), e))?;
+
+        let proof = maybe_proof
+            .ok_or_else(|| ChannelError::channel_proof(RelayerError::queried_proof_not_found()))?;
+
+        let proof = CommitmentProofBytes::try_from(proof)
+            .map_err(|e| ChannelError::channel_proof(RelayerError::malformed_proof(e)))?;
+
+        Ok((channel_end, proof))
+    }
+
+    /// Queries the upgrade in progress on source at the given height, along with its proof.
+    fn query_src_upgrade_with_proof(
+        &self,
+        height: Height,
+    ) -> Result<(Upgrade, CommitmentProofBytes), ChannelError> {
+        let src_channel_id = self
+            .src_channel_id()
+            .ok_or_else(ChannelError::missing_local_channel_id)?;
+
+        let (upgrade, maybe_proof) = self
+            .src_chain()
+            .query_upgrade(
+                QueryUpgradeRequest {
+                    port_id: self.src_port_id().clone(),
+                    channel_id: src_channel_id.clone(),
+                    height: QueryHeight::Specific(height),
+                },
+                IncludeProof::Yes,
+            )
+            .map_err(|e| ChannelError::query(self.src_chain().id(), e))?;
+
+        let proof = maybe_proof
+            .ok_or_else(|| ChannelError::channel_proof(RelayerError::queried_proof_not_found()))?;
+
+        let proof = CommitmentProofBytes::try_from(proof)
+            .map_err(|e| ChannelError::channel_proof(RelayerError::malformed_proof(e)))?;
+
+        Ok((upgrade, proof))
+    }
+
+    /// Builds the proofs for an upgrade message from the proofs queried on source at
+    /// `query_height`, which are verified against the consensus state at the next height.
+    fn build_upgrade_proofs(
+        &self,
+        object_proof: CommitmentProofBytes,
+        other_proof: Option<CommitmentProofBytes>,
+        query_height: Height,
+    ) -> Result<Proofs, ChannelError> {
+        Proofs::new(
+            object_proof,
+            None,
+            None,
+            other_proof,
+            query_height.increment(),
+        )
+        .map(|proofs| self.adapt_proofs_for_dst(proofs))
+        .map_err(|e| ChannelError::channel_proof(RelayerError::malformed_proof(e)))
+    }
+
+    /// Adapts the proofs built on source to the client which verifies them on destination.
+    /// The localhost client reads the proven values from its own store, and only accepts
+    /// the sentinel proof in place of the actual proofs.
+    fn adapt_proofs_for_dst(&self, proofs: Proofs) -> Proofs {
+        if self.dst_connection_id().is_localhost() {
+            sentinel_proofs(&proofs)
+        } else {
+            proofs
+        }
+    }
+
+    fn query_src_latest_height(&self) -> Result<Height, ChannelError> {
+        self.src_chain()
+            .query_latest_height()
+            .map_err(|e| ChannelError::query(self.src_chain().id(), e))
+    }
+
+    fn dst_signer(&self) -> Result<ibc_relayer_types::signer::Signer, ChannelError> {
+        self.dst_chain()
+            .get_signer()
+            .map_err(|e| ChannelError::fetch_signer(self.dst_chain().id(), e))
+    }
+
+    /// Sends the given upgrade messages to destination and returns the expected event,
+    /// identified by `is_expected`, from the response.
+    fn send_chan_upgrade_msgs(
+        &self,
+        msgs: Vec<Any>,
+        msg_type: &'static str,
+        is_expected: fn(&IbcEvent) -> bool,
+    ) -> Result<IbcEvent, ChannelError> {
+        let tm = TrackedMsgs::new_static(msgs, msg_type);
+
+        let events = self
+            .dst_chain()
+            .send_messages_and_wait_commit(tm)
+            .map_err(|e| ChannelError::submit(self.dst_chain().id(), e))?;
+
+        let result = events
+            .into_iter()
+            .find(|event_with_height| {
+                is_expected(&event_with_height.event)
+                    || matches!(event_with_height.event, IbcEvent::ChainError(_))
+            })
+            .ok_or_else(|| {
+                ChannelError::missing_event(format!("no {msg_type} event was in the response"))
+            })?;
+
+        match &result.event {
+            IbcEvent::ChainError(e) => Err(ChannelError::tx_response(e.clone())),
+            event if is_expected(event) => {
+                info!("🆙 {} => {}", self.dst_chain().id(), result);
+                Ok(result.event)
+            }
+            _ => Err(ChannelError::invalid_event(result.event)),
+        }
+    }
+
+    /// Builds the message initiating an upgrade of the destination channel end.
+    /// On chains running ibc-go, the signer must be the chain's authority.
+    pub fn build_chan_upgrade_init(&self, fields: UpgradeFields) -> Result<Vec<Any>, ChannelError> {
+        let dst_channel_id = self
+            .dst_channel_id()
+            .ok_or_else(ChannelError::missing_counterparty_channel_id)?;
+
+        let new_msg = MsgChannelUpgradeInit::new(
+            self.dst_port_id().clone(),
+            dst_channel_id.clone(),
+            fields,
+            self.dst_signer()?,
+        );
+
+        Ok(vec![new_msg.to_any()])
+    }
+
+    pub fn build_chan_upgrade_init_and_send(
+        &self,
+        fields: UpgradeFields,
+    ) -> Result<IbcEvent, ChannelError> {
+        let dst_msgs = self.build_chan_upgrade_init(fields)?;
+
+        self.send_chan_upgrade_msgs(dst_msgs, "ChannelUpgradeInit", |event| {
+            matches!(event, IbcEvent::UpgradeInitChannel(_))
+        })
+        .map_err(|e| {
+            error!("failed ChanUpgradeInit {}: {}", self.b_side, e);
+            e
+        })
+    }
+
+    pub fn build_chan_upgrade_try(&self) -> Result<Vec<Any>, ChannelError> {
+        let dst_channel_id = self
+            .dst_channel_id()
+            .ok_or_else(ChannelError::missing_counterparty_channel_id)?;
+
+        let query_height = self.query_src_latest_height()?;
+        let (src_channel, channel_proof) = self.query_src_channel_with_proof(query_height)?;
+        let (src_upgrade, upgrade_proof) = self.query_src_upgrade_with_proof(query_height)?;
+        let proofs = self.build_upgrade_proofs(channel_proof, Some(upgrade_proof), query_height)?;
+
+        // Build message(s) to update client on destination
+        let mut msgs = self.build_update_client_on_dst(proofs.height())?;
+
+        let new_msg = MsgChannelUpgradeTry {
+            port_id: self.dst_port_id().clone(),
+            channel_id: dst_channel_id.clone(),
+            proposed_upgrade_connection_hops: vec![self.dst_connection_id().clone()],
+            counterparty_upgrade_fields: src_upgrade.fields,
+            counterparty_upgrade_sequence: src_channel.upgrade_sequence(),
+            proofs,
+            signer: self.dst_signer()?,
+        };
+
+        msgs.push(new_msg.to_any());
+        Ok(msgs)
+    }
+
+    pub fn build_chan_upgrade_try_and_send(&self) -> Result<IbcEvent, ChannelError> {
+        let dst_msgs = self.build_chan_upgrade_try()?;
+
+        self.send_chan_upgrade_msgs(dst_msgs, "ChannelUpgradeTry", |event| {
+            matches!(event, IbcEvent::UpgradeTryChannel(_))
+        })
+        .map_err(|e| {
+            error!("failed ChanUpgradeTry {}: {}", self.b_side, e);
+            e
+        })
+    }
+
+    pub fn build_chan_upgrade_ack(&self) -> Result<Vec<Any>, ChannelError> {
+        let dst_channel_id = self
+            .dst_channel_id()
+            .ok_or_else(ChannelError::missing_counterparty_channel_id)?;
+
+        let query_height = self.query_src_latest_height()?;
+        let (_, channel_proof) = self.query_src_channel_with_proof(query_height)?;
+        let (src_upgrade, upgrade_proof) = self.query_src_upgrade_with_proof(query_height)?;
+        let proofs = self.build_upgrade_proofs(channel_proof, Some(upgrade_proof), query_height)?;
+
+        // Build message(s) to update client on destination
+        let mut msgs = self.build_update_client_on_dst(proofs.height())?;
+
+        let new_msg = MsgChannelUpgradeAck {
+            port_id: self.dst_port_id().clone(),
+            channel_id: dst_channel_id.clone(),
+            counterparty_upgrade: src_upgrade,
+            proofs,
+            signer: self.dst_signer()?,
+        };
+
+        msgs.push(new_msg.to_any());
+        Ok(msgs)
+    }
+
+    pub fn build_chan_upgrade_ack_and_send(&self) -> Result<IbcEvent, ChannelError> {
+        let dst_msgs = self.build_chan_upgrade_ack()?;
+
+        self.send_chan_upgrade_msgs(dst_msgs, "ChannelUpgradeAck", |event| {
+            matches!(event, IbcEvent::UpgradeAckChannel(_))
+        })
+        .map_err(|e| {
+            error!("failed ChanUpgradeAck {}: {}", self.b_side, e);
+            e
+        })
+    }
+
+    pub fn build_chan_upgrade_confirm(&self) -> Result<Vec<Any>, ChannelError> {
+        let dst_channel_id = self
+            .dst_channel_id()
+            .ok_or_else(ChannelError::missing_counterparty_channel_id)?;
+
+        let query_height = self.query_src_latest_height()?;
+        let (src_channel, channel_proof) = self.query_src_channel_with_proof(query_height)?;
+        let (src_upgrade, upgrade_proof) = self.query_src_upgrade_with_proof(query_height)?;
+        let proofs = self.build_upgrade_proofs(channel_proof, Some(upgrade_proof), query_height)?;
+
+        // Build message(s) to update client on destination
+        let mut msgs = self.build_update_client_on_dst(proofs.height())?;
+
+        let new_msg = MsgChannelUpgradeConfirm {
+            port_id: self.dst_port_id().clone(),
+            channel_id: dst_channel_id.clone(),
+            counterparty_channel_state: *src_channel.state(),
+            counterparty_upgrade: src_upgrade,
+            proofs,
+            signer: self.dst_signer()?,
+        };
+
+        msgs.push(new_msg.to_any());
+        Ok(msgs)
+    }
+
+    pub fn build_chan_upgrade_confirm_and_send(&self) -> Result<IbcEvent, ChannelError> {
+        let dst_msgs = self.build_chan_upgrade_confirm()?;
+
+        self.send_chan_upgrade_msgs(dst_msgs, "ChannelUpgradeConfirm", |event| {
+            matches!(event, IbcEvent::UpgradeConfirmChannel(_))
+        })
+        .map_err(|e| {
+            error!("failed ChanUpgradeConfirm {}: {}", self.b_side, e);
+            e
+        })
+    }
+
+    pub fn build_chan_upgrade_open(&self) -> Result<Vec<Any>, ChannelError> {
+        let dst_channel_id = self
+            .dst_channel_id()
+            .ok_or_else(ChannelError::missing_counterparty_channel_id)?;
+
+        let query_height = self.query_src_latest_height()?;
+        let (src_channel, channel_proof) = self.query_src_channel_with_proof(query_height)?;
+        let proofs = self.build_upgrade_proofs(channel_proof, None, query_height)?;
+
+        // Build message(s) to update client on destination
+        let mut msgs = self.build_update_client_on_dst(proofs.height())?;
+
+        let new_msg = MsgChannelUpgradeOpen {
+            port_id: self.dst_port_id().clone(),
+            channel_id: dst_channel_id.clone(),
+            counterparty_channel_state: *src_channel.state(),
+            counterparty_upgrade_sequence: src_channel.upgrade_sequence(),
+            proofs,
+            signer: self.dst_signer()?,
+        };
+
+        msgs.push(new_msg.to_any());
+        Ok(msgs)
+    }
+
+    pub fn build_chan_upgrade_open_and_send(&self) -> Result<IbcEvent, ChannelError> {
+        let dst_msgs = self.build_chan_upgrade_open()?;
+
+        self.send_chan_upgrade_msgs(dst_msgs, "ChannelUpgradeOpen", |event| {
+            matches!(event, IbcEvent::UpgradeOpenChannel(_))
+        })
+        .map_err(|e| {
+            error!("failed ChanUpgradeOpen {}: {}", self.b_side, e);
+            e
+        })
+    }
+
+    pub fn build_chan_upgrade_timeout(&self) -> Result<Vec<Any>, ChannelError> {
+        let dst_channel_id = self
+            .dst_channel_id()
+            .ok_or_else(ChannelError::missing_counterparty_channel_id)?;
+
+        let query_height = self.query_src_latest_height()?;
+        let (src_channel, channel_proof) = self.query_src_channel_with_proof(query_height)?;
+        let proofs = self.build_upgrade_proofs(channel_proof, None, query_height)?;
+
+        // Build message(s) to update client on destination
+        let mut msgs = self.build_update_client_on_dst(proofs.height())?;
+
+        let new_msg = MsgChannelUpgradeTimeout {
+            port_id: self.dst_port_id().clone(),
+            channel_id: dst_channel_id.clone(),
+            counterparty_channel: src_channel,
+            proofs,
+            signer: self.dst_signer()?,
+        };
+
+        msgs.push(new_msg.to_any());
+        Ok(msgs)
+    }
+
+    pub fn build_chan_upgrade_timeout_and_send(&self) -> Result<IbcEvent, ChannelError> {
+        let dst_msgs = self.build_chan_upgrade_timeout()?;
+
+        self.send_chan_upgrade_msgs(dst_msgs, "ChannelUpgradeTimeout", |event| {
+            matches!(event, IbcEvent::UpgradeTimeoutChannel(_))
+        })
+        .map_err(|e| {
+            error!("failed ChanUpgradeTimeout {}: {}", self.b_side, e);
+            e
+        })
+    }
+
+    pub fn build_chan_upgrade_cancel(&self) -> Result<Vec<Any>, ChannelError> {
+        let src_channel_id = self
+            .src_channel_id()
+            .ok_or_else(ChannelError::missing_local_channel_id)?;
+        let dst_channel_id = self
+            .dst_channel_id()
+            .ok_or_else(ChannelError::missing_counterparty_channel_id)?;
+
+        let query_height = self.query_src_latest_height()?;
+
+        let (error_receipt, maybe_proof) = self
+            .src_chain()
+            .query_upgrade_error(
+                QueryUpgradeErrorRequest {
+                    port_id: self.src_port_id().clone(),
+                    channel_id: src_channel_id.clone(),
+                    height: QueryHeight::Specific(query_height),
+                },
+                IncludeProof::Yes,
+            )
+            .map_err(|e| ChannelError::query(self.src_chain().id(), e))?;
+
+        let error_receipt_proof = maybe_proof
+            .ok_or_else(|| ChannelError::channel_proof(RelayerError::queried_proof_not_found()))?;
+
+        let error_receipt_proof = CommitmentProofBytes::try_from(error_receipt_proof)
+            .map_err(|e| ChannelError::channel_proof(RelayerError::malformed_proof(e)))?;
+
+        let proofs = self.build_upgrade_proofs(error_receipt_proof, None, query_height)?;
+
+        // Build message(s) to update client on destination
+        let mut msgs = self.build_update_client_on_dst(proofs.height())?;
+
+        let new_msg = MsgChannelUpgradeCancel {
+            port_id: self.dst_port_id().clone(),
+            channel_id: dst_channel_id.clone(),
+            error_receipt,
+            proofs,
+            signer: self.dst_signer()?,
+        };
+
+        msgs.push(new_msg.to_any());
+        Ok(msgs)
+    }
+
+    pub fn build_chan_upgrade_cancel_and_send(&self) -> Result<IbcEvent, ChannelError> {
+        let dst_msgs = self.build_chan_upgrade_cancel()?;
+
+        self.send_chan_upgrade_msgs(dst_msgs, "ChannelUpgradeCancel", |event| {
+            matches!(event, IbcEvent::UpgradeCancelChannel(_))
+        })
+        .map_err(|e| {
+            error!("failed ChanUpgradeCancel {}: {}", self.b_side, e);
+            e
+        })
+    }
+
     pub fn map_chain<ChainC: ChainHandle, ChainD: ChainHandle>(
         self,
         mapper_a: impl Fn(ChainA) -> ChainC,
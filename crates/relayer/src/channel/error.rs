@@ -10,6 +10,7 @@ use ibc_relayer_types::core::ics24_host::identifier::{
 use ibc_relayer_types::events::IbcEvent;
 
 use crate::error::Error as RelayerError;
+use crate::error_kind::{ErrorKind, HasErrorKind};
 use crate::foreign_client::{ForeignClientError, HasExpiredOrFrozenError};
 use crate::supervisor::Error as SupervisorError;
 
@@ -209,3 +210,25 @@ impl HasExpiredOrFrozenError for ChannelError {
         self.detail().is_expired_or_frozen_error()
     }
 }
+
+impl HasErrorKind for ChannelErrorDetail {
+    fn error_kind(&self) -> ErrorKind {
+        match self {
+            Self::Relayer(e) => e.source.error_kind(),
+            Self::ChannelProof(e) => e.source.error_kind(),
+            Self::ClientOperation(e) => e.source.error_kind(),
+            Self::FetchSigner(e) => e.source.error_kind(),
+            Self::Query(e) => e.source.error_kind(),
+            Self::ChainQuery(e) => e.source.error_kind(),
+            Self::Submit(e) => e.source.error_kind(),
+            Self::MaxRetry(e) => e.source.error_kind(),
+            _ => ErrorKind::Other,
+        }
+    }
+}
+
+impl HasErrorKind for ChannelError {
+    fn error_kind(&self) -> ErrorKind {
+        self.detail().error_kind()
+    }
+}
@@ -8,10 +8,22 @@ use ibc_proto::protobuf::Protobuf;
 use serde::{Deserialize, Serialize};
 
 use ibc_proto::google::protobuf::Any;
+use ibc_relayer_types::clients::ics06_solomachine::client_state::{
+    ClientState as SmClientState, SOLOMACHINE_CLIENT_STATE_TYPE_URL,
+};
+use ibc_relayer_types::clients::ics06_solomachine::raw::ClientState as RawSmClientState;
 use ibc_relayer_types::clients::ics07_tendermint::client_state::{
     ClientState as TmClientState, UpgradeOptions as TmUpgradeOptions,
     TENDERMINT_CLIENT_STATE_TYPE_URL,
 };
+use ibc_relayer_types::clients::ics08_wasm::client_state::{
+    ClientState as WasmClientState, WASM_CLIENT_STATE_TYPE_URL,
+};
+use ibc_relayer_types::clients::ics08_wasm::raw::ClientState as RawWasmClientState;
+use ibc_relayer_types::clients::ics09_localhost::client_state::{
+    ClientState as LocalhostClientState, LOCALHOST_CLIENT_STATE_TYPE_URL,
+};
+use ibc_relayer_types::clients::ics09_localhost::raw::ClientState as RawLocalhostClientState;
 use ibc_relayer_types::core::ics02_client::client_state::{
     downcast_client_state, ClientState, UpgradeOptions,
 };
@@ -52,6 +64,9 @@ impl UpgradeOptions for AnyUpgradeOptions {}
 #[serde(tag = "type")]
 pub enum AnyClientState {
     Tendermint(TmClientState),
+    Solomachine(SmClientState),
+    Wasm(WasmClientState),
+    Localhost(LocalhostClientState),
 
     #[cfg(test)]
     Mock(MockClientState),
@@ -61,6 +76,9 @@ impl AnyClientState {
     pub fn latest_height(&self) -> Height {
         match self {
             Self::Tendermint(tm_state) => tm_state.latest_height(),
+            Self::Solomachine(sm_state) => sm_state.latest_height(),
+            Self::Wasm(wasm_state) => wasm_state.latest_height(),
+            Self::Localhost(lh_state) => lh_state.latest_height(),
 
             #[cfg(test)]
             Self::Mock(mock_state) => mock_state.latest_height(),
@@ -70,6 +88,9 @@ impl AnyClientState {
     pub fn frozen_height(&self) -> Option<Height> {
         match self {
             Self::Tendermint(tm_state) => tm_state.frozen_height(),
+            Self::Solomachine(sm_state) => sm_state.frozen_height(),
+            Self::Wasm(wasm_state) => wasm_state.frozen_height(),
+            Self::Localhost(lh_state) => lh_state.frozen_height(),
 
             #[cfg(test)]
             Self::Mock(mock_state) => mock_state.frozen_height(),
@@ -79,6 +100,9 @@ impl AnyClientState {
     pub fn trust_threshold(&self) -> Option<TrustThreshold> {
         match self {
             AnyClientState::Tendermint(state) => Some(state.trust_threshold),
+            AnyClientState::Solomachine(_) => None,
+            AnyClientState::Wasm(state) => Some(state.inner.trust_threshold),
+            AnyClientState::Localhost(_) => None,
 
             #[cfg(test)]
             AnyClientState::Mock(_) => None,
@@ -88,6 +112,9 @@ impl AnyClientState {
     pub fn max_clock_drift(&self) -> Duration {
         match self {
             AnyClientState::Tendermint(state) => state.max_clock_drift,
+            AnyClientState::Solomachine(_) => Duration::new(0, 0),
+            AnyClientState::Wasm(state) => state.inner.max_clock_drift,
+            AnyClientState::Localhost(_) => Duration::new(0, 0),
 
             #[cfg(test)]
             AnyClientState::Mock(_) => Duration::new(0, 0),
@@ -97,6 +124,9 @@ impl AnyClientState {
     pub fn client_type(&self) -> ClientType {
         match self {
             Self::Tendermint(state) => state.client_type(),
+            Self::Solomachine(state) => state.client_type(),
+            Self::Wasm(state) => state.client_type(),
+            Self::Localhost(state) => state.client_type(),
 
             #[cfg(test)]
             Self::Mock(state) => state.client_type(),
@@ -106,11 +136,63 @@ impl AnyClientState {
     pub fn refresh_period(&self) -> Option<Duration> {
         match self {
             AnyClientState::Tendermint(tm_state) => tm_state.refresh_time(),
+            AnyClientState::Solomachine(_) => None,
+            AnyClientState::Wasm(state) => state.inner.refresh_time(),
+            AnyClientState::Localhost(_) => None,
 
             #[cfg(test)]
             AnyClientState::Mock(mock_state) => mock_state.refresh_time(),
         }
     }
+
+    pub fn trusting_period(&self) -> Option<Duration> {
+        match self {
+            AnyClientState::Tendermint(tm_state) => Some(tm_state.trusting_period),
+            AnyClientState::Solomachine(_) => None,
+            AnyClientState::Wasm(state) => Some(state.inner.trusting_period),
+            AnyClientState::Localhost(_) => None,
+
+            #[cfg(test)]
+            AnyClientState::Mock(_) => None,
+        }
+    }
+
+    /// Wraps a Tendermint client state into the client state of an 08-wasm client,
+    /// whose light client contract has the given checksum.
+    pub fn wrap_wasm(self, checksum: Vec<u8>) -> Result<Self, Error> {
+        match self {
+            AnyClientState::Tendermint(tm_state) => Ok(AnyClientState::Wasm(WasmClientState::new(
+                checksum, tm_state,
+            )?)),
+            _ => Err(Error::client_specific(format!(
+                "cannot wrap a client of type {} in an 08-wasm client",
+                self.client_type()
+            ))),
+        }
+    }
+
+    /// The client state as seen by the light client of its reference chain,
+    /// ie. the wrapped client state of an 08-wasm client, or the client state itself.
+    pub fn unwrap_wasm(&self) -> AnyClientState {
+        match self {
+            AnyClientState::Wasm(wasm_state) => {
+                AnyClientState::Tendermint(wasm_state.inner.clone())
+            }
+            _ => self.clone(),
+        }
+    }
+
+    /// Records the chain hosting a localhost client, which is also the chain it verifies,
+    /// since the client state stored on chain does not include it.
+    /// Client states of any other type are returned unchanged.
+    pub fn with_localhost_chain_id(self, chain_id: &ChainId) -> Self {
+        match self {
+            AnyClientState::Localhost(lh_state) => {
+                AnyClientState::Localhost(lh_state.with_chain_id(chain_id.clone()))
+            }
+            _ => self,
+        }
+    }
 }
 
 impl Protobuf<Any> for AnyClientState {}
@@ -127,6 +209,21 @@ impl TryFrom<Any> for AnyClientState {
                     .map_err(Error::decode_raw_client_state)?,
             )),
 
+            SOLOMACHINE_CLIENT_STATE_TYPE_URL => Ok(AnyClientState::Solomachine(
+                Protobuf::<RawSmClientState>::decode_vec(&raw.value)
+                    .map_err(Error::decode_raw_client_state)?,
+            )),
+
+            WASM_CLIENT_STATE_TYPE_URL => Ok(AnyClientState::Wasm(
+                Protobuf::<RawWasmClientState>::decode_vec(&raw.value)
+                    .map_err(Error::decode_raw_client_state)?,
+            )),
+
+            LOCALHOST_CLIENT_STATE_TYPE_URL => Ok(AnyClientState::Localhost(
+                Protobuf::<RawLocalhostClientState>::decode_vec(&raw.value)
+                    .map_err(Error::decode_raw_client_state)?,
+            )),
+
             #[cfg(test)]
             MOCK_CLIENT_STATE_TYPE_URL => Ok(AnyClientState::Mock(
                 Protobuf::<RawMockClientState>::decode_vec(&raw.value)
@@ -145,6 +242,18 @@ impl From<AnyClientState> for Any {
                 type_url: TENDERMINT_CLIENT_STATE_TYPE_URL.to_string(),
                 value: Protobuf::<RawClientState>::encode_vec(&value),
             },
+            AnyClientState::Solomachine(value) => Any {
+                type_url: SOLOMACHINE_CLIENT_STATE_TYPE_URL.to_string(),
+                value: Protobuf::<RawSmClientState>::encode_vec(&value),
+            },
+            AnyClientState::Wasm(value) => Any {
+                type_url: WASM_CLIENT_STATE_TYPE_URL.to_string(),
+                value: Protobuf::<RawWasmClientState>::encode_vec(&value),
+            },
+            AnyClientState::Localhost(value) => Any {
+                type_url: LOCALHOST_CLIENT_STATE_TYPE_URL.to_string(),
+                value: Protobuf::<RawLocalhostClientState>::encode_vec(&value),
+            },
             #[cfg(test)]
             AnyClientState::Mock(value) => Any {
                 type_url: MOCK_CLIENT_STATE_TYPE_URL.to_string(),
@@ -158,6 +267,9 @@ impl ClientState for AnyClientState {
     fn chain_id(&self) -> ChainId {
         match self {
             AnyClientState::Tendermint(tm_state) => tm_state.chain_id(),
+            AnyClientState::Solomachine(sm_state) => sm_state.chain_id(),
+            AnyClientState::Wasm(wasm_state) => wasm_state.chain_id(),
+            AnyClientState::Localhost(lh_state) => lh_state.chain_id(),
 
             #[cfg(test)]
             AnyClientState::Mock(mock_state) => mock_state.chain_id(),
@@ -194,6 +306,20 @@ impl ClientState for AnyClientState {
                 chain_id,
             ),
 
+            AnyClientState::Solomachine(sm_state) => {
+                sm_state.upgrade(upgrade_height, upgrade_options, chain_id)
+            }
+
+            AnyClientState::Wasm(wasm_state) => wasm_state.upgrade(
+                upgrade_height,
+                upgrade_options.as_tm_upgrade_options().unwrap(),
+                chain_id,
+            ),
+
+            AnyClientState::Localhost(lh_state) => {
+                lh_state.upgrade(upgrade_height, upgrade_options, chain_id)
+            }
+
             #[cfg(test)]
             AnyClientState::Mock(mock_state) => {
                 mock_state.upgrade(upgrade_height, upgrade_options, chain_id)
@@ -204,6 +330,9 @@ impl ClientState for AnyClientState {
     fn expired(&self, elapsed_since_latest: Duration) -> bool {
         match self {
             AnyClientState::Tendermint(tm_state) => tm_state.expired(elapsed_since_latest),
+            AnyClientState::Solomachine(sm_state) => sm_state.expired(elapsed_since_latest),
+            AnyClientState::Wasm(wasm_state) => wasm_state.expired(elapsed_since_latest),
+            AnyClientState::Localhost(lh_state) => lh_state.expired(elapsed_since_latest),
 
             #[cfg(test)]
             AnyClientState::Mock(mock_state) => mock_state.expired(elapsed_since_latest),
@@ -217,6 +346,24 @@ impl From<TmClientState> for AnyClientState {
     }
 }
 
+impl From<SmClientState> for AnyClientState {
+    fn from(cs: SmClientState) -> Self {
+        Self::Solomachine(cs)
+    }
+}
+
+impl From<WasmClientState> for AnyClientState {
+    fn from(cs: WasmClientState) -> Self {
+        Self::Wasm(cs)
+    }
+}
+
+impl From<LocalhostClientState> for AnyClientState {
+    fn from(cs: LocalhostClientState) -> Self {
+        Self::Localhost(cs)
+    }
+}
+
 #[cfg(test)]
 impl From<MockClientState> for AnyClientState {
     fn from(cs: MockClientState) -> Self {
@@ -231,6 +378,18 @@ impl From<&dyn ClientState> for AnyClientState {
             return AnyClientState::from(*cs);
         }
 
+        if let Some(cs) = downcast_client_state::<SmClientState>(client_state) {
+            return AnyClientState::from(cs.clone());
+        }
+
+        if let Some(cs) = downcast_client_state::<WasmClientState>(client_state) {
+            return AnyClientState::from(cs.clone());
+        }
+
+        if let Some(cs) = downcast_client_state::<LocalhostClientState>(client_state) {
+            return AnyClientState::from(cs.clone());
+        }
+
         if let Some(cs) = downcast_client_state::<TmClientState>(client_state) {
             AnyClientState::from(cs.clone())
         } else {
@@ -287,6 +446,7 @@ mod tests {
     use ibc_proto::google::protobuf::Any;
     use ibc_relayer_types::clients::ics07_tendermint::client_state::test_util::get_dummy_tendermint_client_state;
     use ibc_relayer_types::clients::ics07_tendermint::header::test_util::get_dummy_tendermint_header;
+    use ibc_relayer_types::core::ics02_client::client_type::ClientType;
     use test_log::test;
 
     use super::AnyClientState;
@@ -300,4 +460,23 @@ mod tests {
         let tm_client_state_back = AnyClientState::try_from(raw).unwrap();
         assert_eq!(tm_client_state, tm_client_state_back);
     }
+
+    #[test]
+    fn wasm_client_state_wraps_tendermint() {
+        let tm_client_state: AnyClientState =
+            get_dummy_tendermint_client_state(get_dummy_tendermint_header()).into();
+
+        let wasm_client_state = tm_client_state.clone().wrap_wasm(vec![1; 32]).unwrap();
+        assert_eq!(wasm_client_state.client_type(), ClientType::Wasm);
+        assert_eq!(
+            wasm_client_state.latest_height(),
+            tm_client_state.latest_height()
+        );
+        assert_eq!(wasm_client_state.unwrap_wasm(), tm_client_state);
+
+        let raw: Any = wasm_client_state.clone().into();
+        assert_eq!(AnyClientState::try_from(raw).unwrap(), wasm_client_state);
+
+        assert!(wasm_client_state.wrap_wasm(vec![1; 32]).is_err());
+    }
 }
@@ -1,9 +1,13 @@
 //! Relayer configuration
 
+pub mod client_overrides;
+pub mod dynamic_gas;
 pub mod error;
+pub mod excluded_sequences;
 pub mod filter;
 pub mod gas_multiplier;
 pub mod proof_specs;
+pub mod refresh_rate;
 pub mod types;
 
 use alloc::collections::BTreeMap;
@@ -31,8 +35,12 @@ use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId
 use ibc_relayer_types::timestamp::ZERO_DURATION;
 
 use crate::chain::ChainType;
+use crate::config::client_overrides::ClientOverrides;
+use crate::config::dynamic_gas::DynamicGasPrice;
+use crate::config::excluded_sequences::ExcludedSequences;
 use crate::config::gas_multiplier::GasMultiplier;
-use crate::config::types::{MaxMsgNum, MaxTxSize, Memo};
+use crate::config::refresh_rate::RefreshRate;
+use crate::config::types::{Ics20FieldSizeLimit, MaxMsgNum, MaxTxSize, Memo};
 use crate::error::Error as RelayerError;
 use crate::extension_options::ExtensionOptionDynamicFeeTx;
 use crate::keyring::Store;
@@ -177,6 +185,10 @@ pub mod default {
         Duration::from_millis(500)
     }
 
+    pub fn poll_interval() -> Duration {
+        Duration::from_secs(1)
+    }
+
     pub fn clock_drift() -> Duration {
         Duration::from_secs(5)
     }
@@ -185,6 +197,10 @@ pub mod default {
         Duration::from_secs(30)
     }
 
+    pub fn remote_signer_timeout() -> Duration {
+        Duration::from_secs(5)
+    }
+
     pub fn trusted_node() -> bool {
         false
     }
@@ -200,6 +216,14 @@ pub mod default {
     pub fn max_grpc_decoding_size() -> Byte {
         Byte::from_bytes(33554432)
     }
+
+    pub fn ics20_max_memo_size() -> Ics20FieldSizeLimit {
+        Ics20FieldSizeLimit::new(true, 32768)
+    }
+
+    pub fn ics20_max_receiver_size() -> Ics20FieldSizeLimit {
+        Ics20FieldSizeLimit::new(true, 2048)
+    }
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
@@ -213,6 +237,10 @@ pub struct Config {
     pub rest: RestConfig,
     #[serde(default)]
     pub telemetry: TelemetryConfig,
+    #[serde(default)]
+    pub tracing: TracingConfig,
+    #[serde(default)]
+    pub state_store: StateStoreConfig,
     #[serde(default = "Vec::new", skip_serializing_if = "Vec::is_empty")]
     pub chains: Vec<ChainConfig>,
 }
@@ -253,7 +281,7 @@ impl Config {
     }
 }
 
-#[derive(Copy, Clone, Debug, Deserialize, Serialize)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct ModeConfig {
     pub clients: Clients,
@@ -279,6 +307,8 @@ impl Default for ModeConfig {
                 enabled: true,
                 refresh: true,
                 misbehaviour: true,
+                refresh_rate: RefreshRate::default(),
+                refresh_horizon: None,
             },
             connections: Connections { enabled: false },
             channels: Channels { enabled: false },
@@ -290,7 +320,7 @@ impl Default for ModeConfig {
     }
 }
 
-#[derive(Copy, Clone, Debug, Default, Deserialize, Serialize)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct Clients {
     pub enabled: bool,
@@ -298,21 +328,32 @@ pub struct Clients {
     pub refresh: bool,
     #[serde(default)]
     pub misbehaviour: bool,
+    /// The fraction of the trusting period of a client after which it is refreshed.
+    #[serde(default)]
+    pub refresh_rate: RefreshRate,
+    /// Refresh clients which would otherwise expire within this duration,
+    /// even if their refresh rate is not reached yet.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "humantime_serde"
+    )]
+    pub refresh_horizon: Option<Duration>,
 }
 
-#[derive(Copy, Clone, Debug, Default, Deserialize, Serialize)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct Connections {
     pub enabled: bool,
 }
 
-#[derive(Copy, Clone, Debug, Default, Deserialize, Serialize)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct Channels {
     pub enabled: bool,
 }
 
-#[derive(Copy, Clone, Debug, Deserialize, Serialize)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct Packets {
     pub enabled: bool,
@@ -324,6 +365,10 @@ pub struct Packets {
     pub tx_confirmation: bool,
     #[serde(default = "default::auto_register_counterparty_payee")]
     pub auto_register_counterparty_payee: bool,
+    /// Back off from relaying on a channel where the packet messages
+    /// submitted by Hermes keep being relayed by another relayer first.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub redundant_backoff: Option<RedundantBackoff>,
 }
 
 impl Default for Packets {
@@ -334,10 +379,24 @@ impl Default for Packets {
             clear_on_start: default::clear_on_start(),
             tx_confirmation: default::tx_confirmation(),
             auto_register_counterparty_payee: default::auto_register_counterparty_payee(),
+            redundant_backoff: None,
         }
     }
 }
 
+/// How to back off from relaying on a channel where the transactions
+/// submitted by Hermes only carry packet messages which another relayer
+/// already relayed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RedundantBackoff {
+    /// The number of consecutive redundant transactions after which to back off.
+    pub after: u64,
+    /// For how long to hold back the packet messages of the channel.
+    #[serde(with = "humantime_serde")]
+    pub delay: Duration,
+}
+
 /// Log levels are wrappers over [`tracing_core::Level`].
 ///
 /// [`tracing_core::Level`]: https://docs.rs/tracing-core/0.1.17/tracing_core/struct.Level.html
@@ -381,6 +440,23 @@ pub struct TelemetryConfig {
     pub enabled: bool,
     pub host: String,
     pub port: u16,
+    #[serde(default)]
+    pub buckets: TelemetryBuckets,
+}
+
+/// Upper bounds of the buckets of the telemetry histograms, in milliseconds.
+/// The histograms which are not configured keep the default buckets of the telemetry.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TelemetryBuckets {
+    /// Time between the block of the `SendPacket` event of a packet on the source chain
+    /// and the block of its `WriteAcknowledgement` event on the destination chain.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub packet_recv_latency: Option<Vec<u64>>,
+    /// Time between the block of the `WriteAcknowledgement` event of a packet on the
+    /// destination chain and the block of its `AcknowledgePacket` event on the source chain.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub packet_ack_latency: Option<Vec<u64>>,
 }
 
 /// Default values for the telemetry configuration.
@@ -392,6 +468,35 @@ impl Default for TelemetryConfig {
             enabled: false,
             host: "127.0.0.1".to_string(),
             port: 3001,
+            buckets: TelemetryBuckets::default(),
+        }
+    }
+}
+
+/// Configuration of the export of the `tracing` spans of the relayer
+/// to an OpenTelemetry collector, over OTLP/HTTP.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TracingConfig {
+    pub enabled: bool,
+    /// URL of the collector endpoint receiving the spans.
+    pub endpoint: String,
+    /// Name of the service reported with the spans.
+    pub service_name: String,
+    /// Fraction of the traces to export, between 0 and 1.
+    pub sampling_ratio: f64,
+}
+
+/// Default values for the tracing configuration.
+///
+/// # IMPORTANT: Remember to update the Hermes guide & the default config.toml whenever these values change.
+impl Default for TracingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            endpoint: "http://127.0.0.1:4318/v1/traces".to_string(),
+            service_name: "hermes".to_string(),
+            sampling_ratio: 1.0,
         }
     }
 }
@@ -402,6 +507,10 @@ pub struct RestConfig {
     pub enabled: bool,
     pub host: String,
     pub port: u16,
+    /// Bearer token which must be supplied by the requests to the endpoints
+    /// performing actions on behalf of the relayer, if set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
 }
 
 impl Default for RestConfig {
@@ -410,10 +519,22 @@ impl Default for RestConfig {
             enabled: false,
             host: "127.0.0.1".to_string(),
             port: 3000,
+            token: None,
         }
     }
 }
 
+/// Configuration of the on-disk store, which records the state of
+/// the packet workers so that they can resume relaying after a restart.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct StateStoreConfig {
+    pub enabled: bool,
+    /// Directory holding the store. Defaults to `$HOME/.hermes/state`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<PathBuf>,
+}
+
 /// It defines the address generation method
 /// TODO: Ethermint `pk_type` to be restricted
 /// after the Cosmos SDK release with ethsecp256k1
@@ -434,6 +555,20 @@ pub enum AddressType {
     },
 }
 
+/// How the relayer gets notified of the IBC events emitted by a chain.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(tag = "mode", rename_all = "lowercase", deny_unknown_fields)]
+pub enum EventSourceMode {
+    /// Subscribe to the events pushed by the node over the `websocket_addr` endpoint.
+    #[default]
+    Push,
+    /// Poll the `/block_results` RPC endpoint at `rpc_addr` for the events of every new block.
+    Pull {
+        #[serde(default = "default::poll_interval", with = "humantime_serde")]
+        interval: Duration,
+    },
+}
+
 impl Display for AddressType {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
         match self {
@@ -450,6 +585,23 @@ pub struct GenesisRestart {
     pub archive_addr: Url,
 }
 
+/// Configuration of an external signing service, to which signing
+/// of the transactions submitted to a chain is delegated.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RemoteSignerConfig {
+    /// Address of the signing service.
+    pub url: Url,
+
+    /// Identifier of the key to sign with, as known to the signing service.
+    /// Defaults to the `key_name` of the chain.
+    pub key_id: Option<String>,
+
+    /// Timeout for the requests to the signing service.
+    #[serde(default = "default::remote_signer_timeout", with = "humantime_serde")]
+    pub timeout: Duration,
+}
+
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct ChainConfig {
@@ -459,14 +611,30 @@ pub struct ChainConfig {
     pub rpc_addr: Url,
     pub websocket_addr: WebSocketClientUrl,
     pub grpc_addr: Url,
+    /// Additional RPC endpoints to fall back on when `rpc_addr` is unhealthy.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub backup_rpc_addrs: Vec<Url>,
+    /// Additional WebSocket endpoints to reconnect to when `websocket_addr` is unavailable.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub backup_websocket_addrs: Vec<WebSocketClientUrl>,
+    /// Additional gRPC endpoints to fall back on when `grpc_addr` is unhealthy.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub backup_grpc_addrs: Vec<Url>,
     #[serde(default = "default::rpc_timeout", with = "humantime_serde")]
     pub rpc_timeout: Duration,
     #[serde(default = "default::batch_delay", with = "humantime_serde")]
     pub batch_delay: Duration,
+    #[serde(default)]
+    pub event_source: EventSourceMode,
     #[serde(default = "default::trusted_node")]
     pub trusted_node: bool,
     pub account_prefix: String,
     pub key_name: String,
+    /// Additional keys from the key store with which to sign transactions.
+    /// When set, batches of IBC messages are distributed across `key_name` and
+    /// these keys in a round-robin fashion, each with its own account sequence.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub additional_key_names: Vec<String>,
     #[serde(default)]
     pub key_store_type: Store,
     pub key_store_folder: Option<PathBuf>,
@@ -540,13 +708,65 @@ pub struct ChainConfig {
 
     pub gas_price: GasPrice,
 
+    /// Query the gas price from the fee market module of the chain,
+    /// falling back on `gas_price` if the query fails.
+    #[serde(default)]
+    pub dynamic_gas_price: DynamicGasPrice,
+
     #[serde(default)]
     pub packet_filter: PacketFilter,
 
+    /// Sequences of the packets which must be skipped when clearing packets, per channel.
+    #[serde(default, skip_serializing_if = "ExcludedSequences::is_empty")]
+    pub excluded_sequences: ExcludedSequences,
+
+    /// Maximum size in bytes of the memo of ICS-20 packets relayed from this chain.
+    #[serde(default = "default::ics20_max_memo_size")]
+    pub ics20_max_memo_size: Ics20FieldSizeLimit,
+
+    /// Maximum size in bytes of the receiver of ICS-20 packets relayed from this chain.
+    #[serde(default = "default::ics20_max_receiver_size")]
+    pub ics20_max_receiver_size: Ics20FieldSizeLimit,
+
+    /// Parameters of the clients hosted by this chain, overridden per counterparty chain.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub client_overrides: BTreeMap<ChainId, ClientOverrides>,
+
     #[serde(default)]
     pub address_type: AddressType,
     #[serde(default = "Vec::new", skip_serializing_if = "Vec::is_empty")]
     pub extension_options: Vec<ExtensionOption>,
+
+    /// Sign transactions with an external signing service rather than
+    /// with the key `key_name` from the key store.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote_signer: Option<RemoteSignerConfig>,
+}
+
+impl ChainConfig {
+    /// All the RPC endpoints of the chain, starting with `rpc_addr`.
+    pub fn rpc_addrs(&self) -> impl Iterator<Item = &Url> {
+        core::iter::once(&self.rpc_addr).chain(&self.backup_rpc_addrs)
+    }
+
+    /// All the WebSocket endpoints of the chain, starting with `websocket_addr`.
+    pub fn websocket_addrs(&self) -> impl Iterator<Item = &WebSocketClientUrl> {
+        core::iter::once(&self.websocket_addr).chain(&self.backup_websocket_addrs)
+    }
+
+    /// All the gRPC endpoints of the chain, starting with `grpc_addr`.
+    pub fn grpc_addrs(&self) -> impl Iterator<Item = &Url> {
+        core::iter::once(&self.grpc_addr).chain(&self.backup_grpc_addrs)
+    }
+
+    /// The parameters overridden for the clients hosted by this chain
+    /// which track the given counterparty chain, if any.
+    pub fn client_overrides_for(&self, counterparty_chain_id: &ChainId) -> ClientOverrides {
+        self.client_overrides
+            .get(counterparty_chain_id)
+            .copied()
+            .unwrap_or_default()
+    }
 }
 
 /// Attempt to load and parse the TOML config file as a `Config`.
@@ -582,8 +802,11 @@ pub(crate) fn store_writer(config: &Config, mut writer: impl Write) -> Result<()
 #[cfg(test)]
 mod tests {
     use core::str::FromStr;
+    use core::time::Duration;
 
-    use super::{load, parse_gas_prices, store_writer};
+    use super::{
+        load, parse_gas_prices, store_writer, EventSourceMode, TelemetryBuckets, TelemetryConfig,
+    };
     use crate::config::GasPrice;
     use test_log::test;
 
@@ -599,6 +822,24 @@ mod tests {
         dbg!(config);
     }
 
+    #[test]
+    fn parse_event_source() {
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/config/fixtures/relayer_conf_example.toml"
+        );
+
+        let config = load(path).expect("could not parse config");
+
+        assert_eq!(config.chains[0].event_source, EventSourceMode::Push);
+        assert_eq!(
+            config.chains[1].event_source,
+            EventSourceMode::Pull {
+                interval: Duration::from_secs(2)
+            }
+        );
+    }
+
     #[test]
     fn parse_valid_fee_filter_config() {
         let path = concat!(
@@ -636,6 +877,30 @@ mod tests {
         store_writer(&config, &mut buffer).unwrap();
     }
 
+    #[test]
+    fn parse_telemetry_buckets() {
+        let config: TelemetryConfig = toml::from_str(
+            r#"
+            enabled = true
+            host = '127.0.0.1'
+            port = 3001
+
+            [buckets]
+            packet_recv_latency = [500, 1000, 2000]
+            "#,
+        )
+        .expect("could not parse telemetry config");
+
+        assert_eq!(
+            config.buckets.packet_recv_latency,
+            Some(vec![500, 1000, 2000])
+        );
+        assert_eq!(
+            config.buckets.packet_ack_latency,
+            TelemetryBuckets::default().packet_ack_latency
+        );
+    }
+
     #[test]
     fn gas_price_from_str() {
         let gp_original = GasPrice::new(10.0, "atom".to_owned());
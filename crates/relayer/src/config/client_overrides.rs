@@ -0,0 +1,100 @@
+//! Client parameters overridden per counterparty chain.
+
+use core::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use tendermint_light_client::verifier::types::TrustThreshold;
+
+/// Parameters of the clients hosted by a chain which track a specific
+/// counterparty chain, overriding the ones otherwise derived from the
+/// configuration of both chains.
+///
+/// ```toml
+/// [chains.client_overrides.'osmosis-1']
+/// trust_threshold = { numerator = '3', denominator = '4' }
+/// trusting_period = '5days'
+/// clock_drift = '10s'
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ClientOverrides {
+    /// The trust threshold of the clients, instead of the
+    /// `trust_threshold` of the counterparty chain.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trust_threshold: Option<TrustThreshold>,
+
+    /// The trusting period of the clients, instead of the
+    /// `trusting_period` of the counterparty chain.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "humantime_serde"
+    )]
+    pub trusting_period: Option<Duration>,
+
+    /// The maximum clock drift of the clients, instead of the one computed
+    /// from the `clock_drift` and `max_block_time` of both chains.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "humantime_serde"
+    )]
+    pub clock_drift: Option<Duration>,
+}
+
+impl ClientOverrides {
+    pub fn is_empty(&self) -> bool {
+        self.trust_threshold.is_none()
+            && self.trusting_period.is_none()
+            && self.clock_drift.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::collections::BTreeMap;
+
+    use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+    #[test]
+    fn deserialize_client_overrides() {
+        let toml_content = r#"
+            [client_overrides.'osmosis-1']
+            trust_threshold = { numerator = '3', denominator = '4' }
+            trusting_period = '5days'
+
+            [client_overrides.'juno-1']
+            clock_drift = '10s'
+            "#;
+
+        #[derive(Debug, Deserialize)]
+        struct DummyConfig {
+            client_overrides: BTreeMap<ChainId, ClientOverrides>,
+        }
+
+        let config: DummyConfig = toml::from_str(toml_content).unwrap();
+
+        let osmosis = &config.client_overrides[&ChainId::from_string("osmosis-1")];
+        assert_eq!(
+            osmosis.trust_threshold,
+            Some(TrustThreshold::new(3, 4).unwrap())
+        );
+        assert_eq!(
+            osmosis.trusting_period,
+            Some(Duration::from_secs(5 * 24 * 3600))
+        );
+        assert_eq!(osmosis.clock_drift, None);
+
+        let juno = &config.client_overrides[&ChainId::from_string("juno-1")];
+        assert_eq!(juno.clock_drift, Some(Duration::from_secs(10)));
+        assert!(juno.trust_threshold.is_none());
+    }
+
+    #[test]
+    fn reject_unknown_client_override() {
+        let err = toml::from_str::<ClientOverrides>("max_block_time = '10s'").unwrap_err();
+        assert!(err.to_string().contains("unknown field"));
+    }
+}
@@ -0,0 +1,163 @@
+use serde::de::Unexpected;
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize};
+
+flex_error::define_error! {
+    Error {
+        MultiplierTooSmall
+            { value: f64 }
+            |e| {
+                format_args!("`dynamic_gas_price.multiplier` must be greater than or equal to {}, found {}",
+                    DynamicGasPrice::MIN_MULTIPLIER, e.value)
+            },
+
+        InvalidMax
+            { value: f64 }
+            |e| {
+                format_args!("`dynamic_gas_price.max` must be a positive number, found {}", e.value)
+            },
+    }
+}
+
+/// Configuration for querying the gas price from the fee market module of
+/// the chain (Osmosis EIP-1559 `txfees` or Skip `feemarket`), instead of
+/// always paying the static `gas_price`.
+///
+/// The queried base fee is multiplied by `multiplier` and capped at `max`.
+/// If the query fails, the static `gas_price` is used instead.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct DynamicGasPrice {
+    pub enabled: bool,
+    pub multiplier: f64,
+    pub max: f64,
+}
+
+impl DynamicGasPrice {
+    const DEFAULT_MULTIPLIER: f64 = 1.1;
+    const DEFAULT_MAX: f64 = 0.6;
+    const MIN_MULTIPLIER: f64 = 1.0;
+
+    pub fn new(enabled: bool, multiplier: f64, max: f64) -> Result<Self, Error> {
+        if multiplier < Self::MIN_MULTIPLIER {
+            return Err(Error::multiplier_too_small(multiplier));
+        }
+
+        if !(max.is_finite() && max > 0.0) {
+            return Err(Error::invalid_max(max));
+        }
+
+        Ok(Self {
+            enabled,
+            multiplier,
+            max,
+        })
+    }
+
+    pub fn disabled() -> Self {
+        Self {
+            enabled: false,
+            multiplier: Self::DEFAULT_MULTIPLIER,
+            max: Self::DEFAULT_MAX,
+        }
+    }
+
+    /// The gas price to pay given the base fee queried from the chain.
+    pub fn apply(&self, base_fee: f64) -> f64 {
+        (base_fee * self.multiplier).min(self.max)
+    }
+}
+
+impl Default for DynamicGasPrice {
+    fn default() -> Self {
+        Self::disabled()
+    }
+}
+
+impl<'de> Deserialize<'de> for DynamicGasPrice {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(deny_unknown_fields)]
+        struct DynGas {
+            #[serde(default)]
+            enabled: bool,
+            #[serde(default = "default_multiplier")]
+            multiplier: f64,
+            #[serde(default = "default_max")]
+            max: f64,
+        }
+
+        fn default_multiplier() -> f64 {
+            DynamicGasPrice::DEFAULT_MULTIPLIER
+        }
+
+        fn default_max() -> f64 {
+            DynamicGasPrice::DEFAULT_MAX
+        }
+
+        let DynGas {
+            enabled,
+            multiplier,
+            max,
+        } = DynGas::deserialize(deserializer)?;
+
+        DynamicGasPrice::new(enabled, multiplier, max).map_err(|e| match e.detail() {
+            ErrorDetail::MultiplierTooSmall(_) => D::Error::invalid_value(
+                Unexpected::Float(multiplier),
+                &format!(
+                    "a floating-point value greater than or equal to {}",
+                    Self::MIN_MULTIPLIER
+                )
+                .as_str(),
+            ),
+            ErrorDetail::InvalidMax(_) => {
+                D::Error::invalid_value(Unexpected::Float(max), &"a positive floating-point value")
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(dead_code)] // the field of the struct `DummyConfig` defined below is never accessed
+mod tests {
+    use super::*;
+
+    use serde::Deserialize;
+    use test_log::test;
+
+    #[derive(Debug, Deserialize)]
+    struct DummyConfig {
+        dynamic_gas_price: DynamicGasPrice,
+    }
+
+    #[test]
+    fn parse_dynamic_gas_price_with_defaults() {
+        let config = toml::from_str::<DummyConfig>("dynamic_gas_price = { enabled = true }")
+            .expect("could not parse config");
+
+        assert_eq!(
+            config.dynamic_gas_price,
+            DynamicGasPrice::new(true, 1.1, 0.6).unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_invalid_dynamic_gas_price_multiplier() {
+        let err = toml::from_str::<DummyConfig>(
+            "dynamic_gas_price = { enabled = true, multiplier = 0.9, max = 0.6 }",
+        )
+        .unwrap_err()
+        .to_string();
+
+        assert!(err.contains("expected a floating-point value greater than or equal to"));
+    }
+
+    #[test]
+    fn dynamic_gas_price_is_capped_at_max() {
+        let dynamic_gas_price = DynamicGasPrice::new(true, 2.0, 0.1).unwrap();
+
+        assert_eq!(dynamic_gas_price.apply(0.025), 0.05);
+        assert_eq!(dynamic_gas_price.apply(0.1), 0.1);
+    }
+}
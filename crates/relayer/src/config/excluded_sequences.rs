@@ -0,0 +1,236 @@
+//! Packet sequences which are never cleared, per channel.
+
+use alloc::collections::BTreeMap;
+use core::fmt;
+use core::ops::RangeInclusive;
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use ibc_relayer_types::core::ics04_channel::packet::Sequence;
+use ibc_relayer_types::core::ics24_host::identifier::ChannelId;
+
+/// Maps channels on a chain to the sequences of the packets sent or acknowledged on
+/// these channels which must be skipped when clearing packets.
+///
+/// This is meant for packets which can never be relayed successfully, and which would
+/// otherwise be retried, and pay for gas, at every clearing interval.
+///
+/// ```toml
+/// [chains.excluded_sequences]
+/// channel-0 = [1, 2, '10-20']
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ExcludedSequences(BTreeMap<ChannelId, Vec<SequenceRange>>);
+
+impl ExcludedSequences {
+    pub fn new(map: BTreeMap<ChannelId, Vec<SequenceRange>>) -> Self {
+        Self(map)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Whether the packet with the given sequence on the given channel must be skipped.
+    pub fn is_excluded(&self, channel_id: &ChannelId, sequence: Sequence) -> bool {
+        self.0
+            .get(channel_id)
+            .map_or(false, |ranges| ranges.iter().any(|r| r.contains(sequence)))
+    }
+}
+
+impl Serialize for ExcludedSequences {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_map(
+            self.0
+                .iter()
+                .map(|(channel_id, ranges)| (channel_id.as_str(), ranges)),
+        )
+    }
+}
+
+impl<'de> Deserialize<'de> for ExcludedSequences {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let map = BTreeMap::<String, Vec<SequenceRange>>::deserialize(deserializer)?;
+
+        map.into_iter()
+            .map(|(channel_id, ranges)| {
+                channel_id
+                    .parse::<ChannelId>()
+                    .map(|channel_id| (channel_id, ranges))
+                    .map_err(|e| {
+                        de::Error::custom(format!("invalid channel id '{channel_id}': {e}"))
+                    })
+            })
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+}
+
+/// An inclusive range of packet sequences, written either as a single sequence
+/// or as a `start-end` string.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SequenceRange {
+    start: Sequence,
+    end: Sequence,
+}
+
+impl SequenceRange {
+    pub fn new(start: Sequence, end: Sequence) -> Self {
+        Self { start, end }
+    }
+
+    pub fn single(sequence: Sequence) -> Self {
+        Self::new(sequence, sequence)
+    }
+
+    pub fn contains(&self, sequence: Sequence) -> bool {
+        self.as_range().contains(&sequence)
+    }
+
+    fn as_range(&self) -> RangeInclusive<Sequence> {
+        self.start..=self.end
+    }
+}
+
+impl fmt::Display for SequenceRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.start == self.end {
+            write!(f, "{}", self.start)
+        } else {
+            write!(f, "{}-{}", self.start, self.end)
+        }
+    }
+}
+
+impl Serialize for SequenceRange {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if self.start == self.end {
+            serializer.serialize_u64(self.start.into())
+        } else {
+            serializer.serialize_str(&self.to_string())
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for SequenceRange {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<SequenceRange, D::Error> {
+        deserializer.deserialize_any(SequenceRangeVisitor)
+    }
+}
+
+struct SequenceRangeVisitor;
+
+impl<'de> de::Visitor<'de> for SequenceRangeVisitor {
+    type Value = SequenceRange;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a packet sequence, or a range of packet sequences such as '10-20'")
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(SequenceRange::single(Sequence::from(v)))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        let v = u64::try_from(v).map_err(|_| E::custom(format!("invalid sequence: {v}")))?;
+        self.visit_u64(v)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        let parse = |s: &str| {
+            s.trim()
+                .parse::<u64>()
+                .map(Sequence::from)
+                .map_err(|e| E::custom(format!("invalid sequence '{s}': {e}")))
+        };
+
+        match v.split_once('-') {
+            Some((start, end)) => {
+                let (start, end) = (parse(start)?, parse(end)?);
+
+                if start > end {
+                    return Err(E::custom(format!(
+                        "invalid sequence range '{v}': start is greater than end"
+                    )));
+                }
+
+                Ok(SequenceRange::new(start, end))
+            }
+            None => parse(v).map(SequenceRange::single),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::str::FromStr;
+
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+
+    #[derive(Debug, Deserialize, Serialize)]
+    struct Config {
+        excluded_sequences: ExcludedSequences,
+    }
+
+    #[test]
+    fn deserialize_excluded_sequences() {
+        let toml_content = r#"
+            [excluded_sequences]
+            channel-0 = [1, 2, '10-20']
+            channel-1 = ['5']
+            "#;
+
+        let config: Config = toml::from_str(toml_content).unwrap();
+        let excluded = config.excluded_sequences;
+
+        let channel_0 = ChannelId::from_str("channel-0").unwrap();
+        let channel_1 = ChannelId::from_str("channel-1").unwrap();
+        let channel_2 = ChannelId::from_str("channel-2").unwrap();
+
+        assert!(excluded.is_excluded(&channel_0, 1.into()));
+        assert!(excluded.is_excluded(&channel_0, 2.into()));
+        assert!(!excluded.is_excluded(&channel_0, 3.into()));
+        assert!(excluded.is_excluded(&channel_0, 10.into()));
+        assert!(excluded.is_excluded(&channel_0, 15.into()));
+        assert!(excluded.is_excluded(&channel_0, 20.into()));
+        assert!(!excluded.is_excluded(&channel_0, 21.into()));
+        assert!(excluded.is_excluded(&channel_1, 5.into()));
+        assert!(!excluded.is_excluded(&channel_2, 1.into()));
+    }
+
+    #[test]
+    fn serialize_excluded_sequences() {
+        let toml_content = r#"
+            [excluded_sequences]
+            channel-0 = [1, '10-20']
+            "#;
+
+        let config: Config = toml::from_str(toml_content).unwrap();
+        let serialized = toml::to_string(&config).unwrap();
+        let deserialized: Config = toml::from_str(&serialized).unwrap();
+
+        assert_eq!(config.excluded_sequences, deserialized.excluded_sequences);
+    }
+
+    #[test]
+    fn reject_invalid_sequence_range() {
+        let toml_content = r#"
+            [excluded_sequences]
+            channel-0 = ['20-10']
+            "#;
+
+        assert!(toml::from_str::<Config>(toml_content).is_err());
+    }
+}
@@ -7,7 +7,7 @@ use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use std::collections::HashMap;
 use std::hash::Hash;
 
-use ibc_relayer_types::applications::transfer::RawCoin;
+use ibc_relayer_types::applications::transfer::{Amount, RawCoin};
 use ibc_relayer_types::bigint::U256;
 use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, PortId};
 use ibc_relayer_types::events::IbcEventType;
@@ -19,6 +19,8 @@ pub struct PacketFilter {
     pub channel_policy: ChannelPolicy,
     #[serde(default)]
     pub min_fees: HashMap<ChannelFilterMatch, FeePolicy>,
+    #[serde(default, skip_serializing_if = "TransferPolicy::allows_all")]
+    pub transfer: TransferPolicy,
 }
 
 impl Default for PacketFilter {
@@ -27,6 +29,7 @@ impl Default for PacketFilter {
         Self {
             channel_policy: ChannelPolicy::default(),
             min_fees: HashMap::new(),
+            transfer: TransferPolicy::default(),
         }
     }
 }
@@ -39,9 +42,14 @@ impl PacketFilter {
         Self {
             channel_policy,
             min_fees,
+            transfer: TransferPolicy::default(),
         }
     }
 
+    pub fn with_transfer_policy(self, transfer: TransferPolicy) -> Self {
+        Self { transfer, ..self }
+    }
+
     pub fn allow(filters: Vec<(PortFilterMatch, ChannelFilterMatch)>) -> PacketFilter {
         PacketFilter::new(
             ChannelPolicy::Allow(ChannelFilters::new(filters)),
@@ -110,6 +118,74 @@ impl MinFee {
     }
 }
 
+/// Represents the policy used to filter ICS-20 fungible token transfers,
+/// based on the base denomination and on the amount of the transferred tokens.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TransferPolicy {
+    #[serde(default)]
+    denoms: DenomPolicy,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    min_amount: Option<u64>,
+}
+
+impl TransferPolicy {
+    pub fn new(denoms: DenomPolicy, min_amount: Option<u64>) -> Self {
+        Self { denoms, min_amount }
+    }
+
+    /// Whether transfers of any denomination and any amount are relayed.
+    pub fn allows_all(&self) -> bool {
+        self.denoms == DenomPolicy::AllowAll && self.min_amount.is_none()
+    }
+
+    /// Returns true if a transfer of the given amount of tokens with the given
+    /// base denomination can be relayed, false otherwise.
+    pub fn should_relay(&self, base_denom: &str, amount: &Amount) -> bool {
+        let enough = self
+            .min_amount
+            .map_or(true, |min_amount| amount.0 >= U256::from(min_amount));
+
+        enough && self.denoms.is_allowed(base_denom)
+    }
+}
+
+/// Represents the ways in which ICS-20 transfers can be filtered
+/// based on the base denomination of the transferred tokens.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(
+    rename_all = "lowercase",
+    tag = "policy",
+    content = "list",
+    deny_unknown_fields
+)]
+pub enum DenomPolicy {
+    /// Allow transfers of the specified base denominations.
+    Allow(Vec<String>),
+    /// Deny transfers of the specified base denominations.
+    Deny(Vec<String>),
+    /// Allow transfers of any base denomination.
+    AllowAll,
+}
+
+impl Default for DenomPolicy {
+    /// By default, allows all denominations.
+    fn default() -> Self {
+        Self::AllowAll
+    }
+}
+
+impl DenomPolicy {
+    /// Returns true if transfers of the given base denomination can be relayed, false otherwise.
+    pub fn is_allowed(&self, base_denom: &str) -> bool {
+        match self {
+            DenomPolicy::Allow(denoms) => denoms.iter().any(|denom| denom == base_denom),
+            DenomPolicy::Deny(denoms) => !denoms.iter().any(|denom| denom == base_denom),
+            DenomPolicy::AllowAll => true,
+        }
+    }
+}
+
 impl Default for ChannelPolicy {
     /// By default, allows all channels & ports.
     fn default() -> Self {
@@ -600,6 +676,34 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn packet_filter_transfer_policy() {
+        let packet_filter = r#"
+            policy = 'allow'
+            list = [
+              ['transfer', 'channel-0'],
+            ]
+
+            [transfer]
+            min_amount = 1000
+            denoms = { policy = 'allow', list = ['uatom', 'uosmo'] }
+            "#;
+
+        let pf: PacketFilter =
+            toml::from_str(packet_filter).expect("could not parse packet filter");
+
+        assert!(!pf.transfer.allows_all());
+        assert!(pf.transfer.should_relay("uatom", &Amount::from(1000u64)));
+        assert!(!pf.transfer.should_relay("uatom", &Amount::from(999u64)));
+        assert!(!pf.transfer.should_relay("ujuno", &Amount::from(1000u64)));
+
+        let deny_policy = DenomPolicy::Deny(vec!["ujuno".to_string()]);
+        assert!(deny_policy.is_allowed("uatom"));
+        assert!(!deny_policy.is_allowed("ujuno"));
+
+        assert!(PacketFilter::default().transfer.allows_all());
+    }
+
     #[test]
     fn to_string_wildcards() {
         let wildcard = "ica*".parse::<Wildcard>().unwrap();
@@ -0,0 +1,157 @@
+use core::fmt::{Display, Error as FmtError, Formatter};
+use core::str::FromStr;
+use core::time::Duration;
+
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+flex_error::define_error! {
+    Error {
+        InvalidFormat
+            { raw: String }
+            |e| {
+                format_args!("invalid refresh rate, must be a fraction of the form `n/d`: {}", e.raw)
+            },
+
+        OutOfRange
+            { numerator: u64, denominator: u64 }
+            |e| {
+                format_args!("refresh rate must be strictly between 0 and 1, found {}/{}",
+                    e.numerator, e.denominator)
+            },
+    }
+}
+
+/// The fraction of the trusting period of a client which may elapse
+/// since its latest update before the client is refreshed.
+///
+/// ```toml
+/// refresh_rate = '1/3'
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RefreshRate {
+    numerator: u64,
+    denominator: u64,
+}
+
+impl RefreshRate {
+    pub fn new(numerator: u64, denominator: u64) -> Result<Self, Error> {
+        if numerator == 0 || numerator >= denominator {
+            return Err(Error::out_of_range(numerator, denominator));
+        }
+
+        Ok(Self {
+            numerator,
+            denominator,
+        })
+    }
+
+    pub fn numerator(&self) -> u64 {
+        self.numerator
+    }
+
+    pub fn denominator(&self) -> u64 {
+        self.denominator
+    }
+
+    /// The duration after which a client with the given trusting period is refreshed.
+    pub fn refresh_period(&self, trusting_period: Duration) -> Duration {
+        let nanos =
+            trusting_period.as_nanos() * u128::from(self.numerator) / u128::from(self.denominator);
+
+        Duration::from_nanos(u64::try_from(nanos).unwrap_or(u64::MAX))
+    }
+}
+
+impl Default for RefreshRate {
+    /// Refresh clients after 2/3 of their trusting period.
+    fn default() -> Self {
+        Self {
+            numerator: 2,
+            denominator: 3,
+        }
+    }
+}
+
+impl Display for RefreshRate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, "{}/{}", self.numerator, self.denominator)
+    }
+}
+
+impl FromStr for RefreshRate {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (numerator, denominator) = s
+            .split_once('/')
+            .and_then(|(n, d)| Some((n.trim().parse().ok()?, d.trim().parse().ok()?)))
+            .ok_or_else(|| Error::invalid_format(s.to_string()))?;
+
+        Self::new(numerator, denominator)
+    }
+}
+
+impl<'de> Deserialize<'de> for RefreshRate {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = String::deserialize(deserializer)?;
+        raw.parse().map_err(D::Error::custom)
+    }
+}
+
+impl Serialize for RefreshRate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde::Deserialize;
+    use test_log::test;
+
+    #[test]
+    fn parse_refresh_rate() {
+        #[derive(Debug, Deserialize)]
+        struct DummyConfig {
+            refresh_rate: RefreshRate,
+        }
+
+        let config: DummyConfig = toml::from_str("refresh_rate = '1/3'").unwrap();
+        assert_eq!(config.refresh_rate, RefreshRate::new(1, 3).unwrap());
+
+        let err = toml::from_str::<DummyConfig>("refresh_rate = '3/2'")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("strictly between 0 and 1"));
+
+        let err = toml::from_str::<DummyConfig>("refresh_rate = '0.5'")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("invalid refresh rate"));
+    }
+
+    #[test]
+    fn refresh_period_of_trusting_period() {
+        let trusting_period = Duration::from_secs(14 * 24 * 3600);
+
+        assert_eq!(
+            RefreshRate::default().refresh_period(trusting_period),
+            2 * trusting_period / 3
+        );
+        assert_eq!(
+            RefreshRate::new(1, 4)
+                .unwrap()
+                .refresh_period(trusting_period),
+            trusting_period / 4
+        );
+    }
+}
@@ -259,6 +259,61 @@ pub mod memo {
     }
 }
 
+pub use ics20_field_size_limit::{Ics20FieldSizeLimit, Ics20FieldSizeValidation};
+
+pub mod ics20_field_size_limit {
+    use core::fmt::{Display, Error as FmtError, Formatter};
+    use serde::{Deserialize, Serialize};
+
+    /// An upper bound on the length of a field of ICS-20 packets,
+    /// eg. the memo or the receiver of a fungible token transfer.
+    ///
+    /// Packets with a field exceeding the limit are not relayed,
+    /// and eventually time out on the source chain instead.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    pub struct Ics20FieldSizeLimit {
+        pub enabled: bool,
+        pub size: usize,
+    }
+
+    /// The outcome of checking a field against an [`Ics20FieldSizeLimit`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Ics20FieldSizeValidation {
+        Valid,
+        Invalid { size: usize, max: usize },
+    }
+
+    impl Ics20FieldSizeLimit {
+        pub fn new(enabled: bool, size: usize) -> Self {
+            Self { enabled, size }
+        }
+
+        /// Checks the length in bytes of the given field against this limit.
+        pub fn check(&self, field: &str) -> Ics20FieldSizeValidation {
+            if self.enabled && field.len() > self.size {
+                Ics20FieldSizeValidation::Invalid {
+                    size: field.len(),
+                    max: self.size,
+                }
+            } else {
+                Ics20FieldSizeValidation::Valid
+            }
+        }
+    }
+
+    impl Display for Ics20FieldSizeValidation {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+            match self {
+                Self::Valid => write!(f, "valid"),
+                Self::Invalid { size, max } => {
+                    write!(f, "{size} bytes, exceeding the limit of {max} bytes")
+                }
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 #[allow(dead_code)] // the fields of the structs defined below are never accessed
 mod tests {
@@ -324,4 +379,26 @@ mod tests {
 
         assert!(err.contains("a string length of at most"));
     }
+
+    #[test]
+    fn parse_ics20_field_size_limit() {
+        #[derive(Debug, Deserialize)]
+        struct DummyConfig {
+            ics20_max_memo_size: Ics20FieldSizeLimit,
+        }
+
+        let config =
+            toml::from_str::<DummyConfig>("ics20_max_memo_size = { enabled = true, size = 4 }")
+                .unwrap();
+
+        let limit = config.ics20_max_memo_size;
+        assert_eq!(limit.check("abcd"), Ics20FieldSizeValidation::Valid);
+        assert_eq!(
+            limit.check("abcde"),
+            Ics20FieldSizeValidation::Invalid { size: 5, max: 4 }
+        );
+
+        let disabled = Ics20FieldSizeLimit::new(false, 4);
+        assert_eq!(disabled.check("abcde"), Ics20FieldSizeValidation::Valid);
+    }
 }
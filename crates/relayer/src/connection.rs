@@ -167,6 +167,10 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Connection<ChainA, ChainB> {
             ));
         }
 
+        if b_to_a_client.id().is_localhost() || a_to_b_client.id().is_localhost() {
+            return Self::localhost(b_to_a_client, a_to_b_client);
+        }
+
         let mut c = Self {
             delay_period,
             a_side: ConnectionSide::new(
@@ -186,6 +190,55 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Connection<ChainA, ChainB> {
         Ok(c)
     }
 
+    /// Returns the connection opened by the host chain on top of the localhost client.
+    ///
+    /// The localhost connection cannot be opened through a handshake, since it is created
+    /// by the chain itself, so this only checks that it exists and is open.
+    fn localhost(
+        b_to_a_client: ForeignClient<ChainA, ChainB>,
+        a_to_b_client: ForeignClient<ChainB, ChainA>,
+    ) -> Result<Self, ConnectionError> {
+        if !b_to_a_client.id().is_localhost() || !a_to_b_client.id().is_localhost() {
+            return Err(ConnectionError::localhost_client_mismatch(
+                b_to_a_client.id().clone(),
+                a_to_b_client.id().clone(),
+            ));
+        }
+
+        let chain = b_to_a_client.dst_chain();
+        let connection_id = ConnectionId::localhost();
+
+        let (connection_end, _) = chain
+            .query_connection(
+                QueryConnectionRequest {
+                    connection_id: connection_id.clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .map_err(|e| ConnectionError::connection_query(connection_id.clone(), e))?;
+
+        if !connection_end.is_open() {
+            return Err(ConnectionError::connection_not_open(
+                *connection_end.state(),
+            ));
+        }
+
+        Ok(Self {
+            delay_period: connection_end.delay_period(),
+            a_side: ConnectionSide::new(
+                chain,
+                b_to_a_client.id().clone(),
+                Some(connection_id.clone()),
+            ),
+            b_side: ConnectionSide::new(
+                a_to_b_client.dst_chain(),
+                a_to_b_client.id().clone(),
+                Some(connection_id),
+            ),
+        })
+    }
+
     pub fn restore_from_event(
         chain: ChainA,
         counterparty_chain: ChainB,
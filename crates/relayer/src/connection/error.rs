@@ -7,6 +7,7 @@ use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId, Connect
 use ibc_relayer_types::events::IbcEvent;
 
 use crate::error::Error as RelayerError;
+use crate::error_kind::{ErrorKind, HasErrorKind};
 use crate::foreign_client::{ForeignClientError, HasExpiredOrFrozenError};
 use crate::supervisor::Error as SupervisorError;
 
@@ -165,6 +166,16 @@ define_error! {
             [ RelayerError ]
             |_| { "failed to build connection proofs" },
 
+        LocalhostClientMismatch
+            {
+                client_id: ClientId,
+                counterparty_client_id: ClientId,
+            }
+            |e| {
+                format!("the localhost client must be used on both ends of a connection, found clients {} and {}",
+                    e.client_id, e.counterparty_client_id)
+            },
+
         ConnectionAlreadyExists
             { connection_id: ConnectionId }
             |e| {
@@ -200,3 +211,25 @@ impl HasExpiredOrFrozenError for ConnectionError {
         self.detail().is_expired_or_frozen_error()
     }
 }
+
+impl HasErrorKind for ConnectionErrorDetail {
+    fn error_kind(&self) -> ErrorKind {
+        match self {
+            Self::Relayer(e) => e.source.error_kind(),
+            Self::ChainQuery(e) => e.source.error_kind(),
+            Self::ConnectionQuery(e) => e.source.error_kind(),
+            Self::ClientOperation(e) => e.source.error_kind(),
+            Self::Submit(e) => e.source.error_kind(),
+            Self::Signer(e) => e.source.error_kind(),
+            Self::ConnectionProof(e) => e.source.error_kind(),
+            Self::MaxRetry(e) => e.source.error_kind(),
+            _ => ErrorKind::Other,
+        }
+    }
+}
+
+impl HasErrorKind for ConnectionError {
+    fn error_kind(&self) -> ErrorKind {
+        self.detail().error_kind()
+    }
+}
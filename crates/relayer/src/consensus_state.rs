@@ -4,9 +4,17 @@ use ibc_proto::ibc::lightclients::tendermint::v1::ConsensusState as RawConsensus
 #[cfg(test)]
 use ibc_proto::ibc::mock::ConsensusState as RawMockConsensusState;
 use ibc_proto::protobuf::Protobuf;
+use ibc_relayer_types::clients::ics06_solomachine::consensus_state::{
+    ConsensusState as SmConsensusState, SOLOMACHINE_CONSENSUS_STATE_TYPE_URL,
+};
+use ibc_relayer_types::clients::ics06_solomachine::raw::ConsensusState as RawSmConsensusState;
 use ibc_relayer_types::clients::ics07_tendermint::consensus_state::{
     ConsensusState as TmConsensusState, TENDERMINT_CONSENSUS_STATE_TYPE_URL,
 };
+use ibc_relayer_types::clients::ics08_wasm::consensus_state::{
+    ConsensusState as WasmConsensusState, WASM_CONSENSUS_STATE_TYPE_URL,
+};
+use ibc_relayer_types::clients::ics08_wasm::raw::ConsensusState as RawWasmConsensusState;
 use ibc_relayer_types::core::ics02_client::client_type::ClientType;
 use ibc_relayer_types::core::ics02_client::consensus_state::{
     downcast_consensus_state, ConsensusState,
@@ -26,6 +34,8 @@ use serde::{Deserialize, Serialize};
 #[non_exhaustive]
 pub enum AnyConsensusState {
     Tendermint(TmConsensusState),
+    Solomachine(SmConsensusState),
+    Wasm(WasmConsensusState),
 
     #[cfg(test)]
     Mock(MockConsensusState),
@@ -35,6 +45,8 @@ impl AnyConsensusState {
     pub fn timestamp(&self) -> Timestamp {
         match self {
             Self::Tendermint(cs_state) => cs_state.timestamp.into(),
+            Self::Solomachine(cs_state) => cs_state.timestamp,
+            Self::Wasm(cs_state) => cs_state.inner.timestamp.into(),
 
             #[cfg(test)]
             Self::Mock(mock_state) => mock_state.timestamp(),
@@ -44,11 +56,26 @@ impl AnyConsensusState {
     pub fn client_type(&self) -> ClientType {
         match self {
             AnyConsensusState::Tendermint(_cs) => ClientType::Tendermint,
+            AnyConsensusState::Solomachine(_cs) => ClientType::Solomachine,
+            AnyConsensusState::Wasm(_cs) => ClientType::Wasm,
 
             #[cfg(test)]
             AnyConsensusState::Mock(_cs) => ClientType::Mock,
         }
     }
+
+    /// Wraps a Tendermint consensus state into the consensus state of an 08-wasm client.
+    pub fn wrap_wasm(self) -> Result<Self, Error> {
+        match self {
+            AnyConsensusState::Tendermint(cs_state) => {
+                Ok(AnyConsensusState::Wasm(WasmConsensusState::new(cs_state)))
+            }
+            _ => Err(Error::client_specific(format!(
+                "cannot wrap a consensus state of type {} in an 08-wasm client",
+                self.client_type()
+            ))),
+        }
+    }
 }
 
 impl Protobuf<Any> for AnyConsensusState {}
@@ -65,6 +92,16 @@ impl TryFrom<Any> for AnyConsensusState {
                     .map_err(Error::decode_raw_client_state)?,
             )),
 
+            SOLOMACHINE_CONSENSUS_STATE_TYPE_URL => Ok(AnyConsensusState::Solomachine(
+                Protobuf::<RawSmConsensusState>::decode_vec(&value.value)
+                    .map_err(Error::decode_raw_client_state)?,
+            )),
+
+            WASM_CONSENSUS_STATE_TYPE_URL => Ok(AnyConsensusState::Wasm(
+                Protobuf::<RawWasmConsensusState>::decode_vec(&value.value)
+                    .map_err(Error::decode_raw_client_state)?,
+            )),
+
             #[cfg(test)]
             MOCK_CONSENSUS_STATE_TYPE_URL => Ok(AnyConsensusState::Mock(
                 Protobuf::<RawMockConsensusState>::decode_vec(&value.value)
@@ -83,6 +120,14 @@ impl From<AnyConsensusState> for Any {
                 type_url: TENDERMINT_CONSENSUS_STATE_TYPE_URL.to_string(),
                 value: Protobuf::<RawConsensusState>::encode_vec(&value),
             },
+            AnyConsensusState::Solomachine(value) => Any {
+                type_url: SOLOMACHINE_CONSENSUS_STATE_TYPE_URL.to_string(),
+                value: Protobuf::<RawSmConsensusState>::encode_vec(&value),
+            },
+            AnyConsensusState::Wasm(value) => Any {
+                type_url: WASM_CONSENSUS_STATE_TYPE_URL.to_string(),
+                value: Protobuf::<RawWasmConsensusState>::encode_vec(&value),
+            },
             #[cfg(test)]
             AnyConsensusState::Mock(value) => Any {
                 type_url: MOCK_CONSENSUS_STATE_TYPE_URL.to_string(),
@@ -105,6 +150,18 @@ impl From<TmConsensusState> for AnyConsensusState {
     }
 }
 
+impl From<SmConsensusState> for AnyConsensusState {
+    fn from(cs: SmConsensusState) -> Self {
+        Self::Solomachine(cs)
+    }
+}
+
+impl From<WasmConsensusState> for AnyConsensusState {
+    fn from(cs: WasmConsensusState) -> Self {
+        Self::Wasm(cs)
+    }
+}
+
 impl From<&dyn ConsensusState> for AnyConsensusState {
     fn from(cs: &dyn ConsensusState) -> Self {
         #[cfg(test)]
@@ -112,6 +169,14 @@ impl From<&dyn ConsensusState> for AnyConsensusState {
             return AnyConsensusState::from(cs.clone());
         }
 
+        if let Some(cs) = downcast_consensus_state::<SmConsensusState>(cs) {
+            return AnyConsensusState::from(cs.clone());
+        }
+
+        if let Some(cs) = downcast_consensus_state::<WasmConsensusState>(cs) {
+            return AnyConsensusState::from(cs.clone());
+        }
+
         if let Some(cs) = downcast_consensus_state::<TmConsensusState>(cs) {
             AnyConsensusState::from(cs.clone())
         } else {
@@ -165,6 +230,8 @@ impl ConsensusState for AnyConsensusState {
     fn root(&self) -> &CommitmentRoot {
         match self {
             Self::Tendermint(cs_state) => cs_state.root(),
+            Self::Solomachine(cs_state) => cs_state.root(),
+            Self::Wasm(cs_state) => cs_state.root(),
 
             #[cfg(test)]
             Self::Mock(mock_state) => mock_state.root(),
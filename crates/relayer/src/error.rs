@@ -17,6 +17,7 @@ use tendermint_light_client::errors::{
 };
 use tendermint_rpc::endpoint::abci_query::AbciQuery;
 use tendermint_rpc::endpoint::broadcast::tx_sync::Response as TxSyncResponse;
+use tendermint_rpc::error::ErrorDetail as TendermintRpcErrorDetail;
 use tendermint_rpc::Error as TendermintRpcError;
 use tonic::{
     metadata::errors::InvalidMetadataValue, transport::Error as TransportError,
@@ -370,6 +371,16 @@ define_error! {
                     e.chain_id, e.estimated_gas, e.max_gas)
             },
 
+        InvalidBaseFee
+            {
+                chain_id: ChainId,
+                base_fee: String,
+            }
+            |e| {
+                format!("{} fee market module returned an invalid base fee: {}",
+                    e.chain_id, e.base_fee)
+            },
+
         HealthCheckJsonRpc
             {
                 chain_id: ChainId,
@@ -591,6 +602,14 @@ define_error! {
             { address: String }
             [ TendermintRpcError ]
             |e| { format!("invalid archive node address {}", e.address) },
+
+        SoloMachine
+            { reason: String }
+            |e| { format!("solo machine error: {}", e.reason) },
+
+        SoloMachineUnsupported
+            { operation: String }
+            |e| { format!("operation not supported by solo machines: {}", e.operation) },
     }
 }
 
@@ -608,6 +627,35 @@ impl Error {
             _ => false,
         }
     }
+
+    /// Whether the error was caused by an RPC endpoint being unreachable
+    /// or failing to respond, rather than by the request which was sent to it.
+    pub fn is_rpc_endpoint_failure(&self) -> bool {
+        match self.detail() {
+            ErrorDetail::Rpc(e) => matches!(
+                e.source,
+                TendermintRpcErrorDetail::Io(_)
+                    | TendermintRpcErrorDetail::Http(_)
+                    | TendermintRpcErrorDetail::Hyper(_)
+                    | TendermintRpcErrorDetail::Server(_)
+                    | TendermintRpcErrorDetail::Timeout(_)
+            ),
+            _ => false,
+        }
+    }
+
+    /// Whether the error was caused by a gRPC endpoint being unreachable
+    /// or failing to respond, rather than by the request which was sent to it.
+    pub fn is_grpc_endpoint_failure(&self) -> bool {
+        match self.detail() {
+            ErrorDetail::GrpcTransport(_) => true,
+            ErrorDetail::GrpcStatus(e) => matches!(
+                e.status.code(),
+                tonic::Code::Unavailable | tonic::Code::DeadlineExceeded | tonic::Code::Cancelled
+            ),
+            _ => false,
+        }
+    }
 }
 
 impl GrpcStatusSubdetail {
@@ -0,0 +1,242 @@
+//! Normalization of the errors encountered by the relayer into a small set of
+//! kinds, by which the errors of the workers are counted in the telemetry.
+
+use core::convert::Infallible;
+use core::fmt::{Display, Error as FmtError, Formatter};
+
+use tendermint_light_client::errors::ErrorDetail as LightClientErrorDetail;
+use tendermint_rpc::endpoint::broadcast::tx_sync;
+
+use crate::error::{Error, ErrorDetail};
+use crate::link::redundancy::is_redundant_tx_error;
+use crate::sdk_error::{
+    sdk_error_from_tx_sync_error_code, ClientErrorDetail, SdkError, SdkErrorDetail,
+};
+
+/// The codespace of the errors of the Cosmos SDK itself.
+const SDK_CODESPACE: &str = "sdk";
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    /// A transaction ran out of gas, or its gas estimate exceeds the configured maximum
+    OutOfGas,
+    /// The fee of a transaction is lower than the minimum required by the chain
+    InsufficientFee,
+    /// The account sequence of the relayer is out of sync with the chain
+    AccountSequenceMismatch,
+    /// A client is expired or frozen
+    ClientExpired,
+    /// All the packet messages of a transaction were already relayed
+    RedundantPackets,
+    /// A transaction failed for another reason
+    TxFailed,
+    /// An RPC or gRPC query or connection to a full node failed
+    Rpc,
+    /// Any other error
+    Other,
+}
+
+impl ErrorKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::OutOfGas => "out-of-gas",
+            Self::InsufficientFee => "insufficient-fee",
+            Self::AccountSequenceMismatch => "account-sequence-mismatch",
+            Self::ClientExpired => "client-expired",
+            Self::RedundantPackets => "redundant-packets",
+            Self::TxFailed => "tx-failed",
+            Self::Rpc => "rpc",
+            Self::Other => "other",
+        }
+    }
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+pub trait HasErrorKind {
+    fn error_kind(&self) -> ErrorKind;
+}
+
+/// The kind of the error of a transaction rejected by `CheckTx`,
+/// or `None` if the transaction passed `CheckTx`.
+pub fn check_tx_error_kind(response: &tx_sync::Response) -> Option<ErrorKind> {
+    if response.code.is_ok() {
+        None
+    } else if is_redundant_tx_error(&response.log) {
+        Some(ErrorKind::RedundantPackets)
+    } else {
+        Some(sdk_error_from_tx_sync_error_code(response.code.into()).error_kind())
+    }
+}
+
+impl HasErrorKind for SdkErrorDetail {
+    fn error_kind(&self) -> ErrorKind {
+        match self {
+            Self::OutOfGas(_) => ErrorKind::OutOfGas,
+            Self::InsufficientFee(_) => ErrorKind::InsufficientFee,
+            Self::IncorrectAccountSequence(_) => ErrorKind::AccountSequenceMismatch,
+            Self::Client(e) => match e.source {
+                ClientErrorDetail::FrozenLightClient(_) | ClientErrorDetail::InactiveClient(_) => {
+                    ErrorKind::ClientExpired
+                }
+                _ => ErrorKind::TxFailed,
+            },
+            Self::UnknownSdk(e) if e.codespace == SDK_CODESPACE => {
+                sdk_error_from_tx_sync_error_code(e.code).error_kind()
+            }
+            Self::UnknownSdk(_) | Self::UnknownTxSync(_) => ErrorKind::TxFailed,
+            Self::UnexpectedOk(_) => ErrorKind::Other,
+        }
+    }
+}
+
+impl HasErrorKind for SdkError {
+    fn error_kind(&self) -> ErrorKind {
+        self.detail().error_kind()
+    }
+}
+
+impl HasErrorKind for ErrorDetail {
+    fn error_kind(&self) -> ErrorKind {
+        match self {
+            Self::CheckTx(e) => check_tx_error_kind(&e.response).unwrap_or(ErrorKind::TxFailed),
+            Self::DeliverTx(e) => e.detail.error_kind(),
+            Self::SendTx(_) | Self::TxNoConfirmation(_) => ErrorKind::TxFailed,
+            Self::TxSimulateGasEstimateExceeded(_) => ErrorKind::OutOfGas,
+            Self::GasPriceTooLow(_) => ErrorKind::InsufficientFee,
+            Self::GrpcStatus(e) if e.is_account_sequence_mismatch_that_requires_refresh() => {
+                ErrorKind::AccountSequenceMismatch
+            }
+            Self::LightClientVerification(e)
+                if matches!(
+                    e.source,
+                    LightClientErrorDetail::TrustedStateOutsideTrustingPeriod(_)
+                ) =>
+            {
+                ErrorKind::ClientExpired
+            }
+            Self::Rpc(_)
+            | Self::RpcResponse(_)
+            | Self::AbciQuery(_)
+            | Self::WebSocket(_)
+            | Self::Grpc(_)
+            | Self::GrpcStatus(_)
+            | Self::GrpcTransport(_) => ErrorKind::Rpc,
+            _ => ErrorKind::Other,
+        }
+    }
+}
+
+impl HasErrorKind for Error {
+    fn error_kind(&self) -> ErrorKind {
+        self.detail().error_kind()
+    }
+}
+
+impl<E: HasErrorKind> HasErrorKind for retry::Error<E> {
+    fn error_kind(&self) -> ErrorKind {
+        self.error.error_kind()
+    }
+}
+
+impl HasErrorKind for Infallible {
+    fn error_kind(&self) -> ErrorKind {
+        match *self {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
+    use ibc_relayer_types::events::IbcEvent;
+
+    use crate::foreign_client::ForeignClientError;
+    use crate::link::error::LinkError;
+    use crate::sdk_error::ClientError;
+
+    #[test]
+    fn sdk_error_kinds() {
+        assert_eq!(SdkError::out_of_gas(11).error_kind(), ErrorKind::OutOfGas);
+        assert_eq!(
+            SdkError::unknown_sdk("sdk".to_string(), 13).error_kind(),
+            ErrorKind::InsufficientFee
+        );
+        assert_eq!(
+            SdkError::unknown_sdk("sdk".to_string(), 32).error_kind(),
+            ErrorKind::AccountSequenceMismatch
+        );
+        assert_eq!(
+            SdkError::client(ClientError::frozen_light_client()).error_kind(),
+            ErrorKind::ClientExpired
+        );
+        assert_eq!(
+            SdkError::unknown_sdk("channel".to_string(), 11).error_kind(),
+            ErrorKind::TxFailed
+        );
+    }
+
+    #[test]
+    fn check_tx_error_kinds() {
+        let response = |code: u32, log: &str| tx_sync::Response {
+            code: code.into(),
+            data: Default::default(),
+            log: log.to_string(),
+            hash: Default::default(),
+        };
+
+        assert_eq!(check_tx_error_kind(&response(0, "")), None);
+        assert_eq!(
+            check_tx_error_kind(&response(13, "insufficient fee")),
+            Some(ErrorKind::InsufficientFee)
+        );
+        assert_eq!(
+            check_tx_error_kind(&response(
+                1,
+                "failed to execute message; message index: 0: packet messages are redundant"
+            )),
+            Some(ErrorKind::RedundantPackets)
+        );
+    }
+
+    #[test]
+    fn nested_error_kinds() {
+        let chain_id = ChainId::from_string("chain-a");
+
+        assert_eq!(
+            LinkError::relayer(Error::tx_simulate_gas_estimate_exceeded(
+                chain_id.clone(),
+                400_000,
+                300_000,
+            ))
+            .error_kind(),
+            ErrorKind::OutOfGas
+        );
+        assert_eq!(
+            LinkError::client(ForeignClientError::expired_or_frozen(
+                ClientId::default(),
+                chain_id,
+                "expired".to_string(),
+            ))
+            .error_kind(),
+            ErrorKind::ClientExpired
+        );
+        assert_eq!(
+            LinkError::send(IbcEvent::ChainError(
+                "failed to execute message; message index: 0: packet messages are redundant"
+                    .to_string()
+            ))
+            .error_kind(),
+            ErrorKind::RedundantPackets
+        );
+        assert_eq!(
+            LinkError::old_packet_clearing_failed().error_kind(),
+            ErrorKind::Other
+        );
+    }
+}
@@ -14,7 +14,7 @@ use ibc_relayer_types::{
     },
     core::ics04_channel::{
         error::Error as ChannelError,
-        events::{self as channel_events, Attributes as ChannelAttributes},
+        events::{self as channel_events, Attributes as ChannelAttributes, UpgradeAttributes},
         packet::Packet,
         timeout::TimeoutHeight,
     },
@@ -28,6 +28,7 @@ use crate::light_client::decode_header;
 
 pub mod bus;
 pub mod monitor;
+pub mod poller;
 pub mod rpc;
 
 #[derive(Clone, Debug, Serialize)]
@@ -109,6 +110,46 @@ pub fn ibc_event_try_from_abci_event(abci_event: &AbciEvent) -> Result<IbcEvent,
             channel_close_confirm_try_from_abci_event(abci_event)
                 .map_err(IbcEventError::channel)?,
         )),
+        Ok(IbcEventType::UpgradeInitChannel) => Ok(IbcEvent::UpgradeInitChannel(
+            channel_upgrade_extract_attributes_from_tx(abci_event)
+                .map_err(IbcEventError::channel)?
+                .into(),
+        )),
+        Ok(IbcEventType::UpgradeTryChannel) => Ok(IbcEvent::UpgradeTryChannel(
+            channel_upgrade_extract_attributes_from_tx(abci_event)
+                .map_err(IbcEventError::channel)?
+                .into(),
+        )),
+        Ok(IbcEventType::UpgradeAckChannel) => Ok(IbcEvent::UpgradeAckChannel(
+            channel_upgrade_extract_attributes_from_tx(abci_event)
+                .map_err(IbcEventError::channel)?
+                .into(),
+        )),
+        Ok(IbcEventType::UpgradeConfirmChannel) => Ok(IbcEvent::UpgradeConfirmChannel(
+            channel_upgrade_extract_attributes_from_tx(abci_event)
+                .map_err(IbcEventError::channel)?
+                .into(),
+        )),
+        Ok(IbcEventType::UpgradeOpenChannel) => Ok(IbcEvent::UpgradeOpenChannel(
+            channel_upgrade_extract_attributes_from_tx(abci_event)
+                .map_err(IbcEventError::channel)?
+                .into(),
+        )),
+        Ok(IbcEventType::UpgradeTimeoutChannel) => Ok(IbcEvent::UpgradeTimeoutChannel(
+            channel_upgrade_extract_attributes_from_tx(abci_event)
+                .map_err(IbcEventError::channel)?
+                .into(),
+        )),
+        Ok(IbcEventType::UpgradeCancelChannel) => Ok(IbcEvent::UpgradeCancelChannel(
+            channel_upgrade_extract_attributes_from_tx(abci_event)
+                .map_err(IbcEventError::channel)?
+                .into(),
+        )),
+        Ok(IbcEventType::UpgradeErrorChannel) => Ok(IbcEvent::UpgradeErrorChannel(
+            channel_upgrade_extract_attributes_from_tx(abci_event)
+                .map_err(IbcEventError::channel)?
+                .into(),
+        )),
         Ok(IbcEventType::SendPacket) => Ok(IbcEvent::SendPacket(
             send_packet_try_from_abci_event(abci_event).map_err(IbcEventError::channel)?,
         )),
@@ -397,6 +438,37 @@ fn channel_extract_attributes_from_tx(
     Ok(attr)
 }
 
+fn channel_upgrade_extract_attributes_from_tx(
+    event: &AbciEvent,
+) -> Result<UpgradeAttributes, ChannelError> {
+    let mut attr = UpgradeAttributes::default();
+
+    for tag in &event.attributes {
+        let key = tag.key.as_str();
+        let value = tag.value.as_str();
+        match key {
+            channel_events::PORT_ID_ATTRIBUTE_KEY => {
+                attr.port_id = value.parse().map_err(ChannelError::identifier)?
+            }
+            channel_events::CHANNEL_ID_ATTRIBUTE_KEY => {
+                attr.channel_id = value.parse().map_err(ChannelError::identifier)?;
+            }
+            channel_events::COUNTERPARTY_PORT_ID_ATTRIBUTE_KEY => {
+                attr.counterparty_port_id = value.parse().map_err(ChannelError::identifier)?;
+            }
+            channel_events::COUNTERPARTY_CHANNEL_ID_ATTRIBUTE_KEY => {
+                attr.counterparty_channel_id = value.parse().ok();
+            }
+            channel_events::UPGRADE_SEQUENCE_ATTRIBUTE_KEY => {
+                attr.upgrade_sequence = value.parse()?;
+            }
+            _ => {}
+        }
+    }
+
+    Ok(attr)
+}
+
 pub fn extract_packet_and_write_ack_from_tx(
     event: &AbciEvent,
 ) -> Result<(Packet, Vec<u8>), ChannelError> {
@@ -564,6 +636,33 @@ mod tests {
         }
     }
 
+    #[test]
+    fn channel_upgrade_event_to_abci_event() {
+        let attributes = UpgradeAttributes {
+            port_id: "transfer".parse().unwrap(),
+            channel_id: "channel-0".parse().unwrap(),
+            counterparty_port_id: "transfer".parse().unwrap(),
+            counterparty_channel_id: Some("channel-1".parse().unwrap()),
+            upgrade_sequence: 2.into(),
+        };
+        let abci_events = vec![
+            AbciEvent::from(channel_events::UpgradeInit::from(attributes.clone())),
+            AbciEvent::from(channel_events::UpgradeTry::from(attributes.clone())),
+            AbciEvent::from(channel_events::UpgradeAck::from(attributes.clone())),
+            AbciEvent::from(channel_events::UpgradeConfirm::from(attributes.clone())),
+            AbciEvent::from(channel_events::UpgradeOpen::from(attributes.clone())),
+            AbciEvent::from(channel_events::UpgradeTimeout::from(attributes.clone())),
+            AbciEvent::from(channel_events::UpgradeCancel::from(attributes.clone())),
+            AbciEvent::from(channel_events::UpgradeError::from(attributes.clone())),
+        ];
+
+        for abci_event in abci_events {
+            let ibc_event = ibc_event_try_from_abci_event(&abci_event).unwrap();
+            assert_eq!(ibc_event.event_type().as_str(), abci_event.kind);
+            assert_eq!(ibc_event.upgrade_attributes(), Some(attributes.clone()));
+        }
+    }
+
     #[test]
     fn packet_event_to_abci_event() {
         let packet = Packet {
@@ -10,15 +10,19 @@ use futures::{
 };
 use tokio::task::JoinHandle;
 use tokio::{runtime::Runtime as TokioRuntime, sync::mpsc};
-use tracing::{debug, error, info, instrument, trace};
+use tracing::{debug, error, info, instrument, trace, warn};
 
 use tendermint_rpc::{
-    client::CompatMode, event::Event as RpcEvent, query::Query, Error as RpcError,
-    SubscriptionClient, WebSocketClient, WebSocketClientDriver, WebSocketClientUrl,
+    client::CompatMode,
+    event::{Event as RpcEvent, EventData as RpcEventData},
+    query::Query,
+    Error as RpcError, SubscriptionClient, WebSocketClient, WebSocketClientDriver,
+    WebSocketClientUrl,
 };
 
 use ibc_relayer_types::{
     core::ics02_client::height::Height, core::ics24_host::identifier::ChainId, events::IbcEvent,
+    timestamp::Timestamp,
 };
 
 use crate::{
@@ -58,6 +62,8 @@ pub struct EventBatch {
     pub chain_id: ChainId,
     pub tracking_id: TrackingId,
     pub height: Height,
+    /// The time of the block at `height`, if the event source received it
+    pub block_time: Option<Timestamp>,
     pub events: Vec<IbcEventWithHeight>,
 }
 
@@ -68,7 +74,7 @@ pub type EventSender = channel::Sender<Result<EventBatch>>;
 pub type EventReceiver = channel::Receiver<Result<EventBatch>>;
 
 #[derive(Clone, Debug)]
-pub struct TxMonitorCmd(channel::Sender<MonitorCmd>);
+pub struct TxMonitorCmd(pub(super) channel::Sender<MonitorCmd>);
 
 impl TxMonitorCmd {
     pub fn shutdown(&self) -> Result<()> {
@@ -118,8 +124,10 @@ pub struct EventMonitor {
     tx_err: mpsc::UnboundedSender<tendermint_rpc::Error>,
     /// Channel where to receive commands
     rx_cmd: channel::Receiver<MonitorCmd>,
-    /// Node Address
-    ws_url: WebSocketClientUrl,
+    /// Node addresses, in order of preference
+    ws_urls: Vec<WebSocketClientUrl>,
+    /// Index of the address of the node currently connected to
+    ws_url_index: usize,
     /// RPC compatibility mode
     rpc_compat: CompatMode,
     /// Queries
@@ -169,7 +177,11 @@ pub mod queries {
 }
 
 impl EventMonitor {
-    /// Create an event monitor, and connect to a node
+    /// Create an event monitor, and connect to a node.
+    ///
+    /// The backup addresses are tried in order when the node at the given
+    /// address cannot be reached, and whenever the connection is lost and
+    /// cannot be re-established with the node currently connected to.
     #[instrument(
         name = "event_monitor.create",
         level = "error",
@@ -179,6 +191,7 @@ impl EventMonitor {
     pub fn new(
         chain_id: ChainId,
         ws_url: WebSocketClientUrl,
+        backup_ws_urls: Vec<WebSocketClientUrl>,
         rpc_compat: CompatMode,
         batch_delay: Duration,
         rt: Arc<TokioRuntime>,
@@ -186,11 +199,25 @@ impl EventMonitor {
         let event_bus = EventBus::new();
         let (tx_cmd, rx_cmd) = channel::unbounded();
 
-        let builder = WebSocketClient::builder(ws_url.clone()).compat_mode(rpc_compat);
+        let ws_urls: Vec<_> = core::iter::once(ws_url).chain(backup_ws_urls).collect();
+
+        let mut connection = None;
 
-        let (client, driver) = rt
-            .block_on(builder.build())
-            .map_err(|_| Error::client_creation_failed(chain_id.clone(), ws_url.clone()))?;
+        for (index, ws_url) in ws_urls.iter().enumerate() {
+            let builder = WebSocketClient::builder(ws_url.clone()).compat_mode(rpc_compat);
+
+            match rt.block_on(builder.build()) {
+                Ok(client) => {
+                    connection = Some((index, client));
+                    break;
+                }
+                Err(e) => warn!("failed to connect to WebSocket endpoint {ws_url}: {e}"),
+            }
+        }
+
+        let (ws_url_index, (client, driver)) = connection.ok_or_else(|| {
+            Error::client_creation_failed(chain_id.clone(), ws_urls[ws_urls.len() - 1].clone())
+        })?;
 
         let (tx_err, rx_err) = mpsc::unbounded_channel();
         let driver_handle = rt.spawn(run_driver(driver, tx_err.clone()));
@@ -209,7 +236,8 @@ impl EventMonitor {
             rx_err,
             tx_err,
             rx_cmd,
-            ws_url,
+            ws_urls,
+            ws_url_index,
             rpc_compat,
             subscriptions: Box::new(futures::stream::empty()),
         };
@@ -217,6 +245,19 @@ impl EventMonitor {
         Ok((monitor, TxMonitorCmd(tx_cmd)))
     }
 
+    /// The address of the node currently connected to.
+    fn ws_url(&self) -> &WebSocketClientUrl {
+        &self.ws_urls[self.ws_url_index]
+    }
+
+    /// Switch to the next node address, if any, for the next reconnection attempt.
+    fn switch_ws_url(&mut self) {
+        if self.ws_urls.len() > 1 {
+            self.ws_url_index = (self.ws_url_index + 1) % self.ws_urls.len();
+            debug!("switching to WebSocket endpoint {}", self.ws_url());
+        }
+    }
+
     /// The list of [`Query`] that this event monitor is subscribing for.
     pub fn queries(&self) -> &[Query] {
         &self.event_queries
@@ -252,13 +293,16 @@ impl EventMonitor {
         fields(chain = %self.chain_id)
     )]
     fn try_reconnect(&mut self) -> Result<()> {
-        trace!("trying to reconnect to WebSocket endpoint {}", self.ws_url);
+        trace!(
+            "trying to reconnect to WebSocket endpoint {}",
+            self.ws_url()
+        );
 
         // Try to reconnect
-        let builder = WebSocketClient::builder(self.ws_url.clone()).compat_mode(self.rpc_compat);
+        let builder = WebSocketClient::builder(self.ws_url().clone()).compat_mode(self.rpc_compat);
 
         let (mut client, driver) = self.rt.block_on(builder.build()).map_err(|_| {
-            Error::client_creation_failed(self.chain_id.clone(), self.ws_url.clone())
+            Error::client_creation_failed(self.chain_id.clone(), self.ws_url().clone())
         })?;
 
         let mut driver_handle = self.rt.spawn(run_driver(driver, self.tx_err.clone()));
@@ -268,7 +312,7 @@ impl EventMonitor {
         core::mem::swap(&mut self.client, &mut client);
         core::mem::swap(&mut self.driver_handle, &mut driver_handle);
 
-        trace!("reconnected to WebSocket endpoint {}", self.ws_url);
+        trace!("reconnected to WebSocket endpoint {}", self.ws_url());
 
         // Shut down previous client
         trace!("gracefully shutting down previous client",);
@@ -298,6 +342,8 @@ impl EventMonitor {
 
     /// Attempt to reconnect the WebSocket client using the given retry strategy.
     ///
+    /// Each failed attempt switches to the next node address, if backup addresses are configured.
+    ///
     /// See the [`retry`](https://docs.rs/retry) crate and the
     /// [`crate::util::retry`] module for more information.
     #[instrument(
@@ -311,12 +357,14 @@ impl EventMonitor {
             // Try to reconnect
             if let Err(e) = self.try_reconnect() {
                 trace!("error when reconnecting: {}", e);
+                self.switch_ws_url();
                 return RetryResult::Retry(());
             }
 
             // Try to resubscribe
             if let Err(e) = self.try_resubscribe() {
                 trace!("error when resubscribing: {}", e);
+                self.switch_ws_url();
                 return RetryResult::Retry(());
             }
 
@@ -326,11 +374,12 @@ impl EventMonitor {
         match result {
             Ok(()) => info!(
                 "successfully reconnected to WebSocket endpoint {}",
-                self.ws_url
+                self.ws_url()
             ),
             Err(e) => error!(
                 "failed to reconnect to {} after {} retries",
-                self.ws_url, e.tries
+                self.ws_url(),
+                e.tries
             ),
         }
     }
@@ -458,13 +507,21 @@ impl EventMonitor {
     }
 }
 
-/// Collect the IBC events from an RPC event
+/// Collect the IBC events from an RPC event, along with the time of the
+/// block of the event, which is only known for `NewBlock` events
 fn collect_events(
     chain_id: &ChainId,
     event: RpcEvent,
-) -> impl Stream<Item = Result<IbcEventWithHeight>> {
+) -> impl Stream<Item = Result<(IbcEventWithHeight, Option<Timestamp>)>> {
+    let block_time = match &event.data {
+        RpcEventData::NewBlock {
+            block: Some(block), ..
+        } => Some(Timestamp::from(block.header.time)),
+        _ => None,
+    };
+
     let events = crate::event::rpc::get_all_events(chain_id, event).unwrap_or_default();
-    stream::iter(events).map(Ok)
+    stream::iter(events).map(move |event| Ok((event, block_time)))
 }
 
 /// Convert a stream of RPC event into a stream of event batches
@@ -485,21 +542,30 @@ fn stream_batches(
         .try_flatten();
 
     // Group events by height
-    let grouped = try_group_while_timeout(events, |ev0, ev1| ev0.height == ev1.height, batch_delay);
+    let grouped = try_group_while_timeout(
+        events,
+        |(ev0, _), (ev1, _)| ev0.height == ev1.height,
+        batch_delay,
+    );
 
     // Convert each group to a batch
-    grouped.map_ok(move |mut events_with_heights| {
-        let height = events_with_heights
+    grouped.map_ok(move |group| {
+        let height = group
             .first()
-            .map(|ev_with_height| ev_with_height.height)
+            .map(|(ev_with_height, _)| ev_with_height.height)
             .expect("internal error: found empty group"); // SAFETY: upheld by `group_while`
 
+        let block_time = group.iter().find_map(|(_, block_time)| *block_time);
+
+        let mut events_with_heights: Vec<_> = group.into_iter().map(|(event, _)| event).collect();
+
         sort_events(&mut events_with_heights);
 
         debug!(chain = %chain_id, len = %events_with_heights.len(), "assembled batch");
 
         EventBatch {
             height,
+            block_time,
             events: events_with_heights,
             chain_id: chain_id.clone(),
             tracking_id: TrackingId::new_uuid(),
@@ -0,0 +1,233 @@
+use alloc::sync::Arc;
+use std::time::Duration;
+
+use crossbeam_channel as channel;
+use tokio::runtime::Runtime as TokioRuntime;
+use tracing::{debug, error, instrument, trace, warn};
+
+use tendermint::block::Height as BlockHeight;
+use tendermint_rpc::{Client, HttpClient};
+
+use ibc_relayer_types::{core::ics24_host::identifier::ChainId, timestamp::Timestamp};
+
+use crate::chain::cosmos::endpoints::{Endpoints, RpcEndpoint};
+use crate::chain::tracking::TrackingId;
+use crate::telemetry;
+use crate::util::lock::{LockExt, RwArc};
+
+use super::{
+    bus::EventBus,
+    monitor::{Error, EventBatch, MonitorCmd, Next, Result, TxMonitorCmd},
+    rpc::get_all_events_from_block_results,
+};
+
+/// Pull-based alternative to the [`EventMonitor`](super::monitor::EventMonitor),
+/// for nodes whose WebSocket endpoint is unreliable or unavailable.
+///
+/// Polls the `/block_results` RPC endpoint of a node at a fixed interval,
+/// and broadcasts the IBC events of every new block as an [`EventBatch`]
+/// to its subscribers, exactly like the event monitor does.
+///
+/// Blocks are fetched one after the other, without skipping any height,
+/// so that a failed RPC request only delays the events until the next poll.
+/// Every poll keeps fetching blocks until it has caught up with the node.
+///
+/// The poller sends its requests to the RPC endpoint currently in use by the
+/// chain, and switches to another endpoint when a request fails.
+pub struct EventPoller {
+    chain_id: ChainId,
+    /// RPC endpoints of the chain, shared with the chain runtime
+    rpc_endpoints: RwArc<Endpoints<RpcEndpoint>>,
+    /// Interval between two polls
+    poll_interval: Duration,
+    /// Event bus for broadcasting events
+    event_bus: EventBus<Arc<Result<EventBatch>>>,
+    /// Channel where to receive commands
+    rx_cmd: channel::Receiver<MonitorCmd>,
+    /// Height of the latest block whose events have been broadcast
+    latest_height: Option<BlockHeight>,
+    /// Tokio runtime
+    rt: Arc<TokioRuntime>,
+}
+
+impl EventPoller {
+    /// Create an event poller for the nodes behind the given RPC endpoints
+    pub fn new(
+        chain_id: ChainId,
+        rpc_endpoints: RwArc<Endpoints<RpcEndpoint>>,
+        poll_interval: Duration,
+        rt: Arc<TokioRuntime>,
+    ) -> (Self, TxMonitorCmd) {
+        let (tx_cmd, rx_cmd) = channel::unbounded();
+
+        let poller = Self {
+            chain_id,
+            rpc_endpoints,
+            poll_interval,
+            event_bus: EventBus::new(),
+            rx_cmd,
+            latest_height: None,
+            rt,
+        };
+
+        (poller, TxMonitorCmd(tx_cmd))
+    }
+
+    /// Event poller loop
+    #[instrument(
+        name = "event_poller",
+        level = "error",
+        skip_all,
+        fields(chain = %self.chain_id)
+    )]
+    pub fn run(mut self) {
+        debug!("starting event poller");
+
+        loop {
+            let next = match self.rx_cmd.recv_timeout(self.poll_interval) {
+                Ok(cmd) => self.handle_cmd(cmd),
+                Err(channel::RecvTimeoutError::Disconnected) => Next::Abort,
+                Err(channel::RecvTimeoutError::Timeout) => self.poll().unwrap_or_else(|e| {
+                    error!("failed to collect events: {e}");
+                    self.propagate_error(e);
+                    Next::Continue
+                }),
+            };
+
+            if let Next::Abort = next {
+                break;
+            }
+        }
+
+        debug!("event poller is shutting down");
+    }
+
+    fn handle_cmd(&mut self, cmd: MonitorCmd) -> Next {
+        match cmd {
+            MonitorCmd::Shutdown => Next::Abort,
+            MonitorCmd::Subscribe(tx) => {
+                if let Err(e) = tx.send(self.event_bus.subscribe()) {
+                    error!("failed to send back subscription: {e}");
+                }
+                Next::Continue
+            }
+        }
+    }
+
+    /// Broadcast the events of the blocks committed since the previous poll,
+    /// until the poller has caught up with the latest height of the node.
+    /// The commands received in the meantime are handled between two blocks.
+    ///
+    /// The first poll only broadcasts the events of the latest block,
+    /// in the same way as a fresh WebSocket subscription would.
+    fn poll(&mut self) -> Result<Next> {
+        loop {
+            let status = self
+                .rt
+                .block_on(self.rpc_client().status())
+                .map_err(|e| self.rpc_failure(e))?;
+            let latest_height = status.sync_info.latest_block_height;
+
+            if self.latest_height >= Some(latest_height) {
+                return Ok(Next::Continue);
+            }
+
+            let start = match self.latest_height {
+                Some(height) => height.increment().value(),
+                None => latest_height.value(),
+            };
+
+            for height in start..=latest_height.value() {
+                while let Ok(cmd) = self.rx_cmd.try_recv() {
+                    if let Next::Abort = self.handle_cmd(cmd) {
+                        return Ok(Next::Abort);
+                    }
+                }
+
+                let height = BlockHeight::try_from(height).map_err(|e| {
+                    Error::collect_events_failed(format!("invalid block height {height}: {e}"))
+                })?;
+
+                let batch = self.fetch_batch(height)?;
+
+                self.latest_height = Some(height);
+                self.process_batch(batch);
+            }
+        }
+    }
+
+    /// The client for the RPC endpoint currently in use
+    fn rpc_client(&self) -> HttpClient {
+        self.rpc_endpoints.acquire_read().current().client.clone()
+    }
+
+    /// Record that a request to the RPC endpoint currently in use failed,
+    /// and switch to another endpoint for the next requests, if any.
+    fn rpc_failure(&self, e: tendermint_rpc::Error) -> Error {
+        let mut endpoints = self.rpc_endpoints.acquire_write();
+        let failed = endpoints.current().to_string();
+
+        if let Some(next) = endpoints.record_failure() {
+            warn!("request to endpoint {failed} failed, switching to endpoint {next}");
+        }
+
+        Error::rpc(e)
+    }
+
+    /// Fetch the results of the block at the given height, and collect its IBC events
+    fn fetch_batch(&self, height: BlockHeight) -> Result<EventBatch> {
+        trace!("fetching results of block {height}");
+
+        let block_results = self
+            .rt
+            .block_on(self.rpc_client().block_results(height))
+            .map_err(|e| self.rpc_failure(e))?;
+
+        let events = get_all_events_from_block_results(&self.chain_id, &block_results)
+            .map_err(Error::collect_events_failed)?;
+
+        let batch_height = events
+            .first()
+            .map(|ev_with_height| ev_with_height.height)
+            .expect("internal error: missing NewBlock event"); // SAFETY: upheld by `get_all_events_from_block_results`
+
+        // The results of a block do not hold its time, which is only needed to record
+        // the packet latencies of blocks with IBC events besides the `NewBlock` event
+        let block_time = if events.len() > 1 && telemetry::packet_latencies() {
+            self.fetch_block_time(height)
+        } else {
+            None
+        };
+
+        Ok(EventBatch {
+            chain_id: self.chain_id.clone(),
+            tracking_id: TrackingId::new_uuid(),
+            height: batch_height,
+            block_time,
+            events,
+        })
+    }
+
+    /// Fetch the time of the block at the given height, if available
+    fn fetch_block_time(&self, height: BlockHeight) -> Option<Timestamp> {
+        match self.rt.block_on(self.rpc_client().block(height)) {
+            Ok(response) => Some(Timestamp::from(response.block.header.time)),
+            Err(e) => {
+                debug!("failed to fetch the time of block {height}: {e}");
+                None
+            }
+        }
+    }
+
+    /// Propagate the error to the subscribers, in the same way as the event monitor does,
+    /// so that the supervisor notices that events may be delayed.
+    fn propagate_error(&mut self, error: Error) {
+        self.event_bus.broadcast(Arc::new(Err(error)));
+    }
+
+    fn process_batch(&mut self, batch: EventBatch) {
+        debug!(chain = %batch.chain_id, len = %batch.events.len(), "emitting batch");
+
+        self.event_bus.broadcast(Arc::new(Ok(batch)));
+    }
+}
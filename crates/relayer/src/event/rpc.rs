@@ -2,7 +2,10 @@ use alloc::collections::BTreeMap as HashMap;
 use core::convert::TryFrom;
 use ibc_relayer_types::applications::ics29_fee::events::DistributionType;
 
-use tendermint_rpc::{event::Event as RpcEvent, event::EventData as RpcEventData};
+use tendermint_rpc::{
+    endpoint::block_results::Response as BlockResultsResponse, event::Event as RpcEvent,
+    event::EventData as RpcEventData,
+};
 
 use ibc_relayer_types::applications::ics31_icq::events::CrossChainQueryPacket;
 use ibc_relayer_types::core::ics02_client::{events as ClientEvents, height::Height};
@@ -204,6 +207,62 @@ pub fn get_all_events(
     Ok(events_with_height)
 }
 
+/// Extract IBC events from the results of a block, as returned by the `/block_results` RPC endpoint.
+///
+/// Unlike the events pushed over WebSocket, the results of a block hold the events emitted by
+/// `BeginBlock`, `DeliverTx` and `EndBlock` as plain ABCI events, which can all be decoded with
+/// [`ibc_event_try_from_abci_event`]. The returned events start with a `NewBlock` event,
+/// followed by the IBC events of the block in the order in which they were emitted.
+///
+/// Only the IBC events matched by the queries of the event monitor are kept,
+/// so that both event sources feed the same events to the relayer.
+pub fn get_all_events_from_block_results(
+    chain_id: &ChainId,
+    block_results: &BlockResultsResponse,
+) -> Result<Vec<IbcEventWithHeight>, String> {
+    let height = Height::new(
+        ChainId::chain_version(chain_id.to_string().as_str()),
+        u64::from(block_results.height),
+    )
+    .map_err(|_| String::from("block_results.height: invalid block height of 0"))?;
+
+    let begin_block_events = block_results.begin_block_events.iter().flatten();
+    let end_block_events = block_results.end_block_events.iter().flatten();
+    let tx_events = block_results
+        .txs_results
+        .iter()
+        .flatten()
+        .flat_map(|tx_result| &tx_result.events);
+
+    let mut events_with_height = vec![IbcEventWithHeight::new(
+        ClientEvents::NewBlock::new(height).into(),
+        height,
+    )];
+
+    for abci_event in begin_block_events.chain(tx_events).chain(end_block_events) {
+        let Ok(ibc_event) = ibc_event_try_from_abci_event(abci_event) else {
+            continue;
+        };
+
+        if event_is_type_client(&ibc_event)
+            || event_is_type_connection(&ibc_event)
+            || event_is_type_channel(&ibc_event)
+            || event_is_type_cross_chain_query(&ibc_event)
+            || event_is_type_incentivized(&ibc_event)
+        {
+            tracing::trace!("extracted {}", ibc_event);
+            events_with_height.push(IbcEventWithHeight::new(ibc_event, height));
+        } else if let IbcEvent::DistributeFeePacket(dist) = ibc_event {
+            // Only record rewarded fees
+            if let DistributionType::Reward = dist.distribution_type {
+                telemetry!(fees_amount, chain_id, &dist.receiver, dist.fee);
+            }
+        }
+    }
+
+    Ok(events_with_height)
+}
+
 fn event_is_type_client(ev: &IbcEvent) -> bool {
     matches!(
         ev,
@@ -334,3 +393,62 @@ fn extract_block_events(
 
     events
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ibc_relayer_types::core::ics03_connection::events::{
+        self as ConnectionEvents, Attributes as ConnectionAttributes,
+    };
+    use ibc_relayer_types::events::IbcEventType;
+    use tendermint::abci::{response::DeliverTx, Event as AbciEvent};
+
+    #[test]
+    fn block_results_events_in_emission_order() {
+        let attributes = ConnectionAttributes {
+            connection_id: Some("connection-0".parse().unwrap()),
+            client_id: "07-tendermint-0".parse().unwrap(),
+            counterparty_connection_id: Some("connection-1".parse().unwrap()),
+            counterparty_client_id: "07-tendermint-1".parse().unwrap(),
+        };
+
+        let open_init = ConnectionEvents::OpenInit::from(attributes.clone());
+        let open_try = ConnectionEvents::OpenTry::from(attributes.clone());
+        let open_ack = ConnectionEvents::OpenAck::from(attributes);
+
+        let tx_result = DeliverTx {
+            events: vec![
+                AbciEvent::new("transfer", [("amount", "100stake")]),
+                AbciEvent::from(open_try),
+            ],
+            ..Default::default()
+        };
+
+        let block_results = BlockResultsResponse {
+            height: 10_u32.into(),
+            txs_results: Some(vec![tx_result]),
+            begin_block_events: Some(vec![AbciEvent::from(open_init)]),
+            end_block_events: Some(vec![AbciEvent::from(open_ack)]),
+            validator_updates: vec![],
+            consensus_param_updates: None,
+        };
+
+        let chain_id = ChainId::new("ibc".to_string(), 0);
+        let events = get_all_events_from_block_results(&chain_id, &block_results).unwrap();
+
+        let height = Height::new(0, 10).unwrap();
+        assert!(events.iter().all(|ev| ev.height == height));
+
+        let event_types: Vec<_> = events.iter().map(|ev| ev.event.event_type()).collect();
+        assert_eq!(
+            event_types,
+            vec![
+                IbcEventType::NewBlock,
+                IbcEventType::OpenInitConnection,
+                IbcEventType::OpenTryConnection,
+                IbcEventType::OpenAckConnection,
+            ]
+        );
+    }
+}
@@ -4,7 +4,7 @@
 //! i.e. they are *foreign* to the relayer. In contrast, the term "local client"
 //! refers to light clients running *locally* as part of the relayer.
 
-use core::{fmt, time::Duration};
+use core::{fmt, iter, time::Duration};
 use std::thread;
 use std::time::Instant;
 
@@ -34,8 +34,10 @@ use crate::chain::handle::ChainHandle;
 use crate::chain::requests::*;
 use crate::chain::tracking::TrackedMsgs;
 use crate::client_state::AnyClientState;
+use crate::config::refresh_rate::RefreshRate;
 use crate::consensus_state::AnyConsensusState;
 use crate::error::Error as RelayerError;
+use crate::error_kind::{ErrorKind, HasErrorKind};
 use crate::event::IbcEventWithHeight;
 use crate::light_client::AnyHeader;
 use crate::misbehaviour::MisbehaviourEvidence;
@@ -301,6 +303,31 @@ impl HasExpiredOrFrozenError for ForeignClientError {
     }
 }
 
+impl HasErrorKind for ForeignClientErrorDetail {
+    fn error_kind(&self) -> ErrorKind {
+        match self {
+            Self::ExpiredOrFrozen(_) | Self::ConsensusStateNotTrusted(_) => {
+                ErrorKind::ClientExpired
+            }
+            Self::ClientCreate(e) => e.source.error_kind(),
+            Self::ClientUpdate(e) => e.source.error_kind(),
+            Self::ClientRefresh(e) => e.source.error_kind(),
+            Self::ClientQuery(e) => e.source.error_kind(),
+            Self::ClientConsensusQuery(e) => e.source.error_kind(),
+            Self::ClientUpgrade(e) => e.source.error_kind(),
+            Self::ClientEventQuery(e) => e.source.error_kind(),
+            Self::Misbehaviour(e) => e.source.error_kind(),
+            _ => ErrorKind::Other,
+        }
+    }
+}
+
+impl HasErrorKind for ForeignClientError {
+    fn error_kind(&self) -> ErrorKind {
+        self.detail().error_kind()
+    }
+}
+
 /// User-supplied options for the [`ForeignClient::build_create_client`] operation.
 ///
 /// Currently, the parameters are specific to the Tendermint-based chains.
@@ -310,6 +337,10 @@ pub struct CreateOptions {
     pub max_clock_drift: Option<Duration>,
     pub trusting_period: Option<Duration>,
     pub trust_threshold: Option<TrustThreshold>,
+
+    /// Checksum of the light client contract with which to create an 08-wasm client,
+    /// for hosts which verify the reference chain through their `08-wasm` module.
+    pub wasm_checksum: Option<Vec<u8>>,
 }
 
 /// Captures the diagnostic of verifying whether a certain
@@ -594,6 +625,7 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
                 e,
             )
         })?;
+        let wasm_checksum = options.wasm_checksum.clone();
         let settings = ClientSettings::for_create_command(options, &src_config, &dst_config);
 
         let client_state: AnyClientState = self
@@ -622,6 +654,18 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
                 )
             })?;
 
+        let (client_state, consensus_state) = match wasm_checksum {
+            Some(checksum) => (
+                client_state
+                    .wrap_wasm(checksum)
+                    .map_err(ForeignClientError::client)?,
+                consensus_state
+                    .wrap_wasm()
+                    .map_err(ForeignClientError::client)?,
+            ),
+            None => (client_state, consensus_state),
+        };
+
         //TODO Get acct_prefix
         let msg = MsgCreateClient::new(client_state.into(), consensus_state.into(), signer)
             .map_err(ForeignClientError::client)?;
@@ -712,6 +756,12 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
             ));
         }
 
+        // The localhost client has no consensus states and is updated by
+        // the host chain itself, so it can never expire.
+        if let AnyClientState::Localhost(_) = client_state {
+            return Ok((client_state, None));
+        }
+
         match self
             .check_consensus_state_trusting_period(&client_state, &client_state.latest_height())?
         {
@@ -793,13 +843,23 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
         }
     }
 
+    pub fn refresh(&mut self) -> Result<Option<Vec<IbcEvent>>, ForeignClientError> {
+        self.refresh_with(RefreshRate::default(), None)
+    }
+
+    /// Refreshes the client once `refresh_rate` of its trusting period has elapsed
+    /// since its latest update, or once it would otherwise expire within `refresh_horizon`.
     #[instrument(
         name = "foreign_client.refresh",
         level = "error",
         skip_all,
         fields(client = %self)
     )]
-    pub fn refresh(&mut self) -> Result<Option<Vec<IbcEvent>>, ForeignClientError> {
+    pub fn refresh_with(
+        &mut self,
+        refresh_rate: RefreshRate,
+        refresh_horizon: Option<Duration>,
+    ) -> Result<Option<Vec<IbcEvent>>, ForeignClientError> {
         fn check_no_errors(
             ibc_events: &[IbcEvent],
             dst_chain_id: ChainId,
@@ -821,7 +881,7 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
 
         // If elapsed < refresh_window for the client, `try_refresh()` will
         // be successful with an empty vector.
-        if let Some(events) = self.try_refresh()? {
+        if let Some(events) = self.try_refresh(refresh_rate, refresh_horizon)? {
             check_no_errors(&events, self.dst_chain().id())?;
             Ok(Some(events))
         } else {
@@ -829,18 +889,43 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
         }
     }
 
-    fn try_refresh(&mut self) -> Result<Option<Vec<IbcEvent>>, ForeignClientError> {
+    fn try_refresh(
+        &mut self,
+        refresh_rate: RefreshRate,
+        refresh_horizon: Option<Duration>,
+    ) -> Result<Option<Vec<IbcEvent>>, ForeignClientError> {
         let (client_state, elapsed) = self.validated_client_state()?;
 
-        // The refresh_window is the maximum duration
-        // we can backoff between subsequent client updates.
-        let refresh_window = client_state.refresh_period();
-
-        match (elapsed, refresh_window) {
+        match (elapsed, client_state.trusting_period()) {
             (None, _) | (_, None) => Ok(None),
-            (Some(elapsed), Some(refresh_window)) => {
-                if elapsed > refresh_window {
-                    info!(?elapsed, ?refresh_window, "client needs to be refreshed");
+            (Some(elapsed), Some(trusting_period)) => {
+                let time_until_expiry = trusting_period.saturating_sub(elapsed);
+
+                telemetry!(
+                    client_time_until_expiry,
+                    &self.src_chain.id(),
+                    &self.dst_chain.id(),
+                    &self.id,
+                    time_until_expiry.as_secs()
+                );
+
+                // The refresh_window is the maximum duration
+                // we can backoff between subsequent client updates.
+                let refresh_window = refresh_rate.refresh_period(trusting_period);
+
+                // A horizon which is not shorter than the trusting period would
+                // trigger an update on every check, so it is ignored.
+                let within_horizon = refresh_horizon
+                    .filter(|horizon| *horizon < trusting_period)
+                    .map_or(false, |horizon| time_until_expiry <= horizon);
+
+                if elapsed > refresh_window || within_horizon {
+                    info!(
+                        ?elapsed,
+                        ?refresh_window,
+                        ?time_until_expiry,
+                        "client needs to be refreshed"
+                    );
 
                     self.build_latest_update_client_and_send()
                         .map_or_else(Err, |ev| Ok(Some(ev)))
@@ -1061,6 +1146,12 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
         target_height: Height,
         trusted_height: Option<Height>,
     ) -> Result<Vec<Any>, ForeignClientError> {
+        // The localhost client is updated by the host chain at every block,
+        // so there is no need to wait for the target height to be reached.
+        if self.id.is_localhost() {
+            return Ok(vec![]);
+        }
+
         crate::time!(
             "wait_and_build_update_client_with_trusted",
             {
@@ -1114,6 +1205,22 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
         // Get the latest client state on destination.
         let (client_state, _) = self.validated_client_state()?;
 
+        // Solo machine clients advance with every signature they verify, so they
+        // only need to be updated when some of the signed proofs were not submitted,
+        // in which case they catch up with the sequence of the target proofs.
+        if let AnyClientState::Solomachine(_) = client_state {
+            if client_state.latest_height() == target_height {
+                return Ok(vec![]);
+            }
+
+            return self.build_update_solomachine(target_height, &client_state);
+        }
+
+        // The localhost client is updated by the host chain at every block.
+        if let AnyClientState::Localhost(_) = client_state {
+            return Ok(vec![]);
+        }
+
         let trusted_height = match maybe_trusted_height {
             Some(trusted_height) => {
                 self.validate_trusted_height(trusted_height, &client_state)?;
@@ -1157,7 +1264,7 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
 
         let (header, support) = self
             .src_chain()
-            .build_header(trusted_height, target_height, client_state.clone())
+            .build_header(trusted_height, target_height, client_state.unwrap_wasm())
             .map_err(|e| {
                 ForeignClientError::client_update(
                     self.dst_chain.id(),
@@ -1176,6 +1283,19 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
 
         self.wait_for_header_validation_delay(&client_state, &header)?;
 
+        // The headers of 08-wasm clients wrap the headers of the reference chain
+        let (header, support) = match client_state {
+            AnyClientState::Wasm(_) => (
+                header.wrap_wasm().map_err(ForeignClientError::client)?,
+                support
+                    .into_iter()
+                    .map(AnyHeader::wrap_wasm)
+                    .collect::<Result<_, _>>()
+                    .map_err(ForeignClientError::client)?,
+            ),
+            _ => (header, support),
+        };
+
         let mut msgs = vec![];
 
         for header in support {
@@ -1214,6 +1334,65 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
         Ok(msgs)
     }
 
+    /// Builds the messages which move a solo machine client to the sequence of the
+    /// proofs signed at `target_height`. Solo machine clients have no trusting period,
+    /// so the headers only need to follow the sequence of the client.
+    fn build_update_solomachine(
+        &self,
+        target_height: Height,
+        client_state: &AnyClientState,
+    ) -> Result<Vec<MsgUpdateClient>, ForeignClientError> {
+        let (header, support) = self
+            .src_chain()
+            .build_header(
+                client_state.latest_height(),
+                target_height,
+                client_state.clone(),
+            )
+            .map_err(|e| {
+                ForeignClientError::client_update(
+                    self.dst_chain.id(),
+                    "failed building header with error".to_string(),
+                    e,
+                )
+            })?;
+
+        let signer = self.dst_chain().get_signer().map_err(|e| {
+            ForeignClientError::client_update(
+                self.dst_chain.id(),
+                "failed getting signer for dst chain".to_string(),
+                e,
+            )
+        })?;
+
+        debug!(
+            "building {} MsgUpdateAnyClient from sequence {} to sequence {}",
+            support.len() + 1,
+            client_state.latest_height(),
+            target_height,
+        );
+
+        let msgs: Vec<_> = support
+            .into_iter()
+            .chain(iter::once(header))
+            .map(|header| MsgUpdateClient {
+                header: header.into(),
+                client_id: self.id.clone(),
+                signer: signer.clone(),
+            })
+            .collect();
+
+        telemetry!(
+            client_updates_submitted,
+            &self.src_chain.id(),
+            &self.dst_chain.id(),
+            &self.id,
+            msgs.len() as u64
+        );
+
+        Ok(msgs)
+    }
+
     pub fn build_latest_update_client_and_send(&self) -> Result<Vec<IbcEvent>, ForeignClientError> {
         self.build_update_client_and_send(QueryHeight::Latest, None)
     }
@@ -1497,6 +1676,11 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
                 })?
         };
 
+        // The localhost client does not verify headers, so it cannot be misbehaving.
+        if let AnyClientState::Localhost(_) = client_state {
+            return Ok(None);
+        }
+
         let consensus_state_heights = if let Some(event) = update {
             vec![event.consensus_height()]
         } else {
@@ -1562,7 +1746,7 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
             // a header for the event height cannot be retrieved from the witness.
             let result = self
                 .src_chain
-                .check_misbehaviour(update_event.clone(), client_state.clone());
+                .check_misbehaviour(update_event.clone(), client_state.unwrap_wasm());
 
             let misbehavior = match result {
                 // Misbehavior check passed.
@@ -1594,8 +1778,16 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
                 }
             };
 
-            if misbehavior.is_some() {
-                return Ok(misbehavior);
+            if let Some(evidence) = misbehavior {
+                // The evidence must be wrapped like the headers of 08-wasm clients
+                let evidence = match client_state {
+                    AnyClientState::Wasm(_) => {
+                        evidence.wrap_wasm().map_err(ForeignClientError::client)?
+                    }
+                    _ => evidence,
+                };
+
+                return Ok(Some(evidence));
             }
 
             // Exit the loop if more than MAX_MISBEHAVIOUR_CHECK_DURATION was spent here.
@@ -1,31 +1,42 @@
 pub mod errors;
 pub use any_signing_key_pair::AnySigningKeyPair;
 pub use ed25519_key_pair::Ed25519KeyPair;
+pub use encryption::{
+    KdfParams, Passphrase, KEYSTORE_PASSPHRASE_ENV, KEYSTORE_PASSPHRASE_FILE_ENV,
+};
 pub use key_type::KeyType;
+pub use remote_signer::RemoteSigner;
 pub use secp256k1_key_pair::Secp256k1KeyPair;
+pub use secp256k1_signing_key::Secp256k1SigningKey;
 pub use signing_key_pair::{SigningKeyPair, SigningKeyPairSized};
 
 mod any_signing_key_pair;
 mod ed25519_key_pair;
+mod encryption;
 mod key_type;
 mod key_utils;
 mod pub_key;
+mod remote_signer;
 mod secp256k1_key_pair;
+mod secp256k1_signing_key;
 mod signing_key_pair;
 
 use alloc::collections::btree_map::BTreeMap as HashMap;
 use std::ffi::OsStr;
-use std::fs::{self, File};
-use std::path::PathBuf;
+use std::fs::{self, File, OpenOptions};
+use std::path::{Path, PathBuf};
 
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 use serde::{Deserialize, Serialize};
+use zeroize::Zeroizing;
 
 use crate::{chain::ChainType, config::ChainConfig};
-use errors::Error;
+use encryption::EncryptedKeyFile;
+use errors::{Error, ErrorDetail};
 
 pub const KEYSTORE_DEFAULT_FOLDER: &str = ".hermes/keys/";
 pub const KEYSTORE_DISK_BACKEND: &str = "keyring-test";
+pub const KEYSTORE_ENCRYPTED_BACKEND: &str = "keyring-encrypted";
 pub const KEYSTORE_FILE_EXTENSION: &str = "json";
 
 /// JSON key seed file
@@ -160,22 +171,133 @@ impl<S: SigningKeyPairSized> KeyStore<S> for Test {
     }
 
     fn keys(&self) -> Result<Vec<(String, S)>, Error> {
-        let dir = fs::read_dir(&self.store).map_err(|e| {
-            Error::key_file_io(
-                self.store.display().to_string(),
-                "failed to list keys".to_string(),
-                e,
-            )
+        key_names(&self.store)?
+            .into_iter()
+            .map(|name| self.get_key(&name).map(|key| (name, key)))
+            .collect()
+    }
+}
+
+/// An on-disk key store where every key entry is encrypted with a key
+/// derived from a passphrase, so that signing keys never hit the disk
+/// in plaintext.
+///
+/// Unlike the other stores, this one is not (de)serializable, so that
+/// the passphrase it holds can neither leak nor get lost along the way.
+#[derive(Clone, Debug)]
+pub struct Encrypted {
+    account_prefix: String,
+    store: PathBuf,
+    passphrase: Passphrase,
+    kdf_params: KdfParams,
+}
+
+impl Encrypted {
+    pub fn new(account_prefix: String, store: PathBuf, passphrase: Passphrase) -> Self {
+        Self {
+            account_prefix,
+            store,
+            passphrase,
+            kdf_params: KdfParams::default(),
+        }
+    }
+
+    pub fn with_kdf_params(self, kdf_params: KdfParams) -> Self {
+        Self { kdf_params, ..self }
+    }
+
+    fn key_file_path(&self, key_name: &str) -> PathBuf {
+        let mut key_file = self.store.join(key_name);
+        key_file.set_extension(KEYSTORE_FILE_EXTENSION);
+        key_file
+    }
+
+    fn decrypt_key_file(&self, key_name: &str) -> Result<Zeroizing<Vec<u8>>, Error> {
+        let key_file = self.key_file_path(key_name);
+        let file_path = key_file.display().to_string();
+
+        if !key_file.as_path().exists() {
+            return Err(Error::key_file_not_found(file_path));
+        }
+
+        let file = File::open(&key_file).map_err(|e| {
+            Error::key_file_io(file_path.clone(), "failed to open file".to_string(), e)
         })?;
 
-        let ext = OsStr::new(KEYSTORE_FILE_EXTENSION);
+        let encrypted: EncryptedKeyFile = serde_json::from_reader(file)
+            .map_err(|e| Error::key_file_decode(file_path.clone(), e))?;
+
+        encrypted.open(&self.passphrase, key_name, &file_path)
+    }
+
+    /// Ensure that the passphrase matches the one used for every key already
+    /// in the store, so that a mistyped passphrase does not silently end up
+    /// encrypting new keys under a different key.
+    fn check_passphrase(&self) -> Result<(), Error> {
+        for existing in key_names(&self.store)? {
+            match self.decrypt_key_file(&existing) {
+                Ok(_) => {}
+                Err(e) if matches!(e.detail(), ErrorDetail::KeyDecryption(_)) => {
+                    return Err(Error::passphrase_mismatch(
+                        self.key_file_path(&existing).display().to_string(),
+                    ));
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<S: SigningKeyPairSized> KeyStore<S> for Encrypted {
+    fn get_key(&self, key_name: &str) -> Result<S, Error> {
+        let plaintext = self.decrypt_key_file(key_name)?;
+
+        serde_json::from_slice(&plaintext).map_err(|e| {
+            Error::key_file_decode(self.key_file_path(key_name).display().to_string(), e)
+        })
+    }
+
+    fn add_key(&mut self, key_name: &str, key_entry: S) -> Result<(), Error> {
+        self.check_passphrase()?;
+
+        let filename = self.key_file_path(key_name);
+        let file_path = filename.display().to_string();
+
+        let plaintext = Zeroizing::new(serde_json::to_vec(&key_entry).map_err(Error::encode)?);
+        let encrypted =
+            EncryptedKeyFile::seal(&self.passphrase, self.kdf_params, key_name, &plaintext)?;
+
+        let mut options = OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+
+        // Only the owner of the key store may read the encrypted key files
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+        let file = options.open(filename).map_err(|e| {
+            Error::key_file_io(file_path.clone(), "failed to create file".to_string(), e)
+        })?;
+
+        serde_json::to_writer_pretty(file, &encrypted)
+            .map_err(|e| Error::key_file_encode(file_path, e))?;
+
+        Ok(())
+    }
+
+    fn remove_key(&mut self, key_name: &str) -> Result<(), Error> {
+        let filename = self.key_file_path(key_name);
+
+        fs::remove_file(filename.clone())
+            .map_err(|e| Error::remove_io_fail(filename.display().to_string(), e))?;
+
+        Ok(())
+    }
 
-        dir.into_iter()
-            .flatten()
-            .map(|entry| entry.path())
-            .filter(|path| path.extension() == Some(ext))
-            .flat_map(|path| path.file_stem().map(OsStr::to_owned))
-            .flat_map(|stem| stem.to_str().map(ToString::to_string))
+    fn keys(&self) -> Result<Vec<(String, S)>, Error> {
+        key_names(&self.store)?
+            .into_iter()
             .map(|name| self.get_key(&name).map(|key| (name, key)))
             .collect()
     }
@@ -185,6 +307,20 @@ impl<S: SigningKeyPairSized> KeyStore<S> for Test {
 pub enum Store {
     Memory,
     Test,
+    Encrypted,
+}
+
+impl Store {
+    /// The store on which the key management commands operate.
+    ///
+    /// Keys added to the in-memory store would be lost as soon as the
+    /// command exits, so those commands fall back to the on-disk store.
+    pub fn persistent(self) -> Self {
+        match self {
+            Self::Memory => Self::Test,
+            store => store,
+        }
+    }
 }
 
 impl Default for Store {
@@ -197,6 +333,8 @@ impl Default for Store {
 pub enum KeyRing<S> {
     Memory(Memory<S>),
     Test(Test),
+    #[serde(skip)]
+    Encrypted(Encrypted),
 }
 
 impl<S: SigningKeyPairSized> KeyRing<S> {
@@ -210,22 +348,27 @@ impl<S: SigningKeyPairSized> KeyRing<S> {
             Store::Memory => Ok(Self::Memory(Memory::new(account_prefix.to_string()))),
 
             Store::Test => {
-                let keys_folder = disk_store_path(chain_id.as_str(), ks_folder)?;
-
-                // Create keys folder if it does not exist
-                fs::create_dir_all(&keys_folder).map_err(|e| {
-                    Error::key_file_io(
-                        keys_folder.display().to_string(),
-                        "failed to create keys folder".to_string(),
-                        e,
-                    )
-                })?;
+                let keys_folder =
+                    disk_store_path(chain_id.as_str(), ks_folder, KEYSTORE_DISK_BACKEND)?;
 
                 Ok(Self::Test(Test::new(
                     account_prefix.to_string(),
                     keys_folder,
                 )))
             }
+
+            Store::Encrypted => {
+                let keys_folder =
+                    disk_store_path(chain_id.as_str(), ks_folder, KEYSTORE_ENCRYPTED_BACKEND)?;
+
+                let passphrase = Passphrase::resolve(chain_id)?;
+
+                Ok(Self::Encrypted(Encrypted::new(
+                    account_prefix.to_string(),
+                    keys_folder,
+                    passphrase,
+                )))
+            }
         }
     }
 
@@ -233,6 +376,7 @@ impl<S: SigningKeyPairSized> KeyRing<S> {
         match self {
             Self::Memory(m) => m.get_key(key_name),
             Self::Test(d) => d.get_key(key_name),
+            Self::Encrypted(e) => e.get_key(key_name),
         }
     }
 
@@ -240,6 +384,7 @@ impl<S: SigningKeyPairSized> KeyRing<S> {
         match self {
             Self::Memory(m) => m.add_key(key_name, key_entry),
             Self::Test(d) => d.add_key(key_name, key_entry),
+            Self::Encrypted(e) => e.add_key(key_name, key_entry),
         }
     }
 
@@ -247,6 +392,7 @@ impl<S: SigningKeyPairSized> KeyRing<S> {
         match self {
             Self::Memory(m) => m.remove_key(key_name),
             Self::Test(d) => <Test as KeyStore<S>>::remove_key(d, key_name),
+            Self::Encrypted(e) => <Encrypted as KeyStore<S>>::remove_key(e, key_name),
         }
     }
 
@@ -254,6 +400,7 @@ impl<S: SigningKeyPairSized> KeyRing<S> {
         match self {
             Self::Memory(m) => m.keys(),
             Self::Test(d) => d.keys(),
+            Self::Encrypted(e) => e.keys(),
         }
     }
 
@@ -261,6 +408,7 @@ impl<S: SigningKeyPairSized> KeyRing<S> {
         match self {
             Self::Memory(m) => &m.account_prefix,
             Self::Test(d) => &d.account_prefix,
+            Self::Encrypted(e) => &e.account_prefix,
         }
     }
 }
@@ -289,9 +437,9 @@ impl KeyRing<Ed25519KeyPair> {
 
 pub fn list_keys(config: &ChainConfig) -> Result<Vec<(String, AnySigningKeyPair)>, Error> {
     let keys = match config.r#type {
-        ChainType::CosmosSdk => {
+        ChainType::CosmosSdk | ChainType::SoloMachine => {
             let keyring = KeyRing::new_secp256k1(
-                Store::Test,
+                config.key_store_type.persistent(),
                 &config.account_prefix,
                 &config.id,
                 &config.key_store_folder,
@@ -306,7 +454,13 @@ pub fn list_keys(config: &ChainConfig) -> Result<Vec<(String, AnySigningKeyPair)
     Ok(keys)
 }
 
-fn disk_store_path(folder_name: &str, keystore_folder: &Option<PathBuf>) -> Result<PathBuf, Error> {
+/// Returns the folder of the given on-disk backend for the given chain,
+/// creating it if it does not exist yet.
+fn disk_store_path(
+    folder_name: &str,
+    keystore_folder: &Option<PathBuf>,
+    backend: &str,
+) -> Result<PathBuf, Error> {
     let ks_folder = match keystore_folder {
         Some(folder) => folder.to_owned(),
         None => {
@@ -315,7 +469,173 @@ fn disk_store_path(folder_name: &str, keystore_folder: &Option<PathBuf>) -> Resu
         }
     };
 
-    let folder = ks_folder.join(folder_name).join(KEYSTORE_DISK_BACKEND);
+    let folder = ks_folder.join(folder_name).join(backend);
+
+    // Create keys folder if it does not exist
+    fs::create_dir_all(&folder).map_err(|e| {
+        Error::key_file_io(
+            folder.display().to_string(),
+            "failed to create keys folder".to_string(),
+            e,
+        )
+    })?;
 
     Ok(folder)
 }
+
+/// Returns the names of the keys stored as files in the given folder.
+fn key_names(folder: &Path) -> Result<Vec<String>, Error> {
+    let dir = fs::read_dir(folder).map_err(|e| {
+        Error::key_file_io(
+            folder.display().to_string(),
+            "failed to list keys".to_string(),
+            e,
+        )
+    })?;
+
+    let ext = OsStr::new(KEYSTORE_FILE_EXTENSION);
+
+    Ok(dir
+        .into_iter()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension() == Some(ext))
+        .flat_map(|path| path.file_stem().map(OsStr::to_owned))
+        .flat_map(|stem| stem.to_str().map(ToString::to_string))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use core::str::FromStr;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use hdpath::StandardHDPath;
+
+    use crate::config::AddressType;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon \
+        abandon abandon abandon abandon abandon about";
+
+    const TEST_KDF: KdfParams = KdfParams {
+        log_n: 4,
+        r: 8,
+        p: 1,
+    };
+
+    fn key_pair() -> Secp256k1KeyPair {
+        let hd_path = StandardHDPath::from_str("m/44'/118'/0'/0/0").unwrap();
+        Secp256k1KeyPair::from_mnemonic(MNEMONIC, &hd_path, &AddressType::Cosmos, "cosmos").unwrap()
+    }
+
+    fn temp_store(name: &str) -> PathBuf {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+
+        let folder = std::env::temp_dir().join(format!("hermes-keyring-{name}-{nanos}"));
+        fs::create_dir_all(&folder).unwrap();
+        folder
+    }
+
+    fn encrypted_store(folder: &Path, passphrase: &str) -> Encrypted {
+        Encrypted::new(
+            "cosmos".to_string(),
+            folder.to_path_buf(),
+            Passphrase::new(passphrase),
+        )
+        .with_kdf_params(TEST_KDF)
+    }
+
+    #[test]
+    fn encrypted_store_roundtrips() {
+        let folder = temp_store("roundtrip");
+        let mut store = encrypted_store(&folder, "passphrase");
+
+        store.add_key("relayer", key_pair()).unwrap();
+
+        let contents = fs::read_to_string(folder.join("relayer.json")).unwrap();
+        assert!(!contents.contains(&key_pair().account()));
+
+        let key: Secp256k1KeyPair = store.get_key("relayer").unwrap();
+        assert_eq!(key.account(), key_pair().account());
+
+        let keys: Vec<(String, Secp256k1KeyPair)> = store.keys().unwrap();
+        assert_eq!(keys.len(), 1);
+        assert_eq!(keys[0].0, "relayer");
+
+        fs::remove_dir_all(folder).unwrap();
+    }
+
+    #[test]
+    fn encrypted_store_rejects_wrong_passphrase() {
+        let folder = temp_store("wrong-passphrase");
+
+        encrypted_store(&folder, "right")
+            .add_key("first", key_pair())
+            .unwrap();
+
+        let mut store = encrypted_store(&folder, "wrong");
+
+        let err = KeyStore::<Secp256k1KeyPair>::get_key(&store, "first").unwrap_err();
+        assert!(matches!(err.detail(), ErrorDetail::KeyDecryption(_)));
+
+        let err = store.add_key("second", key_pair()).unwrap_err();
+        assert!(matches!(err.detail(), ErrorDetail::PassphraseMismatch(_)));
+        assert!(!folder.join("second.json").exists());
+
+        fs::remove_dir_all(folder).unwrap();
+    }
+
+    #[test]
+    fn encrypted_store_checks_passphrase_against_every_key() {
+        let folder = temp_store("every-key");
+
+        encrypted_store(&folder, "first")
+            .add_key("a", key_pair())
+            .unwrap();
+
+        // Sneak in a key encrypted under another passphrase
+        let plaintext = serde_json::to_vec(&key_pair()).unwrap();
+        let sealed =
+            EncryptedKeyFile::seal(&Passphrase::new("second"), TEST_KDF, "b", &plaintext).unwrap();
+        fs::write(folder.join("b.json"), serde_json::to_vec(&sealed).unwrap()).unwrap();
+
+        let err = encrypted_store(&folder, "first")
+            .add_key("c", key_pair())
+            .unwrap_err();
+        assert!(matches!(err.detail(), ErrorDetail::PassphraseMismatch(_)));
+
+        fs::remove_dir_all(folder).unwrap();
+    }
+
+    #[test]
+    fn encrypted_store_rejects_plaintext_keys() {
+        let folder = temp_store("mixed");
+
+        let mut store = encrypted_store(&folder, "passphrase");
+        store.add_key("encrypted", key_pair()).unwrap();
+
+        fs::write(
+            folder.join("plain.json"),
+            serde_json::to_vec(&key_pair()).unwrap(),
+        )
+        .unwrap();
+
+        let key: Secp256k1KeyPair = store.get_key("encrypted").unwrap();
+        assert_eq!(key.account(), key_pair().account());
+
+        let err = KeyStore::<Secp256k1KeyPair>::get_key(&store, "plain").unwrap_err();
+        assert!(matches!(err.detail(), ErrorDetail::KeyFileDecode(_)));
+
+        assert!(KeyStore::<Secp256k1KeyPair>::keys(&store).is_err());
+
+        let err = store.add_key("other", key_pair()).unwrap_err();
+        assert!(matches!(err.detail(), ErrorDetail::KeyFileDecode(_)));
+
+        fs::remove_dir_all(folder).unwrap();
+    }
+}
@@ -0,0 +1,280 @@
+use core::fmt;
+use std::env;
+use std::fs;
+
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng, Payload};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+use serde::{Deserialize, Serialize};
+use zeroize::{Zeroize, Zeroizing};
+
+use super::errors::Error;
+
+/// Environment variable holding the passphrase of the encrypted key store.
+pub const KEYSTORE_PASSPHRASE_ENV: &str = "HERMES_KEYSTORE_PASSPHRASE";
+
+/// Environment variable holding the path to a file containing the passphrase
+/// of the encrypted key store.
+pub const KEYSTORE_PASSPHRASE_FILE_ENV: &str = "HERMES_KEYSTORE_PASSPHRASE_FILE";
+
+const ENCRYPTED_KEY_FILE_VERSION: u8 = 1;
+const SALT_LEN: usize = 32;
+const KEY_LEN: usize = 32;
+
+/// Upper bounds on the scrypt parameters accepted from a key file, so that
+/// a tampered file cannot make the relayer allocate gigabytes of memory
+/// or spin for hours when the key is loaded.
+const MAX_KDF_LOG_N: u8 = 20;
+const MAX_KDF_R: u32 = 16;
+const MAX_KDF_P: u32 = 16;
+
+/// The passphrase from which the encryption key of every entry
+/// in the encrypted key store is derived.
+#[derive(Clone, Default)]
+pub struct Passphrase(String);
+
+impl Passphrase {
+    pub fn new(passphrase: impl Into<String>) -> Self {
+        Self(passphrase.into())
+    }
+
+    /// Look up the passphrase for the key store of the given chain, in order:
+    ///
+    /// 1. the `HERMES_KEYSTORE_PASSPHRASE` environment variable,
+    /// 2. the file pointed to by the `HERMES_KEYSTORE_PASSPHRASE_FILE` environment variable,
+    /// 3. an interactive prompt on the terminal.
+    pub fn resolve(chain_id: &ChainId) -> Result<Self, Error> {
+        if let Ok(passphrase) = env::var(KEYSTORE_PASSPHRASE_ENV) {
+            return Ok(Self(passphrase));
+        }
+
+        if let Ok(file_path) = env::var(KEYSTORE_PASSPHRASE_FILE_ENV) {
+            let contents = fs::read_to_string(&file_path)
+                .map_err(|e| Error::passphrase_file_io(file_path, e))?;
+
+            return Ok(Self(contents.trim_end_matches(['\n', '\r']).to_string()));
+        }
+
+        let passphrase = dialoguer::Password::new()
+            .with_prompt(format!(
+                "Passphrase for the key store of chain '{chain_id}'"
+            ))
+            .allow_empty_password(false)
+            .interact()
+            .map_err(Error::passphrase_prompt)?;
+
+        Ok(Self(passphrase))
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+}
+
+impl fmt::Debug for Passphrase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Passphrase(..)")
+    }
+}
+
+impl Drop for Passphrase {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+/// Parameters of the scrypt key derivation function.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KdfParams {
+    pub log_n: u8,
+    pub r: u32,
+    pub p: u32,
+}
+
+impl KdfParams {
+    fn validate(&self) -> Result<(), Error> {
+        if self.log_n > MAX_KDF_LOG_N || self.r > MAX_KDF_R || self.p > MAX_KDF_P {
+            return Err(Error::invalid_kdf_params(format!(
+                "log_n = {}, r = {}, p = {} exceed the maximum of log_n = {MAX_KDF_LOG_N}, r = {MAX_KDF_R}, p = {MAX_KDF_P}",
+                self.log_n, self.r, self.p
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for KdfParams {
+    fn default() -> Self {
+        Self {
+            log_n: 15,
+            r: 8,
+            p: 1,
+        }
+    }
+}
+
+/// An encrypted key entry, as stored on disk.
+///
+/// The key entry is encrypted with ChaCha20-Poly1305 under a key derived
+/// from the passphrase with scrypt. The name of the key is bound to the
+/// ciphertext as associated data, so that an entry cannot be swapped
+/// for another one without being detected.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EncryptedKeyFile {
+    version: u8,
+    kdf: KdfParams,
+    salt: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+impl EncryptedKeyFile {
+    pub fn seal(
+        passphrase: &Passphrase,
+        kdf: KdfParams,
+        key_name: &str,
+        plaintext: &[u8],
+    ) -> Result<Self, Error> {
+        let mut salt = [0u8; SALT_LEN];
+        OsRng.fill_bytes(&mut salt);
+
+        let key = derive_key(passphrase, &salt, kdf)?;
+        let cipher = ChaCha20Poly1305::new(Key::from_slice(key.as_slice()));
+        let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+
+        let payload = Payload {
+            msg: plaintext,
+            aad: key_name.as_bytes(),
+        };
+
+        let ciphertext = cipher
+            .encrypt(&nonce, payload)
+            .map_err(|_| Error::key_encryption())?;
+
+        Ok(Self {
+            version: ENCRYPTED_KEY_FILE_VERSION,
+            kdf,
+            salt: hex::encode(salt),
+            nonce: hex::encode(nonce),
+            ciphertext: hex::encode(ciphertext),
+        })
+    }
+
+    pub fn open(
+        &self,
+        passphrase: &Passphrase,
+        key_name: &str,
+        file_path: &str,
+    ) -> Result<Zeroizing<Vec<u8>>, Error> {
+        if self.version != ENCRYPTED_KEY_FILE_VERSION {
+            return Err(Error::invalid_encrypted_key_file(
+                file_path.to_string(),
+                format!("unsupported version {}", self.version),
+            ));
+        }
+
+        let decode = |field: &str, value: &str| {
+            hex::decode(value).map_err(|e| {
+                Error::invalid_encrypted_key_file(
+                    file_path.to_string(),
+                    format!("invalid {field}: {e}"),
+                )
+            })
+        };
+
+        let salt = decode("salt", &self.salt)?;
+        let nonce = decode("nonce", &self.nonce)?;
+        let ciphertext = decode("ciphertext", &self.ciphertext)?;
+
+        if nonce.len() != 12 {
+            return Err(Error::invalid_encrypted_key_file(
+                file_path.to_string(),
+                format!("invalid nonce length {}", nonce.len()),
+            ));
+        }
+
+        let key = derive_key(passphrase, &salt, self.kdf)?;
+        let cipher = ChaCha20Poly1305::new(Key::from_slice(key.as_slice()));
+
+        let payload = Payload {
+            msg: &ciphertext,
+            aad: key_name.as_bytes(),
+        };
+
+        cipher
+            .decrypt(Nonce::from_slice(&nonce), payload)
+            .map(Zeroizing::new)
+            .map_err(|_| Error::key_decryption(file_path.to_string()))
+    }
+}
+
+fn derive_key(
+    passphrase: &Passphrase,
+    salt: &[u8],
+    kdf: KdfParams,
+) -> Result<Zeroizing<[u8; KEY_LEN]>, Error> {
+    kdf.validate()?;
+
+    let params = scrypt::Params::new(kdf.log_n, kdf.r, kdf.p, KEY_LEN)
+        .map_err(|e| Error::invalid_kdf_params(e.to_string()))?;
+
+    let mut key = Zeroizing::new([0u8; KEY_LEN]);
+    scrypt::scrypt(passphrase.as_bytes(), salt, &params, key.as_mut_slice())
+        .map_err(|e| Error::invalid_kdf_params(e.to_string()))?;
+
+    Ok(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_KDF: KdfParams = KdfParams {
+        log_n: 4,
+        r: 8,
+        p: 1,
+    };
+
+    #[test]
+    fn seal_then_open_roundtrips() {
+        let passphrase = Passphrase::new("correct horse battery staple");
+        let sealed = EncryptedKeyFile::seal(&passphrase, TEST_KDF, "relayer", b"secret").unwrap();
+
+        assert!(!sealed.ciphertext.contains(&hex::encode(b"secret")));
+
+        let opened = sealed.open(&passphrase, "relayer", "relayer.json").unwrap();
+        assert_eq!(opened.as_slice(), b"secret");
+    }
+
+    #[test]
+    fn open_with_wrong_passphrase_fails() {
+        let sealed =
+            EncryptedKeyFile::seal(&Passphrase::new("right"), TEST_KDF, "relayer", b"secret")
+                .unwrap();
+
+        assert!(sealed
+            .open(&Passphrase::new("wrong"), "relayer", "relayer.json")
+            .is_err());
+    }
+
+    #[test]
+    fn open_under_another_key_name_fails() {
+        let passphrase = Passphrase::new("passphrase");
+        let sealed = EncryptedKeyFile::seal(&passphrase, TEST_KDF, "relayer", b"secret").unwrap();
+
+        assert!(sealed.open(&passphrase, "other", "other.json").is_err());
+    }
+
+    #[test]
+    fn open_with_excessive_kdf_params_fails() {
+        let passphrase = Passphrase::new("passphrase");
+        let mut sealed =
+            EncryptedKeyFile::seal(&passphrase, TEST_KDF, "relayer", b"secret").unwrap();
+
+        sealed.kdf.log_n = MAX_KDF_LOG_N + 1;
+
+        assert!(sealed.open(&passphrase, "relayer", "relayer.json").is_err());
+    }
+}
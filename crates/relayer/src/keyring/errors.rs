@@ -95,6 +95,51 @@ define_error! {
         HomeLocationUnavailable
             |_| { "home location is unavailable" },
 
+        PassphraseFileIo
+            { file_path: String }
+            [ TraceError<IoError> ]
+            |e| {
+                format!("failed to read the key store passphrase from '{}'",
+                    e.file_path)
+            },
+
+        PassphrasePrompt
+            [ TraceError<IoError> ]
+            |_| { "failed to prompt for the key store passphrase" },
+
+        PassphraseMismatch
+            { file_path: String }
+            |e| {
+                format!("the key store passphrase does not match the one used to encrypt the key file at '{}'",
+                    e.file_path)
+            },
+
+        InvalidKdfParams
+            { reason: String }
+            |e| {
+                format!("invalid key derivation parameters: {}", e.reason)
+            },
+
+        KeyEncryption
+            |_| { "failed to encrypt key" },
+
+        KeyDecryption
+            { file_path: String }
+            |e| {
+                format!("failed to decrypt key file at '{}': wrong passphrase or corrupted file",
+                    e.file_path)
+            },
+
+        InvalidEncryptedKeyFile
+            {
+                file_path: String,
+                reason: String,
+            }
+            |e| {
+                format!("invalid encrypted key file at '{}': {}",
+                    e.file_path, e.reason)
+            },
+
         RemoveIoFail
             {
                 file_path: String,
@@ -136,6 +181,43 @@ define_error! {
             },
 
 
+        RemoteSigner
+            {
+                url: String,
+                reason: String,
+            }
+            |e| {
+                format!("request to remote signer at '{}' failed: {}",
+                    e.url, e.reason)
+            },
+
+        RemoteSignerResponse
+            {
+                url: String,
+                reason: String,
+            }
+            |e| {
+                format!("invalid response from remote signer at '{}': {}",
+                    e.url, e.reason)
+            },
+
+        RemoteSignerPublicKeyMismatch
+            {
+                url: String,
+                key_id: String,
+            }
+            |e| {
+                format!("remote signer at '{}' signed with a different public key than the one of key '{}'",
+                    e.url, e.key_id)
+            },
+
+        RemoteSignerInvalidSignature
+            { url: String }
+            |e| {
+                format!("remote signer at '{}' returned a signature which does not verify",
+                    e.url)
+            },
+
         Bs58Decode
             [ TraceError<bs58::decode::Error> ]
             |_| { "bs58 decode error" },
@@ -0,0 +1,290 @@
+//! A signer which forwards the bytes to sign to an external signing service,
+//! so that the private key never has to be present on the relayer host.
+//!
+//! The signing service is expected to expose the following JSON-over-HTTP endpoints,
+//! where all binary values are base64-encoded:
+//!
+//! - `POST <url>/pubkey` with body `{ "key_id": ... }`, which returns
+//!   `{ "pub_key": ... }`, the compressed secp256k1 public key of the given key.
+//! - `POST <url>/sign` with body `{ "key_id": ..., "sign_bytes": ... }`, which returns
+//!   `{ "signature": ..., "pub_key": ... }`, the 64-byte compact secp256k1 signature
+//!   over the SHA-256 digest (or Keccak-256 digest for Ethermint chains) of the sign bytes,
+//!   together with the public key of the key which produced it.
+
+use digest::Digest;
+use generic_array::{typenum::U32, GenericArray};
+use secp256k1::{ecdsa::Signature, Message, PublicKey, Secp256k1};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use subtle_encoding::base64;
+
+use super::errors::Error;
+use super::key_utils::{encode_bech32, keccak256_hash};
+use super::secp256k1_key_pair::{get_address, Secp256k1AddressType};
+use crate::config::{AddressType, RemoteSignerConfig};
+
+#[derive(Debug, Serialize)]
+struct PubKeyRequest<'a> {
+    key_id: &'a str,
+}
+
+#[derive(Debug, Deserialize)]
+struct PubKeyResponse {
+    pub_key: String,
+}
+
+#[derive(Debug, Serialize)]
+struct SignRequest<'a> {
+    key_id: &'a str,
+    sign_bytes: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct SignResponse {
+    signature: String,
+    pub_key: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct RemoteSigner {
+    url: String,
+    key_id: String,
+    agent: ureq::Agent,
+    public_key: PublicKey,
+    address_type: Secp256k1AddressType,
+    account: String,
+}
+
+impl RemoteSigner {
+    /// Connect to the signing service and fetch the public key of the configured key,
+    /// from which the account address on the chain is derived.
+    pub fn connect(
+        config: &RemoteSignerConfig,
+        key_name: &str,
+        address_type: &AddressType,
+        account_prefix: &str,
+    ) -> Result<Self, Error> {
+        let url = config.url.to_string().trim_end_matches('/').to_string();
+        let key_id = config
+            .key_id
+            .clone()
+            .unwrap_or_else(|| key_name.to_string());
+
+        let agent = ureq::AgentBuilder::new().timeout(config.timeout).build();
+
+        let response: PubKeyResponse = post_json(
+            &agent,
+            &format!("{url}/pubkey"),
+            &PubKeyRequest { key_id: &key_id },
+        )?;
+
+        let public_key = decode_public_key(&url, &response.pub_key)?;
+        let address_type = Secp256k1AddressType::try_from(address_type)?;
+        let account = encode_bech32(account_prefix, &get_address(&public_key, address_type))?;
+
+        Ok(Self {
+            url,
+            key_id,
+            agent,
+            public_key,
+            address_type,
+            account,
+        })
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+
+    pub fn account(&self) -> String {
+        self.account.clone()
+    }
+
+    /// Have the signing service sign the given bytes, and check that the returned
+    /// signature was produced by the expected key before handing it out.
+    pub fn sign(&self, message: &[u8]) -> Result<Vec<u8>, Error> {
+        let request = SignRequest {
+            key_id: &self.key_id,
+            sign_bytes: String::from_utf8(base64::encode(message))
+                .expect("base64 encoding is valid UTF-8"),
+        };
+
+        let response: SignResponse =
+            post_json(&self.agent, &format!("{}/sign", self.url), &request)?;
+
+        let public_key = decode_public_key(&self.url, &response.pub_key)?;
+
+        if public_key != self.public_key {
+            return Err(Error::remote_signer_public_key_mismatch(
+                self.url.clone(),
+                self.key_id.clone(),
+            ));
+        }
+
+        let signature = base64::decode(response.signature.as_bytes())
+            .map_err(|e| Error::remote_signer_response(self.url.clone(), e.to_string()))?;
+
+        self.verify(message, &signature)?;
+
+        Ok(signature)
+    }
+
+    fn verify(&self, message: &[u8], signature: &[u8]) -> Result<(), Error> {
+        let hashed_message: GenericArray<u8, U32> = match self.address_type {
+            Secp256k1AddressType::Ethermint => keccak256_hash(message).into(),
+            Secp256k1AddressType::Cosmos => Sha256::digest(message),
+        };
+
+        // SAFETY: hashed_message is 32 bytes, as expected in `Message::from_slice`,
+        // so `unwrap` is safe.
+        let message = Message::from_slice(&hashed_message).unwrap();
+
+        let signature = Signature::from_compact(signature)
+            .map_err(|e| Error::remote_signer_response(self.url.clone(), e.to_string()))?;
+
+        Secp256k1::verification_only()
+            .verify_ecdsa(&message, &signature, &self.public_key)
+            .map_err(|_| Error::remote_signer_invalid_signature(self.url.clone()))
+    }
+}
+
+fn post_json<Req, Res>(agent: &ureq::Agent, url: &str, request: &Req) -> Result<Res, Error>
+where
+    Req: Serialize,
+    Res: for<'de> Deserialize<'de>,
+{
+    let body = serde_json::to_value(request).map_err(Error::encode)?;
+
+    agent
+        .post(url)
+        .send_json(body)
+        .map_err(|e| Error::remote_signer(url.to_string(), e.to_string()))?
+        .into_json()
+        .map_err(|e| Error::remote_signer_response(url.to_string(), e.to_string()))
+}
+
+fn decode_public_key(url: &str, encoded: &str) -> Result<PublicKey, Error> {
+    let bytes = base64::decode(encoded.as_bytes())
+        .map_err(|e| Error::remote_signer_response(url.to_string(), e.to_string()))?;
+
+    PublicKey::from_slice(&bytes)
+        .map_err(|e| Error::remote_signer_response(url.to_string(), e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    use secp256k1::SecretKey;
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    /// A minimal signing service, holding a single secp256k1 key, which serves
+    /// the given number of requests before shutting down.
+    fn spawn_mock_signer(secret_key: SecretKey, requests: usize) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        thread::spawn(move || {
+            let secp = Secp256k1::new();
+            let public_key = PublicKey::from_secret_key(&secp, &secret_key);
+            let encoded_pub_key =
+                String::from_utf8(base64::encode(public_key.serialize())).unwrap();
+
+            for stream in listener.incoming().take(requests) {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+
+                let mut content_length = 0;
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    if header == "\r\n" {
+                        break;
+                    }
+                    if let Some(value) = header.to_lowercase().strip_prefix("content-length:") {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                }
+
+                let mut body = vec![0; content_length];
+                reader.read_exact(&mut body).unwrap();
+                let body: Value = serde_json::from_slice(&body).unwrap();
+
+                let response = if request_line.contains("/pubkey") {
+                    json!({ "pub_key": encoded_pub_key })
+                } else {
+                    let sign_bytes =
+                        base64::decode(body["sign_bytes"].as_str().unwrap().as_bytes()).unwrap();
+                    let digest = Sha256::digest(sign_bytes);
+                    let message = Message::from_slice(&digest).unwrap();
+                    let signature = secp.sign_ecdsa(&message, &secret_key).serialize_compact();
+
+                    json!({
+                        "signature": String::from_utf8(base64::encode(signature)).unwrap(),
+                        "pub_key": encoded_pub_key,
+                    })
+                };
+
+                let response = response.to_string();
+                write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    response.len(),
+                    response
+                )
+                .unwrap();
+            }
+        });
+
+        format!("http://{addr}")
+    }
+
+    fn config(url: String) -> RemoteSignerConfig {
+        RemoteSignerConfig {
+            url: url.parse().unwrap(),
+            key_id: Some("relayer".to_string()),
+            timeout: crate::config::default::remote_signer_timeout(),
+        }
+    }
+
+    #[test]
+    fn remote_signer_signs_with_remote_key() {
+        let secret_key = SecretKey::from_slice(&[0x42; 32]).unwrap();
+        let url = spawn_mock_signer(secret_key, 2);
+
+        let signer =
+            RemoteSigner::connect(&config(url), "unused", &AddressType::Cosmos, "cosmos").unwrap();
+
+        let expected_public_key = PublicKey::from_secret_key(&Secp256k1::new(), &secret_key);
+        assert_eq!(signer.public_key(), &expected_public_key);
+        assert!(signer.account().starts_with("cosmos1"));
+
+        let signature = signer.sign(b"sign bytes").unwrap();
+        assert_eq!(signature.len(), 64);
+    }
+
+    #[test]
+    fn remote_signer_rejects_signature_from_other_key() {
+        let secret_key = SecretKey::from_slice(&[0x42; 32]).unwrap();
+        let url = spawn_mock_signer(secret_key, 1);
+
+        let mut signer =
+            RemoteSigner::connect(&config(url), "unused", &AddressType::Cosmos, "cosmos").unwrap();
+
+        let other_key = SecretKey::from_slice(&[0x43; 32]).unwrap();
+        signer.url = spawn_mock_signer(other_key, 1);
+
+        assert!(signer.sign(b"sign bytes").is_err());
+    }
+}
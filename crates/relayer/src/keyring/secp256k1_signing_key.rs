@@ -0,0 +1,65 @@
+use secp256k1::PublicKey;
+
+use super::errors::Error;
+use super::remote_signer::RemoteSigner;
+use super::{Secp256k1KeyPair, SigningKeyPair};
+
+/// The key with which transactions are signed on a Cosmos chain:
+/// either a key pair loaded from the key ring, or a key held
+/// by a remote signing service.
+#[derive(Clone, Debug)]
+pub enum Secp256k1SigningKey {
+    Local(Secp256k1KeyPair),
+    Remote(RemoteSigner),
+}
+
+impl Secp256k1SigningKey {
+    pub fn public_key(&self) -> &PublicKey {
+        match self {
+            Self::Local(key_pair) => &key_pair.public_key,
+            Self::Remote(signer) => signer.public_key(),
+        }
+    }
+
+    pub fn account(&self) -> String {
+        match self {
+            Self::Local(key_pair) => key_pair.account(),
+            Self::Remote(signer) => signer.account(),
+        }
+    }
+
+    pub fn sign(&self, message: &[u8]) -> Result<Vec<u8>, Error> {
+        match self {
+            Self::Local(key_pair) => key_pair.sign(message),
+            Self::Remote(signer) => signer.sign(message),
+        }
+    }
+
+    /// Same as [`Self::sign`], except that the request to the remote signer,
+    /// which is blocking, is performed off the async runtime.
+    pub async fn sign_async(&self, message: Vec<u8>) -> Result<Vec<u8>, Error> {
+        match self {
+            Self::Local(key_pair) => key_pair.sign(&message),
+            Self::Remote(signer) => {
+                let url = signer.url().to_string();
+                let signer = signer.clone();
+
+                tokio::task::spawn_blocking(move || signer.sign(&message))
+                    .await
+                    .map_err(|e| Error::remote_signer(url, e.to_string()))?
+            }
+        }
+    }
+}
+
+impl From<Secp256k1KeyPair> for Secp256k1SigningKey {
+    fn from(key_pair: Secp256k1KeyPair) -> Self {
+        Self::Local(key_pair)
+    }
+}
+
+impl From<RemoteSigner> for Secp256k1SigningKey {
+    fn from(signer: RemoteSigner) -> Self {
+        Self::Remote(signer)
+    }
+}
@@ -30,6 +30,7 @@ pub mod connection;
 pub mod consensus_state;
 pub mod denom;
 pub mod error;
+pub mod error_kind;
 pub mod event;
 pub mod extension_options;
 pub mod foreign_client;
@@ -39,10 +40,12 @@ pub mod link;
 pub mod misbehaviour;
 pub mod object;
 pub mod path;
+pub mod recover_client;
 pub mod registry;
 pub mod rest;
 pub mod sdk_error;
 pub mod spawn;
+pub mod state_store;
 pub mod supervisor;
 pub mod telemetry;
 pub mod transfer;
@@ -6,9 +6,17 @@ use core::ops::Deref;
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::lightclients::tendermint::v1::Header as RawTmHeader;
 use ibc_proto::protobuf::Protobuf as ErasedProtobuf;
+use ibc_relayer_types::clients::ics06_solomachine::header::{
+    decode_header as sm_decode_header, Header as SolomachineHeader, SOLOMACHINE_HEADER_TYPE_URL,
+};
+use ibc_relayer_types::clients::ics06_solomachine::raw::Header as RawSmHeader;
 use ibc_relayer_types::clients::ics07_tendermint::header::{
     decode_header as tm_decode_header, Header as TendermintHeader, TENDERMINT_HEADER_TYPE_URL,
 };
+use ibc_relayer_types::clients::ics08_wasm::client_message::{
+    decode_header as wasm_decode_header, Header as WasmHeader, WASM_CLIENT_MESSAGE_TYPE_URL,
+};
+use ibc_relayer_types::clients::ics08_wasm::raw::ClientMessage as RawWasmClientMessage;
 use ibc_relayer_types::core::ics02_client::client_type::ClientType;
 use ibc_relayer_types::core::ics02_client::error::Error;
 use ibc_relayer_types::core::ics02_client::events::UpdateClient;
@@ -69,38 +77,63 @@ pub trait LightClient<C: ChainEndpoint>: Send + Sync {
 }
 
 /// Decodes an encoded header into a known `Header` type,
+///
+/// The headers of 08-wasm clients are decoded into the header they wrap,
+/// so that they can be checked for misbehaviour by the light client of
+/// the reference chain.
 pub fn decode_header(header_bytes: &[u8]) -> Result<Box<dyn Header>, Error> {
-    // For now, we only have tendermint; however when there is more than one, we
-    // can try decoding into all the known types, and return an error only if
-    // none work
-    let header: TendermintHeader =
-        ErasedProtobuf::<Any>::decode(header_bytes).map_err(Error::invalid_raw_header)?;
+    let header = AnyHeader::decode(header_bytes).map_err(Error::invalid_raw_header)?;
 
-    Ok(Box::new(header))
+    match header {
+        AnyHeader::Tendermint(header) => Ok(Box::new(header)),
+        AnyHeader::Solomachine(header) => Ok(Box::new(header)),
+        AnyHeader::Wasm(header) => Ok(Box::new(header.inner)),
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 #[allow(clippy::large_enum_variant)]
 pub enum AnyHeader {
     Tendermint(TendermintHeader),
+    Solomachine(SolomachineHeader),
+    Wasm(WasmHeader),
+}
+
+impl AnyHeader {
+    /// Wraps a Tendermint header into the header of an 08-wasm client.
+    pub fn wrap_wasm(self) -> Result<Self, Error> {
+        match self {
+            AnyHeader::Tendermint(header) => Ok(AnyHeader::Wasm(WasmHeader::new(header))),
+            _ => Err(Error::client_specific(format!(
+                "cannot wrap a header of type {} in an 08-wasm client",
+                self.client_type()
+            ))),
+        }
+    }
 }
 
 impl Header for AnyHeader {
     fn client_type(&self) -> ClientType {
         match self {
             Self::Tendermint(header) => header.client_type(),
+            Self::Solomachine(header) => header.client_type(),
+            Self::Wasm(header) => header.client_type(),
         }
     }
 
     fn height(&self) -> Height {
         match self {
             Self::Tendermint(header) => header.height(),
+            Self::Solomachine(header) => header.height(),
+            Self::Wasm(header) => header.height(),
         }
     }
 
     fn timestamp(&self) -> Timestamp {
         match self {
             Self::Tendermint(header) => header.timestamp(),
+            Self::Solomachine(header) => header.timestamp(),
+            Self::Wasm(header) => header.timestamp(),
         }
     }
 }
@@ -118,6 +151,18 @@ impl TryFrom<Any> for AnyHeader {
                 Ok(AnyHeader::Tendermint(val))
             }
 
+            SOLOMACHINE_HEADER_TYPE_URL => {
+                let val = sm_decode_header(raw.value.deref())?;
+
+                Ok(AnyHeader::Solomachine(val))
+            }
+
+            WASM_CLIENT_MESSAGE_TYPE_URL => {
+                let val = wasm_decode_header(raw.value.deref())?;
+
+                Ok(AnyHeader::Wasm(val))
+            }
+
             _ => Err(Error::unknown_header_type(raw.type_url)),
         }
     }
@@ -130,6 +175,14 @@ impl From<AnyHeader> for Any {
                 type_url: TENDERMINT_HEADER_TYPE_URL.to_string(),
                 value: ErasedProtobuf::<RawTmHeader>::encode_vec(&header),
             },
+            AnyHeader::Solomachine(header) => Any {
+                type_url: SOLOMACHINE_HEADER_TYPE_URL.to_string(),
+                value: ErasedProtobuf::<RawSmHeader>::encode_vec(&header),
+            },
+            AnyHeader::Wasm(header) => Any {
+                type_url: WASM_CLIENT_MESSAGE_TYPE_URL.to_string(),
+                value: ErasedProtobuf::<RawWasmClientMessage>::encode_vec(&header),
+            },
         }
     }
 }
@@ -139,3 +192,15 @@ impl From<TendermintHeader> for AnyHeader {
         Self::Tendermint(header)
     }
 }
+
+impl From<SolomachineHeader> for AnyHeader {
+    fn from(header: SolomachineHeader) -> Self {
+        Self::Solomachine(header)
+    }
+}
+
+impl From<WasmHeader> for AnyHeader {
+    fn from(header: WasmHeader) -> Self {
+        Self::Wasm(header)
+    }
+}
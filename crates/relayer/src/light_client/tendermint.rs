@@ -267,8 +267,14 @@ fn io_for_addr(
 }
 
 impl LightClient {
-    pub fn from_config(config: &ChainConfig, peer_id: PeerId) -> Result<Self, Error> {
-        let live_io = io_for_addr(&config.rpc_addr, peer_id, Some(config.rpc_timeout))?;
+    /// Create a light client fetching light blocks from the full node
+    /// with the given peer identifier, listening at the given RPC address.
+    pub fn from_config(
+        config: &ChainConfig,
+        rpc_addr: &rpc::Url,
+        peer_id: PeerId,
+    ) -> Result<Self, Error> {
+        let live_io = io_for_addr(rpc_addr, peer_id, Some(config.rpc_timeout))?;
 
         let io = match &config.genesis_restart {
             None => AnyIo::Prod(live_io),
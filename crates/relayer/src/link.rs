@@ -17,6 +17,7 @@ pub mod operational_data;
 pub mod packet_events;
 
 mod pending;
+pub(crate) mod redundancy;
 mod relay_path;
 mod relay_sender;
 mod relay_summary;
@@ -27,7 +28,7 @@ use tx_hashes::TxHashes;
 // Re-export the telemetries summary
 pub use relay_summary::RelaySummary;
 
-pub use relay_path::{RelayPath, Resubmit};
+pub use relay_path::{RelayPath, Resubmit, Resumption};
 
 #[derive(Clone, Debug)]
 pub struct LinkParameters {
@@ -79,6 +80,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Link<ChainA, ChainB> {
 
         if !a_channel.state_matches(&ChannelState::Open)
             && !a_channel.state_matches(&ChannelState::Closed)
+            && !a_channel.state().is_upgrading()
         {
             return Err(LinkError::invalid_channel_state(
                 a_channel_id.clone(),
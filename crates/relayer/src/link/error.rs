@@ -7,7 +7,10 @@ use ibc_relayer_types::Height;
 use crate::channel::ChannelError;
 use crate::connection::ConnectionError;
 use crate::error::Error;
+use crate::error_kind::{ErrorKind, HasErrorKind};
 use crate::foreign_client::{ForeignClientError, HasExpiredOrFrozenError};
+use crate::link::redundancy::is_redundant_tx_error;
+use crate::state_store::Error as StateStoreError;
 use crate::supervisor::Error as SupervisorError;
 use crate::transfer::TransferError;
 
@@ -21,6 +24,10 @@ define_error! {
             [ SupervisorError ]
             |_| { "error originating from the supervisor" },
 
+        StateStore
+            [ StateStoreError ]
+            |_| { "failed to access the state store of the relaying path" },
+
         Initialization
             [ ChannelError ]
             |_| { "link initialization failed during channel counterparty verification" },
@@ -165,3 +172,36 @@ impl HasExpiredOrFrozenError for LinkError {
         self.detail().is_expired_or_frozen_error()
     }
 }
+
+impl HasErrorKind for LinkErrorDetail {
+    fn error_kind(&self) -> ErrorKind {
+        match self {
+            Self::Relayer(e) => e.source.error_kind(),
+            Self::Initialization(e) => e.source.error_kind(),
+            Self::PacketProofsConstructor(e) => e.source.error_kind(),
+            Self::Query(e) => e.source.error_kind(),
+            Self::Channel(e) => e.source.error_kind(),
+            Self::ChannelNotFound(e) => e.source.error_kind(),
+            Self::Connection(e) => e.source.error_kind(),
+            Self::Client(e) => e.source.error_kind(),
+            Self::Signer(e) => e.source.error_kind(),
+            Self::Send(e) => e.error_kind(),
+            _ => ErrorKind::Other,
+        }
+    }
+}
+
+impl HasErrorKind for SendSubdetail {
+    fn error_kind(&self) -> ErrorKind {
+        match &self.event {
+            IbcEvent::ChainError(log) if is_redundant_tx_error(log) => ErrorKind::RedundantPackets,
+            _ => ErrorKind::TxFailed,
+        }
+    }
+}
+
+impl HasErrorKind for LinkError {
+    fn error_kind(&self) -> ErrorKind {
+        self.detail().error_kind()
+    }
+}
@@ -6,6 +6,7 @@ use ibc_proto::google::protobuf::Any;
 use tracing::{debug, info};
 
 use ibc_relayer_types::core::ics02_client::client_state::ClientState;
+use ibc_relayer_types::core::ics04_channel::packet::Sequence;
 use ibc_relayer_types::Height;
 
 use crate::chain::handle::ChainHandle;
@@ -136,6 +137,15 @@ impl OperationalData {
         }
     }
 
+    /// Returns the sequences of the packets relayed by the messages of this operational data.
+    pub fn sequences(&self) -> Vec<Sequence> {
+        self.batch
+            .iter()
+            .filter_map(|transit| transit.event_with_height.event.packet())
+            .map(|packet| packet.sequence)
+            .collect()
+    }
+
     /// Transforms `self` into the list of events accompanied with the tracking ID.
     pub fn into_events(self) -> TrackedEvents {
         let events = self
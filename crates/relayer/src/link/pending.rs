@@ -2,7 +2,7 @@ use core::iter::Iterator;
 use core::time::Duration;
 use std::time::Instant;
 
-use tracing::{debug, error, trace, trace_span};
+use tracing::{debug, error, info_span, trace, trace_span, warn};
 
 use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
 use ibc_relayer_types::events::IbcEvent;
@@ -10,8 +10,11 @@ use ibc_relayer_types::events::IbcEvent;
 use crate::chain::requests::{QueryTxHash, QueryTxRequest};
 use crate::chain::tracking::TrackingId;
 use crate::error::Error as RelayerError;
+use crate::link::redundancy::PacketMsgsOutcome;
 use crate::link::{error::LinkError, RelayPath};
+use crate::state_store::PathState;
 use crate::telemetry;
+use crate::util::pretty::PrettySlice;
 use crate::util::queue::Queue;
 use crate::{
     chain::handle::ChainHandle,
@@ -49,6 +52,7 @@ pub struct PendingTxs<Chain> {
     pub port_id: PortId,
     pub counterparty_chain_id: ChainId,
     pub pending_queue: Queue<PendingData>,
+    state: Option<PathState>,
 }
 
 impl<Chain> PendingTxs<Chain> {
@@ -64,8 +68,15 @@ impl<Chain> PendingTxs<Chain> {
             port_id,
             counterparty_chain_id,
             pending_queue: Queue::new(),
+            state: None,
         }
     }
+
+    /// Record the pending transactions in the state store of the relaying path,
+    /// so that they can be confirmed after a restart.
+    pub fn set_state(&mut self, state: PathState) {
+        self.state = Some(state);
+    }
 }
 
 impl<Chain: ChainHandle> PendingTxs<Chain> {
@@ -73,6 +84,24 @@ impl<Chain: ChainHandle> PendingTxs<Chain> {
         self.chain.id()
     }
 
+    fn record_unconfirmed(&self, tx_hashes: &TxHashes) {
+        if let Some(state) = &self.state {
+            state
+                .add_unconfirmed_txs(&self.chain_id(), &tx_hashes.0)
+                .unwrap_or_else(|e| warn!("failed to record pending txs in the state store: {e}"));
+        }
+    }
+
+    fn forget_unconfirmed(&self, tx_hashes: &TxHashes) {
+        if let Some(state) = &self.state {
+            state
+                .remove_unconfirmed_txs(&tx_hashes.0)
+                .unwrap_or_else(|e| {
+                    warn!("failed to remove pending txs from the state store: {e}")
+                });
+        }
+    }
+
     /// Insert a new pending transaction to the back of the queue.
     pub fn insert_new_pending_tx(&self, r: AsyncReply, od: OperationalData) {
         let mut tx_hashes = Vec::new();
@@ -116,9 +145,12 @@ impl<Chain: ChainHandle> PendingTxs<Chain> {
             }
         }
 
+        let tx_hashes = TxHashes(tx_hashes);
+        self.record_unconfirmed(&tx_hashes);
+
         let u = PendingData {
             original_od: od,
-            tx_hashes: TxHashes(tx_hashes),
+            tx_hashes,
             submit_time: Instant::now(),
             error_events,
         };
@@ -126,6 +158,37 @@ impl<Chain: ChainHandle> PendingTxs<Chain> {
         self.pending_queue.push_back(u);
     }
 
+    fn record_redundant_msgs<ChainA: ChainHandle, ChainB: ChainHandle>(
+        &self,
+        od: &OperationalData,
+        events: &[IbcEvent],
+        relay_path: &RelayPath<ChainA, ChainB>,
+    ) {
+        let outcome = PacketMsgsOutcome::from_events(&od.batch, events);
+
+        if outcome.packet_msgs == 0 {
+            return;
+        }
+
+        if outcome.redundant_msgs > 0 {
+            warn!(
+                "{} out of {} packet messages were already relayed",
+                outcome.redundant_msgs, outcome.packet_msgs
+            );
+
+            telemetry!(
+                redundant_packet_msgs,
+                outcome.redundant_msgs,
+                &self.chain.id(),
+                &self.channel_id,
+                &self.port_id,
+                &self.counterparty_chain_id
+            );
+        }
+
+        relay_path.record_redundancy(outcome.all_redundant());
+    }
+
     fn check_tx_events(&self, tx_hashes: &TxHashes) -> Result<Option<Vec<IbcEvent>>, RelayerError> {
         let mut all_events = Vec::new();
         for hash in &tx_hashes.0 {
@@ -160,16 +223,24 @@ impl<Chain: ChainHandle> PendingTxs<Chain> {
             let tx_hashes = &pending.tx_hashes;
             let submit_time = &pending.submit_time;
 
+            // Some of the transactions were rejected, so their packets were not relayed
+            if !pending.error_events.is_empty() {
+                relay_path.record_relay_failure();
+            }
+
             if tx_hashes.0.is_empty() {
                 return Ok(Some(RelaySummary::from_events(pending.error_events)));
             }
 
-            let span = trace_span!(
+            let span = info_span!(
                 "processing pending tx",
                 chain = %self.chain_id(),
                 counterparty_chain = %self.counterparty_chain_id,
                 port = %self.port_id,
                 channel = %self.channel_id,
+                tracking_id = %pending.tracking_id(),
+                sequences = %PrettySlice(&pending.original_od.sequences()),
+                tx_hashes = %PrettySlice(&tx_hashes.0),
             );
 
             let _guard = span.enter();
@@ -203,6 +274,8 @@ impl<Chain: ChainHandle> PendingTxs<Chain> {
 
                                 match new_od.map(f) {
                                     Some(Ok(reply)) => {
+                                        // The resubmitted transactions are recorded with their new hashes
+                                        self.forget_unconfirmed(tx_hashes);
                                         self.insert_new_pending_tx(reply, pending.original_od);
                                         Ok(None)
                                     }
@@ -212,12 +285,15 @@ impl<Chain: ChainHandle> PendingTxs<Chain> {
                                     }
                                     None => {
                                         // No operational data was regenerated; nothing to resubmit
+                                        self.forget_unconfirmed(tx_hashes);
                                         Ok(None)
                                     }
                                 }
                             }
                             None => {
                                 // `clear_interval != 0` such that resubmission has been disabled
+                                self.forget_unconfirmed(tx_hashes);
+                                relay_path.record_relay_failure();
                                 Ok(None)
                             }
                         }
@@ -251,6 +327,15 @@ impl<Chain: ChainHandle> PendingTxs<Chain> {
                         &self.counterparty_chain_id
                     );
 
+                    self.forget_unconfirmed(tx_hashes);
+
+                    // Messages for packets which another relayer relayed first are executed
+                    // as no-ops. Only count them if all the transactions were committed,
+                    // as the events of the failed ones are missing.
+                    if pending.error_events.is_empty() {
+                        self.record_redundant_msgs(&pending.original_od, &events, relay_path);
+                    }
+
                     // Append the events corresponding to errors from the pending tx.
                     events.extend(pending.error_events);
 
@@ -0,0 +1,215 @@
+//! Detection of packet messages which were already relayed by another relayer,
+//! and back-off from relaying on paths where Hermes keeps losing the race.
+
+use std::time::Instant;
+
+use tracing::{info, warn};
+
+use ibc_relayer_types::core::ics04_channel::msgs::{
+    acknowledgement, recv_packet, timeout, timeout_on_close,
+};
+use ibc_relayer_types::events::IbcEvent;
+
+use crate::config::RedundantBackoff;
+use crate::link::operational_data::TransitMessage;
+use crate::util::lock::{LockExt, RwArc};
+
+/// Error reported by the `RedundantRelayDecorator` ante handler of ibc-go
+/// when all the packet messages of a transaction were already relayed.
+const REDUNDANT_TX_ERROR: &str = "packet messages are redundant";
+
+/// Whether the given log of a failed transaction reports that
+/// all its packet messages were already relayed.
+pub fn is_redundant_tx_error(log: &str) -> bool {
+    log.contains(REDUNDANT_TX_ERROR)
+}
+
+/// The packet messages of a committed transaction,
+/// and how many of them were redundant.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct PacketMsgsOutcome {
+    pub packet_msgs: u64,
+    pub redundant_msgs: u64,
+}
+
+impl PacketMsgsOutcome {
+    /// Compares the packet messages in the given batch with the events of the
+    /// transactions which carried them.
+    ///
+    /// A packet message which takes effect emits a `recv_packet`, `acknowledge_packet`
+    /// or `timeout_packet` event, whereas ibc-go executes
+    /// a message for a packet which was already relayed as a no-op, without
+    /// emitting any event.
+    pub fn from_events(batch: &[TransitMessage], events: &[IbcEvent]) -> Self {
+        let count_msgs = |type_urls: &[&str]| {
+            batch
+                .iter()
+                .filter(|transit| type_urls.contains(&transit.msg.type_url.as_str()))
+                .count() as u64
+        };
+
+        let count_events = |matches: fn(&IbcEvent) -> bool| {
+            events.iter().filter(|event| matches(event)).count() as u64
+        };
+
+        let recv_msgs = count_msgs(&[recv_packet::TYPE_URL]);
+        let ack_msgs = count_msgs(&[acknowledgement::TYPE_URL]);
+        let timeout_msgs = count_msgs(&[timeout::TYPE_URL, timeout_on_close::TYPE_URL]);
+
+        // Applications may write their acknowledgement asynchronously,
+        // so received packets are not matched with `write_acknowledgement` events
+        let redundant_msgs = recv_msgs.saturating_sub(count_events(|event| {
+            matches!(event, IbcEvent::ReceivePacket(_))
+        })) + ack_msgs.saturating_sub(count_events(|event| {
+            matches!(event, IbcEvent::AcknowledgePacket(_))
+        })) + timeout_msgs.saturating_sub(count_events(|event| {
+            matches!(event, IbcEvent::TimeoutPacket(_))
+        }));
+
+        Self {
+            packet_msgs: recv_msgs + ack_msgs + timeout_msgs,
+            redundant_msgs,
+        }
+    }
+
+    /// Whether the transactions carried packet messages, all of which were redundant.
+    pub fn all_redundant(&self) -> bool {
+        self.packet_msgs > 0 && self.redundant_msgs == self.packet_msgs
+    }
+}
+
+#[derive(Default)]
+struct BackoffState {
+    consecutive_redundant: u64,
+    backoff_until: Option<Instant>,
+}
+
+/// Tracks the redundant transactions submitted on a relaying path, and backs off
+/// from relaying on the path when too many of them are redundant in a row.
+#[derive(Clone)]
+pub struct RedundancyTracker {
+    backoff: Option<RedundantBackoff>,
+    state: RwArc<BackoffState>,
+}
+
+impl RedundancyTracker {
+    pub fn new(backoff: Option<RedundantBackoff>) -> Self {
+        Self {
+            backoff,
+            state: RwArc::new_lock(BackoffState::default()),
+        }
+    }
+
+    /// Records whether the packet messages of a transaction were all redundant,
+    /// and starts backing off if the configured number of consecutive redundant
+    /// transactions is reached.
+    pub fn record(&self, redundant: bool) {
+        let backoff = match &self.backoff {
+            Some(backoff) => backoff,
+            None => return,
+        };
+
+        let mut state = self.state.acquire_write();
+
+        if state.backoff_until.is_some() {
+            return;
+        }
+
+        if !redundant {
+            state.consecutive_redundant = 0;
+            return;
+        }
+
+        state.consecutive_redundant += 1;
+
+        if state.consecutive_redundant >= backoff.after {
+            warn!(
+                "the last {} transactions were redundant, backing off from relaying for {:?}",
+                state.consecutive_redundant, backoff.delay
+            );
+
+            state.consecutive_redundant = 0;
+            state.backoff_until = Some(Instant::now() + backoff.delay);
+        }
+    }
+
+    /// Whether relaying on the path is currently backing off.
+    pub fn is_backing_off(&self) -> bool {
+        matches!(self.state.acquire_read().backoff_until, Some(until) if Instant::now() < until)
+    }
+
+    /// Returns `true` once the back-off period is over, after which
+    /// the messages held back in the meantime must be regenerated.
+    pub fn backoff_ended(&self) -> bool {
+        let mut state = self.state.acquire_write();
+
+        match state.backoff_until {
+            Some(until) if Instant::now() >= until => {
+                info!("resuming relaying after backing off");
+
+                state.backoff_until = None;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::time::Duration;
+
+    use test_log::test;
+
+    #[test]
+    fn detect_redundant_tx_error() {
+        assert!(is_redundant_tx_error(
+            "failed to execute message; message index: 0: packet messages are redundant"
+        ));
+        assert!(!is_redundant_tx_error("out of gas in location: WriteFlat"));
+    }
+
+    #[test]
+    fn backoff_after_consecutive_redundant_txs() {
+        let tracker = RedundancyTracker::new(Some(RedundantBackoff {
+            after: 2,
+            delay: Duration::from_secs(3600),
+        }));
+
+        tracker.record(true);
+        tracker.record(false);
+        tracker.record(true);
+        assert!(!tracker.is_backing_off());
+
+        tracker.record(true);
+        assert!(tracker.is_backing_off());
+        assert!(!tracker.backoff_ended());
+    }
+
+    #[test]
+    fn resume_after_backoff() {
+        let tracker = RedundancyTracker::new(Some(RedundantBackoff {
+            after: 1,
+            delay: Duration::ZERO,
+        }));
+
+        tracker.record(true);
+        assert!(!tracker.is_backing_off());
+        assert!(tracker.backoff_ended());
+        assert!(!tracker.backoff_ended());
+    }
+
+    #[test]
+    fn no_backoff_unless_configured() {
+        let tracker = RedundancyTracker::new(None);
+
+        for _ in 0..10 {
+            tracker.record(true);
+        }
+
+        assert!(!tracker.is_backing_off());
+        assert!(!tracker.backoff_ended());
+    }
+}
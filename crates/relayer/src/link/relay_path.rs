@@ -1,12 +1,18 @@
 use alloc::collections::BTreeMap as HashMap;
+use alloc::collections::BTreeSet;
 use alloc::collections::VecDeque;
 use std::ops::Sub;
+use std::str::FromStr;
 use std::time::{Duration, Instant};
 
 use ibc_proto::google::protobuf::Any;
 use itertools::Itertools;
 use tracing::{debug, error, info, span, trace, warn, Level};
 
+use ibc_relayer_types::applications::transfer::error::Error as Ics20Error;
+use ibc_relayer_types::applications::transfer::packet::raw_packet_data_from_json;
+use ibc_relayer_types::applications::transfer::Amount;
+use ibc_relayer_types::clients::ics09_localhost::sentinel_proofs;
 use ibc_relayer_types::core::ics02_client::events::ClientMisbehaviour as ClientMisbehaviourEvent;
 use ibc_relayer_types::core::ics04_channel::channel::{
     ChannelEnd, Ordering, State as ChannelState,
@@ -16,9 +22,10 @@ use ibc_relayer_types::core::ics04_channel::msgs::{
     acknowledgement::MsgAcknowledgement, chan_close_confirm::MsgChannelCloseConfirm,
     recv_packet::MsgRecvPacket, timeout::MsgTimeout, timeout_on_close::MsgTimeoutOnClose,
 };
-use ibc_relayer_types::core::ics04_channel::packet::{Packet, PacketMsgType};
+use ibc_relayer_types::core::ics04_channel::packet::{Packet, PacketMsgType, Sequence};
 use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
 use ibc_relayer_types::events::{IbcEvent, IbcEventType, WithBlockDataType};
+use ibc_relayer_types::proofs::Proofs;
 use ibc_relayer_types::signer::Signer;
 use ibc_relayer_types::timestamp::Timestamp;
 use ibc_relayer_types::tx_msg::Msg;
@@ -34,14 +41,18 @@ use crate::chain::requests::QueryHeight;
 use crate::chain::requests::QueryHostConsensusStateRequest;
 use crate::chain::requests::QueryNextSequenceReceiveRequest;
 use crate::chain::requests::QueryPacketCommitmentRequest;
-use crate::chain::requests::QueryTxRequest;
 use crate::chain::requests::QueryUnreceivedAcksRequest;
 use crate::chain::requests::QueryUnreceivedPacketsRequest;
 use crate::chain::requests::{IncludeProof, Qualified};
+use crate::chain::requests::{QueryTxHash, QueryTxRequest};
 use crate::chain::tracking::TrackedMsgs;
 use crate::chain::tracking::TrackingId;
 use crate::channel::error::ChannelError;
 use crate::channel::Channel;
+use crate::config::filter::TransferPolicy;
+use crate::config::types::Ics20FieldSizeValidation;
+use crate::config::RedundantBackoff;
+use crate::error_kind::{ErrorKind, HasErrorKind};
 use crate::event::monitor::EventBatch;
 use crate::event::IbcEventWithHeight;
 use crate::foreign_client::{ForeignClient, ForeignClientError};
@@ -53,13 +64,17 @@ use crate::link::packet_events::query_packet_events_with;
 use crate::link::packet_events::query_send_packet_events;
 use crate::link::packet_events::query_write_ack_events;
 use crate::link::pending::PendingTxs;
+use crate::link::redundancy::{is_redundant_tx_error, RedundancyTracker};
 use crate::link::relay_sender::{AsyncReply, SubmitReply};
 use crate::link::relay_summary::RelaySummary;
 use crate::link::{pending, relay_sender};
 use crate::path::PathIdentifiers;
+use crate::state_store::{PathState, UnconfirmedTx};
 use crate::telemetry;
+use crate::transfer::TransferError;
 use crate::util::collate::CollatedIterExt;
-use crate::util::pretty::PrettyEvents;
+use crate::util::lock::{LockExt, RwArc};
+use crate::util::pretty::{PrettyEvents, PrettySlice};
 use crate::util::queue::Queue;
 
 const MAX_RETRIES: usize = 5;
@@ -108,6 +123,77 @@ pub struct RelayPath<ChainA: ChainHandle, ChainB: ChainHandle> {
     // transactions if [`confirm_txes`] is true.
     pending_txs_src: PendingTxs<ChainA>,
     pending_txs_dst: PendingTxs<ChainB>,
+
+    // Records the progress of the relaying path on disk, if enabled.
+    state: Option<PathState>,
+
+    // The progress of the relaying path which is not yet recorded as its checkpoint.
+    pending_checkpoint: RwArc<PendingCheckpoint>,
+
+    // Tracks the transactions whose packet messages were already relayed,
+    // and whether to back off from relaying on this path.
+    redundancy: RedundancyTracker,
+
+    // Whether to record the block times of the packet events of this path,
+    // from which the packet latencies are computed.
+    packet_latencies: bool,
+
+    // The sequences of the packets skipped because they are excluded in the configuration,
+    // or because their ICS-20 fields exceed the size limits, which were already reported,
+    // so that they are only logged and counted once in the telemetry.
+    reported_excluded_sequences: RwArc<BTreeSet<Sequence>>,
+    reported_oversized_packets: RwArc<BTreeSet<Sequence>>,
+}
+
+/// The outcome of resuming a relaying path from the state recorded
+/// in the [`StateStore`](crate::state_store::StateStore).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Resumption {
+    /// The number of transactions submitted before the restart
+    /// which could not be confirmed.
+    pub unconfirmed_txs: usize,
+
+    /// Whether the checkpoint of the path is too far behind the source chain
+    /// for the packets sent since then to be cleared by the periodic packet
+    /// clearing soon enough.
+    pub stale_checkpoint: bool,
+}
+
+impl Resumption {
+    /// Whether pending packets must be cleared when the worker starts, either
+    /// because `clear_on_start` is set, to resubmit the transactions which could
+    /// not be confirmed, or to relay the packets sent since a stale checkpoint.
+    pub fn must_clear_packets(&self, clear_on_start: bool) -> bool {
+        clear_on_start || self.unconfirmed_txs > 0 || self.stale_checkpoint
+    }
+}
+
+/// The transfer policy of the source chain of a relaying path, fetched once per batch
+/// of events or packet clearing, along with the status of the destination chain,
+/// which is fetched once a packet is denied, to check whether it has timed out.
+#[derive(Default)]
+struct TransferPolicyCheck {
+    /// The transfer policy, or `None` if all the transfers are relayed
+    policy: Option<TransferPolicy>,
+
+    /// Whether the transfer policy could not be fetched,
+    /// in which case the transfers are relayed once they have timed out
+    unavailable: bool,
+
+    /// The status of the destination chain, or `None` if it could not be queried
+    dst_status: Option<Option<ChainStatus>>,
+}
+
+/// The latest height at which the events of a relaying path were scheduled,
+/// which becomes the checkpoint of the path once the resulting operational data
+/// has been relayed, and the transactions confirmed.
+#[derive(Debug, Default)]
+struct PendingCheckpoint {
+    height: Option<Height>,
+
+    /// Whether some operational data could not be relayed since the last packet
+    /// clearing, in which case the checkpoint must not move until the next one.
+    failed: bool,
 }
 
 impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
@@ -152,9 +238,159 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             confirm_txes: with_tx_confirmation,
             pending_txs_src: PendingTxs::new(src_chain, src_channel_id, src_port_id, dst_chain_id),
             pending_txs_dst: PendingTxs::new(dst_chain, dst_channel_id, dst_port_id, src_chain_id),
+
+            state: None,
+            pending_checkpoint: RwArc::new_lock(PendingCheckpoint::default()),
+
+            redundancy: RedundancyTracker::new(None),
+
+            packet_latencies: false,
+
+            reported_excluded_sequences: RwArc::new_lock(BTreeSet::new()),
+            reported_oversized_packets: RwArc::new_lock(BTreeSet::new()),
+        })
+    }
+
+    /// Record the progress of this relaying path in the given state store.
+    pub fn set_state(&mut self, state: PathState) {
+        self.pending_txs_src.set_state(state.clone());
+        self.pending_txs_dst.set_state(state.clone());
+        self.state = Some(state);
+    }
+
+    /// Back off from relaying on this path after the given number of consecutive
+    /// transactions whose packet messages were all already relayed by another relayer.
+    pub fn set_redundant_backoff(&mut self, backoff: Option<RedundantBackoff>) {
+        self.redundancy = RedundancyTracker::new(backoff);
+    }
+
+    /// Record the latencies of the packets relayed on this path in the telemetry.
+    pub fn set_packet_latencies(&mut self, enabled: bool) {
+        self.packet_latencies = enabled;
+    }
+
+    /// Resume relaying from the state recorded before a restart, if any.
+    ///
+    /// Checks whether the transactions which were still pending when the relayer
+    /// stopped have been committed since, and whether the checkpoint of the path
+    /// is less than `clear_interval` blocks behind the latest height of the source chain.
+    ///
+    /// The checkpoint is not a height from which packets are cleared, since packet
+    /// clearing always considers all the pending packets of the channel. It only
+    /// decides whether clearing on start can be skipped when `clear_on_start` is off.
+    /// A checkpoint ahead of the source chain, e.g. because the node is lagging behind
+    /// or the chain was reset, is considered stale.
+    pub fn resume(&self, clear_interval: u64) -> Result<Resumption, LinkError> {
+        let state = match &self.state {
+            Some(state) => state,
+            None => {
+                return Ok(Resumption {
+                    unconfirmed_txs: 0,
+                    stale_checkpoint: false,
+                })
+            }
+        };
+
+        let pending = state.unconfirmed_txs().map_err(LinkError::state_store)?;
+
+        let mut unconfirmed_txs = 0;
+        for tx in &pending {
+            if !self.is_tx_committed(tx) {
+                unconfirmed_txs += 1;
+            }
+        }
+
+        // The transactions are either committed, or will be submitted again
+        // when clearing the packets, so there is no need to track them anymore.
+        let hashes = pending.iter().map(|tx| tx.hash).collect_vec();
+        state
+            .remove_unconfirmed_txs(&hashes)
+            .map_err(LinkError::state_store)?;
+
+        let stale_checkpoint = match state.checkpoint().map_err(LinkError::state_store)? {
+            Some(checkpoint) => {
+                let latest_height = self
+                    .src_chain()
+                    .query_latest_height()
+                    .map_err(LinkError::relayer)?;
+
+                let blocks_behind = latest_height
+                    .revision_height()
+                    .checked_sub(checkpoint.revision_height());
+
+                clear_interval == 0
+                    || latest_height.revision_number() != checkpoint.revision_number()
+                    || blocks_behind.map_or(true, |blocks| blocks >= clear_interval)
+            }
+            None => false,
+        };
+
+        info!(
+            checkpoint_is_stale = stale_checkpoint,
+            unconfirmed_txs, "resuming relaying from the state recorded before the restart"
+        );
+
+        Ok(Resumption {
+            unconfirmed_txs,
+            stale_checkpoint,
         })
     }
 
+    fn is_tx_committed(&self, tx: &UnconfirmedTx) -> bool {
+        let request = QueryTxRequest::Transaction(QueryTxHash(tx.hash));
+
+        let result = if tx.chain_id == self.src_chain().id() {
+            self.src_chain().query_txs(request)
+        } else {
+            self.dst_chain().query_txs(request)
+        };
+
+        match result {
+            Ok(events) => !events.is_empty(),
+            Err(e) => {
+                warn!(tx_hash = %tx.hash, "failed to query transaction submitted before the restart: {e}");
+                false
+            }
+        }
+    }
+
+    /// Record that the events of this relaying path up to the given height
+    /// have been scheduled. The checkpoint of the path moves to that height
+    /// once the resulting operational data has been relayed.
+    fn schedule_checkpoint(&self, height: Height) {
+        let mut pending = self.pending_checkpoint.acquire_write();
+        pending.height = pending.height.max(Some(height));
+    }
+
+    /// Record that some operational data could not be relayed, which holds back
+    /// the checkpoint of this relaying path until the next packet clearing.
+    pub(crate) fn record_relay_failure(&self) {
+        self.pending_checkpoint.acquire_write().failed = true;
+    }
+
+    /// Move the checkpoint of this relaying path to the latest scheduled height,
+    /// if all the operational data scheduled so far has been relayed successfully,
+    /// and, with `tx_confirmation` enabled, all the transactions have been confirmed.
+    fn maybe_advance_checkpoint(&self) {
+        let state = match &self.state {
+            Some(state) => state,
+            None => return,
+        };
+
+        let relayed = self.src_operational_data.is_empty()
+            && self.dst_operational_data.is_empty()
+            && self.pending_txs_src.pending_queue.is_empty()
+            && self.pending_txs_dst.pending_queue.is_empty();
+
+        let pending = self.pending_checkpoint.acquire_read();
+
+        if let (true, false, Some(height)) = (relayed, pending.failed, pending.height) {
+            state
+                .advance_checkpoint(height)
+                .unwrap_or_else(|e| warn!("failed to record checkpoint in the state store: {e}"));
+        }
+    }
+
     pub fn src_chain(&self) -> &ChainA {
         self.channel.src_chain()
     }
@@ -305,6 +541,47 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             .max_block_time)
     }
 
+    /// Filters out the sequences which the configuration of the source chain
+    /// excludes from clearing on the source channel.
+    pub(crate) fn exclude_sequences(
+        &self,
+        sequences: Vec<Sequence>,
+    ) -> Result<Vec<Sequence>, LinkError> {
+        let excluded_sequences = self
+            .src_chain()
+            .config()
+            .map_err(LinkError::relayer)?
+            .excluded_sequences;
+
+        let (excluded, sequences): (Vec<Sequence>, Vec<Sequence>) = sequences
+            .into_iter()
+            .partition(|sequence| excluded_sequences.is_excluded(self.src_channel_id(), *sequence));
+
+        // Only report the sequences which were not skipped by a previous clearing
+        let newly_excluded = {
+            let mut reported = self.reported_excluded_sequences.acquire_write();
+
+            excluded
+                .into_iter()
+                .filter(|sequence| reported.insert(*sequence))
+                .collect_vec()
+        };
+
+        if !newly_excluded.is_empty() {
+            info!(
+                src_chain = %self.src_chain().id(),
+                src_channel = %self.src_channel_id(),
+                total = newly_excluded.len(),
+                sequences = %newly_excluded.iter().copied().collated().format(", "),
+                "skipping packets whose sequences are excluded in the configuration of the source chain",
+            );
+
+            telemetry!(self.record_excluded_sequences(newly_excluded.len()));
+        }
+
+        Ok(sequences)
+    }
+
     fn unordered_channel(&self) -> bool {
         self.channel.ordering == Ordering::Unordered
     }
@@ -350,6 +627,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         let proofs = self
             .src_chain()
             .build_channel_proofs(self.src_port_id(), src_channel_id, event.height)
+            .map(|proofs| self.adapt_proofs_for_dst(proofs))
             .map_err(|e| LinkError::channel(ChannelError::channel_proof(e)))?;
 
         // Build the domain type message
@@ -372,6 +650,8 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
     ) -> TrackedEvents {
         let src_channel_id = self.src_channel_id();
 
+        let mut transfer_policy = self.transfer_policy_check();
+
         let mut result = vec![];
 
         for event_with_height in events.into_iter() {
@@ -379,6 +659,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
                 IbcEvent::SendPacket(send_packet_ev) => {
                     if src_channel_id == send_packet_ev.src_channel_id()
                         && self.src_port_id() == send_packet_ev.src_port_id()
+                        && self.transfer_policy_allows(&mut transfer_policy, &send_packet_ev.packet)
                     {
                         result.push(event_with_height);
                     }
@@ -412,6 +693,118 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         TrackedEvents::new(result, tracking_id)
     }
 
+    /// Fetches the transfer policy of the packet filter configured for the source chain,
+    /// to check the packets of a batch of events or of a packet clearing against it.
+    fn transfer_policy_check(&self) -> TransferPolicyCheck {
+        let mut check = TransferPolicyCheck::default();
+
+        if self.src_port_id() != &PortId::transfer() {
+            return check;
+        }
+
+        match self.src_chain().config() {
+            Ok(config) => {
+                let policy = config.packet_filter.transfer;
+
+                if !policy.allows_all() {
+                    check.policy = Some(policy);
+                }
+            }
+            Err(e) => {
+                warn!(
+                    "failed to fetch the transfer policy of the source chain, \
+                    only relaying the transfers which have timed out: {e}"
+                );
+
+                check.unavailable = true;
+            }
+        }
+
+        check
+    }
+
+    /// Checks a packet sent on a `transfer` port against the transfer policy of the
+    /// packet filter configured for the source chain. Packets which cannot be checked,
+    /// eg. because their data cannot be decoded, are not relayed.
+    ///
+    /// Packets which are not allowed are still relayed once they have timed out on the
+    /// destination chain, so that their timeout is relayed back to the source chain.
+    fn transfer_policy_allows(&self, check: &mut TransferPolicyCheck, packet: &Packet) -> bool {
+        if !check.unavailable {
+            let Some(policy) = &check.policy else {
+                return true;
+            };
+
+            match self.check_transfer_policy(policy, packet) {
+                Ok(true) => return true,
+                Ok(false) => {}
+                Err(e) => {
+                    warn!(
+                        packet = %packet,
+                        "not relaying packet which could not be checked against the transfer policy: {e}",
+                    );
+                }
+            }
+        }
+
+        let dst_status = check.dst_status.get_or_insert_with(|| {
+            self.dst_chain()
+                .query_application_status()
+                .map_err(|e| {
+                    warn!(
+                        "failed to check whether the packets denied by the transfer policy have timed out: {e}",
+                    );
+                })
+                .ok()
+        });
+
+        dst_status.as_ref().map_or(false, |status| {
+            packet.timed_out(&status.timestamp, status.height)
+        })
+    }
+
+    fn check_transfer_policy(
+        &self,
+        policy: &TransferPolicy,
+        packet: &Packet,
+    ) -> Result<bool, LinkError> {
+        let to_link_error = |e: Ics20Error| LinkError::packet(TransferError::token_transfer(e));
+
+        let data = raw_packet_data_from_json(&packet.data).map_err(to_link_error)?;
+        let amount = Amount::from_str(&data.amount).map_err(to_link_error)?;
+        let base_denom = self.resolve_base_denom(&data.denom)?;
+
+        let allowed = policy.should_relay(&base_denom, &amount);
+
+        if !allowed {
+            debug!(
+                packet = %packet,
+                %base_denom,
+                %amount,
+                "transfer is not allowed by the transfer policy of the packet filter"
+            );
+        }
+
+        Ok(allowed)
+    }
+
+    /// Resolves the base denomination of the tokens transferred by an ICS-20 packet.
+    /// Denominations of the form `ibc/{hash}` are resolved through the denomination
+    /// trace registered for the hash on the source chain, the others by stripping
+    /// their trace path.
+    fn resolve_base_denom(&self, denom: &str) -> Result<String, LinkError> {
+        if let Some(hash) = denom.strip_prefix("ibc/") {
+            let denom_trace = self
+                .src_chain()
+                .query_denom_trace(hash.to_string())
+                .map_err(|e| LinkError::query(self.src_chain().id(), e))?;
+
+            return Ok(denom_trace.base_denom);
+        }
+
+        Ok(strip_trace_path(denom).to_string())
+    }
+
     fn relay_pending_packets(&self, height: Option<Height>) -> Result<(), LinkError> {
         let _span = span!(Level::ERROR, "relay_pending_packets", ?height).entered();
 
@@ -447,6 +840,14 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
 
         debug!(height = ?clear_height, "done relaying pending packets at clear height");
 
+        // All the pending packets up to the clear height are now scheduled,
+        // including the ones whose operational data could not be relayed before.
+        if let Some(clear_height) = clear_height {
+            let mut pending = self.pending_checkpoint.acquire_write();
+            pending.height = pending.height.max(Some(clear_height));
+            pending.failed = false;
+        }
+
         Ok(())
     }
 
@@ -460,6 +861,10 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         )
         .entered();
 
+        if self.packet_latencies {
+            telemetry!(self.record_packet_latencies(&batch));
+        }
+
         // Collect relevant events from the incoming batch & adjust their height.
         let events = self.filter_relaying_events(batch.events, batch.tracking_id);
 
@@ -471,7 +876,11 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         });
 
         // Transform the events into operational data items
-        self.events_to_operational_data(events)
+        self.events_to_operational_data(events)?;
+
+        self.schedule_checkpoint(batch.height);
+
+        Ok(())
     }
 
     /// Produces and schedules operational data for this relaying path based on the input events.
@@ -642,7 +1051,15 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         initial_od: OperationalData,
     ) -> Result<S::Reply, LinkError> {
         // We will operate on potentially different operational data if the initial one fails.
-        let _span = span!(Level::INFO, "relay", odata = %initial_od.info()).entered();
+        let _span = span!(
+            Level::INFO,
+            "relay",
+            odata = %initial_od.info(),
+            tracking_id = %initial_od.tracking_id,
+            sequences = %PrettySlice(&initial_od.sequences()),
+            tx_hashes = tracing::field::Empty,
+        )
+        .entered();
 
         let mut odata = initial_od;
 
@@ -655,6 +1072,20 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
                     // Done with this op. data
                     info!("submitted");
 
+                    // The transactions rejected by `CheckTx` are only
+                    // reported in the reply of the asynchronous sender
+                    self.record_tx_errors(reply.error_kinds());
+
+                    let redundant_txs = reply.redundant_txs();
+
+                    if redundant_txs > 0 {
+                        self.record_redundant_txs(odata.target, redundant_txs);
+                    } else if !self.confirm_txes {
+                        // Otherwise, whether the packet messages were executed as no-ops
+                        // is recorded once the transactions are confirmed
+                        self.redundancy.record(false);
+                    }
+
                     telemetry!({
                         let (chain, counterparty, channel_id, port_id) =
                             self.target_info(odata.target);
@@ -667,6 +1098,14 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
                             port_id,
                             &counterparty,
                         );
+
+                        ibc_telemetry::global().tx_costs_submitted(
+                            reply.tx_hashes(),
+                            &chain,
+                            channel_id,
+                            port_id,
+                            &counterparty,
+                        );
                     });
 
                     return Ok(reply);
@@ -674,6 +1113,13 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
                 Err(LinkError(error::LinkErrorDetail::Send(e), _)) => {
                     // This error means we could retry
                     error!("error {}", e.event);
+
+                    self.record_tx_errors(vec![e.error_kind()]);
+
+                    if matches!(&e.event, IbcEvent::ChainError(log) if is_redundant_tx_error(log)) {
+                        self.record_redundant_txs(odata.target, 1);
+                    }
+
                     if i + 1 == MAX_RETRIES {
                         error!("{}/{} retries exhausted. giving up", i + 1, MAX_RETRIES)
                     } else {
@@ -812,6 +1258,51 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         }
     }
 
+    /// Counts the errors of the transactions which failed to be submitted in the
+    /// telemetry, as errors of the packet worker of this path, since they are not
+    /// returned to it.
+    #[cfg_attr(not(feature = "telemetry"), allow(unused_variables))]
+    fn record_tx_errors(&self, kinds: Vec<ErrorKind>) {
+        telemetry!({
+            for kind in kinds {
+                ibc_telemetry::global().worker_errors(
+                    &self.src_chain().id(),
+                    ibc_telemetry::state::WorkerType::Packet,
+                    kind.as_str(),
+                );
+            }
+        });
+    }
+
+    /// Records transactions rejected because all their packet messages were already
+    /// relayed, which happens when another relayer submitted them first.
+    fn record_redundant_txs(&self, target: OperationalDataTarget, count: usize) {
+        warn!(
+            "{} transaction(s) to the {} chain rejected because their packet messages were already relayed",
+            count, target
+        );
+
+        telemetry!({
+            let (chain, counterparty, channel_id, port_id) = self.target_info(target);
+
+            ibc_telemetry::global().redundant_packet_txs(
+                count as u64,
+                &chain,
+                channel_id,
+                port_id,
+                &counterparty,
+            );
+        });
+
+        self.redundancy.record(true);
+    }
+
+    /// Records whether the packet messages of a committed transaction were all
+    /// executed as no-ops, because another relayer relayed them first.
+    pub(crate) fn record_redundancy(&self, redundant: bool) {
+        self.redundancy.record(redundant);
+    }
+
     /// Checks if a sent packet has been received on destination.
     fn send_packet_received_on_dst(&self, packet: &Packet) -> Result<bool, LinkError> {
         let unreceived_packet = self
@@ -1110,6 +1601,8 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
 
         let query_height = opt_query_height.unwrap_or(src_response_height);
 
+        let sequences = self.exclude_sequences(sequences)?;
+
         // Skip: no relevant events found.
         if sequences.is_empty() {
             return Ok(());
@@ -1123,6 +1616,8 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             "sequence numbers of unreceived packets to send to the destination chain out of the ones with commitments on the source chain",
         );
 
+        let mut transfer_policy = self.transfer_policy_check();
+
         // Chunk-up the list of sequence nrs. into smaller parts,
         // and schedule operational data incrementally across each chunk.
         for events_chunk in query_packet_events_with(
@@ -1132,6 +1627,16 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             &self.path_id,
             query_send_packet_events,
         ) {
+            let events_chunk = events_chunk
+                .into_iter()
+                .filter(|event_with_height| match &event_with_height.event {
+                    IbcEvent::SendPacket(event) => {
+                        self.transfer_policy_allows(&mut transfer_policy, &event.packet)
+                    }
+                    _ => true,
+                })
+                .collect::<Vec<_>>();
+
             // Update telemetry info
             telemetry!({
                 for event_with_height in events_chunk.iter() {
@@ -1170,6 +1675,8 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
 
         let query_height = opt_query_height.unwrap_or(src_response_height);
 
+        let sequences = self.exclude_sequences(sequences)?;
+
         // Skip: no relevant events found.
         if sequences.is_empty() {
             return Ok(());
@@ -1199,6 +1706,10 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
     }
 
     fn build_recv_packet(&self, packet: &Packet, height: Height) -> Result<Option<Any>, LinkError> {
+        if !self.ics20_fields_within_limits(packet)? {
+            return Ok(None);
+        }
+
         let proofs = self
             .src_chain()
             .build_packet_proofs(
@@ -1208,6 +1719,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
                 packet.sequence,
                 height,
             )
+            .map(|proofs| self.adapt_proofs_for_dst(proofs))
             .map_err(|e| LinkError::packet_proofs_constructor(self.src_chain().id(), e))?;
 
         let msg = MsgRecvPacket::new(packet.clone(), proofs.clone(), self.dst_signer()?);
@@ -1217,6 +1729,50 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         Ok(Some(msg.to_any()))
     }
 
+    /// Checks the memo and the receiver of an ICS-20 packet against the size limits
+    /// configured for the source chain. Packets which exceed either limit are not
+    /// relayed, and will eventually time out instead.
+    fn ics20_fields_within_limits(&self, packet: &Packet) -> Result<bool, LinkError> {
+        // Only fungible token transfers are subject to the limits
+        let data = match raw_packet_data_from_json(&packet.data) {
+            Ok(data) => data,
+            Err(_) => return Ok(true),
+        };
+
+        let config = self.src_chain().config().map_err(LinkError::relayer)?;
+
+        let validations = [
+            ("memo", config.ics20_max_memo_size.check(&data.memo)),
+            (
+                "receiver",
+                config.ics20_max_receiver_size.check(&data.receiver),
+            ),
+        ];
+
+        for (field, validation) in validations {
+            if let Ics20FieldSizeValidation::Invalid { .. } = validation {
+                // The packet is checked again on every clearing until it times out,
+                // but only reported the first time
+                if self
+                    .reported_oversized_packets
+                    .acquire_write()
+                    .insert(packet.sequence)
+                {
+                    warn!(
+                        packet = %packet,
+                        "not relaying ICS-20 packet whose {field} is {validation}",
+                    );
+
+                    telemetry!(self.record_oversized_ics20_packet());
+                }
+
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
     fn build_ack_from_recv_event(
         &self,
         event: &WriteAcknowledgement,
@@ -1233,6 +1789,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
                 packet.sequence,
                 height,
             )
+            .map(|proofs| self.adapt_proofs_for_dst(proofs))
             .map_err(|e| LinkError::packet_proofs_constructor(self.src_chain().id(), e))?;
 
         let msg = MsgAcknowledgement::new(
@@ -1283,6 +1840,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
                 next_sequence_received,
                 height,
             )
+            .map(|proofs| self.adapt_proofs_for_src(proofs))
             .map_err(|e| LinkError::packet_proofs_constructor(self.dst_chain().id(), e))?;
 
         let msg = MsgTimeout::new(
@@ -1332,6 +1890,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
                 next_sequence_received,
                 height,
             )
+            .map(|proofs| self.adapt_proofs_for_src(proofs))
             .map_err(|e| LinkError::packet_proofs_constructor(self.dst_chain().id(), e))?;
 
         let msg = MsgTimeoutOnClose::new(
@@ -1436,6 +1995,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
                             // The relaying process failed; return all of the subsequent pieces of operational
                             // data along with the underlying error that occurred.
                             Err(e) => {
+                                self.record_relay_failure();
                                 unprocessed.extend(operations);
 
                                 return Err((unprocessed, e));
@@ -1472,6 +2032,16 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
     /// dropped. Subsequent pending operational data items that went unprocessed
     /// are queued up again for re-submission.
     pub fn execute_schedule(&mut self) -> Result<(), LinkError> {
+        // Hold back the operational data while backing off from relaying on this path,
+        // then regenerate it to drop the packets relayed by others in the meantime.
+        if self.redundancy.is_backing_off() {
+            return Ok(());
+        }
+
+        if self.redundancy.backoff_ended() {
+            self.regenerate_schedule()?;
+        }
+
         let src_od_iter = self.src_operational_data.take().into_iter();
 
         match self.execute_schedule_for_target_chain(src_od_iter, OperationalDataTarget::Source) {
@@ -1497,11 +2067,32 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         Ok(())
     }
 
+    /// Regenerates all the scheduled operational data from their events,
+    /// leaving out the events which were handled in the meantime.
+    fn regenerate_schedule(&mut self) -> Result<(), LinkError> {
+        let scheduled = self
+            .src_operational_data
+            .take()
+            .into_iter()
+            .chain(self.dst_operational_data.take());
+
+        for od in scheduled {
+            self.events_to_operational_data(od.into_events())?;
+        }
+
+        Ok(())
+    }
+
     /// Kicks off the process of relaying pending txs to the source and destination chains.
     ///
     /// See [`Resubmit::from_clear_interval`] for more info about the `resubmit` parameter.
+    ///
+    /// This also moves the checkpoint of the path once all the scheduled operational
+    /// data has been relayed. Without `tx_confirmation`, the transactions are assumed
+    /// to be committed as soon as they are submitted.
     pub fn process_pending_txs(&self, resubmit: Resubmit) -> RelaySummary {
         if !self.confirm_txes {
+            self.maybe_advance_checkpoint();
             return RelaySummary::empty();
         }
 
@@ -1520,6 +2111,8 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         });
 
         summary_src.extend(summary_dst);
+        self.maybe_advance_checkpoint();
+
         summary_src
     }
 
@@ -1757,6 +2350,25 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         Ok((elapsed_src_ods, elapsed_dst_ods))
     }
 
+    /// Adapts the proofs built on source to the client which verifies them on destination.
+    /// The localhost client only accepts the sentinel proof in place of the actual proofs.
+    fn adapt_proofs_for_dst(&self, proofs: Proofs) -> Proofs {
+        if self.dst_connection_id().is_localhost() {
+            sentinel_proofs(&proofs)
+        } else {
+            proofs
+        }
+    }
+
+    /// Adapts the proofs built on destination to the client which verifies them on source.
+    fn adapt_proofs_for_src(&self, proofs: Proofs) -> Proofs {
+        if self.src_connection_id().is_localhost() {
+            sentinel_proofs(&proofs)
+        } else {
+            proofs
+        }
+    }
+
     fn restore_src_client(&self) -> ForeignClient<ChainA, ChainB> {
         ForeignClient::restore(
             self.src_client_id().clone(),
@@ -1834,6 +2446,83 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         }
     }
 
+    /// Records the block times of the packet events of this path in the given batch
+    /// of events from the source chain, from which the packet latencies are computed.
+    ///
+    /// The reception of a packet is recorded at the block of its `WriteAcknowledgement`
+    /// event, which is emitted by the chain on which the packet is received.
+    #[cfg(feature = "telemetry")]
+    fn record_packet_latencies(&self, batch: &EventBatch) {
+        let Some(block_time) = batch.block_time else {
+            return;
+        };
+
+        for event_with_height in &batch.events {
+            match &event_with_height.event {
+                IbcEvent::SendPacket(ev)
+                    if ev.src_channel_id() == self.src_channel_id()
+                        && ev.src_port_id() == self.src_port_id() =>
+                {
+                    ibc_telemetry::global().packet_sent(
+                        ev.packet.sequence.into(),
+                        block_time,
+                        &self.src_chain().id(),
+                        self.src_channel_id(),
+                        self.src_port_id(),
+                    );
+                }
+                IbcEvent::WriteAcknowledgement(ev)
+                    if ev.dst_channel_id() == self.src_channel_id()
+                        && ev.dst_port_id() == self.src_port_id() =>
+                {
+                    ibc_telemetry::global().packet_received(
+                        ev.packet.sequence.into(),
+                        block_time,
+                        &self.dst_chain().id(),
+                        ev.src_channel_id(),
+                        ev.src_port_id(),
+                        &self.src_chain().id(),
+                    );
+                }
+                IbcEvent::AcknowledgePacket(ev)
+                    if ev.src_channel_id() == self.src_channel_id()
+                        && ev.src_port_id() == self.src_port_id() =>
+                {
+                    ibc_telemetry::global().packet_acknowledged(
+                        ev.packet.sequence.into(),
+                        block_time,
+                        &self.src_chain().id(),
+                        self.src_channel_id(),
+                        self.src_port_id(),
+                        &self.dst_chain().id(),
+                    );
+                }
+                _ => {}
+            }
+        }
+    }
+
+    #[cfg(feature = "telemetry")]
+    fn record_excluded_sequences(&self, count: usize) {
+        ibc_telemetry::global().excluded_packet_sequences(
+            count as u64,
+            &self.src_chain().id(),
+            self.src_channel_id(),
+            self.src_port_id(),
+            &self.dst_chain().id(),
+        );
+    }
+
+    #[cfg(feature = "telemetry")]
+    fn record_oversized_ics20_packet(&self) {
+        ibc_telemetry::global().oversized_ics20_packets(
+            &self.src_chain().id(),
+            self.src_channel_id(),
+            self.src_port_id(),
+            &self.dst_chain().id(),
+        );
+    }
+
     #[cfg(feature = "telemetry")]
     fn record_cleared_send_packet(&self, event_with_height: &IbcEventWithHeight) {
         if let IbcEvent::SendPacket(send_packet_ev) = &event_with_height.event {
@@ -1875,3 +2564,26 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         }
     }
 }
+
+/// Strips the trace path from a denomination, ie. the leading `{port}/{channel}` pairs
+/// whose channel is a valid channel identifier, as ibc-go does. Base denominations may
+/// themselves contain slashes, eg. `factory/{creator}/{subdenom}`, which are kept.
+fn strip_trace_path(denom: &str) -> &str {
+    let is_channel_id = |id: &str| {
+        id.strip_prefix("channel-")
+            .map_or(false, |n| n.parse::<u64>().is_ok())
+    };
+
+    let mut base_denom = denom;
+
+    loop {
+        let mut parts = base_denom.splitn(3, '/');
+
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some(_port_id), Some(channel_id), Some(rest)) if is_channel_id(channel_id) => {
+                base_denom = rest;
+            }
+            _ => return base_denom,
+        }
+    }
+}
@@ -1,15 +1,19 @@
 use core::fmt::{Display, Error as FmtError, Formatter};
 
+use tendermint::Hash as TxHash;
 use tendermint_rpc::endpoint::broadcast::tx_sync;
-use tracing::info;
+use tracing::field::display;
+use tracing::{info, Span};
 
 use ibc_relayer_types::events::IbcEvent;
 
 use crate::chain::handle::ChainHandle;
 use crate::chain::tracking::TrackedMsgs;
+use crate::error_kind::{check_tx_error_kind, ErrorKind};
 use crate::link::error::LinkError;
+use crate::link::redundancy::is_redundant_tx_error;
 use crate::link::RelaySummary;
-use crate::util::pretty::{PrettyCode, PrettyEvents};
+use crate::util::pretty::{PrettyCode, PrettyEvents, PrettySlice};
 
 pub trait SubmitReply {
     /// Creates a new, empty instance, i.e., comprising zero replies.
@@ -17,6 +21,15 @@ pub trait SubmitReply {
 
     /// Counts the number of replies that this instance contains.
     fn len(&self) -> usize;
+
+    /// The hashes of the transactions submitted, if they are known.
+    fn tx_hashes(&self) -> Vec<TxHash>;
+
+    /// Counts the transactions rejected because all their packet messages were already relayed.
+    fn redundant_txs(&self) -> usize;
+
+    /// The kinds of the errors of the transactions rejected by `CheckTx`.
+    fn error_kinds(&self) -> Vec<ErrorKind>;
 }
 
 impl SubmitReply for RelaySummary {
@@ -27,6 +40,19 @@ impl SubmitReply for RelaySummary {
     fn len(&self) -> usize {
         self.events.len()
     }
+
+    fn tx_hashes(&self) -> Vec<TxHash> {
+        vec![]
+    }
+
+    fn redundant_txs(&self) -> usize {
+        // Rejected transactions are reported as errors by the synchronous sender
+        0
+    }
+
+    fn error_kinds(&self) -> Vec<ErrorKind> {
+        vec![]
+    }
 }
 
 /// Captures the ability to submit messages to a chain.
@@ -85,6 +111,24 @@ impl SubmitReply for AsyncReply {
     fn len(&self) -> usize {
         self.responses.len()
     }
+
+    fn tx_hashes(&self) -> Vec<TxHash> {
+        self.responses.iter().map(|r| r.hash).collect()
+    }
+
+    fn redundant_txs(&self) -> usize {
+        self.responses
+            .iter()
+            .filter(|r| r.code.is_err() && is_redundant_tx_error(&r.log))
+            .count()
+    }
+
+    fn error_kinds(&self) -> Vec<ErrorKind> {
+        self.responses
+            .iter()
+            .filter_map(check_tx_error_kind)
+            .collect()
+    }
 }
 
 // TODO(Adi): Consider removing the senders and keep only a generic
@@ -100,6 +144,11 @@ impl Submit for AsyncSender {
             .map_err(LinkError::relayer)?;
         let reply = AsyncReply { responses: a };
 
+        // Record the hashes in the enclosing `relay` span, which
+        // ties the confirmation of the transactions to their packets.
+        let tx_hashes = reply.tx_hashes();
+        Span::current().record("tx_hashes", display(PrettySlice(&tx_hashes)));
+
         // Note: There may be errors in the reply, for example:
         // `Response { code: Err(11), data: Data([]), log: Log("Too much gas wanted: 35000000, maximum is 25000000: out of gas")`
         // The runtime deliberately did not catch or retry on such errors.
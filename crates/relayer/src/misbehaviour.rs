@@ -1,7 +1,14 @@
 use ibc_proto::{google::protobuf::Any, protobuf::Protobuf};
+use ibc_relayer_types::clients::ics06_solomachine::misbehaviour::{
+    Misbehaviour as SmMisbehaviour, SOLOMACHINE_MISBEHAVIOUR_TYPE_URL,
+};
 use ibc_relayer_types::clients::ics07_tendermint::misbehaviour::{
     Misbehaviour as TmMisbehaviour, TENDERMINT_MISBEHAVIOR_TYPE_URL,
 };
+use ibc_relayer_types::clients::ics08_wasm::client_message::{
+    Misbehaviour as WasmMisbehaviour, WASM_CLIENT_MESSAGE_TYPE_URL,
+};
+use ibc_relayer_types::clients::ics08_wasm::raw::ClientMessage as RawWasmClientMessage;
 use ibc_relayer_types::core::{
     ics02_client::{error::Error, misbehaviour::Misbehaviour},
     ics24_host::identifier::ClientId,
@@ -21,19 +28,51 @@ pub struct MisbehaviourEvidence {
     pub supporting_headers: Vec<AnyHeader>,
 }
 
+impl MisbehaviourEvidence {
+    /// Wraps Tendermint evidence into evidence for an 08-wasm client.
+    pub fn wrap_wasm(self) -> Result<Self, Error> {
+        Ok(Self {
+            misbehaviour: self.misbehaviour.wrap_wasm()?,
+            supporting_headers: self
+                .supporting_headers
+                .into_iter()
+                .map(AnyHeader::wrap_wasm)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[allow(clippy::large_enum_variant)]
 pub enum AnyMisbehaviour {
     Tendermint(TmMisbehaviour),
+    Solomachine(SmMisbehaviour),
+    Wasm(WasmMisbehaviour),
 
     #[cfg(test)]
     Mock(MockMisbehaviour),
 }
 
+impl AnyMisbehaviour {
+    /// Wraps a Tendermint misbehaviour into the misbehaviour of an 08-wasm client.
+    pub fn wrap_wasm(self) -> Result<Self, Error> {
+        match self {
+            AnyMisbehaviour::Tendermint(misbehaviour) => {
+                Ok(AnyMisbehaviour::Wasm(WasmMisbehaviour::new(misbehaviour)))
+            }
+            _ => Err(Error::client_specific(format!(
+                "cannot wrap misbehaviour {self} in an 08-wasm client"
+            ))),
+        }
+    }
+}
+
 impl Misbehaviour for AnyMisbehaviour {
     fn client_id(&self) -> &ClientId {
         match self {
             Self::Tendermint(misbehaviour) => misbehaviour.client_id(),
+            Self::Solomachine(misbehaviour) => misbehaviour.client_id(),
+            Self::Wasm(misbehaviour) => misbehaviour.client_id(),
 
             #[cfg(test)]
             Self::Mock(misbehaviour) => misbehaviour.client_id(),
@@ -43,6 +82,8 @@ impl Misbehaviour for AnyMisbehaviour {
     fn height(&self) -> Height {
         match self {
             Self::Tendermint(misbehaviour) => misbehaviour.height(),
+            Self::Solomachine(misbehaviour) => misbehaviour.height(),
+            Self::Wasm(misbehaviour) => misbehaviour.height(),
 
             #[cfg(test)]
             Self::Mock(misbehaviour) => misbehaviour.height(),
@@ -61,6 +102,15 @@ impl TryFrom<Any> for AnyMisbehaviour {
                 TmMisbehaviour::decode_vec(&raw.value).map_err(Error::decode_raw_misbehaviour)?,
             )),
 
+            SOLOMACHINE_MISBEHAVIOUR_TYPE_URL => Ok(AnyMisbehaviour::Solomachine(
+                SmMisbehaviour::decode_vec(&raw.value).map_err(Error::decode_raw_misbehaviour)?,
+            )),
+
+            WASM_CLIENT_MESSAGE_TYPE_URL => Ok(AnyMisbehaviour::Wasm(
+                Protobuf::<RawWasmClientMessage>::decode_vec(&raw.value)
+                    .map_err(Error::decode_raw_misbehaviour)?,
+            )),
+
             #[cfg(test)]
             MOCK_MISBEHAVIOUR_TYPE_URL => Ok(AnyMisbehaviour::Mock(
                 MockMisbehaviour::decode_vec(&raw.value).map_err(Error::decode_raw_misbehaviour)?,
@@ -79,6 +129,16 @@ impl From<AnyMisbehaviour> for Any {
                 value: misbehaviour.encode_vec(),
             },
 
+            AnyMisbehaviour::Solomachine(misbehaviour) => Any {
+                type_url: SOLOMACHINE_MISBEHAVIOUR_TYPE_URL.to_string(),
+                value: misbehaviour.encode_vec(),
+            },
+
+            AnyMisbehaviour::Wasm(misbehaviour) => Any {
+                type_url: WASM_CLIENT_MESSAGE_TYPE_URL.to_string(),
+                value: Protobuf::<RawWasmClientMessage>::encode_vec(&misbehaviour),
+            },
+
             #[cfg(test)]
             AnyMisbehaviour::Mock(misbehaviour) => Any {
                 type_url: MOCK_MISBEHAVIOUR_TYPE_URL.to_string(),
@@ -92,6 +152,8 @@ impl core::fmt::Display for AnyMisbehaviour {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
         match self {
             AnyMisbehaviour::Tendermint(tm) => write!(f, "{tm}"),
+            AnyMisbehaviour::Solomachine(sm) => write!(f, "{sm}"),
+            AnyMisbehaviour::Wasm(wasm) => write!(f, "{wasm}"),
 
             #[cfg(test)]
             AnyMisbehaviour::Mock(mock) => write!(f, "{mock:?}"),
@@ -105,6 +167,18 @@ impl From<TmMisbehaviour> for AnyMisbehaviour {
     }
 }
 
+impl From<SmMisbehaviour> for AnyMisbehaviour {
+    fn from(misbehaviour: SmMisbehaviour) -> Self {
+        Self::Solomachine(misbehaviour)
+    }
+}
+
+impl From<WasmMisbehaviour> for AnyMisbehaviour {
+    fn from(misbehaviour: WasmMisbehaviour) -> Self {
+        Self::Wasm(misbehaviour)
+    }
+}
+
 #[cfg(test)]
 impl From<MockMisbehaviour> for AnyMisbehaviour {
     fn from(misbehaviour: MockMisbehaviour) -> Self {
@@ -0,0 +1,526 @@
+//! Recovery of expired or frozen clients by substitution, either through
+//! `MsgRecoverClient` (ibc-go v8 and later) or through the legacy
+//! `ClientUpdateProposal` governance proposal.
+
+use core::time::Duration;
+use std::thread;
+
+use bech32::ToBase32;
+use flex_error::define_error;
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use tracing::{debug, info};
+
+use tendermint::Hash as TxHash;
+
+use ibc_proto::cosmos::base::v1beta1::Coin;
+use ibc_proto::cosmos::gov::v1beta1::MsgSubmitProposal as LegacyMsgSubmitProposal;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::core::client::v1::ClientUpdateProposal;
+use ibc_relayer_types::core::ics02_client::msgs::recover_client::MsgRecoverClient;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
+use ibc_relayer_types::signer::SignerError;
+use ibc_relayer_types::tx_msg::Msg;
+use ibc_relayer_types::Height;
+
+use crate::chain::handle::ChainHandle;
+use crate::chain::requests::{IncludeProof, QueryClientStateRequest, QueryHeight};
+use crate::chain::tracking::TrackedMsgs;
+use crate::client_state::AnyClientState;
+use crate::error::Error;
+use crate::foreign_client::{
+    extract_client_id, CreateOptions, ForeignClient, ForeignClientError, HasExpiredOrFrozenError,
+};
+
+/// Number of times the substitute client is updated while waiting
+/// for it to move past the latest height of the subject client.
+const MAX_CATCH_UP_ATTEMPTS: u32 = 10;
+
+/// Delay between two updates of the substitute client.
+const CATCH_UP_BACKOFF: Duration = Duration::from_secs(2);
+
+define_error! {
+    RecoverClientError {
+        Query
+            [ Error ]
+            |_| { "error during a query" },
+
+        Key
+            [ Error ]
+            |_| { "key error" },
+
+        Submit
+            { chain_id: ChainId }
+            [ Error ]
+            |e| {
+                format!("failed while submitting the client recovery message to chain {0}", e.chain_id)
+            },
+
+        CheckTx
+            {
+                chain_id: ChainId,
+                code: u32,
+                log: String,
+            }
+            |e| {
+                format!("the client recovery transaction was rejected by chain {0} with code {1}: {2}",
+                    e.chain_id, e.code, e.log)
+            },
+
+        ForeignClient
+            [ ForeignClientError ]
+            |_| { "foreign client error" },
+
+        InvalidAuthority
+            { authority: String }
+            [ SignerError ]
+            |e| { format!("invalid client module authority '{}'", e.authority) },
+
+        ClientNotExpiredOrFrozen
+            {
+                client_id: ClientId,
+                chain_id: ChainId,
+            }
+            |e| {
+                format!("client {0} on chain {1} is neither expired nor frozen and does not need to be recovered",
+                    e.client_id, e.chain_id)
+            },
+
+        SubstituteBehind
+            {
+                substitute_client_id: ClientId,
+                substitute_height: Height,
+                subject_height: Height,
+            }
+            |e| {
+                format!("substitute client {0} at height {1} did not catch up with the subject client at height {2}",
+                    e.substitute_client_id, e.substitute_height, e.subject_height)
+            },
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct RecoverClientOptions {
+    pub subject_client_id: ClientId,
+    /// An existing client to substitute the subject client with,
+    /// instead of creating a fresh one.
+    pub substitute_client_id: Option<ClientId>,
+    pub title: String,
+    pub summary: String,
+    pub amount: u64,
+    pub denom: String,
+    /// The authority of the IBC client module on the host chain,
+    /// instead of the address of the governance module.
+    pub authority: Option<String>,
+    /// Whether to recover the client through a `ClientUpdateProposal`,
+    /// for host chains running ibc-go v7 or earlier.
+    pub legacy: bool,
+}
+
+/// A substitute client which has caught up with the subject client,
+/// along with the proposal which recovers the subject client with it.
+#[derive(Clone, Debug)]
+pub struct ClientRecovery {
+    pub subject_client_id: ClientId,
+    pub substitute_client_id: ClientId,
+    pub authority: String,
+
+    /// The proposal, in the format expected by `tx gov submit-proposal`.
+    pub proposal: Value,
+}
+
+/// Prepares the recovery of the expired or frozen client `opts.subject_client_id`
+/// hosted by `host_chain`, which tracks `counterparty_chain`.
+///
+/// Unless `opts.substitute_client_id` is given, creates a substitute client with
+/// the same parameters as the subject client, then updates it until its latest
+/// height is past the one of the subject client.
+pub fn prepare_client_recovery<HostChain: ChainHandle, CounterpartyChain: ChainHandle>(
+    host_chain: HostChain,
+    counterparty_chain: CounterpartyChain,
+    opts: &RecoverClientOptions,
+) -> Result<ClientRecovery, RecoverClientError> {
+    let subject = ForeignClient::find(
+        counterparty_chain.clone(),
+        host_chain.clone(),
+        &opts.subject_client_id,
+    )
+    .map_err(RecoverClientError::foreign_client)?;
+
+    match subject.validated_client_state() {
+        Ok(_) => {
+            return Err(RecoverClientError::client_not_expired_or_frozen(
+                opts.subject_client_id.clone(),
+                host_chain.id(),
+            ))
+        }
+        Err(e) if e.is_expired_or_frozen_error() => {}
+        Err(e) => return Err(RecoverClientError::foreign_client(e)),
+    }
+
+    let subject_state = query_client_state(&host_chain, &opts.subject_client_id)?;
+
+    let substitute = match &opts.substitute_client_id {
+        Some(substitute_client_id) => {
+            ForeignClient::find(counterparty_chain, host_chain.clone(), substitute_client_id)
+                .map_err(RecoverClientError::foreign_client)?
+        }
+        None => create_substitute(host_chain.clone(), counterparty_chain, &subject_state)?,
+    };
+
+    wait_for_substitute(&substitute, subject_state.latest_height())?;
+
+    let authority = match &opts.authority {
+        Some(authority) => authority.clone(),
+        None => {
+            let config = host_chain.config().map_err(RecoverClientError::query)?;
+            gov_module_address(&config.account_prefix).map_err(RecoverClientError::key)?
+        }
+    };
+
+    let proposal = build_proposal(&authority, &opts.subject_client_id, substitute.id(), opts);
+
+    Ok(ClientRecovery {
+        subject_client_id: opts.subject_client_id.clone(),
+        substitute_client_id: substitute.id().clone(),
+        authority,
+        proposal,
+    })
+}
+
+/// Submits the recovery to `host_chain`.
+///
+/// If the relayer key is the authority of the IBC client module, the subject
+/// client is recovered directly with a `MsgRecoverClient`. Otherwise, the
+/// recovery is submitted as a governance proposal, with the given deposit.
+pub fn submit_client_recovery(
+    host_chain: impl ChainHandle,
+    recovery: &ClientRecovery,
+    opts: &RecoverClientOptions,
+) -> Result<TxHash, RecoverClientError> {
+    let signer = host_chain.get_signer().map_err(RecoverClientError::key)?;
+
+    let msg = if opts.legacy {
+        let proposal = ClientUpdateProposal {
+            title: opts.title.clone(),
+            description: opts.summary.clone(),
+            subject_client_id: recovery.subject_client_id.to_string(),
+            substitute_client_id: recovery.substitute_client_id.to_string(),
+        };
+
+        let msg = LegacyMsgSubmitProposal {
+            content: Some(encode_any(CLIENT_UPDATE_PROPOSAL_TYPE_URL, &proposal)),
+            initial_deposit: vec![deposit(opts)],
+            proposer: signer.to_string(),
+        };
+
+        encode_any("/cosmos.gov.v1beta1.MsgSubmitProposal", &msg)
+    } else {
+        let recover_msg = MsgRecoverClient::new(
+            recovery.subject_client_id.clone(),
+            recovery.substitute_client_id.clone(),
+            recovery.authority.parse().map_err(|e| {
+                RecoverClientError::invalid_authority(recovery.authority.clone(), e)
+            })?,
+        );
+
+        if signer.as_ref() == recovery.authority {
+            info!("relayer key is the client module authority, recovering the client directly");
+
+            recover_msg.to_any()
+        } else {
+            let msg = MsgSubmitProposal {
+                messages: vec![recover_msg.to_any()],
+                initial_deposit: vec![deposit(opts)],
+                proposer: signer.to_string(),
+                metadata: String::new(),
+                title: opts.title.clone(),
+                summary: opts.summary.clone(),
+            };
+
+            encode_any("/cosmos.gov.v1.MsgSubmitProposal", &msg)
+        }
+    };
+
+    // As for chain upgrades, no IBC event is emitted before the proposal passes,
+    // so the transaction can only be confirmed through its `CheckTx` response.
+    let responses = host_chain
+        .send_messages_and_wait_check_tx(TrackedMsgs::new_single(msg, "recover client"))
+        .map_err(|e| RecoverClientError::submit(host_chain.id(), e))?;
+
+    let response = &responses[0];
+
+    if response.code.is_err() {
+        return Err(RecoverClientError::check_tx(
+            host_chain.id(),
+            response.code.value(),
+            response.log.clone(),
+        ));
+    }
+
+    Ok(response.hash)
+}
+
+/// Returns the address of the governance module, which is the default
+/// authority of the IBC client module, with the given account prefix.
+pub fn gov_module_address(account_prefix: &str) -> Result<String, Error> {
+    let address = &Sha256::digest(b"gov")[..20];
+
+    bech32::encode(account_prefix, address.to_base32(), bech32::Variant::Bech32)
+        .map_err(Error::bech32_encoding)
+}
+
+const CLIENT_UPDATE_PROPOSAL_TYPE_URL: &str = "/ibc.core.client.v1.ClientUpdateProposal";
+
+/// `cosmos.gov.v1.MsgSubmitProposal` as of Cosmos SDK 0.47, whose `title`
+/// and `summary` fields are missing from the definition in `ibc-proto`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, prost::Message)]
+struct MsgSubmitProposal {
+    #[prost(message, repeated, tag = "1")]
+    messages: Vec<Any>,
+    #[prost(message, repeated, tag = "2")]
+    initial_deposit: Vec<Coin>,
+    #[prost(string, tag = "3")]
+    proposer: String,
+    #[prost(string, tag = "4")]
+    metadata: String,
+    #[prost(string, tag = "5")]
+    title: String,
+    #[prost(string, tag = "6")]
+    summary: String,
+}
+
+fn query_client_state(
+    chain: &impl ChainHandle,
+    client_id: &ClientId,
+) -> Result<AnyClientState, RecoverClientError> {
+    let (client_state, _) = chain
+        .query_client_state(
+            QueryClientStateRequest {
+                client_id: client_id.clone(),
+                height: QueryHeight::Latest,
+            },
+            IncludeProof::No,
+        )
+        .map_err(RecoverClientError::query)?;
+
+    Ok(client_state)
+}
+
+/// Creates a client with the parameters of the subject client which the client
+/// module requires to match, ie. everything but the heights and trusting period.
+fn create_substitute<HostChain: ChainHandle, CounterpartyChain: ChainHandle>(
+    host_chain: HostChain,
+    counterparty_chain: CounterpartyChain,
+    subject_state: &AnyClientState,
+) -> Result<ForeignClient<HostChain, CounterpartyChain>, RecoverClientError> {
+    let options = match subject_state {
+        AnyClientState::Tendermint(state) => CreateOptions {
+            max_clock_drift: Some(state.max_clock_drift),
+            trust_threshold: Some(state.trust_threshold),
+            ..Default::default()
+        },
+        AnyClientState::Wasm(state) => CreateOptions {
+            max_clock_drift: Some(state.inner.max_clock_drift),
+            trust_threshold: Some(state.inner.trust_threshold),
+            wasm_checksum: Some(state.checksum.clone()),
+            ..Default::default()
+        },
+        _ => CreateOptions::default(),
+    };
+
+    let client = ForeignClient::restore(ClientId::default(), host_chain, counterparty_chain);
+
+    let event_with_height = client
+        .build_create_client_and_send(options)
+        .map_err(RecoverClientError::foreign_client)?;
+
+    let substitute_client_id = extract_client_id(&event_with_height.event)
+        .map_err(RecoverClientError::foreign_client)?
+        .clone();
+
+    info!(id = %substitute_client_id, "🍭 substitute client was created successfully");
+
+    Ok(ForeignClient::restore(
+        substitute_client_id,
+        client.dst_chain(),
+        client.src_chain(),
+    ))
+}
+
+/// Updates the substitute client until its latest height is past `subject_height`,
+/// which the client module requires to recover the subject client with it.
+fn wait_for_substitute<HostChain: ChainHandle, CounterpartyChain: ChainHandle>(
+    substitute: &ForeignClient<HostChain, CounterpartyChain>,
+    subject_height: Height,
+) -> Result<(), RecoverClientError> {
+    let mut attempts = 0;
+
+    loop {
+        let (client_state, _) = substitute
+            .validated_client_state()
+            .map_err(RecoverClientError::foreign_client)?;
+
+        let substitute_height = client_state.latest_height();
+
+        if substitute_height > subject_height {
+            return Ok(());
+        }
+
+        if attempts == MAX_CATCH_UP_ATTEMPTS {
+            return Err(RecoverClientError::substitute_behind(
+                substitute.id().clone(),
+                substitute_height,
+                subject_height,
+            ));
+        }
+
+        attempts += 1;
+
+        debug!(
+            %substitute_height, %subject_height,
+            "substitute client {} is behind the subject client, updating it",
+            substitute.id()
+        );
+
+        substitute
+            .build_latest_update_client_and_send()
+            .map_err(RecoverClientError::foreign_client)?;
+
+        thread::sleep(CATCH_UP_BACKOFF);
+    }
+}
+
+fn build_proposal(
+    authority: &str,
+    subject_client_id: &ClientId,
+    substitute_client_id: &ClientId,
+    opts: &RecoverClientOptions,
+) -> Value {
+    let message = if opts.legacy {
+        json!({
+            "@type": "/cosmos.gov.v1.MsgExecLegacyContent",
+            "content": {
+                "@type": CLIENT_UPDATE_PROPOSAL_TYPE_URL,
+                "title": opts.title,
+                "description": opts.summary,
+                "subject_client_id": subject_client_id,
+                "substitute_client_id": substitute_client_id,
+            },
+            "authority": authority,
+        })
+    } else {
+        json!({
+            "@type": ibc_relayer_types::core::ics02_client::msgs::recover_client::TYPE_URL,
+            "subject_client_id": subject_client_id,
+            "substitute_client_id": substitute_client_id,
+            "signer": authority,
+        })
+    };
+
+    json!({
+        "messages": [message],
+        "metadata": "",
+        "deposit": format!("{}{}", opts.amount, opts.denom),
+        "title": opts.title,
+        "summary": opts.summary,
+    })
+}
+
+fn deposit(opts: &RecoverClientOptions) -> Coin {
+    Coin {
+        denom: opts.denom.clone(),
+        amount: opts.amount.to_string(),
+    }
+}
+
+fn encode_any<M: prost::Message>(type_url: &str, msg: &M) -> Any {
+    Any {
+        type_url: type_url.to_string(),
+        value: msg.encode_to_vec(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(legacy: bool) -> RecoverClientOptions {
+        RecoverClientOptions {
+            subject_client_id: "07-tendermint-0".parse().unwrap(),
+            substitute_client_id: None,
+            title: "Recover client".to_string(),
+            summary: "Recover the expired client 07-tendermint-0".to_string(),
+            amount: 10000000,
+            denom: "stake".to_string(),
+            authority: None,
+            legacy,
+        }
+    }
+
+    #[test]
+    fn gov_module_address_for_prefix() {
+        assert_eq!(
+            gov_module_address("cosmos").unwrap(),
+            "cosmos10d07y265gmmuvt4z0w9aw880jnsr700j6zn9kn"
+        );
+    }
+
+    #[test]
+    fn recover_client_proposal() {
+        let opts = options(false);
+        let substitute_client_id = "07-tendermint-1".parse().unwrap();
+        let authority = "cosmos10d07y265gmmuvt4z0w9aw880jnsr700j6zn9kn";
+
+        let proposal = build_proposal(
+            authority,
+            &opts.subject_client_id,
+            &substitute_client_id,
+            &opts,
+        );
+
+        assert_eq!(
+            proposal,
+            json!({
+                "messages": [{
+                    "@type": "/ibc.core.client.v1.MsgRecoverClient",
+                    "subject_client_id": "07-tendermint-0",
+                    "substitute_client_id": "07-tendermint-1",
+                    "signer": authority,
+                }],
+                "metadata": "",
+                "deposit": "10000000stake",
+                "title": "Recover client",
+                "summary": "Recover the expired client 07-tendermint-0",
+            })
+        );
+    }
+
+    #[test]
+    fn legacy_client_update_proposal() {
+        let opts = options(true);
+        let substitute_client_id = "07-tendermint-1".parse().unwrap();
+        let authority = "cosmos10d07y265gmmuvt4z0w9aw880jnsr700j6zn9kn";
+
+        let proposal = build_proposal(
+            authority,
+            &opts.subject_client_id,
+            &substitute_client_id,
+            &opts,
+        );
+
+        let message = &proposal["messages"][0];
+        assert_eq!(message["@type"], "/cosmos.gov.v1.MsgExecLegacyContent");
+        assert_eq!(message["authority"], authority);
+        assert_eq!(
+            message["content"]["@type"],
+            "/ibc.core.client.v1.ClientUpdateProposal"
+        );
+        assert_eq!(message["content"]["subject_client_id"], "07-tendermint-0");
+        assert_eq!(
+            message["content"]["substitute_client_id"],
+            "07-tendermint-1"
+        );
+    }
+}
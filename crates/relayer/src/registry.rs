@@ -81,6 +81,11 @@ impl<Chain: ChainHandle> Registry<Chain> {
         }
     }
 
+    /// Replace the configuration with which new chain runtimes are spawned.
+    pub fn update_config(&mut self, config: Config) {
+        self.config = config;
+    }
+
     /// Shutdown the runtime associated with the given chain identifier.
     pub fn shutdown(&mut self, chain_id: &ChainId) {
         if let Some(handle) = self.handles.remove(chain_id) {
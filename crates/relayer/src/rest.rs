@@ -1,11 +1,14 @@
 use crossbeam_channel::TryRecvError;
 use tracing::{error, trace};
 
+use ibc_relayer_types::events::IbcEvent;
+
 use crate::{
     config::Config,
+    object::Object,
     rest::request::ReplySender,
-    rest::request::{Request, VersionInfo},
-    supervisor::dump_state::SupervisorState,
+    rest::request::{ClearPacketsParams, Request, UpdateClientParams, VersionInfo},
+    supervisor::{dump_state::SupervisorState, reload::ConfigDiff},
 };
 
 pub mod request;
@@ -25,12 +28,13 @@ pub const VER: &str = env!(
 pub type Receiver = crossbeam_channel::Receiver<Request>;
 
 // TODO: Unify this enum with `SupervisorCmd`
-//  We won't unify yet as it is possible we will never implement
-//  REST API `/chain` adding endpoint; instead of `/chain` we might
-//  implement `/reload` for supporting a broader range of functionality
-//  e.g., adjusting chain config, removing chains, etc.
 pub enum Command {
     DumpState(ReplySender<SupervisorState>),
+    ReloadConfig(ReplySender<ConfigDiff>),
+    ClearPackets(ClearPacketsParams, ReplySender<Vec<Object>>),
+    UpdateClient(UpdateClientParams, ReplySender<Vec<IbcEvent>>),
+    PauseWorker(Object, ReplySender<()>),
+    ResumeWorker(Object, ReplySender<()>),
 }
 
 /// Process incoming REST requests.
@@ -82,6 +86,36 @@ pub fn process_incoming_requests(config: &Config, channel: &Receiver) -> Option<
 
                 return Some(Command::DumpState(reply_to));
             }
+
+            Request::ReloadConfig { reply_to } => {
+                trace!("ReloadConfig");
+
+                return Some(Command::ReloadConfig(reply_to));
+            }
+
+            Request::ClearPackets { params, reply_to } => {
+                trace!("ClearPackets {:?}", params);
+
+                return Some(Command::ClearPackets(params, reply_to));
+            }
+
+            Request::UpdateClient { params, reply_to } => {
+                trace!("UpdateClient {:?}", params);
+
+                return Some(Command::UpdateClient(params, reply_to));
+            }
+
+            Request::PauseWorker { object, reply_to } => {
+                trace!("PauseWorker {}", object.short_name());
+
+                return Some(Command::PauseWorker(object, reply_to));
+            }
+
+            Request::ResumeWorker { object, reply_to } => {
+                trace!("ResumeWorker {}", object.short_name());
+
+                return Some(Command::ResumeWorker(object, reply_to));
+            }
         },
         Err(e) => {
             if !matches!(e, TryRecvError::Empty) {
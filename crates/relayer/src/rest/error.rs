@@ -23,6 +23,21 @@ pub enum RestApiError {
     #[error("failed while parsing the request body into a chain configuration: {0}")]
     InvalidChainConfig(String),
 
+    #[error("failed to reload the configuration: {0}")]
+    ConfigReload(String),
+
+    #[error("failed to clear packets: {0}")]
+    ClearPackets(String),
+
+    #[error("failed to update client: {0}")]
+    UpdateClient(String),
+
+    #[error("could not find a worker for object: {0}")]
+    WorkerNotFound(String),
+
+    #[error("missing or invalid bearer token")]
+    Unauthorized,
+
     #[error("not implemented")]
     Unimplemented,
 }
@@ -36,6 +51,11 @@ impl RestApiError {
             RestApiError::ChainConfigNotFound(_) => "ChainConfigNotFound",
             RestApiError::InvalidChainId(_, _) => "InvalidChainId",
             RestApiError::InvalidChainConfig(_) => "InvalidChainConfig",
+            RestApiError::ConfigReload(_) => "ConfigReload",
+            RestApiError::ClearPackets(_) => "ClearPackets",
+            RestApiError::UpdateClient(_) => "UpdateClient",
+            RestApiError::WorkerNotFound(_) => "WorkerNotFound",
+            RestApiError::Unauthorized => "Unauthorized",
             RestApiError::Unimplemented => "Unimplemented",
         }
     }
@@ -1,8 +1,16 @@
 use serde::{Deserialize, Serialize};
 
-use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+use ibc_relayer_types::{
+    core::ics24_host::identifier::{ChainId, ChannelId, ClientId, PortId},
+    events::IbcEvent,
+};
 
-use crate::{config::ChainConfig, rest::RestApiError, supervisor::dump_state::SupervisorState};
+use crate::{
+    config::ChainConfig,
+    object::Object,
+    rest::RestApiError,
+    supervisor::{dump_state::SupervisorState, reload::ConfigDiff},
+};
 
 pub type ReplySender<T> = crossbeam_channel::Sender<Result<T, RestApiError>>;
 pub type ReplyReceiver<T> = crossbeam_channel::Receiver<Result<T, RestApiError>>;
@@ -17,6 +25,22 @@ pub struct VersionInfo {
     pub version: String,
 }
 
+/// Parameters of a request to clear the pending packets on a channel.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ClearPacketsParams {
+    pub chain_id: ChainId,
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+}
+
+/// Parameters of a request to update a client.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct UpdateClientParams {
+    /// The chain hosting the client.
+    pub chain_id: ChainId,
+    pub client_id: ClientId,
+}
+
 /// REST API request variants
 #[derive(Clone, Debug)]
 pub enum Request {
@@ -36,4 +60,28 @@ pub enum Request {
         chain_id: ChainId,
         reply_to: ReplySender<ChainConfig>,
     },
+
+    ReloadConfig {
+        reply_to: ReplySender<ConfigDiff>,
+    },
+
+    ClearPackets {
+        params: ClearPacketsParams,
+        reply_to: ReplySender<Vec<Object>>,
+    },
+
+    UpdateClient {
+        params: UpdateClientParams,
+        reply_to: ReplySender<Vec<IbcEvent>>,
+    },
+
+    PauseWorker {
+        object: Object,
+        reply_to: ReplySender<()>,
+    },
+
+    ResumeWorker {
+        object: Object,
+        reply_to: ReplySender<()>,
+    },
 }
@@ -31,6 +31,10 @@ define_error! {
         InsufficientFee
             { code: u32 }
             |_| { "the price configuration for this chain may be too low! please check the `gas_price.price` Hermes config.toml".to_string() },
+
+        IncorrectAccountSequence
+            { code: u32 }
+            |_| { "the account sequence of the relayer is out of sync with the chain".to_string() },
     }
 }
 
@@ -193,6 +197,7 @@ pub fn sdk_error_from_tx_sync_error_code(code: u32) -> SdkError {
         // on Hermes side. We'll inform the user to check for misconfiguration.
         11 => SdkError::out_of_gas(code),
         13 => SdkError::insufficient_fee(code),
+        32 => SdkError::incorrect_account_sequence(code),
         _ => SdkError::unknown_tx_sync(code),
     }
 }
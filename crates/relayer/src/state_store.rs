@@ -0,0 +1,249 @@
+//! Embedded on-disk store for the state of the packet workers, which allows
+//! them to confirm the transactions they submitted before a restart, and to
+//! skip clearing packets on start when `clear_on_start` is disabled and their
+//! checkpoint is recent enough.
+//!
+//! For every relaying path, ie. for every packet worker, the store records:
+//! - the latest height of the source chain up to which the worker has relayed
+//!   the IBC events, called the checkpoint of the path;
+//! - the hashes of the transactions it has submitted, but which are not yet confirmed.
+
+use std::path::{Path, PathBuf};
+
+use flex_error::{define_error, TraceError};
+use serde::{Deserialize, Serialize};
+use tendermint::Hash as TxHash;
+
+use ibc_relayer_types::{core::ics24_host::identifier::ChainId, Height};
+
+use crate::{config::StateStoreConfig, object::Packet};
+
+pub const STATE_STORE_DEFAULT_FOLDER: &str = ".hermes/state/";
+
+const CHECKPOINTS_TREE: &str = "checkpoints";
+const UNCONFIRMED_TXS_TREE: &str = "unconfirmed_txs";
+
+define_error! {
+    Error {
+        HomeLocationUnavailable
+            |_| { "home location is unavailable" },
+
+        Open
+            { path: PathBuf }
+            [ TraceError<sled::Error> ]
+            |e| { format_args!("failed to open the state store at {}", e.path.display()) },
+
+        Store
+            [ TraceError<sled::Error> ]
+            |_| { "failed to access the state store" },
+
+        Encode
+            [ TraceError<serde_json::Error> ]
+            |_| { "failed to encode a value to write to the state store" },
+
+        Decode
+            [ TraceError<serde_json::Error> ]
+            |_| { "failed to decode a value read from the state store" },
+    }
+}
+
+/// A transaction submitted by a packet worker, which was not confirmed yet.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnconfirmedTx {
+    /// The chain the transaction was submitted to.
+    pub chain_id: ChainId,
+    pub hash: TxHash,
+}
+
+/// Handle to the state store, which can be cheaply cloned and shared between workers.
+#[derive(Clone, Debug)]
+pub struct StateStore {
+    checkpoints: sled::Tree,
+    unconfirmed_txs: sled::Tree,
+}
+
+impl StateStore {
+    /// Open the store at the location given in the configuration,
+    /// creating it if it does not exist yet.
+    pub fn open(config: &StateStoreConfig) -> Result<Self, Error> {
+        let path = match &config.path {
+            Some(path) => path.clone(),
+            None => {
+                let home = dirs_next::home_dir().ok_or_else(Error::home_location_unavailable)?;
+                home.join(STATE_STORE_DEFAULT_FOLDER)
+            }
+        };
+
+        let db = sled::open(&path).map_err(|e| Error::open(path.clone(), e))?;
+
+        Self::from_db(&db, &path)
+    }
+
+    fn from_db(db: &sled::Db, path: &Path) -> Result<Self, Error> {
+        let open_tree = |name| {
+            db.open_tree(name)
+                .map_err(|e| Error::open(path.to_path_buf(), e))
+        };
+
+        Ok(Self {
+            checkpoints: open_tree(CHECKPOINTS_TREE)?,
+            unconfirmed_txs: open_tree(UNCONFIRMED_TXS_TREE)?,
+        })
+    }
+
+    /// Get a handle to the part of the store holding the state of the given path.
+    pub fn path_state(&self, path: &Packet) -> PathState {
+        PathState {
+            store: self.clone(),
+            key: path.short_name(),
+        }
+    }
+}
+
+/// Handle to the state of a single relaying path in the [`StateStore`].
+#[derive(Clone, Debug)]
+pub struct PathState {
+    store: StateStore,
+    key: String,
+}
+
+impl PathState {
+    /// The latest height up to which the events of the path have been relayed, if any.
+    pub fn checkpoint(&self) -> Result<Option<Height>, Error> {
+        self.store
+            .checkpoints
+            .get(&self.key)
+            .map_err(Error::store)?
+            .map(|value| serde_json::from_slice(&value).map_err(Error::decode))
+            .transpose()
+    }
+
+    /// Move the checkpoint of the path to the given height,
+    /// unless it is already at a greater height.
+    pub fn advance_checkpoint(&self, height: Height) -> Result<(), Error> {
+        let current = self.checkpoint()?;
+
+        if current.map_or(true, |current| current < height) {
+            let value = serde_json::to_vec(&height).map_err(Error::encode)?;
+
+            self.store
+                .checkpoints
+                .insert(&self.key, value)
+                .map_err(Error::store)?;
+        }
+
+        Ok(())
+    }
+
+    /// The transactions submitted for the path which are not confirmed yet.
+    pub fn unconfirmed_txs(&self) -> Result<Vec<UnconfirmedTx>, Error> {
+        self.store
+            .unconfirmed_txs
+            .scan_prefix(self.tx_key_prefix())
+            .values()
+            .map(|value| {
+                let value = value.map_err(Error::store)?;
+                serde_json::from_slice(&value).map_err(Error::decode)
+            })
+            .collect()
+    }
+
+    /// Record transactions which were submitted to the given chain.
+    pub fn add_unconfirmed_txs(&self, chain_id: &ChainId, hashes: &[TxHash]) -> Result<(), Error> {
+        let mut batch = sled::Batch::default();
+
+        for hash in hashes {
+            let tx = UnconfirmedTx {
+                chain_id: chain_id.clone(),
+                hash: *hash,
+            };
+
+            let value = serde_json::to_vec(&tx).map_err(Error::encode)?;
+            batch.insert(self.tx_key(hash).as_bytes(), value);
+        }
+
+        self.store
+            .unconfirmed_txs
+            .apply_batch(batch)
+            .map_err(Error::store)
+    }
+
+    /// Forget about transactions, once they are confirmed or abandoned.
+    pub fn remove_unconfirmed_txs(&self, hashes: &[TxHash]) -> Result<(), Error> {
+        let mut batch = sled::Batch::default();
+
+        for hash in hashes {
+            batch.remove(self.tx_key(hash).as_bytes());
+        }
+
+        self.store
+            .unconfirmed_txs
+            .apply_batch(batch)
+            .map_err(Error::store)
+    }
+
+    fn tx_key_prefix(&self) -> String {
+        format!("{}#", self.key)
+    }
+
+    fn tx_key(&self, hash: &TxHash) -> String {
+        format!("{}{hash}", self.tx_key_prefix())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn store() -> StateStore {
+        let db = sled::Config::new().temporary(true).open().unwrap();
+        StateStore::from_db(&db, Path::new("")).unwrap()
+    }
+
+    fn path(src_chain_id: &str) -> Packet {
+        Packet {
+            dst_chain_id: "ibc-1".parse().unwrap(),
+            src_chain_id: src_chain_id.parse().unwrap(),
+            src_channel_id: "channel-0".parse().unwrap(),
+            src_port_id: "transfer".parse().unwrap(),
+        }
+    }
+
+    #[test]
+    fn checkpoint_only_moves_forward() {
+        let state = store().path_state(&path("ibc-0"));
+        assert_eq!(state.checkpoint().unwrap(), None);
+
+        let height = |h| Height::new(0, h).unwrap();
+
+        state.advance_checkpoint(height(10)).unwrap();
+        state.advance_checkpoint(height(5)).unwrap();
+        assert_eq!(state.checkpoint().unwrap(), Some(height(10)));
+
+        state.advance_checkpoint(height(12)).unwrap();
+        assert_eq!(state.checkpoint().unwrap(), Some(height(12)));
+    }
+
+    #[test]
+    fn unconfirmed_txs_are_tracked_per_path() {
+        let store = store();
+        let state = store.path_state(&path("ibc-0"));
+        let other = store.path_state(&path("ibc-2"));
+
+        let chain_id: ChainId = "ibc-1".parse().unwrap();
+        let hashes = [TxHash::Sha256([1; 32]), TxHash::Sha256([2; 32])];
+
+        state.add_unconfirmed_txs(&chain_id, &hashes).unwrap();
+        assert_eq!(state.unconfirmed_txs().unwrap().len(), 2);
+        assert!(other.unconfirmed_txs().unwrap().is_empty());
+
+        state.remove_unconfirmed_txs(&hashes[..1]).unwrap();
+        assert_eq!(
+            state.unconfirmed_txs().unwrap(),
+            vec![UnconfirmedTx {
+                chain_id,
+                hash: hashes[1],
+            }]
+        );
+    }
+}
@@ -1,34 +1,47 @@
 use alloc::collections::btree_map::BTreeMap as HashMap;
 use alloc::sync::Arc;
 use core::convert::Infallible;
+use core::mem;
 use core::ops::Deref;
 use core::time::Duration;
-use std::sync::RwLock;
+use std::path::PathBuf;
+use std::sync::{Mutex, RwLock};
 
 use crossbeam_channel::{unbounded, Receiver, Sender};
 use itertools::Itertools;
 use tracing::{debug, error, error_span, info, instrument, trace, warn};
 
 use ibc_relayer_types::{
-    core::ics24_host::identifier::{ChainId, ChannelId, PortId},
+    core::{
+        ics02_client::client_state::ClientState,
+        ics24_host::identifier::{ChainId, ChannelId, PortId},
+    },
     events::IbcEvent,
     Height,
 };
 
 use crate::{
-    chain::{endpoint::HealthCheck, handle::ChainHandle, tracking::TrackingId},
-    config::Config,
+    chain::{
+        counterparty::channel_connection_client,
+        endpoint::HealthCheck,
+        handle::ChainHandle,
+        requests::{IncludeProof, QueryClientStateRequest, QueryHeight},
+        tracking::TrackingId,
+    },
+    config::{self, Config},
     event::{
         monitor::{self, Error as EventError, ErrorDetail as EventErrorDetail, EventBatch},
         IbcEventWithHeight,
     },
-    object::Object,
+    foreign_client::ForeignClient,
+    object::{self, Object},
     registry::{Registry, SharedRegistry},
     rest,
+    state_store::StateStore,
     supervisor::scan::ScanMode,
     telemetry,
     util::{
-        lock::LockExt,
+        lock::{LockExt, RwArc},
         task::{spawn_background_task, Next, TaskError, TaskHandle},
     },
     worker::WorkerMap,
@@ -43,6 +56,9 @@ pub use error::{Error, ErrorDetail};
 pub mod dump_state;
 use dump_state::SupervisorState;
 
+pub mod reload;
+use reload::ConfigDiff;
+
 pub mod scan;
 pub mod spawn;
 
@@ -63,8 +79,14 @@ type Subscription = Receiver<ArcBatch>;
 pub struct SupervisorHandle {
     pub sender: Sender<SupervisorCmd>,
     tasks: Vec<TaskHandle>,
+    /// The batch workers, kept apart from the other tasks as the ones
+    /// of the chains affected by a configuration reload are restarted
+    batch_tasks: BatchTasks,
 }
 
+/// The batch workers of the supervisor, by chain
+type BatchTasks = RwArc<HashMap<ChainId, TaskHandle>>;
+
 /// Options for the supervisor
 #[derive(Debug)]
 pub struct SupervisorOptions {
@@ -75,6 +97,25 @@ pub struct SupervisorOptions {
     /// even when an allow list is configured for a chain and the full scan could
     /// be omitted.
     pub force_full_scan: bool,
+
+    /// Path to the configuration file, which is loaded again
+    /// when the supervisor is asked to reload its configuration.
+    pub config_path: Option<PathBuf>,
+}
+
+/// The state shared by the tasks of the supervisor,
+/// which is updated when the configuration is reloaded.
+#[derive(Clone)]
+struct SupervisorContext<Chain: ChainHandle> {
+    config: RwArc<Config>,
+    config_path: Option<PathBuf>,
+    registry: SharedRegistry<Chain>,
+    client_state_filter: RwArc<FilterPolicy>,
+    workers: RwArc<WorkerMap>,
+    batch_tasks: BatchTasks,
+    /// Held for the whole duration of a configuration reload, so that reloads
+    /// triggered concurrently through SIGHUP and the REST API do not interleave
+    reload_lock: Arc<Mutex<()>>,
 }
 
 /**
@@ -91,9 +132,16 @@ pub fn spawn_supervisor(
 ) -> Result<SupervisorHandle, Error> {
     let (sender, receiver) = unbounded();
 
-    let tasks = spawn_supervisor_tasks(config, registry, rest_rx, receiver, options)?;
+    crate::telemetry::set_packet_latencies(config.telemetry.enabled);
+
+    let (tasks, batch_tasks) =
+        spawn_supervisor_tasks(config, registry, rest_rx, receiver, options)?;
 
-    Ok(SupervisorHandle { sender, tasks })
+    Ok(SupervisorHandle {
+        sender,
+        tasks,
+        batch_tasks,
+    })
 }
 
 impl SupervisorHandle {
@@ -105,6 +153,13 @@ impl SupervisorHandle {
        is by respawning a new supervisor using [`spawn_supervisor`].
     */
     pub fn shutdown(self) {
+        let batch_tasks = mem::take(&mut *self.batch_tasks.acquire_write());
+
+        for (_, task) in batch_tasks {
+            // Send the shutdown signals in parallel
+            task.shutdown();
+        }
+
         for task in self.tasks {
             // Send the shutdown signals in parallel
             task.shutdown();
@@ -117,6 +172,12 @@ impl SupervisorHandle {
         for task in self.tasks {
             task.join();
         }
+
+        let batch_tasks = mem::take(&mut *self.batch_tasks.acquire_write());
+
+        for (_, task) in batch_tasks {
+            task.join();
+        }
     }
 
     /// Ask the supervisor to dump its internal state
@@ -131,15 +192,28 @@ impl SupervisorHandle {
 
         Ok(state)
     }
+
+    /// Ask the supervisor to reload its configuration file
+    pub fn reload_config(&self) -> Result<ConfigDiff, Error> {
+        let (tx, rx) = crossbeam_channel::bounded(1);
+
+        self.sender
+            .send(SupervisorCmd::ReloadConfig(tx))
+            .map_err(|_| Error::handle_send())?;
+
+        rx.recv().map_err(|_| Error::handle_recv())?
+    }
 }
 
+/// Spawn the tasks of the supervisor, returning the batch workers apart
+/// from the other tasks, as they are restarted on configuration reloads.
 pub fn spawn_supervisor_tasks<Chain: ChainHandle>(
     config: Config,
     registry: SharedRegistry<Chain>,
     rest_rx: Option<rest::Receiver>,
     cmd_rx: Receiver<SupervisorCmd>,
     options: SupervisorOptions,
-) -> Result<Vec<TaskHandle>, Error> {
+) -> Result<(Vec<TaskHandle>, BatchTasks), Error> {
     if options.health_check {
         health_check(&config, &mut registry.write());
     }
@@ -154,8 +228,14 @@ pub fn spawn_supervisor_tasks<Chain: ChainHandle>(
         }
     }
 
-    let workers = Arc::new(RwLock::new(WorkerMap::new()));
-    let client_state_filter = Arc::new(RwLock::new(FilterPolicy::default()));
+    let mut workers = WorkerMap::new();
+    if config.state_store.enabled {
+        let state_store = StateStore::open(&config.state_store).map_err(Error::state_store)?;
+        workers = workers.with_state_store(state_store);
+    }
+
+    let workers = Arc::new(RwLock::new(workers));
+    let client_state_filter = Arc::new(RwLock::new(FilterPolicy::new(&config)));
 
     let scan = chain_scanner(
         &config,
@@ -176,70 +256,96 @@ pub fn spawn_supervisor_tasks<Chain: ChainHandle>(
 
     let subscriptions = init_subscriptions(&config, &mut registry.write())?;
 
+    let config = RwArc::new_lock(config);
+
     let batch_tasks = spawn_batch_workers(
         &config,
-        registry.clone(),
-        client_state_filter,
-        workers.clone(),
+        &registry,
+        &client_state_filter,
+        &workers,
         subscriptions,
     );
 
-    let cmd_task = spawn_cmd_worker(registry.clone(), workers.clone(), cmd_rx);
+    let ctx = SupervisorContext {
+        config,
+        config_path: options.config_path,
+        registry,
+        client_state_filter,
+        workers,
+        batch_tasks: RwArc::new_lock(batch_tasks),
+        reload_lock: Arc::new(Mutex::new(())),
+    };
+
+    let cmd_task = spawn_cmd_worker(ctx.clone(), cmd_rx);
 
     let mut tasks = vec![cmd_task];
-    tasks.extend(batch_tasks);
+
+    let batch_tasks = ctx.batch_tasks.clone();
 
     if let Some(rest_rx) = rest_rx {
-        let rest_task = spawn_rest_worker(config, registry, workers, rest_rx);
+        let rest_task = spawn_rest_worker(ctx, rest_rx);
         tasks.push(rest_task);
     }
 
-    Ok(tasks)
+    Ok((tasks, batch_tasks))
 }
 
 fn spawn_batch_workers<Chain: ChainHandle>(
-    config: &Config,
-    registry: SharedRegistry<Chain>,
-    client_state_filter: Arc<RwLock<FilterPolicy>>,
-    workers: Arc<RwLock<WorkerMap>>,
+    config: &RwArc<Config>,
+    registry: &SharedRegistry<Chain>,
+    client_state_filter: &RwArc<FilterPolicy>,
+    workers: &RwArc<WorkerMap>,
     subscriptions: Vec<(Chain, Subscription)>,
-) -> Vec<TaskHandle> {
-    let mut handles = Vec::with_capacity(subscriptions.len());
-
-    for (chain, subscription) in subscriptions {
-        let config = config.clone();
-        let registry = registry.clone();
-        let client_state_filter = client_state_filter.clone();
-        let workers = workers.clone();
-
-        let handle = spawn_background_task(
-            error_span!("worker.batch", chain = %chain.id()),
-            Some(Duration::from_millis(5)),
-            move || -> Result<Next, TaskError<Infallible>> {
-                if let Ok(batch) = subscription.try_recv() {
-                    handle_batch(
-                        &config,
-                        &mut registry.write(),
-                        &mut client_state_filter.acquire_write(),
-                        &mut workers.acquire_write(),
-                        chain.clone(),
-                        batch,
-                    );
-                }
+) -> HashMap<ChainId, TaskHandle> {
+    subscriptions
+        .into_iter()
+        .map(|(chain, subscription)| {
+            let chain_id = chain.id();
+
+            let handle = spawn_batch_worker(
+                config.clone(),
+                registry.clone(),
+                client_state_filter.clone(),
+                workers.clone(),
+                chain,
+                subscription,
+            );
 
-                Ok(Next::Continue)
-            },
-        );
+            (chain_id, handle)
+        })
+        .collect()
+}
 
-        handles.push(handle);
-    }
+fn spawn_batch_worker<Chain: ChainHandle>(
+    config: RwArc<Config>,
+    registry: SharedRegistry<Chain>,
+    client_state_filter: RwArc<FilterPolicy>,
+    workers: RwArc<WorkerMap>,
+    chain: Chain,
+    subscription: Subscription,
+) -> TaskHandle {
+    spawn_background_task(
+        error_span!("worker.batch", chain = %chain.id()),
+        Some(Duration::from_millis(5)),
+        move || -> Result<Next, TaskError<Infallible>> {
+            if let Ok(batch) = subscription.try_recv() {
+                handle_batch(
+                    &config.acquire_read(),
+                    &mut registry.write(),
+                    &mut client_state_filter.acquire_write(),
+                    &mut workers.acquire_write(),
+                    chain.clone(),
+                    batch,
+                );
+            }
 
-    handles
+            Ok(Next::Continue)
+        },
+    )
 }
 
-pub fn spawn_cmd_worker<Chain: ChainHandle>(
-    registry: SharedRegistry<Chain>,
-    workers: Arc<RwLock<WorkerMap>>,
+fn spawn_cmd_worker<Chain: ChainHandle>(
+    ctx: SupervisorContext<Chain>,
     cmd_rx: Receiver<SupervisorCmd>,
 ) -> TaskHandle {
     spawn_background_task(
@@ -249,7 +355,16 @@ pub fn spawn_cmd_worker<Chain: ChainHandle>(
             if let Ok(cmd) = cmd_rx.try_recv() {
                 match cmd {
                     SupervisorCmd::DumpState(reply_to) => {
-                        dump_state(&registry.read(), &workers.acquire_read(), reply_to);
+                        dump_state(&ctx.registry.read(), &ctx.workers.acquire_read(), reply_to);
+                    }
+                    SupervisorCmd::ReloadConfig(reply_to) => {
+                        let result = reload_config(&ctx);
+
+                        if let Err(e) = &result {
+                            error!("failed to reload configuration: {}", e);
+                        }
+
+                        let _ = reply_to.try_send(result);
                     }
                 }
             }
@@ -259,17 +374,15 @@ pub fn spawn_cmd_worker<Chain: ChainHandle>(
     )
 }
 
-pub fn spawn_rest_worker<Chain: ChainHandle>(
-    config: Config,
-    registry: SharedRegistry<Chain>,
-    workers: Arc<RwLock<WorkerMap>>,
+fn spawn_rest_worker<Chain: ChainHandle>(
+    ctx: SupervisorContext<Chain>,
     rest_rx: rest::Receiver,
 ) -> TaskHandle {
     spawn_background_task(
         error_span!("rest"),
         Some(Duration::from_millis(500)),
         move || -> Result<Next, TaskError<Infallible>> {
-            handle_rest_requests(&config, &registry.read(), &workers.acquire_read(), &rest_rx);
+            handle_rest_requests(&ctx, &rest_rx);
 
             Ok(Next::Continue)
         },
@@ -504,6 +617,27 @@ pub fn collect_events(
                     || Object::client_from_chan_open_events(&attributes, src_chain).ok(),
                 );
             }
+            IbcEvent::UpgradeInitChannel(..)
+            | IbcEvent::UpgradeTryChannel(..)
+            | IbcEvent::UpgradeAckChannel(..)
+            | IbcEvent::UpgradeConfirmChannel(..)
+            | IbcEvent::UpgradeOpenChannel(..)
+            | IbcEvent::UpgradeErrorChannel(..) => {
+                collect_event(
+                    &mut collected,
+                    event_with_height.clone(),
+                    mode.channels.enabled,
+                    || {
+                        event_with_height
+                            .event
+                            .clone()
+                            .upgrade_attributes()
+                            .and_then(|attr| {
+                                Object::channel_from_chan_upgrade_events(&attr, src_chain).ok()
+                            })
+                    },
+                );
+            }
             IbcEvent::SendPacket(ref packet) => {
                 collect_event(
                     &mut collected,
@@ -528,6 +662,15 @@ pub fn collect_events(
                     || Object::for_write_ack(packet, src_chain).ok(),
                 );
             }
+            // Only used to record the latency of acknowledgements in the telemetry
+            IbcEvent::AcknowledgePacket(ref packet) => {
+                collect_event(
+                    &mut collected,
+                    event_with_height.clone(),
+                    mode.packets.enabled && config.telemetry.enabled,
+                    || Object::for_acknowledge_packet(packet, src_chain).ok(),
+                );
+            }
             IbcEvent::CloseInitChannel(ref packet) => {
                 collect_event(
                     &mut collected,
@@ -616,24 +759,8 @@ fn init_subscriptions<Chain: ChainHandle>(
     let mut subscriptions = Vec::with_capacity(chains.len());
 
     for chain_config in chains {
-        let chain = match registry.get_or_spawn(&chain_config.id) {
-            Ok(chain) => chain,
-            Err(e) => {
-                error!(
-                    "failed to spawn chain runtime for {}: {}",
-                    chain_config.id, e
-                );
-
-                continue;
-            }
-        };
-
-        match chain.subscribe() {
-            Ok(subscription) => subscriptions.push((chain, subscription)),
-            Err(e) => error!(
-                "failed to subscribe to events of {}: {}",
-                chain_config.id, e
-            ),
+        if let Some(subscription) = subscribe(registry, &chain_config.id) {
+            subscriptions.push(subscription);
         }
     }
 
@@ -646,6 +773,154 @@ fn init_subscriptions<Chain: ChainHandle>(
     Ok(subscriptions)
 }
 
+/// Spawn the runtime of the given chain if needed, and subscribe to its events.
+fn subscribe<Chain: ChainHandle>(
+    registry: &mut Registry<Chain>,
+    chain_id: &ChainId,
+) -> Option<(Chain, Subscription)> {
+    let chain = match registry.get_or_spawn(chain_id) {
+        Ok(chain) => chain,
+        Err(e) => {
+            error!("failed to spawn chain runtime for {}: {}", chain_id, e);
+
+            return None;
+        }
+    };
+
+    match chain.subscribe() {
+        Ok(subscription) => Some((chain, subscription)),
+        Err(e) => {
+            error!("failed to subscribe to events of {}: {}", chain_id, e);
+
+            None
+        }
+    }
+}
+
+/// Load the configuration file again and apply the changes with respect to
+/// the current configuration, restarting only the chain runtimes and workers
+/// affected by these changes.
+///
+/// Only changes to the `chains` and `mode` sections are applied, changes to
+/// the other sections require restarting the relayer.
+#[instrument(name = "supervisor.reload_config", level = "error", skip_all)]
+fn reload_config<Chain: ChainHandle>(ctx: &SupervisorContext<Chain>) -> Result<ConfigDiff, Error> {
+    let _reload_guard = ctx.reload_lock.lock().expect("poisoned lock");
+
+    let config_path = ctx
+        .config_path
+        .as_ref()
+        .ok_or_else(Error::missing_config_path)?;
+
+    let config = config::load(config_path).map_err(Error::load_config)?;
+
+    let diff = ConfigDiff::new(&ctx.config.acquire_read(), &config);
+
+    if diff.is_empty() {
+        info!("configuration is unchanged");
+        return Ok(diff);
+    }
+
+    info!(
+        added = ?diff.added,
+        removed = ?diff.removed,
+        updated = ?diff.updated,
+        mode_updated = diff.mode_updated,
+        "applying configuration changes"
+    );
+
+    // Stop processing the events of the removed and updated chains. This must be done
+    // without holding any lock, as the batch workers acquire them when processing events.
+    for chain_id in diff.chains_to_stop() {
+        let batch_task = ctx.batch_tasks.acquire_write().remove(chain_id);
+
+        if let Some(batch_task) = batch_task {
+            batch_task.shutdown_and_wait();
+        }
+    }
+
+    let stopped = {
+        let mut workers = ctx.workers.acquire_write();
+
+        let stopped = workers
+            .objects()
+            .filter(|object| diff.affects(object))
+            .cloned()
+            .collect_vec();
+
+        for object in &stopped {
+            debug!("shutting down worker {}", object.short_name());
+            workers.shutdown_worker(object);
+        }
+
+        stopped
+    };
+
+    {
+        let mut registry = ctx.registry.write();
+
+        for chain_id in diff.chains_to_stop() {
+            registry.shutdown(chain_id);
+        }
+
+        registry.update_config(config.clone());
+    }
+
+    *ctx.config.acquire_write() = config.clone();
+
+    for chain_id in diff.chains_to_start() {
+        let subscription = subscribe(&mut ctx.registry.write(), chain_id);
+
+        if let Some((chain, subscription)) = subscription {
+            let batch_task = spawn_batch_worker(
+                ctx.config.clone(),
+                ctx.registry.clone(),
+                ctx.client_state_filter.clone(),
+                ctx.workers.clone(),
+                chain,
+                subscription,
+            );
+
+            ctx.batch_tasks
+                .acquire_write()
+                .insert(chain_id.clone(), batch_task);
+        }
+    }
+
+    let mut registry = ctx.registry.write();
+    let mut client_state_filter = ctx.client_state_filter.acquire_write();
+    let mut workers = ctx.workers.acquire_write();
+
+    // The cached filtering decisions may not hold under the new configuration
+    *client_state_filter = FilterPolicy::new(&config);
+
+    for chain_id in diff.chains_to_scan(&config, &stopped) {
+        let chain_config = match config.find_chain(&chain_id) {
+            Some(chain_config) => chain_config,
+            None => continue,
+        };
+
+        let scan = chain_scanner(
+            &config,
+            &mut registry,
+            &mut client_state_filter,
+            ScanMode::Auto,
+        )
+        .scan_chain(chain_config);
+
+        match scan {
+            Ok(scan) => {
+                spawn_context(&config, &mut registry, &mut workers).spawn_workers_for_chain(scan)
+            }
+            Err(e) => error!(chain = %chain_id, "failed to scan chain: {}", e),
+        }
+    }
+
+    info!("configuration reloaded");
+
+    Ok(diff)
+}
+
 /// Dump the state of the supervisor into a [`SupervisorState`] value,
 /// and send it back through the given channel.
 fn dump_state<Chain: ChainHandle>(
@@ -665,30 +940,185 @@ fn state<Chain: ChainHandle>(registry: &Registry<Chain>, workers: &WorkerMap) ->
 }
 
 fn handle_rest_requests<Chain: ChainHandle>(
-    config: &Config,
-    registry: &Registry<Chain>,
-    workers: &WorkerMap,
+    ctx: &SupervisorContext<Chain>,
     rest_rx: &rest::Receiver,
 ) {
-    if let Some(cmd) = rest::process_incoming_requests(config, rest_rx) {
-        handle_rest_cmd(registry, workers, cmd);
+    let cmd = rest::process_incoming_requests(&ctx.config.acquire_read(), rest_rx);
+
+    if let Some(cmd) = cmd {
+        handle_rest_cmd(ctx, cmd);
     }
 }
 
 #[instrument(name = "supervisor.handle_rest_cmd", level = "error", skip_all)]
-fn handle_rest_cmd<Chain: ChainHandle>(
-    registry: &Registry<Chain>,
-    workers: &WorkerMap,
-    m: rest::Command,
-) {
+fn handle_rest_cmd<Chain: ChainHandle>(ctx: &SupervisorContext<Chain>, m: rest::Command) {
     match m {
         rest::Command::DumpState(reply) => {
-            let state = state(registry, workers);
+            let state = state(&ctx.registry.read(), &ctx.workers.acquire_read());
             reply
                 .send(Ok(state))
                 .unwrap_or_else(|e| error!("error replying to a REST request {}", e));
         }
+        rest::Command::ReloadConfig(reply) => {
+            let result = reload_config(ctx).map_err(|e| {
+                error!("failed to reload configuration: {}", e);
+                rest::RestApiError::ConfigReload(e.to_string())
+            });
+
+            reply
+                .send(result)
+                .unwrap_or_else(|e| error!("error replying to a REST request {}", e));
+        }
+        rest::Command::ClearPackets(params, reply) => {
+            let result =
+                clear_packets_on_channel(&ctx.registry, &ctx.workers.acquire_read(), &params);
+
+            reply
+                .send(result)
+                .unwrap_or_else(|e| error!("error replying to a REST request {}", e));
+        }
+        rest::Command::UpdateClient(params, reply) => {
+            let result = update_client(&ctx.registry, &params);
+
+            reply
+                .send(result)
+                .unwrap_or_else(|e| error!("error replying to a REST request {}", e));
+        }
+        rest::Command::PauseWorker(object, reply) => {
+            let result = if ctx.workers.acquire_write().pause(&object) {
+                info!(worker = %object.short_name(), "worker paused");
+                Ok(())
+            } else {
+                Err(rest::RestApiError::WorkerNotFound(object.short_name()))
+            };
+
+            reply
+                .send(result)
+                .unwrap_or_else(|e| error!("error replying to a REST request {}", e));
+        }
+        rest::Command::ResumeWorker(object, reply) => {
+            let result = if ctx.workers.acquire_write().resume(&object) {
+                info!(worker = %object.short_name(), "worker resumed");
+                Ok(())
+            } else {
+                Err(rest::RestApiError::WorkerNotFound(object.short_name()))
+            };
+
+            reply
+                .send(result)
+                .unwrap_or_else(|e| error!("error replying to a REST request {}", e));
+        }
+    }
+}
+
+/// Instruct the packet workers of the given channel to clear its pending packets,
+/// in both directions.
+///
+/// The packets are cleared by the workers themselves, so that they do not race
+/// with the transactions the workers already have in flight. This function
+/// returns as soon as the command has been enqueued, with the packet workers
+/// which were instructed to clear the packets.
+#[instrument(
+    name = "supervisor.clear_packets_on_channel",
+    level = "error",
+    skip_all,
+    fields(
+        chain = %params.chain_id,
+        port = %params.port_id,
+        channel = %params.channel_id,
+    )
+)]
+fn clear_packets_on_channel<Chain: ChainHandle>(
+    registry: &SharedRegistry<Chain>,
+    workers: &WorkerMap,
+    params: &rest::request::ClearPacketsParams,
+) -> Result<Vec<Object>, rest::RestApiError> {
+    let to_rest_error =
+        |e: &dyn core::fmt::Display| rest::RestApiError::ClearPackets(e.to_string());
+
+    let chain = registry
+        .get_or_spawn(&params.chain_id)
+        .map_err(|e| to_rest_error(&e))?;
+
+    let channel = channel_connection_client(&chain, &params.port_id, &params.channel_id)
+        .map_err(|e| to_rest_error(&e))?;
+
+    let counterparty_chain_id = channel.client.client_state.chain_id();
+    let counterparty = channel.channel.channel_end.counterparty();
+
+    let mut objects = vec![Object::Packet(object::Packet {
+        dst_chain_id: counterparty_chain_id.clone(),
+        src_chain_id: params.chain_id.clone(),
+        src_channel_id: params.channel_id.clone(),
+        src_port_id: params.port_id.clone(),
+    })];
+
+    if let Some(counterparty_channel_id) = counterparty.channel_id() {
+        objects.push(Object::Packet(object::Packet {
+            dst_chain_id: params.chain_id.clone(),
+            src_chain_id: counterparty_chain_id,
+            src_channel_id: counterparty_channel_id.clone(),
+            src_port_id: counterparty.port_id().clone(),
+        }));
+    }
+
+    let mut cleared = Vec::new();
+
+    for object in &objects {
+        if let Some(worker) = workers.get(object) {
+            debug!(worker = %object.short_name(), "clearing pending packets");
+
+            worker.clear_pending_packets();
+            cleared.push(object.clone());
+        }
     }
+
+    if cleared.is_empty() {
+        Err(rest::RestApiError::WorkerNotFound(objects[0].short_name()))
+    } else {
+        Ok(cleared)
+    }
+}
+
+/// Update the given client to the latest height of its reference chain,
+/// and return the events emitted by the update transaction.
+#[instrument(
+    name = "supervisor.update_client",
+    level = "error",
+    skip_all,
+    fields(chain = %params.chain_id, client = %params.client_id)
+)]
+fn update_client<Chain: ChainHandle>(
+    registry: &SharedRegistry<Chain>,
+    params: &rest::request::UpdateClientParams,
+) -> Result<Vec<IbcEvent>, rest::RestApiError> {
+    let to_rest_error =
+        |e: &dyn core::fmt::Display| rest::RestApiError::UpdateClient(e.to_string());
+
+    let host_chain = registry
+        .get_or_spawn(&params.chain_id)
+        .map_err(|e| to_rest_error(&e))?;
+
+    let (client_state, _) = host_chain
+        .query_client_state(
+            QueryClientStateRequest {
+                client_id: params.client_id.clone(),
+                height: QueryHeight::Latest,
+            },
+            IncludeProof::No,
+        )
+        .map_err(|e| to_rest_error(&e))?;
+
+    let reference_chain = registry
+        .get_or_spawn(&client_state.chain_id())
+        .map_err(|e| to_rest_error(&e))?;
+
+    let client = ForeignClient::find(reference_chain, host_chain, &params.client_id)
+        .map_err(|e| to_rest_error(&e))?;
+
+    client
+        .build_update_client_and_send(QueryHeight::Latest, None)
+        .map_err(|e| to_rest_error(&e))
 }
 
 #[instrument(
@@ -770,6 +1200,7 @@ fn process_batch<Chain: ChainHandle>(
 
         worker.send_events(
             batch.height,
+            batch.block_time,
             events_with_heights,
             batch.chain_id.clone(),
             batch.tracking_id,
@@ -16,6 +16,7 @@ use crate::chain::requests::{
     IncludeProof, QueryChannelRequest, QueryClientStateRequest, QueryConnectionRequest, QueryHeight,
 };
 use crate::client_state::AnyClientState;
+use crate::config::Config;
 use crate::error::Error as RelayerError;
 use crate::object;
 use crate::registry::Registry;
@@ -83,9 +84,37 @@ pub struct FilterPolicy {
     /// client id, channel id, or connection id, with an
     /// [`Allow`](Permission::Allow) status.
     permission_cache: HashMap<CacheKey, Permission>,
+
+    /// The trust thresholds overridden in the configuration, keyed by
+    /// the chain hosting the clients and by the chain they track.
+    /// Clients with such a trust threshold are allowed, even if it lies
+    /// outside of the default bounds.
+    trust_threshold_overrides: HashMap<(ChainId, ChainId), TrustThreshold>,
 }
 
 impl FilterPolicy {
+    pub fn new(config: &Config) -> Self {
+        let trust_threshold_overrides = config
+            .chains
+            .iter()
+            .flat_map(|chain_config| {
+                chain_config.client_overrides.iter().filter_map(
+                    |(counterparty_chain_id, overrides)| {
+                        let trust_threshold = overrides.trust_threshold?;
+                        let key = (chain_config.id.clone(), counterparty_chain_id.clone());
+
+                        Some((key, trust_threshold.into()))
+                    },
+                )
+            })
+            .collect();
+
+        Self {
+            permission_cache: HashMap::new(),
+            trust_threshold_overrides,
+        }
+    }
+
     /// Given a connection end and the underlying client for that
     /// connection, controls both the client as well as the
     /// client on the counterparty chain.
@@ -179,7 +208,21 @@ impl FilterPolicy {
             return *permission;
         }
 
+        let overridden_threshold = self
+            .trust_threshold_overrides
+            .get(&(host_chain.clone(), state.chain_id()));
+
         let permission = match state.trust_threshold() {
+            Some(threshold) if overridden_threshold == Some(&threshold) => {
+                trace!(
+                    "client {} on chain {} has the trust threshold {} set in the client overrides",
+                    client_id,
+                    host_chain,
+                    threshold
+                );
+
+                Permission::Allow
+            }
             Some(threshold) => {
                 if threshold < LOWER_BOUND {
                     trace!(
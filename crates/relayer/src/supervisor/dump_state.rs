@@ -16,11 +16,18 @@ pub struct WorkerDesc {
     pub id: WorkerId,
     pub object: Object,
     pub data: Option<WorkerData>,
+    #[serde(default)]
+    pub paused: bool,
 }
 
 impl WorkerDesc {
-    pub fn new(id: WorkerId, object: Object, data: Option<WorkerData>) -> Self {
-        Self { id, object, data }
+    pub fn new(id: WorkerId, object: Object, data: Option<WorkerData>, paused: bool) -> Self {
+        Self {
+            id,
+            object,
+            data,
+            paused,
+        }
     }
 }
 
@@ -38,7 +45,7 @@ impl SupervisorState {
         chains.sort();
 
         let workers = workers
-            .map(|h| WorkerDesc::new(h.id(), h.object().clone(), h.data().cloned()))
+            .map(|h| WorkerDesc::new(h.id(), h.object().clone(), h.data().cloned(), h.is_paused()))
             .into_group_map_by(|desc| desc.object.object_type())
             .into_iter()
             .update(|(_, os)| os.sort_by_key(|desc| desc.object.short_name()))
@@ -61,7 +68,16 @@ impl Display for SupervisorState {
         for (tpe, objects) in &self.workers {
             writeln!(f, "* {tpe:?} workers:")?;
             for desc in objects {
-                writeln!(f, "  - {} (id: {})", desc.object.short_name(), desc.id)?;
+                if desc.paused {
+                    writeln!(
+                        f,
+                        "  - {} (id: {}, paused)",
+                        desc.object.short_name(),
+                        desc.id
+                    )?;
+                } else {
+                    writeln!(f, "  - {} (id: {})", desc.object.short_name(), desc.id)?;
+                }
                 if let Some(WorkerData::Client {
                     misbehaviour,
                     refresh,
@@ -75,3 +91,37 @@ impl Display for SupervisorState {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::object::Wallet;
+    use crate::util::lock::{LockExt, RwArc};
+
+    #[test]
+    fn state_reports_paused_workers() {
+        let chain_id: ChainId = "mock-0".parse().unwrap();
+        let object = Object::Wallet(Wallet {
+            chain_id: chain_id.clone(),
+        });
+
+        let handle = WorkerHandle::new(
+            WorkerId::new(1),
+            object.clone(),
+            None,
+            None,
+            <RwArc<bool>>::new_lock(false),
+            vec![],
+        );
+        handle.pause();
+
+        let state = SupervisorState::new(vec![chain_id], core::iter::once(&handle));
+
+        assert_eq!(
+            state.workers[&ObjectType::Wallet],
+            vec![WorkerDesc::new(WorkerId::new(1), object, None, true)]
+        );
+        assert!(state.to_string().contains("(id: 1, paused)"));
+    }
+}
@@ -3,8 +3,10 @@ use flex_error::define_error;
 use ibc_relayer_types::core::ics03_connection::connection::Counterparty;
 use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, ConnectionId, PortId};
 
+use crate::config::Error as ConfigError;
 use crate::error::Error as RelayerError;
 use crate::spawn::SpawnError;
+use crate::state_store::Error as StateStoreError;
 use crate::supervisor::scan::Error as ScanError;
 
 define_error! {
@@ -70,6 +72,17 @@ define_error! {
             [ ScanError ]
             |_| { "supervisor encountered an error when scanning chains" },
 
+        MissingConfigPath
+            |_| { "cannot reload the configuration, as the supervisor does not know the path to the configuration file" },
+
+        LoadConfig
+            [ ConfigError ]
+            |_| { "failed to load the configuration file" },
+
+        StateStore
+            [ StateStoreError ]
+            |_| { "failed to open the state store" },
+
         HandleSend
             |_| { "failed to send a command to the supervisor through a channel" },
 
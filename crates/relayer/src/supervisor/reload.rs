@@ -0,0 +1,175 @@
+use alloc::collections::BTreeSet;
+
+use serde::{Deserialize, Serialize};
+
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+use crate::{config::Config, object::Object};
+
+/// The changes between the configuration the supervisor is running with
+/// and a newly loaded configuration, as far as the supervisor is concerned.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfigDiff {
+    /// Chains which are only present in the new configuration.
+    pub added: Vec<ChainId>,
+    /// Chains which are only present in the old configuration.
+    pub removed: Vec<ChainId>,
+    /// Chains which are present in both configurations, with different settings.
+    pub updated: Vec<ChainId>,
+    /// Whether the `mode` section changed, in which case all workers are affected.
+    pub mode_updated: bool,
+}
+
+impl ConfigDiff {
+    pub fn new(old: &Config, new: &Config) -> Self {
+        let mut diff = Self {
+            mode_updated: old.mode != new.mode,
+            ..Self::default()
+        };
+
+        for old_chain in &old.chains {
+            match new.find_chain(&old_chain.id) {
+                None => diff.removed.push(old_chain.id.clone()),
+                Some(new_chain) if new_chain != old_chain => {
+                    diff.updated.push(old_chain.id.clone())
+                }
+                Some(_) => {}
+            }
+        }
+
+        for new_chain in &new.chains {
+            if !old.has_chain(&new_chain.id) {
+                diff.added.push(new_chain.id.clone());
+            }
+        }
+
+        diff
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.updated.is_empty()
+            && !self.mode_updated
+    }
+
+    /// The chains whose runtime and event subscription must be shut down.
+    pub fn chains_to_stop(&self) -> impl Iterator<Item = &ChainId> {
+        self.removed.iter().chain(self.updated.iter())
+    }
+
+    /// The chains whose runtime and event subscription must be (re)started.
+    pub fn chains_to_start(&self) -> impl Iterator<Item = &ChainId> {
+        self.added.iter().chain(self.updated.iter())
+    }
+
+    /// Whether the worker for the given [`Object`] must be restarted
+    /// for the new configuration to take effect.
+    pub fn affects(&self, object: &Object) -> bool {
+        self.mode_updated
+            || self
+                .chains_to_stop()
+                .any(|chain_id| object.for_chain(chain_id))
+    }
+
+    /// The chains to scan for workers to spawn after the affected
+    /// workers have been shut down, given these workers' objects.
+    pub fn chains_to_scan(&self, new: &Config, stopped: &[Object]) -> BTreeSet<ChainId> {
+        let mut chains = BTreeSet::new();
+
+        if self.mode_updated {
+            chains.extend(new.chains.iter().map(|c| c.id.clone()));
+        }
+
+        chains.extend(self.chains_to_start().cloned());
+
+        // Workers are spawned when scanning their source chain, so the source chain
+        // of every stopped worker must be scanned again, unless it has been removed.
+        chains.extend(
+            stopped
+                .iter()
+                .map(|object| object.src_chain_id())
+                .filter(|chain_id| new.has_chain(chain_id))
+                .cloned(),
+        );
+
+        chains
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::config::load;
+    use crate::object::{Packet, Wallet};
+
+    fn config() -> Config {
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/config/fixtures/relayer_conf_example.toml"
+        );
+
+        load(path).expect("could not parse config")
+    }
+
+    #[test]
+    fn same_config_has_empty_diff() {
+        assert!(ConfigDiff::new(&config(), &config()).is_empty());
+    }
+
+    #[test]
+    fn diff_tracks_added_removed_and_updated_chains() {
+        let old = config();
+        let mut new = config();
+
+        let removed = new.chains.remove(0);
+        new.chains[0].max_gas = Some(123456);
+
+        let mut added = removed.clone();
+        added.id = ChainId::new("ibc".to_string(), 2);
+        new.chains.push(added.clone());
+
+        let diff = ConfigDiff::new(&old, &new);
+
+        assert_eq!(diff.removed, vec![removed.id.clone()]);
+        assert_eq!(diff.updated, vec![new.chains[0].id.clone()]);
+        assert_eq!(diff.added, vec![added.id.clone()]);
+        assert!(!diff.mode_updated);
+
+        let unaffected = Object::Wallet(Wallet {
+            chain_id: added.id.clone(),
+        });
+        assert!(!diff.affects(&unaffected));
+
+        let packet = Object::Packet(Packet {
+            dst_chain_id: new.chains[0].id.clone(),
+            src_chain_id: removed.id,
+            src_channel_id: Default::default(),
+            src_port_id: "transfer".parse().unwrap(),
+        });
+        assert!(diff.affects(&packet));
+
+        // The source chain of the packet worker was removed, so it is not scanned again.
+        let scanned = diff.chains_to_scan(&new, &[packet]);
+        assert_eq!(
+            scanned,
+            [new.chains[0].id.clone(), added.id].into_iter().collect()
+        );
+    }
+
+    #[test]
+    fn mode_update_affects_all_workers() {
+        let old = config();
+        let mut new = config();
+        new.mode.packets.clear_interval += 1;
+
+        let diff = ConfigDiff::new(&old, &new);
+
+        assert!(diff.mode_updated);
+        assert!(diff.affects(&Object::Wallet(Wallet {
+            chain_id: new.chains[0].id.clone(),
+        })));
+        assert_eq!(diff.chains_to_scan(&new, &[]).len(), new.chains.len());
+    }
+}
@@ -244,9 +244,13 @@ impl<'a, Chain: ChainHandle> SpawnContext<'a, Chain> {
             chan_state_dst
         );
 
+        // Packets must keep being relayed while the channel ends are flushing
+        // their in-flight packets during an upgrade
+        let is_relayable = |state: ChannelState| state.is_open() || state.is_upgrading();
+
         if (mode.clients.enabled || mode.packets.enabled)
-            && chan_state_src.is_open()
-            && (chan_state_dst.is_open() || chan_state_dst.is_closed())
+            && is_relayable(chan_state_src)
+            && (is_relayable(chan_state_dst) || chan_state_dst.is_closed())
         {
             if mode.clients.enabled {
                 // Spawn the client worker
@@ -302,6 +306,22 @@ impl<'a, Chain: ChainHandle> SpawnContext<'a, Chain> {
                 }
             }
 
+            if mode.channels.enabled
+                && (chan_state_src.is_upgrading() || chan_state_dst.is_upgrading())
+            {
+                // Spawn the channel worker to complete the upgrade once both ends are flushed
+                let channel_object = Object::Channel(Channel {
+                    dst_chain_id: counterparty_chain.id(),
+                    src_chain_id: chain.id(),
+                    src_channel_id: channel_scan.channel.channel_id.clone(),
+                    src_port_id: channel_scan.channel.port_id,
+                });
+
+                self.workers
+                    .spawn(chain, counterparty_chain, &channel_object, self.config)
+                    .then(|| info!("spawned channel worker: {}", channel_object.short_name()));
+            }
+
             Ok(mode.clients.enabled)
         } else if mode.channels.enabled {
             let has_packets = || {
@@ -311,8 +331,11 @@ impl<'a, Chain: ChainHandle> SpawnContext<'a, Chain> {
                     .is_empty()
             };
 
-            // Determine if open handshake is required
-            let open_handshake = chan_state_dst.less_or_equal_progress(ChannelState::TryOpen)
+            // Determine if open handshake is required. The upgrade states rank after `Closed`,
+            // but they are not part of the open handshake, which is never required then.
+            let open_handshake = !chan_state_src.is_upgrading()
+                && !chan_state_dst.is_upgrading()
+                && chan_state_dst.less_or_equal_progress(ChannelState::TryOpen)
                 && chan_state_dst.less_or_equal_progress(chan_state_src);
 
             // Determine if close handshake is required, i.e. if channel state on source is `Closed`,
@@ -1,3 +1,5 @@
+use core::sync::atomic::{AtomicBool, Ordering};
+
 // If the `telemetry` feature is enabled, re-export the `ibc-telemetry` state.
 #[cfg(feature = "telemetry")]
 pub type Telemetry = alloc::sync::Arc<ibc_telemetry::TelemetryState>;
@@ -10,6 +12,20 @@ pub struct TelemetryDisabled;
 #[cfg(not(feature = "telemetry"))]
 pub type Telemetry = TelemetryDisabled;
 
+static PACKET_LATENCIES: AtomicBool = AtomicBool::new(false);
+
+/// Enable or disable the recording of the packet latencies, for which the
+/// event sources fetch the time of the blocks which hold IBC events, when
+/// they do not receive it along with the events.
+pub fn set_packet_latencies(enabled: bool) {
+    PACKET_LATENCIES.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether the packet latencies are recorded.
+pub fn packet_latencies() -> bool {
+    cfg!(feature = "telemetry") && PACKET_LATENCIES.load(Ordering::Relaxed)
+}
+
 /// A macro to send metric updates via a telemetry handle,
 /// only if the `telemetry` feature is enabled.
 /// Otherwise, it compiles to a no-op.
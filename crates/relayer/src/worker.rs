@@ -1,5 +1,6 @@
 use alloc::sync::Arc;
 use core::fmt::{Display, Error as FmtError, Formatter};
+use crossbeam_channel::Receiver;
 use ibc_relayer_types::core::ics04_channel::channel::Ordering;
 use serde::{Deserialize, Serialize};
 use std::sync::Mutex;
@@ -11,6 +12,8 @@ use crate::{
     chain::handle::{ChainHandle, ChainHandlePair},
     config::Config,
     object::Object,
+    state_store::StateStore,
+    util::lock::{LockExt, RwArc},
 };
 
 pub mod retry_strategy;
@@ -54,13 +57,27 @@ impl Display for WorkerId {
     }
 }
 
+/// Drain the commands received by a paused handshake worker, keeping only
+/// the latest batch of events in `pending`, since the worker steps the
+/// handshake from the last event it receives.
+fn coalesce_paused_commands(cmd_rx: &Receiver<WorkerCmd>, pending: &mut Option<WorkerCmd>) {
+    for cmd in cmd_rx.try_iter() {
+        if let WorkerCmd::IbcEvents { .. } = cmd {
+            *pending = Some(cmd);
+        }
+    }
+}
+
 pub fn spawn_worker_tasks<ChainA: ChainHandle, ChainB: ChainHandle>(
     chains: ChainHandlePair<ChainA, ChainB>,
     id: WorkerId,
     object: Object,
     config: &Config,
+    paused: bool,
+    state_store: Option<&StateStore>,
 ) -> WorkerHandle {
     let mut task_handles = Vec::new();
+    let paused = <RwArc<bool>>::new_lock(paused);
 
     let (cmd_tx, data) = match &object {
         Object::Client(client) => {
@@ -68,7 +85,11 @@ pub fn spawn_worker_tasks<ChainA: ChainHandle, ChainB: ChainHandle>(
 
             let (mut refresh, mut misbehaviour) = (false, false);
 
-            let refresh_task = client::spawn_refresh_client(client.clone());
+            let refresh_task = client::spawn_refresh_client(
+                client.clone(),
+                config.mode.clients.refresh_rate,
+                config.mode.clients.refresh_horizon,
+            );
             if let Some(refresh_task) = refresh_task {
                 task_handles.push(refresh_task);
                 refresh = true;
@@ -76,7 +97,8 @@ pub fn spawn_worker_tasks<ChainA: ChainHandle, ChainB: ChainHandle>(
 
             let cmd_tx = if config.mode.clients.misbehaviour {
                 let (cmd_tx, cmd_rx) = crossbeam_channel::unbounded();
-                let misbehavior_task = client::detect_misbehavior_task(cmd_rx, client);
+                let misbehavior_task =
+                    client::detect_misbehavior_task(cmd_rx, client, paused.clone());
                 if let Some(task) = misbehavior_task {
                     task_handles.push(task);
                     misbehaviour = true;
@@ -96,15 +118,20 @@ pub fn spawn_worker_tasks<ChainA: ChainHandle, ChainB: ChainHandle>(
         }
         Object::Connection(connection) => {
             let (cmd_tx, cmd_rx) = crossbeam_channel::unbounded();
-            let connection_task =
-                connection::spawn_connection_worker(connection.clone(), chains, cmd_rx);
+            let connection_task = connection::spawn_connection_worker(
+                connection.clone(),
+                chains,
+                cmd_rx,
+                paused.clone(),
+            );
             task_handles.push(connection_task);
 
             (Some(cmd_tx), None)
         }
         Object::Channel(channel) => {
             let (cmd_tx, cmd_rx) = crossbeam_channel::unbounded();
-            let channel_task = channel::spawn_channel_worker(channel.clone(), chains, cmd_rx);
+            let channel_task =
+                channel::spawn_channel_worker(channel.clone(), chains, cmd_rx, paused.clone());
             task_handles.push(channel_task);
 
             (Some(cmd_tx), None)
@@ -123,11 +150,35 @@ pub fn spawn_worker_tasks<ChainA: ChainHandle, ChainB: ChainHandle>(
             );
 
             match link_res {
-                Ok(link) => {
+                Ok(mut link) => {
+                    link.a_to_b
+                        .set_redundant_backoff(packets_config.redundant_backoff);
+                    link.a_to_b.set_packet_latencies(config.telemetry.enabled);
+
                     let channel_ordering = link.a_to_b.channel().ordering;
-                    let should_clear_on_start =
+                    let mut should_clear_on_start =
                         packets_config.clear_on_start || channel_ordering == Ordering::Ordered;
 
+                    if let Some(state_store) = state_store {
+                        link.a_to_b.set_state(state_store.path_state(path));
+
+                        // Packets sent while the relayer was stopped are cleared on start,
+                        // unless the periodic packet clearing is due soon enough
+                        // and there are no transactions left to resubmit.
+                        let must_clear = link
+                            .a_to_b
+                            .resume(packets_config.clear_interval)
+                            .map(|resumption| {
+                                resumption.must_clear_packets(packets_config.clear_on_start)
+                            })
+                            .unwrap_or_else(|e| {
+                                error!("failed to resume from the state store: {}", e);
+                                packets_config.clear_on_start
+                            });
+
+                        should_clear_on_start = must_clear || channel_ordering == Ordering::Ordered;
+                    }
+
                     let (cmd_tx, cmd_rx) = crossbeam_channel::unbounded();
                     let link = Arc::new(Mutex::new(link));
                     let resubmit = Resubmit::from_clear_interval(packets_config.clear_interval);
@@ -156,6 +207,7 @@ pub fn spawn_worker_tasks<ChainA: ChainHandle, ChainB: ChainHandle>(
                             link.clone(),
                             path.clone(),
                             filter,
+                            paused.clone(),
                         ),
                         None => packet::spawn_packet_cmd_worker(
                             cmd_rx,
@@ -163,11 +215,13 @@ pub fn spawn_worker_tasks<ChainA: ChainHandle, ChainB: ChainHandle>(
                             should_clear_on_start,
                             packets_config.clear_interval,
                             path.clone(),
+                            paused.clone(),
                         ),
                     };
                     task_handles.push(packet_task);
 
-                    let link_task = packet::spawn_packet_worker(path.clone(), link, resubmit);
+                    let link_task =
+                        packet::spawn_packet_worker(path.clone(), link, resubmit, paused.clone());
                     task_handles.push(link_task);
 
                     (Some(cmd_tx), None)
@@ -195,6 +249,7 @@ pub fn spawn_worker_tasks<ChainA: ChainHandle, ChainB: ChainHandle>(
                 chains.b,
                 cmd_rx,
                 cross_chain_query.clone(),
+                paused.clone(),
             );
             task_handles.push(cross_chain_query_task);
 
@@ -202,5 +257,5 @@ pub fn spawn_worker_tasks<ChainA: ChainHandle, ChainB: ChainHandle>(
         }
     };
 
-    WorkerHandle::new(id, object, data, cmd_tx, task_handles)
+    WorkerHandle::new(id, object, data, cmd_tx, paused, task_handles)
 }
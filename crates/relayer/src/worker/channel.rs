@@ -3,16 +3,17 @@ use crossbeam_channel::Receiver;
 use tracing::{debug, error_span};
 
 use crate::channel::{channel_handshake_retry, Channel as RelayChannel};
+use crate::util::lock::{LockExt, RwArc};
 use crate::util::retry::RetryResult;
-use crate::util::task::{spawn_background_task, Next, TaskError, TaskHandle};
+use crate::util::task::{Next, TaskError, TaskHandle};
 use crate::{
     chain::handle::{ChainHandle, ChainHandlePair},
-    object::Channel,
+    object::{Channel, ObjectType},
     util::retry::retry_with_index,
 };
 
-use super::error::RunError;
-use super::WorkerCmd;
+use super::error::{spawn_worker_task, RunError};
+use super::{coalesce_paused_commands, WorkerCmd};
 
 fn max_block_times<ChainA: ChainHandle, ChainB: ChainHandle>(
     chains: &ChainHandlePair<ChainA, ChainB>,
@@ -32,14 +33,23 @@ pub fn spawn_channel_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
     channel: Channel,
     chains: ChainHandlePair<ChainA, ChainB>,
     cmd_rx: Receiver<WorkerCmd>,
+    paused: RwArc<bool>,
 ) -> TaskHandle {
     let mut complete_handshake_on_new_block = true;
-    spawn_background_task(
+    let mut pending = None;
+    spawn_worker_task(
+        channel.src_chain_id.clone(),
+        ObjectType::Channel,
         error_span!("worker.channel", channel = %channel.short_name()),
         Some(Duration::from_millis(200)),
         move || {
+            if *paused.acquire_read() {
+                coalesce_paused_commands(&cmd_rx, &mut pending);
+                return Ok(Next::Continue);
+            }
+
             let max_block_times = max_block_times(&chains);
-            if let Ok(cmd) = cmd_rx.try_recv() {
+            if let Some(cmd) = pending.take().or_else(|| cmd_rx.try_recv().ok()) {
                 match cmd {
                     WorkerCmd::IbcEvents { batch } => {
                         // there can be up to two event for this channel, e.g. init and try.
@@ -49,7 +59,7 @@ pub fn spawn_channel_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
 
                         complete_handshake_on_new_block = false;
                         if let Some(event_with_height) = last_event {
-                            retry_with_index(
+                            let next = retry_with_index(
                                 channel_handshake_retry::default_strategy(max_block_times),
                                 |index| match RelayChannel::restore_from_event(
                                     chains.a.clone(),
@@ -61,7 +71,19 @@ pub fn spawn_channel_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
                                     Err(_) => RetryResult::Retry(index),
                                 },
                             )
-                            .map_err(|e| TaskError::Fatal(RunError::retry(e)))
+                            .map_err(|e| TaskError::Fatal(RunError::retry(e)))?;
+
+                            // Keep stepping the upgrade on new blocks while the channel ends
+                            // are flushing their in-flight packets
+                            let is_upgrade = event_with_height
+                                .event
+                                .clone()
+                                .upgrade_attributes()
+                                .is_some();
+                            complete_handshake_on_new_block =
+                                is_upgrade && matches!(next, Next::Continue);
+
+                            Ok(next)
                         } else {
                             Ok(Next::Continue)
                         }
@@ -78,7 +100,8 @@ pub fn spawn_channel_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
                             .map_err(|e| TaskError::Fatal(RunError::ics02(e)))?;
 
                         complete_handshake_on_new_block = false;
-                        retry_with_index(
+                        let mut is_upgrading = false;
+                        let next = retry_with_index(
                             channel_handshake_retry::default_strategy(max_block_times),
                             |index| match RelayChannel::restore_from_state(
                                 chains.a.clone(),
@@ -87,12 +110,18 @@ pub fn spawn_channel_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
                                 height,
                             ) {
                                 Ok((mut handshake_channel, state)) => {
+                                    is_upgrading = state.is_upgrading();
                                     handshake_channel.step_state(state, index)
                                 }
                                 Err(_) => RetryResult::Retry(index),
                             },
                         )
-                        .map_err(|e| TaskError::Fatal(RunError::retry(e)))
+                        .map_err(|e| TaskError::Fatal(RunError::retry(e)))?;
+
+                        complete_handshake_on_new_block =
+                            is_upgrading && matches!(next, Next::Continue);
+
+                        Ok(next)
                     }
 
                     // nothing to do
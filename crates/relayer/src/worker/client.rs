@@ -9,13 +9,17 @@ use tracing::{debug, debug_span, error_span, trace, warn};
 use ibc_relayer_types::core::ics02_client::events::UpdateClient;
 use ibc_relayer_types::events::IbcEvent;
 
+use crate::config::refresh_rate::RefreshRate;
+use crate::object::ObjectType;
+use crate::util::lock::{LockExt, RwArc};
 use crate::util::retry::clamp_total;
-use crate::util::task::{spawn_background_task, Next, TaskError, TaskHandle};
+use crate::util::task::{Next, TaskError, TaskHandle};
 use crate::{
     chain::handle::ChainHandle,
     foreign_client::{ForeignClient, MisbehaviourResults},
 };
 
+use super::error::spawn_worker_task;
 use super::WorkerCmd;
 
 const REFRESH_INTERVAL: Duration = Duration::from_secs(2); // 2 seconds
@@ -25,6 +29,8 @@ const MAX_REFRESH_TOTAL_DELAY: Duration = Duration::from_secs(60 * 60 * 24); //
 
 pub fn spawn_refresh_client<ChainA: ChainHandle, ChainB: ChainHandle>(
     mut client: ForeignClient<ChainA, ChainB>,
+    refresh_rate: RefreshRate,
+    mut refresh_horizon: Option<Duration>,
 ) -> Option<TaskHandle> {
     if client.is_expired_or_frozen() {
         warn!(
@@ -35,10 +41,29 @@ pub fn spawn_refresh_client<ChainA: ChainHandle, ChainB: ChainHandle>(
         return None;
     }
 
+    if let (Some(horizon), Ok((client_state, _))) =
+        (refresh_horizon, client.validated_client_state())
+    {
+        if let Some(trusting_period) = client_state.trusting_period() {
+            if horizon >= trusting_period {
+                warn!(
+                    client = %client.id,
+                    ?horizon,
+                    ?trusting_period,
+                    "ignoring refresh horizon which is not shorter than the client's trusting period",
+                );
+
+                refresh_horizon = None;
+            }
+        }
+    }
+
     // Compute the refresh interval as a fraction of the client's trusting period
     // If the trusting period or the client state is not retrieved, fallback to a default value.
     let mut next_refresh = Instant::now() + REFRESH_INTERVAL;
-    Some(spawn_background_task(
+    Some(spawn_worker_task(
+        client.src_chain.id(),
+        ObjectType::Client,
         error_span!(
             "worker.client.refresh",
             client = %client.id,
@@ -54,7 +79,9 @@ pub fn spawn_refresh_client<ChainA: ChainHandle, ChainB: ChainHandle>(
             }
 
             // Use retry mechanism only if `client.refresh()` fails.
-            let res = retry_with_index(refresh_strategy(), |_| client.refresh());
+            let res = retry_with_index(refresh_strategy(), |_| {
+                client.refresh_with(refresh_rate, refresh_horizon)
+            });
 
             match res {
                 // If `client.refresh()` was successful, update the `next_refresh` call.
@@ -74,6 +101,7 @@ pub fn spawn_refresh_client<ChainA: ChainHandle, ChainB: ChainHandle>(
 pub fn detect_misbehavior_task<ChainA: ChainHandle, ChainB: ChainHandle>(
     receiver: Receiver<WorkerCmd>,
     client: ForeignClient<ChainB, ChainA>,
+    paused: RwArc<bool>,
 ) -> Option<TaskHandle> {
     if client.is_expired_or_frozen() {
         warn!(
@@ -88,7 +116,9 @@ pub fn detect_misbehavior_task<ChainA: ChainHandle, ChainB: ChainHandle>(
 
     let mut initial_check_done = false;
 
-    let handle = spawn_background_task(
+    let handle = spawn_worker_task(
+        client.src_chain.id(),
+        ObjectType::Client,
         error_span!(
             "worker.client.misbehaviour",
             client = %client.id,
@@ -97,6 +127,11 @@ pub fn detect_misbehavior_task<ChainA: ChainHandle, ChainB: ChainHandle>(
         ),
         Some(Duration::from_millis(600)),
         move || -> Result<Next, TaskError<Infallible>> {
+            // Leave the commands in the channel while the worker is paused
+            if *paused.acquire_read() {
+                return Ok(Next::Continue);
+            }
+
             if !initial_check_done {
                 initial_check_done = true;
 
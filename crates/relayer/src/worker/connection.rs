@@ -3,28 +3,38 @@ use crossbeam_channel::Receiver;
 use tracing::{debug, error_span};
 
 use crate::connection::Connection as RelayConnection;
-use crate::util::task::{spawn_background_task, Next, TaskError, TaskHandle};
+use crate::util::lock::{LockExt, RwArc};
+use crate::util::task::{Next, TaskError, TaskHandle};
 use crate::{
     chain::handle::{ChainHandle, ChainHandlePair},
-    object::Connection,
+    object::{Connection, ObjectType},
     util::retry::retry_with_index,
     worker::retry_strategy,
 };
 
-use super::error::RunError;
-use super::WorkerCmd;
+use super::error::{spawn_worker_task, RunError};
+use super::{coalesce_paused_commands, WorkerCmd};
 
 pub fn spawn_connection_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
     connection: Connection,
     chains: ChainHandlePair<ChainA, ChainB>,
     cmd_rx: Receiver<WorkerCmd>,
+    paused: RwArc<bool>,
 ) -> TaskHandle {
     let mut complete_handshake_on_new_block = true;
-    spawn_background_task(
+    let mut pending = None;
+    spawn_worker_task(
+        connection.src_chain_id.clone(),
+        ObjectType::Connection,
         error_span!("worker.connection", connection = %connection.short_name()),
         Some(Duration::from_millis(200)),
         move || {
-            if let Ok(cmd) = cmd_rx.try_recv() {
+            if *paused.acquire_read() {
+                coalesce_paused_commands(&cmd_rx, &mut pending);
+                return Ok(Next::Continue);
+            }
+
+            if let Some(cmd) = pending.take().or_else(|| cmd_rx.try_recv().ok()) {
                 match cmd {
                     WorkerCmd::IbcEvents { batch } => {
                         // there can be up to two event for this connection, e.g. init and try.
@@ -1,4 +1,4 @@
-use super::error::RunError;
+use super::error::{spawn_worker_task, RunError};
 use crate::chain::handle::ChainHandle;
 use crate::chain::requests::{
     CrossChainQueryRequest, IncludeProof, QueryConnectionRequest, QueryHeight,
@@ -7,8 +7,9 @@ use crate::chain::tracking::TrackedMsgs;
 use crate::error::Error;
 use crate::event::IbcEventWithHeight;
 use crate::foreign_client::ForeignClient;
-use crate::object::CrossChainQuery;
-use crate::util::task::{spawn_background_task, Next, TaskError, TaskHandle};
+use crate::object::{CrossChainQuery, ObjectType};
+use crate::util::lock::{LockExt, RwArc};
+use crate::util::task::{Next, TaskError, TaskHandle};
 use crate::worker::WorkerCmd;
 
 use crossbeam_channel::Receiver;
@@ -41,11 +42,19 @@ pub fn spawn_cross_chain_query_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
     chain_b_handle: ChainB,
     cmd_rx: Receiver<WorkerCmd>,
     cross_chain_query: CrossChainQuery,
+    paused: RwArc<bool>,
 ) -> TaskHandle {
-    spawn_background_task(
+    spawn_worker_task(
+        cross_chain_query.src_chain_id.clone(),
+        ObjectType::CrossChainQuery,
         info_span!("cross chain query"),
         Some(Duration::from_millis(1000)),
         move || {
+            // Leave the commands in the channel while the worker is paused
+            if *paused.acquire_read() {
+                return Ok(Next::Continue);
+            }
+
             if let Ok(cmd) = cmd_rx.try_recv() {
                 handle_cross_chain_query(
                     chain_a_handle.clone(),
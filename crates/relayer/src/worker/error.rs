@@ -1,10 +1,19 @@
+use core::fmt::Display;
+use core::time::Duration;
+
 use crossbeam_channel::RecvError;
 use flex_error::{define_error, DisplayOnly};
 use ibc_relayer_types::core::ics02_client::error::Error as Ics02Error;
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 
 use crate::channel::ChannelError;
 use crate::connection::ConnectionError;
+use crate::error::Error as RelayerError;
+use crate::error_kind::{ErrorKind, HasErrorKind};
 use crate::link::error::LinkError;
+use crate::object::ObjectType;
+use crate::telemetry;
+use crate::util::task::{spawn_background_task, Next, TaskError, TaskHandle};
 
 define_error! {
     RunError {
@@ -33,6 +42,88 @@ define_error! {
             | _ | { "error receiving from channel: sender end has been closed" },
 
         Query
-            | _ | { "error occurred during querying" }
+            | _ | { "error occurred during querying" },
+
+        Key
+            [ RelayerError ]
+            | _ | { "failed to get key in use by the relayer" },
+
+        Balance
+            [ RelayerError ]
+            | _ | { "failed to query balance for the account" },
+    }
+}
+
+impl HasErrorKind for RunErrorDetail {
+    fn error_kind(&self) -> ErrorKind {
+        match self {
+            Self::Connection(e) => e.source.error_kind(),
+            Self::Channel(e) => e.source.error_kind(),
+            Self::Link(e) => e.source.error_kind(),
+            Self::Key(e) => e.source.error_kind(),
+            Self::Balance(e) => e.source.error_kind(),
+            Self::Query(_) => ErrorKind::Rpc,
+            _ => ErrorKind::Other,
+        }
+    }
+}
+
+impl HasErrorKind for RunError {
+    fn error_kind(&self) -> ErrorKind {
+        self.detail().error_kind()
+    }
+}
+
+/// Wraps the step runner of a task of a worker, so that the errors it returns
+/// are counted in the telemetry by chain, type of worker and kind of error.
+#[cfg_attr(not(feature = "telemetry"), allow(unused_variables))]
+fn record_task_errors<E: HasErrorKind>(
+    chain_id: ChainId,
+    object_type: ObjectType,
+    mut step_runner: impl FnMut() -> Result<Next, TaskError<E>> + Send + Sync + 'static,
+) -> impl FnMut() -> Result<Next, TaskError<E>> + Send + Sync + 'static {
+    move || {
+        let result = step_runner();
+
+        if let Err(TaskError::Ignore(e) | TaskError::Fatal(e)) = &result {
+            telemetry!(
+                worker_errors,
+                &chain_id,
+                worker_type(object_type),
+                e.error_kind().as_str(),
+            );
+        }
+
+        result
+    }
+}
+
+/// Spawns a task of a worker with [`spawn_background_task`], counting the errors
+/// of its step runner in the telemetry by chain, type of worker and kind of error.
+pub fn spawn_worker_task<E: Display + HasErrorKind>(
+    chain_id: ChainId,
+    object_type: ObjectType,
+    span: tracing::Span,
+    interval_pause: Option<Duration>,
+    step_runner: impl FnMut() -> Result<Next, TaskError<E>> + Send + Sync + 'static,
+) -> TaskHandle {
+    spawn_background_task(
+        span,
+        interval_pause,
+        record_task_errors(chain_id, object_type, step_runner),
+    )
+}
+
+#[cfg(feature = "telemetry")]
+fn worker_type(object_type: ObjectType) -> ibc_telemetry::state::WorkerType {
+    use ibc_telemetry::state::WorkerType;
+
+    match object_type {
+        ObjectType::Client => WorkerType::Client,
+        ObjectType::Connection => WorkerType::Connection,
+        ObjectType::Channel => WorkerType::Channel,
+        ObjectType::Packet => WorkerType::Packet,
+        ObjectType::Wallet => WorkerType::Wallet,
+        ObjectType::CrossChainQuery => WorkerType::CrossChainQuery,
     }
 }
@@ -8,6 +8,7 @@ use tracing::{debug, trace};
 
 use ibc_relayer_types::{
     core::{ics02_client::events::NewBlock, ics24_host::identifier::ChainId},
+    timestamp::Timestamp,
     Height,
 };
 
@@ -30,6 +31,7 @@ pub struct WorkerHandle {
     object: Object,
     data: Option<WorkerData>,
     tx: RwArc<Option<Sender<WorkerCmd>>>,
+    paused: RwArc<bool>,
     task_handles: Vec<TaskHandle>,
 }
 
@@ -39,6 +41,7 @@ impl WorkerHandle {
         object: Object,
         data: Option<WorkerData>,
         tx: Option<Sender<WorkerCmd>>,
+        paused: RwArc<bool>,
         task_handles: Vec<TaskHandle>,
     ) -> Self {
         Self {
@@ -46,6 +49,7 @@ impl WorkerHandle {
             object,
             data,
             tx: <RwArc<_>>::new_lock(tx),
+            paused,
             task_handles,
         }
     }
@@ -67,6 +71,7 @@ impl WorkerHandle {
     pub fn send_events(
         &self,
         height: Height,
+        block_time: Option<Timestamp>,
         events: Vec<IbcEventWithHeight>,
         chain_id: ChainId,
         tracking_id: TrackingId,
@@ -74,6 +79,7 @@ impl WorkerHandle {
         let batch = EventBatch {
             chain_id,
             height,
+            block_time,
             events,
             tracking_id,
         };
@@ -82,7 +88,14 @@ impl WorkerHandle {
     }
 
     /// Send a batch of [`NewBlock`] event to the worker.
+    ///
+    /// The event is dropped if the worker is paused, so that the new blocks
+    /// do not pile up in the command channel until it is resumed.
     pub fn send_new_block(&self, height: Height, new_block: NewBlock) {
+        if self.is_paused() {
+            return;
+        }
+
         self.try_send_command(WorkerCmd::NewBlock { height, new_block });
     }
 
@@ -91,6 +104,27 @@ impl WorkerHandle {
         self.try_send_command(WorkerCmd::ClearPendingPackets);
     }
 
+    /// Pause the worker until it is resumed.
+    ///
+    /// A paused packet worker keeps scheduling the events it receives, and the
+    /// packets it is instructed to clear, but does not submit any transaction.
+    /// Channel and connection workers only keep the latest batch of events they
+    /// receive, while the other workers leave their commands pending.
+    /// Client workers keep refreshing their client, so that it does not expire.
+    pub fn pause(&self) {
+        *self.paused.acquire_write() = true;
+    }
+
+    /// Resume the worker after it has been paused.
+    pub fn resume(&self) {
+        *self.paused.acquire_write() = false;
+    }
+
+    /// Whether the worker is paused.
+    pub fn is_paused(&self) -> bool {
+        *self.paused.acquire_read()
+    }
+
     /// Shutdown all worker tasks without waiting for them to terminate.
     pub fn shutdown(&self) {
         for task in self.task_handles.iter() {
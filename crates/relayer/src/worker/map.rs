@@ -1,4 +1,5 @@
 use alloc::collections::btree_map::BTreeMap as HashMap;
+use alloc::collections::BTreeSet;
 use core::mem;
 
 use ibc_relayer_types::core::ics02_client::events::NewBlock;
@@ -10,6 +11,7 @@ use crate::{
     chain::handle::{ChainHandle, ChainHandlePair},
     config::Config,
     object::Object,
+    state_store::StateStore,
     telemetry,
 };
 
@@ -19,6 +21,11 @@ use super::{spawn_worker_tasks, WorkerHandle, WorkerId};
 #[derive(Debug)]
 pub struct WorkerMap {
     workers: HashMap<Object, WorkerHandle>,
+    /// The objects whose workers have been paused, and must stay paused
+    /// if they are spawned again. This set is not persisted, so it is
+    /// empty when the relayer starts.
+    paused: BTreeSet<Object>,
+    state_store: Option<StateStore>,
     latest_worker_id: WorkerId,
 }
 
@@ -26,6 +33,8 @@ impl Default for WorkerMap {
     fn default() -> Self {
         Self {
             workers: HashMap::new(),
+            paused: BTreeSet::new(),
+            state_store: None,
             latest_worker_id: WorkerId::new(0),
         }
     }
@@ -39,11 +48,22 @@ impl WorkerMap {
         Self::default()
     }
 
+    /// Record the progress of the packet workers in the given state store.
+    pub fn with_state_store(mut self, state_store: StateStore) -> Self {
+        self.state_store = Some(state_store);
+        self
+    }
+
     /// Returns `true` if there is a spawned [`WorkerHandle`] associated with the given [`Object`].
     pub fn contains(&self, object: &Object) -> bool {
         self.workers.contains_key(object)
     }
 
+    /// Returns the [`WorkerHandle`] associated with the given [`Object`], if any.
+    pub fn get(&self, object: &Object) -> Option<&WorkerHandle> {
+        self.workers.get(object)
+    }
+
     /// Remove the [`WorkerHandle`] associated with the given [`Object`] from
     /// the map and wait for its thread to terminate.
     pub fn remove_stopped(&mut self, id: WorkerId, object: Object) -> bool {
@@ -166,6 +186,8 @@ impl WorkerMap {
             self.next_worker_id(),
             object.clone(),
             config,
+            self.paused.contains(object),
+            self.state_store.as_ref(),
         )
     }
 
@@ -186,6 +208,11 @@ impl WorkerMap {
             .collect()
     }
 
+    /// List the [`Object`]s for which there is an associated worker.
+    pub fn objects(&self) -> impl Iterator<Item = &Object> {
+        self.workers.keys()
+    }
+
     /// List the [`WorkerHandle`]s associated with the given chain.
     pub fn workers_for_chain(&self, chain_id: &ChainId) -> Vec<&WorkerHandle> {
         self.workers
@@ -199,6 +226,40 @@ impl WorkerMap {
         self.workers.values()
     }
 
+    /// Pause the worker associated with the given [`Object`].
+    ///
+    /// The worker stays paused until it is resumed, even if it is
+    /// shut down and spawned again in the meantime, eg. when the
+    /// configuration is reloaded.
+    ///
+    /// Returns `false` if there is no such worker.
+    pub fn pause(&mut self, object: &Object) -> bool {
+        match self.workers.get(object) {
+            Some(handle) => {
+                handle.pause();
+                self.paused.insert(object.clone());
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Resume the worker associated with the given [`Object`].
+    ///
+    /// Returns `false` if there is no such worker, and the object
+    /// was not paused either.
+    pub fn resume(&mut self, object: &Object) -> bool {
+        let was_paused = self.paused.remove(object);
+
+        match self.workers.get(object) {
+            Some(handle) => {
+                handle.resume();
+                true
+            }
+            None => was_paused,
+        }
+    }
+
     /// Shutdown the worker associated with the given [`Object`], synchronously.
     pub fn shutdown_worker(&mut self, object: &Object) {
         if let Some(handle) = self.workers.remove(object) {
@@ -29,12 +29,12 @@ use crate::event::monitor::EventBatch;
 use crate::foreign_client::HasExpiredOrFrozenError;
 use crate::link::Resubmit;
 use crate::link::{error::LinkError, Link};
-use crate::object::Packet;
+use crate::object::{ObjectType, Packet};
 use crate::telemetry;
 use crate::util::lock::{LockExt, RwArc};
-use crate::util::task::{spawn_background_task, Next, TaskError, TaskHandle};
+use crate::util::task::{Next, TaskError, TaskHandle};
 
-use super::error::RunError;
+use super::error::{spawn_worker_task, RunError};
 use super::WorkerCmd;
 
 const INCENTIVIZED_CACHE_TTL: Duration = Duration::from_secs(10 * 60);
@@ -57,6 +57,7 @@ pub fn spawn_packet_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
     // Mutex is used to prevent race condition between the packet workers
     link: Arc<Mutex<Link<ChainA, ChainB>>>,
     resubmit: Resubmit,
+    paused: RwArc<bool>,
 ) -> TaskHandle {
     let span = {
         let relay_path = &link.lock().unwrap().a_to_b;
@@ -69,10 +70,27 @@ pub fn spawn_packet_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
         )
     };
 
-    spawn_background_task(span, Some(Duration::from_millis(1000)), move || {
+    let chain_id = path.src_chain_id.clone();
+
+    let step_runner = move || {
+        // While the worker is paused, the command worker keeps scheduling
+        // the operational data built from incoming events, but nothing gets
+        // submitted until the worker is resumed.
+        if *paused.acquire_read() {
+            return Ok(Next::Continue);
+        }
+
         handle_execute_schedule(&mut link.lock().unwrap(), &path, resubmit)?;
         Ok(Next::Continue)
-    })
+    };
+
+    spawn_worker_task(
+        chain_id,
+        ObjectType::Packet,
+        span,
+        Some(Duration::from_millis(1000)),
+        step_runner,
+    )
 }
 
 pub fn spawn_packet_cmd_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
@@ -82,6 +100,7 @@ pub fn spawn_packet_cmd_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
     mut should_clear_on_start: bool,
     clear_interval: u64,
     path: Packet,
+    paused: RwArc<bool>,
 ) -> TaskHandle {
     let span = {
         let relay_path = &link.lock().unwrap().a_to_b;
@@ -94,7 +113,9 @@ pub fn spawn_packet_cmd_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
         )
     };
 
-    spawn_background_task(span, Some(Duration::from_millis(200)), move || {
+    let chain_id = path.src_chain_id.clone();
+
+    let step_runner = move || {
         if let Ok(cmd) = cmd_rx.try_recv() {
             // Try to clear pending packets. At different levels down in `handle_packet_cmd` there
             // are retries mechanisms for MAX_RETRIES (current value hardcoded at 5).
@@ -107,11 +128,20 @@ pub fn spawn_packet_cmd_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
                 clear_interval,
                 &path,
                 cmd,
+                *paused.acquire_read(),
             )?;
         }
 
         Ok(Next::Continue)
-    })
+    };
+
+    spawn_worker_task(
+        chain_id,
+        ObjectType::Packet,
+        span,
+        Some(Duration::from_millis(200)),
+        step_runner,
+    )
 }
 
 pub fn spawn_incentivized_packet_cmd_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
@@ -120,6 +150,7 @@ pub fn spawn_incentivized_packet_cmd_worker<ChainA: ChainHandle, ChainB: ChainHa
     link: Arc<Mutex<Link<ChainA, ChainB>>>,
     path: Packet,
     fee_filter: FeePolicy,
+    paused: RwArc<bool>,
 ) -> TaskHandle {
     let span = {
         let relay_path = &link.lock().unwrap().a_to_b;
@@ -141,7 +172,9 @@ pub fn spawn_incentivized_packet_cmd_worker<ChainA: ChainHandle, ChainB: ChainHa
             .build(),
     );
 
-    spawn_background_task(span, Some(Duration::from_millis(200)), move || {
+    let chain_id = path.src_chain_id.clone();
+
+    let step_runner = move || {
         if let Ok(cmd) = cmd_rx.try_recv() {
             handle_incentivized_packet_cmd(
                 &mut link.lock().unwrap(),
@@ -149,11 +182,20 @@ pub fn spawn_incentivized_packet_cmd_worker<ChainA: ChainHandle, ChainB: ChainHa
                 cmd,
                 &incentivized_recv_cache,
                 &fee_filter,
+                *paused.acquire_read(),
             )?;
         }
 
         Ok(Next::Continue)
-    })
+    };
+
+    spawn_worker_task(
+        chain_id,
+        ObjectType::Packet,
+        span,
+        Some(Duration::from_millis(200)),
+        step_runner,
+    )
 }
 
 /// Receives worker commands and handles them accordingly.
@@ -167,13 +209,16 @@ pub fn spawn_incentivized_packet_cmd_worker<ChainA: ChainHandle, ChainB: ChainHa
 /// Given a `ClearPendingPackets` command, clears pending packets.
 ///
 /// Regardless of the incoming command, this method also refreshes and
-/// and executes any scheduled operational data that is ready.
+/// and executes any scheduled operational data that is ready, unless
+/// the worker is `paused`, in which case the operational data is only
+/// scheduled, to be executed once the worker is resumed.
 fn handle_packet_cmd<ChainA: ChainHandle, ChainB: ChainHandle>(
     link: &mut Link<ChainA, ChainB>,
     should_clear_on_start: &mut bool,
     clear_interval: u64,
     path: &Packet,
     cmd: WorkerCmd,
+    paused: bool,
 ) -> Result<(), TaskError<RunError>> {
     // Handle packet clearing which is triggered from a command
     let (do_clear, maybe_height) = match &cmd {
@@ -204,12 +249,12 @@ fn handle_packet_cmd<ChainA: ChainHandle, ChainB: ChainHandle>(
         if *should_clear_on_start {
             *should_clear_on_start = false;
         }
-        handle_clear_packet(link, clear_interval, path, maybe_height)?;
+        handle_clear_packet(link, clear_interval, path, maybe_height, paused)?;
     }
 
     // Handle command-specific task
     if let WorkerCmd::IbcEvents { batch } = cmd {
-        handle_update_schedule(link, clear_interval, path, batch)
+        handle_update_schedule(link, clear_interval, path, batch, paused)
     } else {
         Ok(())
     }
@@ -224,13 +269,15 @@ fn handle_packet_cmd<ChainA: ChainHandle, ChainB: ChainHandle>(
 /// `IbcEvent` commands.
 ///
 /// Regardless of the incoming command, this method also refreshes and
-/// and executes any scheduled operational data that is ready.
+/// and executes any scheduled operational data that is ready, unless
+/// the worker is `paused`.
 fn handle_incentivized_packet_cmd<ChainA: ChainHandle, ChainB: ChainHandle>(
     link: &mut Link<ChainA, ChainB>,
     path: &Packet,
     cmd: WorkerCmd,
     incentivized_recv_cache: &RwArc<Cache<Sequence, IncentivizedPacket>>,
     fee_filter: &FeePolicy,
+    paused: bool,
 ) -> Result<(), TaskError<RunError>> {
     // Handle command-specific task
     if let WorkerCmd::IbcEvents { mut batch } = cmd {
@@ -251,7 +298,7 @@ fn handle_incentivized_packet_cmd<ChainA: ChainHandle, ChainB: ChainHandle>(
             //IbcEvent::WriteAcknowledgement(ack) => get_incentivized_for_write_acknowledgement(link, ack, event.height.revision_height(), incentivized_ack_cache.clone()),
         }
         filter_batch(batch.borrow_mut(), incentivized_recv_cache, fee_filter);
-        handle_update_schedule(link, 0, path, batch)
+        handle_update_schedule(link, 0, path, batch, paused)
     } else {
         Ok(())
     }
@@ -354,11 +401,16 @@ fn handle_update_schedule<ChainA: ChainHandle, ChainB: ChainHandle>(
     clear_interval: u64,
     path: &Packet,
     batch: EventBatch,
+    paused: bool,
 ) -> Result<(), TaskError<RunError>> {
     link.a_to_b
         .update_schedule(batch)
         .map_err(handle_link_error_in_task)?;
 
+    if paused {
+        return Ok(());
+    }
+
     handle_execute_schedule(link, path, Resubmit::from_clear_interval(clear_interval))
 }
 
@@ -367,11 +419,16 @@ fn handle_clear_packet<ChainA: ChainHandle, ChainB: ChainHandle>(
     clear_interval: u64,
     path: &Packet,
     height: Option<Height>,
+    paused: bool,
 ) -> Result<(), TaskError<RunError>> {
     link.a_to_b
         .schedule_packet_clearing(height)
         .map_err(handle_link_error_in_task)?;
 
+    if paused {
+        return Ok(());
+    }
+
     handle_execute_schedule(link, path, Resubmit::from_clear_interval(clear_interval))
 }
 
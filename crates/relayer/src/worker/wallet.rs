@@ -4,48 +4,80 @@ use tracing::{error_span, trace, warn};
 
 use crate::{
     chain::handle::ChainHandle,
+    object::ObjectType,
     telemetry,
-    util::task::{spawn_background_task, Next, TaskError, TaskHandle},
+    util::task::{Next, TaskError, TaskHandle},
+    worker::{error::spawn_worker_task, RunError},
 };
 
 pub fn spawn_wallet_worker<Chain: ChainHandle>(chain: Chain) -> TaskHandle {
     let span = error_span!("wallet", chain = %chain.id());
 
-    spawn_background_task(span, Some(Duration::from_secs(5)), move || {
-        let key = chain.get_key().map_err(|e| {
-            TaskError::Fatal(format!("failed to get key in use by the relayer: {e}"))
-        })?;
-
-        let balance = chain.query_balance(None, None).map_err(|e| {
-            TaskError::Ignore(format!("failed to query balance for the account: {e}"))
-        })?;
-
-        match balance.amount.parse::<f64>() {
-            Ok(amount) => {
-                telemetry!(
-                    wallet_balance,
-                    &chain.id(),
-                    &key.account(),
-                    amount,
-                    &balance.denom,
-                );
-                trace!(%amount, denom = %balance.denom, account = %key.account(), "wallet balance");
-                telemetry!(
-                    update_period_fees,
-                    &chain.id(),
-                    &key.account(),
-                    &balance.denom
-                );
-            }
-            Err(e) => {
-                warn!(
-                    %balance.amount, denom = %balance.denom, account = %key.account(),
-                    "unable to parse the wallet balance into a f64, the balance will therefore not be reported to telemetry. Reason: {}", e
-                );
-            }
+    let chain_id = chain.id();
+
+    // The balances of the additional signing keys are reported as well,
+    // as any of them may run out of funds
+    let additional_key_names = chain
+        .config()
+        .map(|config| config.additional_key_names)
+        .unwrap_or_default();
+
+    let step_runner = move || {
+        let key = chain
+            .get_key()
+            .map_err(|e| TaskError::Fatal(RunError::key(e)))?;
+
+        report_balance(&chain, None, &key.account())?;
+
+        for key_name in &additional_key_names {
+            let key = chain
+                .get_key_by_name(key_name.clone())
+                .map_err(|e| TaskError::Ignore(RunError::key(e)))?;
+
+            report_balance(&chain, Some(key_name), &key.account())?;
         }
+
         Ok(Next::Continue)
-    })
+    };
+
+    spawn_worker_task(
+        chain_id,
+        ObjectType::Wallet,
+        span,
+        Some(Duration::from_secs(5)),
+        step_runner,
+    )
+}
+
+fn report_balance<Chain: ChainHandle>(
+    chain: &Chain,
+    key_name: Option<&str>,
+    account: &str,
+) -> Result<(), TaskError<RunError>> {
+    let balance = chain
+        .query_balance(key_name.map(ToString::to_string), None)
+        .map_err(|e| TaskError::Ignore(RunError::balance(e)))?;
+
+    match balance.amount.parse::<f64>() {
+        Ok(amount) => {
+            telemetry!(wallet_balance, &chain.id(), account, amount, &balance.denom);
+            trace!(%amount, denom = %balance.denom, %account, "wallet balance");
+            telemetry!(
+                update_period_fees,
+                &chain.id(),
+                &account.to_string(),
+                &balance.denom
+            );
+        }
+        Err(e) => {
+            warn!(
+                %balance.amount, denom = %balance.denom, %account,
+                "unable to parse the wallet balance into a f64, the balance will therefore not be reported to telemetry. Reason: {}", e
+            );
+        }
+    }
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -7,19 +7,34 @@ use std::error::Error;
 use std::net::{SocketAddr, ToSocketAddrs};
 use std::sync::Arc;
 
-use once_cell::sync::Lazy;
+use once_cell::sync::OnceCell;
 use tokio::task::JoinHandle;
 
-pub use crate::state::TelemetryState;
+pub use crate::state::{HistogramBuckets, TelemetryState};
 
 pub fn new_state() -> Arc<TelemetryState> {
     Arc::new(TelemetryState::default())
 }
 
-static GLOBAL_STATE: Lazy<Arc<TelemetryState>> = Lazy::new(new_state);
+static GLOBAL_STATE: OnceCell<Arc<TelemetryState>> = OnceCell::new();
+
+/// Initializes the global telemetry state with the given histogram buckets.
+///
+/// Returns `None` if the global state was already initialized,
+/// in which case it keeps its buckets.
+pub fn init(buckets: HistogramBuckets) -> Option<&'static Arc<TelemetryState>> {
+    let mut initialized = false;
+
+    let state = GLOBAL_STATE.get_or_init(|| {
+        initialized = true;
+        Arc::new(TelemetryState::new(buckets))
+    });
+
+    initialized.then_some(state)
+}
 
 pub fn global() -> &'static Arc<TelemetryState> {
-    &GLOBAL_STATE
+    GLOBAL_STATE.get_or_init(new_state)
 }
 
 pub type BoxError = Box<dyn Error + Send + Sync>;
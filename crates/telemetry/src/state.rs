@@ -7,7 +7,7 @@ use std::{
 use dashmap::{DashMap, DashSet};
 use opentelemetry::{
     global,
-    metrics::{Counter, ObservableGauge, UpDownCounter},
+    metrics::{Counter, Histogram, ObservableGauge, UpDownCounter},
     Context, KeyValue,
 };
 use opentelemetry_prometheus::PrometheusExporter;
@@ -17,6 +17,7 @@ use ibc_relayer_types::{
     applications::transfer::Coin,
     core::ics24_host::identifier::{ChainId, ChannelId, ClientId, PortId},
     signer::Signer,
+    timestamp::Timestamp,
 };
 
 use tendermint::Time;
@@ -26,6 +27,41 @@ use crate::path_identifier::PathIdentifier;
 const EMPTY_BACKLOG_SYMBOL: u64 = 0;
 const BACKLOG_CAPACITY: usize = 1000;
 const BACKLOG_RESET_THRESHOLD: usize = 900;
+const PACKET_TIMESTAMPS_CAPACITY: u64 = 100_000;
+
+/// Upper bounds of the buckets of the histograms recorded by the telemetry, in milliseconds.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HistogramBuckets {
+    /// Buckets of the `packet_recv_latency` histogram
+    pub packet_recv_latency: Vec<u64>,
+
+    /// Buckets of the `packet_ack_latency` histogram
+    pub packet_ack_latency: Vec<u64>,
+}
+
+/// Default upper bounds of the buckets of the packet latency histograms, in milliseconds.
+const PACKET_LATENCY_BUCKETS: [u64; 8] = [1000, 5000, 10000, 20000, 30000, 60000, 120000, 300000];
+
+impl Default for HistogramBuckets {
+    fn default() -> Self {
+        Self {
+            packet_recv_latency: PACKET_LATENCY_BUCKETS.to_vec(),
+            packet_ack_latency: PACKET_LATENCY_BUCKETS.to_vec(),
+        }
+    }
+}
+
+/// Gas and fees of a transaction submitted by Hermes, retained until both
+/// the chain or path it was submitted for and the gas it used are known.
+#[derive(Clone, Debug, Default)]
+struct TxCost {
+    gas_wanted: u64,
+    gas_used: Option<u64>,
+    fees: Vec<(String, u64)>,
+    /// Labels of the chain or path the transaction was submitted for,
+    /// once its gas wanted and fees have been recorded.
+    labels: Option<Vec<KeyValue>>,
+}
 
 const QUERY_TYPES_CACHE: [&str; 4] = [
     "query_latest_height",
@@ -97,12 +133,19 @@ pub struct TelemetryState {
     /// Number of workers per type
     workers: UpDownCounter<i64>,
 
+    /// Number of errors returned by the tasks of the workers,
+    /// per chain, type of worker and kind of error
+    worker_errors: Counter<u64>,
+
     /// Number of client update messages submitted per client
     client_updates_submitted: Counter<u64>,
 
     /// Number of misbehaviours detected and submitted per client
     client_misbehaviours_submitted: Counter<u64>,
 
+    /// Time left until each client expires, in seconds, as of its latest refresh check
+    client_time_until_expiry: ObservableGauge<u64>,
+
     /// Number of confirmed receive packets per channel
     receive_packets_confirmed: Counter<u64>,
 
@@ -144,6 +187,37 @@ pub struct TelemetryState {
     /// Used for computing the `tx_latency` metric.
     in_flight_events: moka::sync::Cache<String, Instant>,
 
+    /// Amount of gas requested by the transactions Hermes submitted
+    tx_gas_wanted: Counter<u64>,
+
+    /// Amount of gas consumed by the transactions Hermes submitted
+    tx_gas_used: Counter<u64>,
+
+    /// Amount of fees paid for the transactions Hermes submitted, per denom.
+    /// The fees are counted once a transaction is accepted in the mempool, so this is
+    /// an upper bound, which includes the fees of transactions evicted from the mempool.
+    tx_fees_paid: Counter<u64>,
+
+    /// Time between the block of the SendPacket event of a packet on the source chain
+    /// and the block of the WriteAcknowledgement event emitted when the packet was
+    /// received on the destination chain, per path. Milliseconds.
+    packet_recv_latency: Histogram<u64>,
+
+    /// Time between the block of the WriteAcknowledgement event of a packet on the
+    /// destination chain and the block of the AcknowledgePacket event emitted when
+    /// the acknowledgement was relayed to the source chain, per path. Milliseconds.
+    packet_ack_latency: Histogram<u64>,
+
+    /// Records the block time of the latest event of each packet, by packet source
+    /// path and sequence, until the next event of the packet is observed.
+    /// Used for computing the `packet_recv_latency` and `packet_ack_latency` metrics.
+    packet_timestamps: moka::sync::Cache<(PathIdentifier, u64), Timestamp>,
+
+    /// Records the gas and fees of the transactions submitted by Hermes, by transaction hash,
+    /// until their cost is attributed to a chain or path and the gas they used is observed.
+    /// Used for computing the `tx_gas_wanted`, `tx_gas_used` and `tx_fees_paid` metrics.
+    tx_costs: moka::sync::Cache<String, TxCost>,
+
     /// Number of SendPacket events received
     send_packet_events: Counter<u64>,
 
@@ -159,6 +233,22 @@ pub struct TelemetryState {
     /// Number of WriteAcknowledgement events received during the initial and periodic clearing
     cleared_acknowledgment_events: Counter<u64>,
 
+    /// Number of packets skipped during the initial and periodic clearing,
+    /// because their sequence is excluded in the configuration of the chain
+    excluded_packet_sequences: Counter<u64>,
+
+    /// Number of ICS-20 packets which were not relayed, because their memo
+    /// or their receiver exceeds the size limit configured for the chain
+    oversized_ics20_packets: Counter<u64>,
+
+    /// Number of transactions rejected because all their packet messages
+    /// were already relayed, e.g. by another relayer
+    redundant_packet_txs: Counter<u64>,
+
+    /// Number of packet messages executed as no-ops in committed transactions,
+    /// because their packets were already relayed, e.g. by another relayer
+    redundant_packet_msgs: Counter<u64>,
+
     /// Records the sequence number of the oldest pending packet. This corresponds to
     /// the sequence number of the oldest SendPacket event for which no
     /// WriteAcknowledgement or Timeout events have been received. The value is 0 if all the
@@ -261,10 +351,14 @@ impl TelemetryState {
         self.send_packet_events.add(&cx, 0, labels);
         self.acknowledgement_events.add(&cx, 0, labels);
         self.timeout_events.add(&cx, 0, labels);
+        self.redundant_packet_txs.add(&cx, 0, labels);
+        self.redundant_packet_msgs.add(&cx, 0, labels);
 
         if clear_packets {
             self.cleared_send_packet_events.add(&cx, 0, labels);
             self.cleared_acknowledgment_events.add(&cx, 0, labels);
+            self.excluded_packet_sequences.add(&cx, 0, labels);
+            self.oversized_ics20_packets.add(&cx, 0, labels);
         }
 
         self.backlog_oldest_sequence.observe(&cx, 0, labels);
@@ -323,6 +417,19 @@ impl TelemetryState {
         self.workers.add(&cx, count, labels);
     }
 
+    /// Record an error returned by a task of a worker of the given type
+    pub fn worker_errors(&self, chain_id: &ChainId, worker_type: WorkerType, kind: &str) {
+        let cx = Context::current();
+
+        let labels = &[
+            KeyValue::new("chain", chain_id.to_string()),
+            KeyValue::new("type", worker_type.to_string()),
+            KeyValue::new("kind", kind.to_string()),
+        ];
+
+        self.worker_errors.add(&cx, 1, labels);
+    }
+
     /// Update the number of client updates per client
     pub fn client_updates_submitted(
         &self,
@@ -361,6 +468,25 @@ impl TelemetryState {
         self.client_misbehaviours_submitted.add(&cx, count, labels);
     }
 
+    /// Record the time left until a client expires, in seconds
+    pub fn client_time_until_expiry(
+        &self,
+        src_chain: &ChainId,
+        dst_chain: &ChainId,
+        client: &ClientId,
+        seconds: u64,
+    ) {
+        let cx = Context::current();
+
+        let labels = &[
+            KeyValue::new("src_chain", src_chain.to_string()),
+            KeyValue::new("dst_chain", dst_chain.to_string()),
+            KeyValue::new("client", client.to_string()),
+        ];
+
+        self.client_time_until_expiry.observe(&cx, seconds, labels);
+    }
+
     /// Number of receive packets relayed, per channel
     #[allow(clippy::too_many_arguments)]
     pub fn receive_packets_confirmed(
@@ -576,6 +702,116 @@ impl TelemetryState {
         }
     }
 
+    /// Record the gas limit and the fees of a transaction accepted
+    /// in the mempool of a chain, until its cost is attributed.
+    ///
+    /// The fees are counted as paid when the cost is attributed, even
+    /// if the transaction is later evicted from the mempool.
+    pub fn tx_fee(&self, tx_hash: impl ToString, gas_wanted: u64, fees: Vec<(String, u64)>) {
+        self.tx_costs.insert(
+            tx_hash.to_string(),
+            TxCost {
+                gas_wanted,
+                fees,
+                ..TxCost::default()
+            },
+        );
+    }
+
+    /// Record the gas consumed by a transaction, as reported by its `DeliverTx` result.
+    /// The gas is attributed to the chain or path of the transaction, once that is known.
+    pub fn tx_gas_used(&self, tx_hash: impl ToString, gas_used: u64) {
+        let tx_hash = tx_hash.to_string();
+
+        let Some(mut cost) = self.tx_costs.get(&tx_hash) else {
+            return;
+        };
+
+        match &cost.labels {
+            Some(labels) => {
+                self.tx_gas_used.add(&Context::current(), gas_used, labels);
+                self.tx_costs.invalidate(&tx_hash);
+            }
+            None => {
+                cost.gas_used = Some(gas_used);
+                self.tx_costs.insert(tx_hash, cost);
+            }
+        }
+    }
+
+    /// Attribute the gas and fees of the given committed transactions to a chain,
+    /// for transactions which were not submitted on behalf of a specific path.
+    pub fn tx_costs_committed<H: ToString>(
+        &self,
+        tx_hashes: impl IntoIterator<Item = H>,
+        chain_id: &ChainId,
+    ) {
+        let labels = vec![KeyValue::new("chain", chain_id.to_string())];
+
+        self.attribute_tx_costs(tx_hashes, labels);
+    }
+
+    /// Attribute the gas and fees of the given transactions to a path, as soon as they are
+    /// submitted. Their gas used is attributed once their result is observed.
+    pub fn tx_costs_submitted<H: ToString>(
+        &self,
+        tx_hashes: impl IntoIterator<Item = H>,
+        chain_id: &ChainId,
+        channel_id: &ChannelId,
+        port_id: &PortId,
+        counterparty_chain_id: &ChainId,
+    ) {
+        let labels = vec![
+            KeyValue::new("chain", chain_id.to_string()),
+            KeyValue::new("counterparty", counterparty_chain_id.to_string()),
+            KeyValue::new("channel", channel_id.to_string()),
+            KeyValue::new("port", port_id.to_string()),
+        ];
+
+        self.attribute_tx_costs(tx_hashes, labels);
+    }
+
+    fn attribute_tx_costs<H: ToString>(
+        &self,
+        tx_hashes: impl IntoIterator<Item = H>,
+        labels: Vec<KeyValue>,
+    ) {
+        let cx = Context::current();
+
+        for tx_hash in tx_hashes {
+            let tx_hash = tx_hash.to_string();
+
+            // Each transaction is accounted for only once
+            let Some(mut cost) = self.tx_costs.get(&tx_hash) else {
+                continue;
+            };
+
+            if cost.labels.is_some() {
+                continue;
+            }
+
+            self.tx_gas_wanted.add(&cx, cost.gas_wanted, &labels);
+
+            for (denom, amount) in cost.fees.drain(..) {
+                let mut fee_labels = labels.clone();
+                fee_labels.push(KeyValue::new("denom", denom));
+
+                self.tx_fees_paid.add(&cx, amount, &fee_labels);
+            }
+
+            match cost.gas_used {
+                Some(gas_used) => {
+                    self.tx_gas_used.add(&cx, gas_used, &labels);
+                    self.tx_costs.invalidate(&tx_hash);
+                }
+                None => {
+                    cost.labels = Some(labels.clone());
+                    self.tx_costs.insert(tx_hash, cost);
+                }
+            }
+        }
+    }
+
     pub fn send_packet_events(
         &self,
         _seq_nr: u64,
@@ -679,6 +915,216 @@ impl TelemetryState {
         self.cleared_acknowledgment_events.add(&cx, 1, labels);
     }
 
+    /// Records the number of packets skipped while clearing packets, because
+    /// their sequence is excluded in the configuration of the chain.
+    pub fn excluded_packet_sequences(
+        &self,
+        count: u64,
+        chain_id: &ChainId,
+        channel_id: &ChannelId,
+        port_id: &PortId,
+        counterparty_chain_id: &ChainId,
+    ) {
+        let cx = Context::current();
+
+        let labels: &[KeyValue; 4] = &[
+            KeyValue::new("chain", chain_id.to_string()),
+            KeyValue::new("counterparty", counterparty_chain_id.to_string()),
+            KeyValue::new("channel", channel_id.to_string()),
+            KeyValue::new("port", port_id.to_string()),
+        ];
+
+        self.excluded_packet_sequences.add(&cx, count, labels);
+    }
+
+    /// Records an ICS-20 packet which was not relayed, because its memo or
+    /// its receiver exceeds the size limit configured for the chain.
+    pub fn oversized_ics20_packets(
+        &self,
+        chain_id: &ChainId,
+        channel_id: &ChannelId,
+        port_id: &PortId,
+        counterparty_chain_id: &ChainId,
+    ) {
+        let cx = Context::current();
+
+        let labels: &[KeyValue; 4] = &[
+            KeyValue::new("chain", chain_id.to_string()),
+            KeyValue::new("counterparty", counterparty_chain_id.to_string()),
+            KeyValue::new("channel", channel_id.to_string()),
+            KeyValue::new("port", port_id.to_string()),
+        ];
+
+        self.oversized_ics20_packets.add(&cx, 1, labels);
+    }
+
+    /// Records transactions which were rejected because all their
+    /// packet messages were already relayed, e.g. by another relayer.
+    pub fn redundant_packet_txs(
+        &self,
+        count: u64,
+        chain_id: &ChainId,
+        channel_id: &ChannelId,
+        port_id: &PortId,
+        counterparty_chain_id: &ChainId,
+    ) {
+        let cx = Context::current();
+
+        let labels: &[KeyValue; 4] = &[
+            KeyValue::new("chain", chain_id.to_string()),
+            KeyValue::new("counterparty", counterparty_chain_id.to_string()),
+            KeyValue::new("channel", channel_id.to_string()),
+            KeyValue::new("port", port_id.to_string()),
+        ];
+
+        self.redundant_packet_txs.add(&cx, count, labels);
+    }
+
+    /// Records packet messages which were executed as no-ops, because
+    /// their packets were already relayed, e.g. by another relayer.
+    pub fn redundant_packet_msgs(
+        &self,
+        count: u64,
+        chain_id: &ChainId,
+        channel_id: &ChannelId,
+        port_id: &PortId,
+        counterparty_chain_id: &ChainId,
+    ) {
+        let cx = Context::current();
+
+        let labels: &[KeyValue; 4] = &[
+            KeyValue::new("chain", chain_id.to_string()),
+            KeyValue::new("counterparty", counterparty_chain_id.to_string()),
+            KeyValue::new("channel", channel_id.to_string()),
+            KeyValue::new("port", port_id.to_string()),
+        ];
+
+        self.redundant_packet_msgs.add(&cx, count, labels);
+    }
+
+    /// Records the block time of the SendPacket event of a packet, from which
+    /// the `packet_recv_latency` is computed once the packet is received.
+    pub fn packet_sent(
+        &self,
+        seq_nr: u64,
+        block_time: Timestamp,
+        chain_id: &ChainId,
+        channel_id: &ChannelId,
+        port_id: &PortId,
+    ) {
+        let path_uid = PathIdentifier::new(
+            chain_id.to_string(),
+            channel_id.to_string(),
+            port_id.to_string(),
+        );
+
+        self.packet_timestamps
+            .insert((path_uid, seq_nr), block_time);
+    }
+
+    /// Records the `packet_recv_latency` of a packet sent on the given source chain,
+    /// channel and port, given the block time of the WriteAcknowledgement event
+    /// emitted when the packet was received on the destination chain.
+    ///
+    /// For applications which acknowledge packets asynchronously, this block is
+    /// later than the one in which the packet was received.
+    pub fn packet_received(
+        &self,
+        seq_nr: u64,
+        block_time: Timestamp,
+        src_chain_id: &ChainId,
+        src_channel_id: &ChannelId,
+        src_port_id: &PortId,
+        dst_chain_id: &ChainId,
+    ) {
+        let key = (
+            PathIdentifier::new(
+                src_chain_id.to_string(),
+                src_channel_id.to_string(),
+                src_port_id.to_string(),
+            ),
+            seq_nr,
+        );
+
+        if let Some(sent_time) = self.packet_timestamps.get(&key) {
+            self.record_packet_latency(
+                &self.packet_recv_latency,
+                sent_time,
+                block_time,
+                src_chain_id,
+                src_channel_id,
+                src_port_id,
+                dst_chain_id,
+            );
+        }
+
+        self.packet_timestamps.insert(key, block_time);
+    }
+
+    /// Records the `packet_ack_latency` of a packet sent on the given source chain,
+    /// channel and port, given the block time of the AcknowledgePacket event emitted
+    /// when its acknowledgement was relayed to the source chain.
+    pub fn packet_acknowledged(
+        &self,
+        seq_nr: u64,
+        block_time: Timestamp,
+        src_chain_id: &ChainId,
+        src_channel_id: &ChannelId,
+        src_port_id: &PortId,
+        dst_chain_id: &ChainId,
+    ) {
+        let key = (
+            PathIdentifier::new(
+                src_chain_id.to_string(),
+                src_channel_id.to_string(),
+                src_port_id.to_string(),
+            ),
+            seq_nr,
+        );
+
+        if let Some(received_time) = self.packet_timestamps.get(&key) {
+            self.record_packet_latency(
+                &self.packet_ack_latency,
+                received_time,
+                block_time,
+                src_chain_id,
+                src_channel_id,
+                src_port_id,
+                dst_chain_id,
+            );
+        }
+
+        self.packet_timestamps.invalidate(&key);
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn record_packet_latency(
+        &self,
+        histogram: &Histogram<u64>,
+        start: Timestamp,
+        end: Timestamp,
+        src_chain_id: &ChainId,
+        src_channel_id: &ChannelId,
+        src_port_id: &PortId,
+        dst_chain_id: &ChainId,
+    ) {
+        let cx = Context::current();
+
+        let latency = match end.duration_since(&start) {
+            Some(latency) => latency.as_millis() as u64,
+            None => return,
+        };
+
+        let labels = &[
+            KeyValue::new("chain", src_chain_id.to_string()),
+            KeyValue::new("counterparty", dst_chain_id.to_string()),
+            KeyValue::new("channel", src_channel_id.to_string()),
+            KeyValue::new("port", src_port_id.to_string()),
+        ];
+
+        histogram.record(&cx, latency, labels);
+    }
+
     /// Inserts in the backlog a new event for the given sequence number.
     /// This happens when the relayer observed a new SendPacket event.
     pub fn backlog_insert(
@@ -881,7 +1327,9 @@ use opentelemetry::sdk::metrics::aggregators::{histogram, last_value, sum};
 use opentelemetry::sdk::metrics::sdk_api::Descriptor;
 
 #[derive(Debug)]
-struct CustomAggregatorSelector;
+struct CustomAggregatorSelector {
+    buckets: HistogramBuckets,
+}
 
 impl AggregatorSelector for CustomAggregatorSelector {
     fn aggregator_for(&self, descriptor: &Descriptor) -> Option<Arc<dyn Aggregator + Send + Sync>> {
@@ -890,6 +1338,7 @@ impl AggregatorSelector for CustomAggregatorSelector {
             "backlog_oldest_sequence" => Some(Arc::new(last_value())),
             "backlog_oldest_timestamp" => Some(Arc::new(last_value())),
             "backlog_size" => Some(Arc::new(last_value())),
+            "client_time_until_expiry" => Some(Arc::new(last_value())),
             // Prometheus' supports only collector for histogram, sum, and last value aggregators.
             // https://docs.rs/opentelemetry-prometheus/0.10.0/src/opentelemetry_prometheus/lib.rs.html#411-418
             // TODO: Once quantile sketches are supported, replace histograms with that.
@@ -899,19 +1348,36 @@ impl AggregatorSelector for CustomAggregatorSelector {
             "tx_latency_confirmed" => Some(Arc::new(histogram(&[
                 1000.0, 5000.0, 9000.0, 13000.0, 17000.0, 20000.0,
             ]))),
+            "packet_recv_latency" => Some(Arc::new(histogram(&bounds(
+                &self.buckets.packet_recv_latency,
+            )))),
+            "packet_ack_latency" => Some(Arc::new(histogram(&bounds(
+                &self.buckets.packet_ack_latency,
+            )))),
             "ics29_period_fees" => Some(Arc::new(last_value())),
             _ => Some(Arc::new(sum())),
         }
     }
 }
 
+fn bounds(buckets: &[u64]) -> Vec<f64> {
+    buckets.iter().map(|&bound| bound as f64).collect()
+}
+
 impl Default for TelemetryState {
     fn default() -> Self {
+        Self::new(HistogramBuckets::default())
+    }
+}
+
+impl TelemetryState {
+    /// Creates the telemetry state, with the given buckets for its histograms.
+    pub fn new(buckets: HistogramBuckets) -> Self {
         use opentelemetry::sdk::export::metrics::aggregation;
         use opentelemetry::sdk::metrics::{controllers, processors};
 
         let controller = controllers::basic(processors::factory(
-            CustomAggregatorSelector,
+            CustomAggregatorSelector { buckets },
             aggregation::cumulative_temporality_selector(),
         ))
         .build();
@@ -928,6 +1394,11 @@ impl Default for TelemetryState {
                 .with_description("Number of workers")
                 .init(),
 
+            worker_errors: meter
+                .u64_counter("worker_errors")
+                .with_description("Number of errors returned by the tasks of the workers")
+                .init(),
+
             client_updates_submitted: meter
                 .u64_counter("client_updates_submitted")
                 .with_description("Number of client update messages submitted")
@@ -938,6 +1409,12 @@ impl Default for TelemetryState {
                 .with_description("Number of misbehaviours detected and submitted")
                 .init(),
 
+            client_time_until_expiry: meter
+                .u64_observable_gauge("client_time_until_expiry")
+                .with_unit(Unit::new("s"))
+                .with_description("Time left until each client expires, as of its latest refresh check")
+                .init(),
+
             receive_packets_confirmed: meter
                 .u64_counter("receive_packets_confirmed")
                 .with_description("Number of confirmed receive packets. Available if relayer runs with Tx confirmation enabled")
@@ -1010,6 +1487,26 @@ impl Default for TelemetryState {
                 .with_description("Number of WriteAcknowledgement events received during the initial and periodic clearing")
                 .init(),
 
+            excluded_packet_sequences: meter
+                .u64_counter("excluded_packet_sequences")
+                .with_description("Number of packets skipped during the initial and periodic clearing because their sequence is excluded")
+                .init(),
+
+            oversized_ics20_packets: meter
+                .u64_counter("oversized_ics20_packets")
+                .with_description("Number of ICS-20 packets not relayed because their memo or receiver exceeds the configured size limit")
+                .init(),
+
+            redundant_packet_txs: meter
+                .u64_counter("redundant_packet_txs")
+                .with_description("Number of transactions rejected because all their packet messages were already relayed")
+                .init(),
+
+            redundant_packet_msgs: meter
+                .u64_counter("redundant_packet_msgs")
+                .with_description("Number of packet messages executed as no-ops because their packets were already relayed")
+                .init(),
+
             tx_latency_submitted: meter
                 .u64_observable_gauge("tx_latency_submitted")
                 .with_unit(Unit::new("milliseconds"))
@@ -1031,6 +1528,44 @@ impl Default for TelemetryState {
                 .time_to_idle(Duration::from_secs(30 * 60)) // Remove entries if they have been idle for 30 minutes
                 .build(),
 
+            packet_recv_latency: meter
+                .u64_histogram("packet_recv_latency")
+                .with_unit(Unit::new("milliseconds"))
+                .with_description("Time between the block of the SendPacket event of a packet on the source chain \
+                    and the block of the WriteAcknowledgement event emitted when the packet was received on the destination chain. Milliseconds.")
+                .init(),
+
+            packet_ack_latency: meter
+                .u64_histogram("packet_ack_latency")
+                .with_unit(Unit::new("milliseconds"))
+                .with_description("Time between the block in which a packet was received on the destination chain \
+                    and the block in which its acknowledgement was relayed to the source chain. Milliseconds.")
+                .init(),
+
+            packet_timestamps: moka::sync::Cache::builder()
+                .max_capacity(PACKET_TIMESTAMPS_CAPACITY)
+                .time_to_live(Duration::from_secs(24 * 60 * 60)) // Remove entries after 1 day
+                .build(),
+
+            tx_gas_wanted: meter
+                .u64_counter("tx_gas_wanted")
+                .with_description("Amount of gas requested by the transactions Hermes submitted")
+                .init(),
+
+            tx_gas_used: meter
+                .u64_counter("tx_gas_used")
+                .with_description("Amount of gas consumed by the transactions Hermes submitted")
+                .init(),
+
+            tx_fees_paid: meter
+                .u64_counter("tx_fees_paid")
+                .with_description("Amount of fees paid for the transactions Hermes submitted, including the transactions evicted from the mempool")
+                .init(),
+
+            tx_costs: moka::sync::Cache::builder()
+                .time_to_live(Duration::from_secs(60 * 60)) // Remove entries after 1 hour
+                .build(),
+
             backlogs: DashMap::new(),
 
             backlog_oldest_sequence: meter
@@ -1065,3 +1600,129 @@ impl Default for TelemetryState {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(seconds: u64) -> Timestamp {
+        Timestamp::from_nanoseconds(seconds * 1_000_000_000).unwrap()
+    }
+
+    /// Returns the number of samples and their sum in the given histogram
+    fn histogram(state: &TelemetryState, name: &str) -> (u64, f64) {
+        state
+            .gather()
+            .iter()
+            .find(|family| family.get_name() == name)
+            .map(|family| {
+                let histogram = family.get_metric()[0].get_histogram();
+                (histogram.get_sample_count(), histogram.get_sample_sum())
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the sum of the values of the given counter for the given chain
+    fn counter(state: &TelemetryState, name: &str, chain: &str) -> f64 {
+        state
+            .gather()
+            .iter()
+            .filter(|family| family.get_name() == name)
+            .flat_map(|family| family.get_metric())
+            .filter(|metric| {
+                metric
+                    .get_label()
+                    .iter()
+                    .any(|label| label.get_name() == "chain" && label.get_value() == chain)
+            })
+            .map(|metric| metric.get_counter().get_value())
+            .sum()
+    }
+
+    /// Returns the gas wanted, the gas used and the fees paid by the given chain
+    fn tx_costs(state: &TelemetryState, chain: &str) -> (f64, f64, f64) {
+        (
+            counter(state, "tx_gas_wanted_total", chain),
+            counter(state, "tx_gas_used_total", chain),
+            counter(state, "tx_fees_paid_total", chain),
+        )
+    }
+
+    /// Creates a telemetry state, one at a time, since the exporter of
+    /// each state is installed as the global meter provider
+    fn new_state() -> TelemetryState {
+        static LOCK: Mutex<()> = Mutex::new(());
+
+        let _guard = LOCK.lock().unwrap();
+
+        TelemetryState::new(HistogramBuckets::default())
+    }
+
+    #[test]
+    fn tx_costs_counted_once() {
+        let state = new_state();
+
+        let fees = || vec![("stake".to_string(), 50)];
+
+        // The metrics are only collected once per collect period,
+        // so they are checked once all the transactions are recorded
+
+        // Gas used observed twice after the cost is attributed to a path
+        state.tx_fee("tx-0", 100, fees());
+        state.tx_costs_submitted(
+            ["tx-0"],
+            &ChainId::from_string("chain-0"),
+            &ChannelId::new(0),
+            &PortId::transfer(),
+            &ChainId::from_string("chain-1"),
+        );
+        state.tx_gas_used("tx-0", 80);
+        state.tx_gas_used("tx-0", 80);
+
+        // Gas used observed before the cost is attributed twice to a chain
+        state.tx_fee("tx-1", 100, fees());
+        state.tx_gas_used("tx-1", 80);
+        state.tx_costs_committed(["tx-1"], &ChainId::from_string("chain-2"));
+        state.tx_costs_committed(["tx-1"], &ChainId::from_string("chain-2"));
+
+        // Cost attributed twice before the gas used is observed
+        state.tx_fee("tx-2", 100, fees());
+        state.tx_costs_committed(["tx-2"], &ChainId::from_string("chain-3"));
+        state.tx_costs_committed(["tx-2"], &ChainId::from_string("chain-3"));
+        state.tx_gas_used("tx-2", 80);
+
+        // Gas used of a transaction whose cost is never attributed
+        state.tx_fee("tx-3", 100, fees());
+        state.tx_gas_used("tx-3", 80);
+
+        assert_eq!(tx_costs(&state, "chain-0"), (100.0, 80.0, 50.0));
+        assert_eq!(tx_costs(&state, "chain-2"), (100.0, 80.0, 50.0));
+        assert_eq!(tx_costs(&state, "chain-3"), (100.0, 80.0, 50.0));
+    }
+
+    #[test]
+    fn packet_latencies() {
+        let state = new_state();
+
+        let src_chain = ChainId::from_string("src-0");
+        let dst_chain = ChainId::from_string("dst-0");
+        let channel = ChannelId::new(0);
+        let port = PortId::transfer();
+
+        // The metrics are only collected once per collect period,
+        // so they are checked once all the packets are recorded
+
+        state.packet_sent(1, at(10), &src_chain, &channel, &port);
+        state.packet_received(1, at(12), &src_chain, &channel, &port, &dst_chain);
+        state.packet_acknowledged(1, at(15), &src_chain, &channel, &port, &dst_chain);
+
+        // The timestamps of the packet are evicted once it is acknowledged
+        state.packet_acknowledged(1, at(20), &src_chain, &channel, &port, &dst_chain);
+
+        // The latency of a packet whose SendPacket event was not observed is not recorded
+        state.packet_received(2, at(20), &src_chain, &channel, &port, &dst_chain);
+
+        assert_eq!(histogram(&state, "packet_recv_latency"), (1, 2000.0));
+        assert_eq!(histogram(&state, "packet_ack_latency"), (1, 3000.0));
+    }
+}
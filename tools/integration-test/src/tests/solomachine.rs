@@ -0,0 +1,191 @@
+//! Tests relaying packets between a Cosmos chain and the in-process solo machine
+//! of Hermes.
+//!
+//! Every proof signed by the solo machine consumes the sequence of its client on
+//! the Cosmos chain. The test signs a proof which is never submitted, and asserts
+//! that the acknowledgement signed after it is still relayed, by updating the
+//! client of the solo machine up to the sequence of the acknowledgement proof.
+
+use ibc_relayer::chain::requests::QueryPacketCommitmentsRequest;
+use ibc_relayer::chain::ChainType;
+use ibc_relayer::channel::Channel;
+use ibc_relayer::connection::Connection;
+use ibc_relayer::keyring::{KeyRing, Store};
+use ibc_relayer::link::{Link, LinkParameters};
+use ibc_relayer_types::core::ics04_channel::packet::{PacketMsgType, Sequence};
+use ibc_relayer_types::core::ics04_channel::version::Version;
+use ibc_relayer_types::Height;
+
+use ibc_test_framework::bootstrap::binary::chain::bootstrap_foreign_client;
+use ibc_test_framework::prelude::*;
+use ibc_test_framework::types::tagged::{DualTagged, MonoTagged};
+use ibc_test_framework::types::wallet::WalletAddress;
+use ibc_test_framework::util::random::random_u128_range;
+
+const SOLO_MACHINE_ID: &str = "solo-machine";
+
+#[test]
+fn test_solo_machine_skipped_proof() -> Result<(), Error> {
+    run_binary_chain_test(&SoloMachineTest)
+}
+
+pub struct SoloMachineTest;
+
+impl TestOverrides for SoloMachineTest {
+    fn modify_relayer_config(&self, config: &mut Config) {
+        let mut chain_config = config.chains[0].clone();
+
+        chain_config.id = ChainId::from_string(SOLO_MACHINE_ID);
+        chain_config.r#type = ChainType::SoloMachine;
+
+        config.chains.push(chain_config);
+    }
+
+    fn should_spawn_supervisor(&self) -> bool {
+        false
+    }
+}
+
+impl BinaryChainTest for SoloMachineTest {
+    fn run<ChainA: ChainHandle, ChainB: ChainHandle>(
+        &self,
+        _config: &TestConfig,
+        relayer: RelayerDriver,
+        chains: ConnectedChains<ChainA, ChainB>,
+    ) -> Result<(), Error> {
+        let solo_machine_id = ChainId::from_string(SOLO_MACHINE_ID);
+
+        let solo_machine_config = relayer
+            .config
+            .find_chain(&solo_machine_id)
+            .cloned()
+            .ok_or_else(|| eyre!("missing solo machine config"))?;
+
+        // The solo machine signs with the key of the relayer on chain A
+        let mut keyring = KeyRing::new_secp256k1(
+            Store::Test,
+            &solo_machine_config.account_prefix,
+            &solo_machine_id,
+            &solo_machine_config.key_store_folder,
+        )
+        .map_err(handle_generic_error)?;
+
+        keyring
+            .add_key(
+                &solo_machine_config.key_name,
+                chains.node_a.wallets().relayer().value().key.clone(),
+            )
+            .map_err(handle_generic_error)?;
+
+        let chain_a = chains.handle_a().clone();
+        let solo_machine = relayer
+            .registry
+            .get_or_spawn(&solo_machine_id)
+            .map_err(handle_generic_error)?;
+
+        let client_on_solo_machine =
+            bootstrap_foreign_client(&chain_a, &solo_machine, Default::default())?;
+        let client_on_chain_a =
+            bootstrap_foreign_client(&solo_machine, &chain_a, Default::default())?;
+
+        let connection = Connection::new(
+            client_on_chain_a,
+            client_on_solo_machine,
+            Duration::from_secs(0),
+        )?;
+
+        let port_id = PortId::transfer();
+
+        let channel = Channel::new(
+            connection,
+            Ordering::Unordered,
+            port_id.clone(),
+            port_id.clone(),
+            Some(Version::ics20()),
+        )?;
+
+        let channel_id_a = channel
+            .a_side
+            .channel_id()
+            .ok_or_else(|| eyre!("expect channel id"))?
+            .clone();
+
+        let channel_id_solo_machine = channel
+            .b_side
+            .channel_id()
+            .ok_or_else(|| eyre!("expect channel id"))?
+            .clone();
+
+        let amount = random_u128_range(1000, 5000);
+
+        info!("sending a token transfer from chain A to the solo machine");
+
+        chains.node_a.chain_driver().ibc_transfer_token(
+            &DualTagged::<ChainA, ChainB, _>::new(&port_id),
+            &DualTagged::new(&channel_id_a),
+            &chains.node_a.wallets().user1(),
+            &MonoTagged::new(&WalletAddress(SOLO_MACHINE_ID.to_string())),
+            &chains.node_a.denom().with_amount(amount).as_ref(),
+        )?;
+
+        let link_to_solo_machine = Link::new_from_opts(
+            chain_a.clone(),
+            solo_machine.clone(),
+            LinkParameters {
+                src_port_id: port_id.clone(),
+                src_channel_id: channel_id_a.clone(),
+            },
+            true,
+            false,
+        )?;
+
+        link_to_solo_machine.relay_recv_packet_and_timeout_messages()?;
+
+        info!("signing an acknowledgement proof which is never submitted");
+
+        solo_machine.build_packet_proofs(
+            PacketMsgType::Ack,
+            &port_id,
+            &channel_id_solo_machine,
+            Sequence::from(1),
+            Height::new(0, 1).map_err(handle_generic_error)?,
+        )?;
+
+        let link_to_chain_a = Link::new_from_opts(
+            solo_machine,
+            chain_a.clone(),
+            LinkParameters {
+                src_port_id: port_id.clone(),
+                src_channel_id: channel_id_solo_machine,
+            },
+            true,
+            false,
+        )?;
+
+        link_to_chain_a.relay_ack_packet_messages()?;
+
+        assert_eventually_succeed(
+            "packet acknowledged on chain A",
+            10,
+            Duration::from_secs(1),
+            || {
+                let (sequences, _) =
+                    chain_a.query_packet_commitments(QueryPacketCommitmentsRequest {
+                        port_id: port_id.clone(),
+                        channel_id: channel_id_a.clone(),
+                        pagination: None,
+                    })?;
+
+                if sequences.is_empty() {
+                    Ok(())
+                } else {
+                    Err(Error::generic(eyre!(
+                        "expected no pending packet commitments, found {sequences:?}"
+                    )))
+                }
+            },
+        )?;
+
+        Ok(())
+    }
+}
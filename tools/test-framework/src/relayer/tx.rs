@@ -7,6 +7,7 @@ use ibc_proto::cosmos::tx::v1beta1::Fee;
 use ibc_relayer::chain::cosmos::gas::calculate_fee;
 use ibc_relayer::chain::cosmos::types::config::TxConfig;
 use ibc_relayer::chain::cosmos::types::gas::GasConfig;
+use ibc_relayer::config::dynamic_gas::DynamicGasPrice;
 use ibc_relayer::config::{AddressType, GasPrice};
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 use tendermint_rpc::Url;
@@ -33,6 +34,7 @@ pub fn gas_config_for_test() -> GasConfig {
         max_gas,
         gas_multiplier,
         gas_price,
+        dynamic_gas_price: DynamicGasPrice::disabled(),
         max_fee,
         fee_granter,
     }
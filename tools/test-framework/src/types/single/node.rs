@@ -140,12 +140,17 @@ impl FullNode {
             rpc_addr: Url::from_str(&self.chain_driver.rpc_address())?,
             websocket_addr: WebSocketClientUrl::from_str(&self.chain_driver.websocket_address())?,
             grpc_addr: Url::from_str(&self.chain_driver.grpc_address())?,
+            backup_rpc_addrs: Vec::new(),
+            backup_websocket_addrs: Vec::new(),
+            backup_grpc_addrs: Vec::new(),
             rpc_timeout: ibc_relayer::config::default::rpc_timeout(),
             batch_delay: ibc_relayer::config::default::batch_delay(),
+            event_source: Default::default(),
             trusted_node: false,
             genesis_restart: None,
             account_prefix: self.chain_driver.account_prefix.clone(),
             key_name: self.wallets.relayer.id.0.clone(),
+            additional_key_names: Vec::new(),
             key_store_type: Store::Test,
             key_store_folder: Some(hermes_keystore_dir.into()),
             store_prefix: "ibc".to_string(),
@@ -163,12 +168,18 @@ impl FullNode {
             ccv_consumer_chain: false,
             trust_threshold: Default::default(),
             gas_price: config::GasPrice::new(0.003, "stake".to_string()),
+            dynamic_gas_price: Default::default(),
             packet_filter: Default::default(),
+            excluded_sequences: Default::default(),
+            ics20_max_memo_size: ibc_relayer::config::default::ics20_max_memo_size(),
+            ics20_max_receiver_size: ibc_relayer::config::default::ics20_max_receiver_size(),
+            client_overrides: Default::default(),
             address_type: chain_type.address_type(),
             memo_prefix: Default::default(),
             proof_specs: Default::default(),
             extension_options: Default::default(),
             sequential_batch_tx: false,
+            remote_signer: None,
         })
     }
 